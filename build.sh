@@ -54,6 +54,10 @@ cp "$SRC_BIN" "$OUTPUT_DIR/"
 # Copy resources
 echo "📄 Copying resources..."
 cp -r sounds $OUTPUT_DIR/ 2>/dev/null || echo "⚠️  sounds directory not found"
+# Self-test/benchmark clip; without it the app falls back to the OS text-to-speech
+for f in selftest.wav selftest.txt; do
+  [ -f "sounds/$f" ] || echo "⚠️  sounds/$f missing (see sounds/README.md)"
+done
 # Root-level config.toml / prompt.txt / dictionary.txt are deprecated (not bundled)
 cp docs/manual.html $OUTPUT_DIR/ 2>/dev/null || true
 cp docs/manual.ja.html $OUTPUT_DIR/ 2>/dev/null || true
@@ -10,6 +10,7 @@ section-devices = Input & Output Devices
 btn-stop-level-meter = Stop Level Check
 btn-start-level-meter = Check Input Level
 label-input-level = Input Level:
+tooltip-input-clipping = The input hit -0.1 dBFS in the last second. Lower the input gain to avoid distortion.
 label-input-sensitivity = Input Gain:
 btn-auto-adjust = Auto Adjust
 tooltip-auto-adjust = Adjust current input level to -12 dBFS
@@ -21,10 +22,40 @@ tooltip-test-recording = Start recording using the selected input device
 btn-stop-and-save = Stop & Save
 tooltip-stop-and-save = Stop recording and select save destination
 msg-test-recording-saved = Test recording saved
+label-test-stream-errors = Stream errors:
+label-test-dropouts = Dropouts:
+tooltip-test-dropouts = Stream errors are overruns or device errors reported by the audio driver; dropouts are gaps between captured buffers. Either can make recordings choppy: try another input host, close other audio apps, or use a larger buffer in your sound server
+label-test-latency = Capture latency (avg / max):
+tooltip-test-latency = Time from capture by the device until the audio reached HootVoice, as reported by the driver
+label-test-not-reported = not reported by the driver
+label-test-sample-rate = Measured sample rate:
+label-test-nominal = nominal
+label-test-too-short = record at least 5 seconds to measure
+label-preroll = Pre-roll:
+preroll-note = Keeps the microphone open while idle so the first syllable is not cut off. Idle audio is discarded unless a recording starts.
+msg-input-device-missing = The selected microphone is disconnected. Recording uses the system default for now.
+btn-input-reselect = Use selected microphone again
+label-input-auto-reselect = Switch back when the microphone is reconnected
+tooltip-input-auto-reselect = If the selected microphone is unplugged, recordings use the system default. With this on, the selected microphone is used again as soon as it is plugged back in.
 label-output-device = Output Device (Sound Effects):
 label-play-sounds = Play sounds
 label-volume = Volume
 btn-test-play = Test Play
+label-sound-start = Recording start
+label-sound-complete = Completed
+label-sound-error = Error / no speech
+label-sound-default = Default
+btn-choose-file = Choose…
+btn-sound-use-default = Use default
+tooltip-sound-file-missing = File not found; the default sound is used instead.
+note-event-sounds = Unset or missing files fall back to the bundled sounds.
+chk-readback-tts = Read the transcript aloud
+tooltip-readback-tts = Speaks the final text with the system voice (say on macOS, SAPI on Windows, espeak-ng or spd-say on Linux) after it is pasted. Uses the output device and volume above; a new recording stops it.
+label-complete-sound-timing = Completion sound:
+tooltip-complete-sound-timing = When the completion sound plays relative to auto-paste
+option-sound-after-paste = After pasting
+option-sound-before-paste = Before pasting (wait for it to finish)
+option-sound-silent-auto-paste = Off while auto-pasting
 section-speech-model = Speech Model
 label-preset = Preset:
 btn-change = Change
@@ -34,7 +65,19 @@ label-model-folder = Model Folder:
 btn-open-folder = Open Folder
 label-selection = Selected:
 label-language = Input Language:
+label-source-language = Source Language:
+label-whisper-task = Task:
+option-task-transcribe = Transcribe
+option-task-translate = Translate to English
+tooltip-whisper-task = Transcribe keeps the spoken language. Translate uses Whisper's built-in translation to produce English text from speech in any language.
+hint-whisper-translate = Translation targets English only. The language above is the spoken (source) language; dictionary and LLM steps run on the English result.
 msg-language-accuracy = Specifying the input language can improve recognition accuracy.
+label-auto-detect-fallback = Fallback language:
+option-auto-detect-fallback-none = None (keep detected)
+tooltip-auto-detect-fallback = Language used when auto-detect is unsure, e.g. for very short clips
+label-auto-detect-min-confidence = Min. confidence:
+hint-auto-detect-fallback = Below this detection probability the fallback language is used. The Logs tab shows each chunk's detected language and probability to help tune it.
+hint-auto-detect-fallback-remote = Needs the local model: the remote endpoint picks the language itself and reports no detection probability.
 option-auto-detect = Auto Detect
 option-japanese-ja = Japanese (ja)
 option-english-en = English (en)
@@ -49,6 +92,7 @@ option-hindi-hi = Hindi (hi)
 title-download-model = Download Speech Recognition Data
 msg-download-whisper = Download the training data (Whisper) for converting audio to text.
 msg-download-once = After downloading once, it is not needed again. Depending on network conditions, this may take several minutes.
+msg-model-low-memory = This model needs a large share of this computer's memory and may make the system swap or slow down. Consider a smaller model.
 btn-yes = Yes
 btn-no = No
 btn-cancel = Cancel
@@ -56,29 +100,163 @@ msg-cancelling = Cancelling...
 label-current-setting = Current Setting:
 label-current-used = Currently Used:
 label-current-used-pending = apply after switching
+msg-model-preset-mismatch = ⚠ The loaded model file does not match the selected preset, so accuracy and speed may differ from what you expect (loaded / selected):
 header-advanced = Advanced Settings (Whisper optimizations & chunking)
 header-whisper-opt = Whisper Optimization
 chk-no-timestamps = No timestamps
 chk-token-timestamps = Token timestamps
 chk-use-physical-cores = Prefer physical cores
+chk-whisper-threads = Set thread count manually
+tooltip-whisper-threads = Fixed number of CPU threads per transcription instead of the automatic choice (physical or logical cores, at most 4).
+hint-whisper-threads = Limited to the number of CPU cores. More threads are not always faster: beyond the performance cores they compete for memory bandwidth and can slow transcription down. With parallel chunks each worker uses this many threads.
+chk-normalize-audio = Normalize recording volume
+tooltip-normalize-audio = Boost quiet recordings to a -3 dBFS peak before transcription. Transcription starts after recording stops.
+chk-trim-silence = Trim silence before and after speech
+tooltip-trim-silence = Cut the quiet parts before you start and after you stop speaking (keeping a short margin) before transcription. Faster, and avoids made-up text at the end of a clip.
+chk-spoken-commands = Spoken punctuation commands
+tooltip-spoken-commands = Convert spoken commands in the transcript, e.g. "comma", "period", "question mark", "new line", "new paragraph", "open quote"/"close quote" (Japanese: 句点, 読点, 改行, 改段落, かぎかっこ). Works without the LLM and runs before the dictionary.
+chk-autocapitalize = Capitalize sentences
+tooltip-autocapitalize = Capitalize the first letter of each sentence (and "I" in English) after dictionary replacement. Skipped when LLM post-processing runs, and for languages without letter case such as Japanese.
+chk-keep-last-recording = Keep the last recording for re-transcription
+tooltip-keep-last-recording = Keeps the raw audio of the last recording in memory (about 2 MB per minute) so it can be transcribed again after changing the model or language.
 header-chunking = Chunk Splitting (VAD)
 label-split-strategy = Split Strategy:
 option-normal = Normal
 option-aggressive = Fine split (early)
+option-custom = Custom
+label-vad-silence-db = Silence level:
+tooltip-vad-silence-db = Frames quieter than this count as silence. Raise it if background hum keeps chunks from splitting.
+label-vad-min-silence = Silence before split:
+label-vad-min-speech = Minimum chunk length:
+tooltip-vad-min-speech = A chunk is not split on silence until it is at least this long.
+note-vad-custom = Choosing a preset fills these values; moving a slider switches to Custom.
+label-min-speech = Minimum speech:
+tooltip-min-speech = Recordings with less voiced audio than this (measured with the silence threshold above) are treated as "no speech": nothing is transcribed, sent to the LLM, or pasted. Prevents Whisper's silence hallucinations such as "Thank you." 0 disables.
+chk-min-segment-confidence = Drop low-confidence segments below
+tooltip-min-segment-confidence = Leaves out Whisper segments whose average token probability is below this value; they are often hallucinations on noisy audio. Local Whisper only.
+hint-min-segment-confidence = Dropped segments are listed in the log with their confidence. Raise the value slowly: quiet or accented speech also scores lower.
+chk-hallucination-filter = Suppress common Whisper hallucinations
+tooltip-hallucination-filter = When the whole transcript is a stock phrase Whisper produces on silence or noise ("Thanks for watching", "ご視聴ありがとうございました"), nothing is pasted or sent to the LLM.
+label-hallucination-phrases = Hallucination phrases
+label-hallucination-builtin = Built-in:
+hint-hallucination-phrases = Your own recurring artifacts. A transcript is suppressed only when it consists entirely of one of these phrases (case, spacing and punctuation are ignored).
+btn-hallucination-add = Add phrase
+label-parallel-chunks = Parallel chunks:
+tooltip-parallel-chunks = Number of speech chunks transcribed at the same time. 1 transcribes one chunk after another.
+hint-parallel-chunks = Helps on many-core CPUs when long recordings or files queue up chunks. Each extra worker allocates its own Whisper state (roughly 100–500 MB more RAM/VRAM depending on the model) and the CPU threads are split between workers. Chunks are already transcribed without context from earlier chunks, so the text is reassembled in order unchanged. Applies from the next recording.
 header-auto-stop = Auto Stop (Advanced)
 label-auto-stop-silence = Auto stop on silence:
 label-disabled = (Disabled)
 label-auto-stop-silence-tip = (Stops automatically after continued silence)
 label-max-recording-time = Max recording time:
+label-max-record-action = At the limit:
+option-max-record-stop = Transcribe and stop
+option-max-record-restart = Transcribe and keep recording
+option-max-record-warn = Transcribe, stop and warn
+hint-max-record-action = The floating window counts down the last 10 seconds and a chime plays. "Keep recording" starts a new recording right away for continuous dictation.
+tooltip-max-record-countdown = Seconds until the max recording time
+label-stop-phrase = Stop phrase:
+hint-stop-phrase-example = e.g. end dictation
+hint-stop-phrase = Saying this phrase stops the recording (case and punctuation are ignored). The phrase is removed from the transcript. Checking it re-runs Whisper on the last few seconds while recording, which uses extra CPU. Leave empty to disable.
 section-floating-window = 🪟 Floating Window
 label-opacity = Opacity:
 chk-always-on-top = Always on Top
 heading-settings-usage = How to Use Settings
 label-ui-language = Display Language:
+label-floating-style = Floating window style:
+label-theme = Theme:
+option-theme-system = System
+option-theme-light = Light
+option-theme-dark = Dark
+label-ui-scale = UI scale:
+btn-ui-scale-reset = Reset
+label-vsync = VSync:
+option-vsync-auto = Auto (off on Linux)
+option-vsync-on = On
+option-vsync-off = Off
+tooltip-vsync = Turn on if the window tears; turn off if you see "Dropped frame" timeouts in the log. The HOOTVOICE_VSYNC environment variable overrides this
+note-vsync-restart = VSync changes take effect after restarting HootVoice
+option-floating-full = Full
+option-floating-mini = Mini HUD
+tooltip-floating-style = Mini HUD shows only a state-colored dot (and elapsed time while recording). Hover or click it to expand; drag to move.
+chk-show-floating-on-record = Show the floating window when recording starts
+tooltip-show-floating-on-record = Off: recording starts without opening or switching any window; rely on the sounds or the Waybar module for feedback
+label-start-hidden = Start hidden
+tooltip-start-hidden = Launch without showing any window; the floating window appears when recording starts from the hotkey. Open Settings again with the Waybar/socket "show" command (SIGUSR2 on Linux). The first-run setup is always shown.
+label-keep-main-window = Keep this window open with the floating window
+tooltip-keep-main-window = Showing the floating window (or starting a recording) no longer hides or minimizes this window, so both can stay on screen.
+label-status-colors = Status colors:
+option-status-colors-default = Default
+option-status-colors-high-contrast = High contrast
+option-status-colors-colorblind = Colorblind-friendly
+option-status-colors-custom = Custom
+tooltip-floating-collapse = Collapse to the mini HUD
 option-auto-os = Auto (match OS)
 option-english = English
 option-japanese = Japanese
-label-auto-paste = Auto Paste (copy only when OFF)
+label-auto-paste = Auto paste
+chk-output-clipboard = Copy to clipboard
+tooltip-output-clipboard = Put the transcript on the clipboard (auto-paste always uses the clipboard)
+label-output-append-files = Append to notes files:
+btn-output-append-add = Add file…
+tooltip-output-append-add = Choose a file that every transcript is appended to, with a timestamp line
+tooltip-output-append-remove = Stop appending to this file
+hint-output-append-files = Each entry starts with a "--- YYYY-MM-DD HH:MM ---" line. Use {"{{"}date{"}}"} in the file name for one note per day.
+msg-output-no-targets = No output is selected: transcripts only go to the history.
+chk-restore-clipboard = Restore previous clipboard after pasting
+tooltip-restore-clipboard = Saves the clipboard before the transcription is copied and puts it back shortly after the paste. Only text can be restored.
+chk-review-before-paste = Review before paste
+tooltip-review-before-paste = After transcription, show the text in an editable window. Nothing is copied or pasted until you press Paste.
+chk-compare-before-paste = Compare raw and LLM text before paste
+tooltip-compare-before-paste = When LLM post-processing changed the transcript, show the Whisper text and the LLM result side by side and paste the one you pick.
+label-transcript-prefix = Text before transcript:
+label-transcript-suffix = Text after transcript:
+hint-transcript-boilerplate = Added around every transcript before it is copied or pasted (e.g. a greeting and a signature). {"{{"}date{"}}"} and {"{{"}time{"}}"} are replaced with the current date and time. Leave empty to disable.
+label-remote-transcription = Transcribe with a remote API (OpenAI-compatible)
+tooltip-remote-transcription = Send each recorded chunk to an /audio/transcriptions endpoint instead of running Whisper on this computer
+label-remote-base-url = API base URL:
+label-remote-api-key = API key:
+label-remote-model = Model:
+btn-run-self-test = Run self-test
+tooltip-run-self-test = Transcribe a bundled sample clip with the current model, dictionary and LLM settings (no microphone needed)
+title-self-test = Self-test
+msg-self-test-running = Running the sample through the pipeline…
+msg-self-test-empty = (no text)
+msg-self-test-passed = All stages completed. Compare the output with the spoken sample to judge accuracy.
+msg-self-test-failed = A stage failed; later stages were not run.
+btn-run-benchmark = Benchmark models
+tooltip-run-benchmark = Transcribe the bundled sample clip with every downloaded model and compare speed and accuracy (large models take a while)
+title-benchmark = Model benchmark
+msg-benchmark-running = Loading each downloaded model and transcribing the sample…
+msg-benchmark-no-models = No models have been downloaded yet.
+label-benchmark-model = Model
+label-benchmark-load = Load
+label-benchmark-transcribe = Transcribe
+label-benchmark-speed = Speed
+label-benchmark-accuracy = Accuracy
+hint-benchmark = Speed is audio length ÷ processing time (above 1× is faster than real time). Accuracy is 1 − word error rate against the sample's reference text; hover it to see the transcript.
+selftest-stage-sample = Sample audio
+selftest-stage-transcription = Transcription
+selftest-stage-dictionary = Dictionary
+selftest-stage-llm = LLM post-processing
+selftest-skipped-llm-disabled = Skipped (LLM post-processing is off)
+hint-remote-transcription = Recorded audio is uploaded to this server. An empty API key uses the OPENAI_API_KEY environment variable. The local model is still loaded for the stop phrase and as a fallback when this is turned off.
+btn-review-paste = Paste
+btn-review-paste-raw = Paste Whisper text
+btn-review-paste-llm = Paste LLM result
+label-review-raw = Whisper
+label-review-llm = LLM
+tooltip-review-paste = Copy/paste the edited text (Ctrl+Enter). Esc discards.
+btn-review-discard = Discard
+btn-review-rerun = Re-run
+tooltip-review-rerun = Transcribe the same recording again with the current settings
+label-paste-method = Paste method:
+option-paste-ctrl-v = Ctrl+V
+option-paste-ctrl-shift-v = Ctrl+Shift+V (terminals)
+option-paste-middle-click = Middle click (primary selection)
+option-paste-type-text = Type the text
+note-paste-middle-click = Pastes at the mouse pointer. Requires 'xdotool' (X11) or 'ydotool' (Wayland).
+note-paste-type-text = Types each character with wtype/xdotool/ydotool. Slower, but works where paste is blocked.
 heading-llm = LLM Post-processing
 llm-description = Sends Whisper transcripts to a local LLM-compatible API so you can polish the text, apply custom transformations, and reuse the processed result.
 label-llm-enable = Enable LLM post-processing
@@ -87,6 +265,9 @@ label-llm-api-base = API Base URL:
 placeholder-llm-api-base = e.g. http://localhost:11434/v1
 note-llm-api-base-local = Ollama default: http://localhost:11434/v1 / LM Studio: http://localhost:1234/v1
 label-llm-model = Model:
+label-llm-mode-model = Model for this mode:
+tooltip-llm-mode-model = Leave empty to use the model above.
+option-llm-mode-model-global = Use the global model
 placeholder-llm-model = e.g. llama3.1:8b
 llm-model-dropdown-placeholder = Pick from fetched models
 btn-llm-fetch-models = Fetch models
@@ -95,6 +276,7 @@ msg-llm-fetch-empty = No model list yet. Click "Fetch models" if you need sugges
 label-llm-mode = Output mode:
 llm-mode-format = Formatting (punctuation + polite tone)
 llm-mode-summary = Summary (short bullet list)
+llm-mode-furigana = Furigana (kanji readings)
 llm-mode-custom = Custom prompt
 llm-mode-custom-add = Add custom mode
 llm-mode-custom-draft = Custom mode (draft)
@@ -106,6 +288,15 @@ btn-llm-save-custom = Save as new mode
 tooltip-llm-save-custom = Add this prompt to the output mode list
 btn-llm-delete-custom = Delete mode
 tooltip-llm-delete-custom = Remove the selected custom mode
+llm-mode-chain-suffix = chain
+llm-chain-default-name = Format then summarize
+btn-llm-new-chain = New chain
+tooltip-llm-new-chain = Create a mode that runs several modes in a row, each one processing the previous output
+label-llm-chain-steps = Steps (run in order):
+btn-llm-chain-add-step = Add step
+tooltip-llm-chain-remove-step = Remove this step
+tooltip-llm-delete-chain = Delete this chain (the modes it uses are kept)
+hint-llm-chain = If a step fails, the output of the last successful step is used and a warning is shown.
 btn-llm-duplicate-custom = Duplicate as new mode
 tooltip-llm-duplicate-custom = Save the current prompt as an additional custom mode
 msg-llm-custom-invalid = Enter both a name and a prompt before saving.
@@ -134,11 +325,42 @@ msg-llm-profile-error-profile-missing = Profile not found.
 msg-llm-profile-error-not-editable = This profile cannot be edited.
 msg-llm-profile-error-remove-locale = Failed to remove locale from profile.
 label-llm-max-input = Max input characters:
+label-llm-oversize-input = Longer transcripts:
+option-llm-oversize-truncate = Truncate to the maximum
+option-llm-oversize-chunk = Split into several requests
+hint-llm-oversize-input = Splitting sends one request per piece and joins the results; summaries are summarized once more at the end.
+label-llm-max-output = Limit output tokens:
+note-llm-max-output-unlimited = No limit is sent; the server decides the response length.
+label-llm-temperature = Temperature:
+tooltip-llm-temperature = Lower is more literal (0.0 for strict formatting); higher is more creative.
+btn-llm-preview-prompt = Preview Prompt
+tooltip-llm-preview-prompt = Show the exact messages that would be sent, without calling the LLM
+title-llm-preview-prompt = Prompt Preview
+llm-preview-sample-transcript = This is a sample transcript for checking the prompt template.
+msg-llm-preview-transcript-appended = The user prompt has no {"{{"}transcript{"}}"} placeholder, so the transcript is appended at the end.
+label-llm-preview-no-system = (no system prompt)
+label-llm-preview-payload = Request JSON
+btn-llm-preview-copy-payload = Copy JSON
 label-llm-timeout = Timeout:
 note-llm-timeout-unit = (seconds)
+label-llm-models-timeout = Model list / test timeout:
+tooltip-llm-models-timeout = Used when fetching the model list and for Test connection, so an unreachable server is reported quickly. Post-processing uses the timeout above.
+label-llm-poll-models = Retry the model list until the server is up
+label-llm-summary-markdown = Clean Markdown list output
+tooltip-llm-summary-markdown = Summary mode only: rewrite the result as a "- " bullet list with a blank line before it, whatever bullet style the model used. Custom modes are not changed.
+label-llm-summary-heading = Add a date heading (## YYYY-MM-DD)
+tooltip-llm-poll-models = While this tab is open and no models are listed (e.g. Ollama is not running yet), fetch the list again every few seconds with backoff. Stops once the list loads or the tab is closed.
+msg-llm-waiting-server = Waiting for the LLM server…
 label-llm-apply-autopaste = Use LLM output for auto paste
+label-clipboard-mode = Clipboard contents:
+option-clipboard-polished = Polished text only
+option-clipboard-raw = Raw transcript only
+option-clipboard-raw-then-polished = Raw, then polished (raw stays in clipboard history)
+option-clipboard-both = Both, separated by ---
 btn-llm-test-connection = Test connection
 msg-llm-test-running = Testing… (may take a few seconds)
+btn-llm-test-cancel = Cancel
+msg-llm-test-cancelled = Connection test cancelled
 label-llm-test-enable = Show LLM Post-process Test
 label-llm-test-no-history = No transcription history available. Record something first.
 label-llm-test-select-transcript = Select transcript to test
@@ -152,6 +374,21 @@ label-llm-test-output = LLM Output
 btn-llm-test-copy-output = Copy
 msg-llm-history-load-failed = Failed to read LLM history
 heading-llm-history = History
+warn-history-stored = History keeps the full transcript and LLM output of each post-processed dictation as plain text on this computer:
+chk-history-enabled = Save history
+label-history-redaction = Redaction patterns
+hint-history-redaction = Regular expressions; matches are replaced with [redacted] in both the transcript and the LLM output before an entry is saved. Existing entries are not changed.
+msg-history-redaction-invalid = Invalid pattern (ignored)
+btn-history-redaction-add = Add pattern
+btn-history-redaction-card = Add card-number pattern
+label-history-max-entries = Keep up to (entries):
+chk-history-retention = Delete entries older than
+label-history-days = days
+hint-history-retention = Limits apply when the next entry is saved; entries outside them are hidden right away.
+btn-history-clear = Clear history
+msg-history-clear-confirm = Delete all history entries?
+btn-history-clear-confirm = Delete all
+msg-history-clear-failed = Failed to clear history
 label-llm-history-empty = No history yet
 label-llm-history-details = Details
 label-llm-history-select-entry = Select an entry from the list above
@@ -162,6 +399,10 @@ label-llm-history-base-url = LLM API Base:
 label-llm-history-language = Language:
 label-llm-history-language-auto = Auto (Whisper setting)
 label-llm-history-latency = LLM Processing Time:
+label-llm-history-recording = Recording:
+label-llm-history-chars = chars
+label-llm-history-words = words
+label-llm-history-wpm = Words per minute:
 label-llm-history-truncated = Input was truncated to the maximum length
 label-llm-history-transcript = Transcript
 label-llm-history-output = LLM Output
@@ -170,6 +411,19 @@ btn-llm-history-copy-output = Copy LLM Output
 tooltip-llm-history-copy-transcript = Copy the transcript to the clipboard
 tooltip-llm-history-copy-output = Copy the LLM output to the clipboard
 tooltip-llm-history-select-row = Show details
+hint-history-search = Search transcripts and LLM output
+label-history-date = Date:
+option-history-date-all = All time
+option-history-date-today = Today
+option-history-date-7d = Last 7 days
+option-history-date-30d = Last 30 days
+msg-history-no-matches = No entries match the filter
+btn-llm-history-rerun = Reprocess
+tooltip-llm-history-rerun = Process the stored transcript again with the mode chosen on the left and the current LLM settings. The result is shown here and not added to the history
+tooltip-llm-history-rerun-no-transcript = The transcript of this entry was not stored
+label-llm-history-rerun-output = Reprocessed output
+label-llm-history-diff = Changes made by the LLM
+hint-llm-history-diff = Red, struck through: removed from the transcript. Green: added by the LLM.
 label-llm-history-duration-column = Duration
 label-llm-history-custom-prompts = Custom prompts used
 label-llm-history-custom-system = System prompt
@@ -177,6 +431,21 @@ label-llm-history-custom-user = User prompt
 label-dev-tools = Developer Tools:
 btn-launch-setup-wizard = Launch Initial Setup Wizard
 btn-reset-defaults = Reset All Settings to Default
+label-config-bundle = Settings file:
+btn-export-config = Export…
+btn-import-config = Import…
+hint-config-bundle = One .zip with your settings (including custom LLM prompts) and dictionary, for moving to another machine. Microphone/output devices, window positions, the model path and custom sound files are not included.
+title-import-config = Import settings
+label-bundle-created = Exported:
+label-bundle-contents = Contains:
+label-bundle-settings = settings
+label-bundle-dictionary = dictionary entries
+msg-import-config-confirm = Your current settings and dictionary will be overwritten (device selections on this machine are kept). A backup of the current files is saved first.
+btn-import-config-overwrite = Import and overwrite
+msg-config-exported = Exported:
+msg-config-export-failed = Export failed:
+msg-config-imported = Imported. Previous files backed up to:
+msg-config-import-failed = Import failed:
 msg-applied-defaults = Applied default settings
 title-reset-defaults = Reset Settings
 msg-reset-defaults-confirm = Restore all settings to their defaults? Custom dictionaries will be removed.
@@ -196,8 +465,25 @@ msg-update-download-failed = Download failed
 btn-open-downloads = Open Folder
 section-dictionary = Dictionary
 dict-description = Automatically replace aliases with standard form (longer aliases take precedence, applies immediately after saving)
+label-dict-fuzzy-distance = Fuzzy match max edits:
+tooltip-dict-fuzzy-distance = Maximum character edits for entries with fuzzy matching. Short aliases allow fewer edits (about one per four letters); spacing differences are always ignored.
+chk-dict-fuzzy = Fuzzy match (near-miss words)
+tooltip-dict-fuzzy = Also replace whole words that differ slightly from an alias, e.g. "kuber netes" or a plural form
+label-dict-locales = Languages
+hint-dict-locales = Apply only when the transcription language (selected or auto-detected) is one of these. None selected: all languages.
 label-save-location = Save location:
 msg-dict-reloaded = Dictionary reloaded
+btn-dict-reapply-last = Apply to last transcript
+tooltip-dict-reapply-last = Run the saved dictionary on the last transcript again (Whisper is not re-run) and copy the result to the clipboard
+tooltip-dict-reapply-unavailable = No transcript yet
+chk-dict-reapply-llm = Also run LLM post-processing
+msg-dict-reapplied = Re-applying the dictionary to the last transcript; the result will be copied to the clipboard
+msg-dict-reapply-busy = Busy with another transcription; try again when it has finished
+msg-dict-conflicts = Conflicting entries (the replacement that wins is arbitrary):
+label-dict-conflict-alias = Alias
+label-dict-conflict-canonical = Duplicate standard form
+btn-dict-merge = Merge
+tooltip-dict-merge = Merge these entries into the first one (aliases, conditions and languages are combined)
 btn-add-entry = Add entry
 search-hint = Search by standard form, alias, or included words
 btn-clear = Clear
@@ -205,6 +491,8 @@ col-standard = Standard
 col-aliases = Alias
 col-include = Conditions (contains)
 col-actions = Actions
+col-enabled = On
+tooltip-dict-enabled = Uncheck to keep the entry but stop applying it
 none = (None)
 btn-edit = Edit
 btn-delete = Delete
@@ -220,13 +508,46 @@ btn-save-apply = Save & Apply
 msg-dict-saved = [Dictionary] Changes saved and applied
 msg-dict-save-failed = [Dictionary] Save failed:
 msg-dict-reload-failed = [Dictionary] Reload failed:
+btn-dict-import-csv = Import CSV
+btn-dict-export-csv = Export CSV
+msg-dict-import-added = [Dictionary] CSV imported. Added:
+msg-dict-import-merged = Merged:
+msg-dict-import-skipped = Skipped (empty standard form):
+msg-dict-import-failed = [Dictionary] CSV import failed:
+msg-dict-exported = [Dictionary] Exported CSV:
+msg-dict-export-failed = [Dictionary] CSV export failed:
 section-hotkey = Hotkey Settings
 label-start-stop-recording = Start/Stop Recording:
 hotkey-help-examples = Example: Cmd+Shift+R / Ctrl+Alt+F9
 hotkey-help-modifiers = Modifiers: Ctrl/Control, Shift, Alt/Option, Cmd/Command/Super/Win
 hotkey-help-keys = Keys: A–Z, 0–9, F1–F12, Space, Enter/Return, Tab, Escape/Esc
 hotkey-help-separator = Use '+' to join keys; case-insensitive (e.g. cmd+shift+r)
+btn-hotkey-capture = Press keys…
+btn-hotkey-capture-cancel = Cancel
+tooltip-hotkey-capture = Record the hotkey by pressing it. Escape cancels.
+label-hotkey-capture = Press the key combination:
+msg-hotkey-capture-invalid = This combination cannot be used as a hotkey
 hotkey-restart-note = After changing hotkeys, restart the app to apply (especially on macOS).
+chk-ipc-socket = Accept commands on a local socket (Waybar, scripts)
+tooltip-ipc-socket = Lets click handlers control HootVoice without its PID: `hootvoice --send toggle` starts/stops recording, `hootvoice --send show` opens Settings. Socket:
+note-ipc-socket-restart = Restart the app to apply the socket setting.
+label-hotkey-mode = Hotkey mode:
+label-hotkey-debounce = Ignore repeated presses within:
+tooltip-hotkey-debounce = A second start/stop within this interval is ignored, so a fumbled double press does not start and immediately stop recording. 0 disables.
+option-hotkey-toggle = Toggle (press to start/stop)
+option-hotkey-push-to-talk = Push-to-talk (hold to record)
+hotkey-push-to-talk-note = Push-to-talk needs key-release events (Windows, macOS, X11). Where they are unavailable, a second press stops recording; SIGUSR1 always toggles.
+label-hotkey-bindings = Additional hotkeys:
+option-hotkey-action-toggle = Start/stop recording
+option-hotkey-action-toggle-mode = Record with LLM mode
+option-hotkey-action-settings = Show settings
+option-hotkey-action-pause = Pause/resume hotkeys
+option-hotkey-action-undo-paste = Undo last paste
+option-hotkey-action-paste-last = Paste last transcription again
+btn-add-hotkey = Add hotkey
+msg-hotkey-invalid = Invalid hotkey
+msg-hotkey-conflict = Conflicts with another hotkey
+msg-hotkey-conflict-primary = The recording hotkey conflicts with an additional hotkey
 
 troubleshoot-hotkey-title = If hotkeys don’t work
 hotkey-macos-desc = On macOS, “Input Monitoring” permission is required for global hotkeys.
@@ -255,6 +576,8 @@ hotkey-windows-check = · Ensure no resident app already uses the same shortcut
 hotkey-windows-try-others = If not improved, try a different combination.
 
 troubleshoot-autopaste-title = If auto paste doesn’t work
+label-auto-paste-delay = Delay before pasting:
+tooltip-auto-paste-delay = Wait this long after HootVoice hides before sending the paste keystroke. Raise it if the target app sometimes misses the paste because it has not regained focus yet.
 autopaste-macos-desc = On macOS, auto paste (sending ⌘V) requires the following permissions.
 autopaste-macos-acc = · Accessibility: Allow “control your Mac” (enable HootVoice)
 autopaste-macos-auto = · Automation: Allow controlling “System Events” (first time approval dialog)
@@ -281,6 +604,13 @@ autopaste-windows-hints2 = · Admin privileges or group policies may affect beha
 autopaste-windows-note = Alternatively, use clipboard copy only and paste manually.
 
 msg-settings-saved = Settings saved
+msg-settings-reverted = Changes reverted
+msg-settings-unsaved = Unsaved changes
+btn-save = Save
+btn-revert = Revert
+tooltip-revert-settings = Discard the changes made since the last save
+chk-settings-autosave = Save settings automatically
+tooltip-settings-autosave = When off, changes take effect right away but are only written to settings.toml when you press Save; Revert restores the last saved settings
 
 wizard-title = First-time Setup
 btn-back = Back
@@ -319,12 +649,47 @@ status-idle = Idle
 status-recording = Recording
 status-processing = Processing
 status-busy = Busy
+status-queued = Next recording queued
+msg-queue-cancelled = Queued recording cancelled
+status-hotkey-paused = Hotkey paused
+msg-error-model-load = Failed to load the Whisper model
+msg-model-load-failed-banner = The speech model failed to load — download or select a model below.
+btn-dismiss = Dismiss
+msg-error-no-speech = No speech detected
+msg-error-max-duration = Max recording time reached
+msg-error-llm = LLM failed; used Whisper text
+msg-error-llm-truncated = LLM input was truncated
+msg-error-clipboard = Could not copy to the clipboard
+msg-error-paste = Auto paste failed
+msg-error-audio-file = Could not read the audio file
+msg-error-append-file = Could not append the transcript to the notes file
+msg-error-transcription = Transcription failed; nothing was pasted
 status-post-processing = LLM post-processing
+msg-llm-backoff-active = LLM post-processing paused after repeated failures. Retrying in
+btn-llm-retry-now = Retry Now
 btn-toggle-recording = Start/Stop Recording
 btn-show-floating = Show Floating
+btn-pause-hotkey = Pause hotkey
+tooltip-pause-hotkey = While paused, the recording hotkey and SIGUSR1 do nothing. Recording from this window still works.
+btn-undo-paste = Undo last paste
+tooltip-undo-paste = Removes the last auto-pasted text by pressing Backspace once per character (available for 30 seconds). Best-effort: if the cursor moved or you typed since, other text may be deleted.
+btn-retranscribe = Re-transcribe
+tooltip-retranscribe = Transcribe the last recording again with the current model, language and settings. The result is copied to the clipboard.
+btn-export-subtitles = Export subtitles…
+tooltip-export-subtitles = Save the last transcription with timestamps as SRT or WebVTT (choose the .srt or .vtt extension). Times are measured from the start of the recording or file.
+msg-subtitles-exported = Subtitles saved:
+msg-subtitles-export-failed = Subtitle export failed:
+msg-transcribing-file = Transcribing file:
+msg-drop-not-audio = Not an audio file (wav, mp3, flac, ogg):
+hint-drop-audio = Drop audio files to transcribe them (result is copied to the clipboard)
 title-debug-log = Debug Log
 label-auto-scroll = Auto Scroll
 msg-log-cleared = Logs cleared
+btn-copy-diagnostics = Copy diagnostics
+tooltip-copy-diagnostics = Copy version, OS, model, device and LLM settings plus recent log lines for a bug report. API keys and your home folder are masked.
+btn-save-diagnostics = Save diagnostics…
+msg-diagnostics-copied = Diagnostics copied to the clipboard
+msg-diagnostics-saved = Diagnostics saved:
 msg-recording-started = Recording started
 msg-processing = Processing...
 msg-recording-stopped = Recording stopped
@@ -333,6 +698,11 @@ msg-busy = Busy, please wait
 # Download status messages
 msg-download-started = Download started
 msg-download-completed = Download complete
+msg-download-verify-failed = The downloaded model is corrupted and was deleted. Please download it again.
+label-model-mirror = Download mirror:
+hint-model-mirror = Optional base URL replacing huggingface.co for model downloads (the model file path is kept). Leave empty for the default.
+label-download-url = Download URL:
+msg-model-mirror-invalid = Invalid mirror URL
 
 # Model quality/speed panel
 label-speed = Speed:
@@ -10,6 +10,7 @@ section-devices = 入出力デバイス
 btn-stop-level-meter = 入力レベル確認を終了
 btn-start-level-meter = 入力レベルを確認
 label-input-level = 入力レベル:
+tooltip-input-clipping = 直近1秒間に入力が -0.1 dBFS に達しました。歪みを防ぐため入力感度を下げてください。
 label-input-sensitivity = 入力感度:
 btn-auto-adjust = 自動調整
 tooltip-auto-adjust = 現在の入力レベルを -12 dBFS に合わせます
@@ -21,10 +22,40 @@ tooltip-test-recording = 選択中の入力デバイスで録音を開始しま
 btn-stop-and-save = 停止して保存
 tooltip-stop-and-save = 録音を停止し保存先を選択します
 msg-test-recording-saved = テスト録音を保存しました
+label-test-stream-errors = ストリームエラー:
+label-test-dropouts = 音切れ:
+tooltip-test-dropouts = ストリームエラーはオーディオドライバーが報告したオーバーランやデバイスエラー、音切れは取り込んだバッファ間の欠落です。どちらも録音が途切れる原因になります。別の入力ホストを試す、他の音声アプリを閉じる、サウンドサーバーのバッファを大きくする、などをお試しください
+label-test-latency = 取り込み遅延（平均 / 最大）:
+tooltip-test-latency = デバイスで取り込まれてからHootVoiceに届くまでの時間（ドライバーの報告値）
+label-test-not-reported = ドライバーから報告されていません
+label-test-sample-rate = 実測サンプルレート:
+label-test-nominal = 公称
+label-test-too-short = 測定には5秒以上録音してください
+label-preroll = プリロール:
+preroll-note = 待機中もマイクを開いたままにし、話し始めが欠けないようにします。録音を開始しない限り待機中の音声は破棄されます。
+msg-input-device-missing = 選択したマイクが接続されていません。現在はシステム既定のマイクで録音します。
+btn-input-reselect = 選択したマイクに戻す
+label-input-auto-reselect = マイクが再接続されたら自動で戻す
+tooltip-input-auto-reselect = 選択したマイクが外れるとシステム既定のマイクで録音します。オンにすると、再接続された時点で選択したマイクに戻ります。
 label-output-device = 出力デバイス(効果音):
 label-play-sounds = 効果音を鳴らす
 label-volume = 音量
 btn-test-play = テスト再生
+label-sound-start = 録音開始
+label-sound-complete = 完了
+label-sound-error = エラー / 音声なし
+label-sound-default = 既定
+btn-choose-file = 選択…
+btn-sound-use-default = 既定に戻す
+tooltip-sound-file-missing = ファイルが見つかりません。既定のサウンドを使用します。
+note-event-sounds = 未設定またはファイルが無い場合は同梱のサウンドを使用します。
+chk-readback-tts = 文字起こしを読み上げる
+tooltip-readback-tts = ペースト後に最終テキストをシステムの音声で読み上げます（macOSはsay、WindowsはSAPI、Linuxはespeak-ngまたはspd-say）。上の出力デバイスと音量を使い、新しい録音を始めると止まります。
+label-complete-sound-timing = 完了音:
+tooltip-complete-sound-timing = 自動貼り付けに対して完了音を鳴らすタイミング
+option-sound-after-paste = 貼り付けの後
+option-sound-before-paste = 貼り付けの前（鳴り終わるまで待つ）
+option-sound-silent-auto-paste = 自動貼り付け時は鳴らさない
 section-speech-model = 音声モデル
 label-preset = プリセット:
 btn-change = 変更
@@ -34,7 +65,19 @@ label-model-folder = モデルフォルダ:
 btn-open-folder = フォルダを開く
 label-selection = 選択:
 label-language = 入力言語:
+label-source-language = 話す言語（翻訳元）:
+label-whisper-task = タスク:
+option-task-transcribe = 文字起こし
+option-task-translate = 英語に翻訳
+tooltip-whisper-task = 文字起こしは話した言語のまま出力します。翻訳はWhisper内蔵の翻訳機能で、どの言語の音声からも英語のテキストを出力します。
+hint-whisper-translate = 翻訳先は英語のみです。上の言語は話す（翻訳元の）言語になります。辞書とLLMの処理は英語の結果に対して行われます。
 msg-language-accuracy = 入力言語を指定すると認識精度が向上します。
+label-auto-detect-fallback = フォールバック言語:
+option-auto-detect-fallback-none = なし（検出結果を使う）
+tooltip-auto-detect-fallback = 自動検出の確信度が低いとき（短い音声など）に使う言語
+label-auto-detect-min-confidence = 最小確信度:
+hint-auto-detect-fallback = 検出確率がこの値未満のときはフォールバック言語を使います。ログタブに各チャンクの検出言語と確率が表示されるので、調整の目安にしてください。
+hint-auto-detect-fallback-remote = ローカルモデル専用です。リモートのエンドポイントは自分で言語を判定し、検出確率を返しません。
 option-auto-detect = 自動検出
 option-japanese-ja = 日本語 (ja)
 option-english-en = 英語 (en)
@@ -49,6 +92,7 @@ option-hindi-hi = ヒンディー語 (hi)
 title-download-model = 音声認識データのダウンロード
 msg-download-whisper = 音声を文字に変換するための学習データ（Whisper）をダウンロードします。
 msg-download-once = 一度ダウンロードすると次回以降は不要です。回線状況により数分かかる場合があります。
+msg-model-low-memory = このモデルはこのPCのメモリの大部分を必要とし、スワップや動作の遅延が起こる可能性があります。より小さいモデルをご検討ください。
 btn-yes = はい
 btn-no = いいえ
 btn-cancel = キャンセル
@@ -56,29 +100,163 @@ msg-cancelling = キャンセル中...
 label-current-setting = 現在設定:
 label-current-used = 現在使用中:
 label-current-used-pending = 切替で反映
+msg-model-preset-mismatch = ⚠ 読み込まれたモデルファイルが選択中のプリセットと一致しません。精度や速度が想定と異なる場合があります（読み込み済み / 選択中）:
 header-advanced = 詳細設定 (Whisper最適化・チャンク)
 header-whisper-opt = Whisper最適化
 chk-no-timestamps = タイムスタンプなし
 chk-token-timestamps = トークン・タイムスタンプ
 chk-use-physical-cores = 物理コア優先
+chk-whisper-threads = スレッド数を手動で指定
+tooltip-whisper-threads = 自動選択（物理または論理コア、最大4）の代わりに、1回の文字起こしで使うCPUスレッド数を固定します。
+hint-whisper-threads = CPUコア数までに制限されます。スレッドを増やすほど速くなるとは限りません。高性能コアを超えるとメモリ帯域を奪い合い、かえって遅くなることがあります。並列チャンク使用時は各ワーカーがこのスレッド数を使います。
+chk-normalize-audio = 録音音量を正規化
+tooltip-normalize-audio = 文字起こし前に録音全体のピークを -3 dBFS に揃えます。文字起こしは録音停止後に開始されます。
+chk-trim-silence = 発話前後の無音を削除
+tooltip-trim-silence = 話し始める前と話し終えた後の無音部分を（少し余白を残して）削除してから文字起こしします。処理が速くなり、末尾の誤認識も防げます。
+chk-spoken-commands = 音声コマンドで句読点を入力
+tooltip-spoken-commands = 文字起こし中の「句点」「読点」「改行」「改段落」「かぎかっこ」「かぎかっこ閉じ」などを記号や改行に変換します（英語: comma, period, new line など）。LLMなしで動作し、辞書の前に適用されます。
+chk-autocapitalize = 文頭を大文字にする
+tooltip-autocapitalize = 辞書の置換後に各文の先頭の文字 (英語では代名詞の "I" も) を大文字にします。LLM 後処理を行う場合や、日本語など大文字・小文字のない言語では何もしません。
+chk-keep-last-recording = 直前の録音を再文字起こし用に保持
+tooltip-keep-last-recording = 直前の録音の音声をメモリに保持し（1 分あたり約 2 MB）、モデルや言語を変更した後にもう一度文字起こしできるようにします。
 header-chunking = チャンク分割（VAD）
 label-split-strategy = 分割戦略:
 option-normal = 通常
 option-aggressive = 細かく分割（早め）
+option-custom = カスタム
+label-vad-silence-db = 無音レベル:
+tooltip-vad-silence-db = これより小さい音は無音として扱います。背景ノイズで分割されにくい場合は上げてください。
+label-vad-min-silence = 分割までの無音時間:
+label-vad-min-speech = 最小チャンク長:
+tooltip-vad-min-speech = チャンクがこの長さに達するまでは無音でも分割しません。
+note-vad-custom = プリセットを選ぶと値が入力されます。スライダーを動かすとカスタムに切り替わります。
+label-min-speech = 最小発話時間:
+tooltip-min-speech = 発話と判定された音声（上の無音しきい値で判定）がこれより短い録音は「音声なし」として扱い、文字起こし・LLM・貼り付けを行いません。無音時に Whisper が「ご視聴ありがとうございました」などを出力するのを防ぎます。0 で無効。
+chk-min-segment-confidence = 信頼度の低いセグメントを除外（しきい値）
+tooltip-min-segment-confidence = 平均トークン確率がこの値未満のWhisperセグメントを除外します。雑音の多い音声でのハルシネーションであることが多いです。ローカルWhisperのみ。
+hint-min-segment-confidence = 除外したセグメントは信頼度と一緒にログに表示されます。小さな声や訛りのある発話も低めになるため、少しずつ上げてください。
+chk-hallucination-filter = Whisper の定型ハルシネーションを抑制
+tooltip-hallucination-filter = 文字起こし全体が、無音やノイズ時に Whisper が出力しがちな定型文（「ご視聴ありがとうございました」「Thanks for watching」など）だけの場合、貼り付けや LLM への送信を行いません。
+label-hallucination-phrases = ハルシネーション語句
+label-hallucination-builtin = 組み込み:
+hint-hallucination-phrases = よく出る誤出力を追加できます。文字起こし全体がいずれかの語句だけの場合にのみ抑制します（大文字小文字・空白・句読点は無視）。
+btn-hallucination-add = 語句を追加
+label-parallel-chunks = 並列チャンク数:
+tooltip-parallel-chunks = 同時に文字起こしする音声チャンクの数です。1 の場合は 1 つずつ順番に処理します。
+hint-parallel-chunks = 長い録音やファイルでチャンクが溜まる場合、コア数の多い CPU で効果があります。ワーカーを増やすごとに Whisper の作業領域が追加で確保され（モデルにより約 100〜500 MB の RAM/VRAM）、CPU スレッドはワーカー間で分配されます。チャンクはもともと前のチャンクの文脈なしで処理されるため、結果は順番通りに結合され内容は変わりません。次の録音から反映されます。
 header-auto-stop = 自動停止（高度設定）
 label-auto-stop-silence = 無音で自動停止:
 label-disabled = (無効)
 label-auto-stop-silence-tip = (継続した無音で自動停止)
 label-max-recording-time = 最大録音時間:
+label-max-record-action = 上限に達したとき:
+option-max-record-stop = 文字起こしして停止
+option-max-record-restart = 文字起こしして録音を続ける
+option-max-record-warn = 文字起こしして停止し、警告を表示
+hint-max-record-action = 最後の10秒はフローティングウィンドウにカウントダウンが表示され、チャイムが鳴ります。「録音を続ける」は連続ディクテーション用に、すぐ次の録音を開始します。
+tooltip-max-record-countdown = 最大録音時間までの残り秒数
+label-stop-phrase = 停止フレーズ:
+hint-stop-phrase-example = 例: 入力終了
+hint-stop-phrase = このフレーズを話すと録音を停止します（大文字小文字・句読点は無視）。フレーズ自体は文字起こしから取り除かれます。録音中に直近数秒をWhisperで繰り返し認識するため、CPU負荷が増えます。空欄で無効。
 section-floating-window = 🪟 フローティングウィンドウ
 label-opacity = 透明度:
 chk-always-on-top = 常に最前面
 heading-settings-usage = 設定の操作方法
 label-ui-language = 表示言語:
+label-floating-style = フローティングの表示形式:
+label-theme = テーマ:
+option-theme-system = システムに合わせる
+option-theme-light = ライト
+option-theme-dark = ダーク
+label-ui-scale = UI の大きさ:
+btn-ui-scale-reset = リセット
+label-vsync = VSync:
+option-vsync-auto = 自動（Linuxではオフ）
+option-vsync-on = オン
+option-vsync-off = オフ
+tooltip-vsync = 画面のちらつき（ティアリング）があればオン、ログに「Dropped frame」のタイムアウトが出る場合はオフにしてください。環境変数 HOOTVOICE_VSYNC が設定されている場合はそちらが優先されます
+note-vsync-restart = VSyncの変更はHootVoiceの再起動後に反映されます
+option-floating-full = 通常
+option-floating-mini = ミニHUD
+tooltip-floating-style = ミニHUDは状態色のドット（録音中は経過時間も）だけを表示します。ホバーまたはクリックで展開、ドラッグで移動できます。
+chk-show-floating-on-record = 録音開始時にフローティングウィンドウを表示
+tooltip-show-floating-on-record = OFFにすると録音開始時にウィンドウを開いたり切り替えたりしません。効果音やWaybarモジュールで状態を確認します
+label-start-hidden = 非表示で起動
+tooltip-start-hidden = ウィンドウを表示せずに起動し、ホットキーで録音を始めるとフローティングウィンドウが表示されます。設定はWaybar/ソケットの「show」コマンド（LinuxではSIGUSR2）で開けます。初回セットアップは常に表示されます。
+label-keep-main-window = フローティングウィンドウと同時にこのウィンドウを表示したままにする
+tooltip-keep-main-window = フローティングウィンドウの表示時や録音開始時にこのウィンドウを非表示・最小化しません。両方を画面に並べておけます。
+label-status-colors = 状態の色:
+option-status-colors-default = 標準
+option-status-colors-high-contrast = ハイコントラスト
+option-status-colors-colorblind = 色覚多様性に配慮
+option-status-colors-custom = カスタム
+tooltip-floating-collapse = ミニHUDに戻す
 option-auto-os = 自動（OSに合わせる）
 option-english = English
 option-japanese = 日本語
-label-auto-paste = 自動ペースト（OFFのときはコピーのみ）
+label-auto-paste = 自動ペースト
+chk-output-clipboard = クリップボードにコピー
+tooltip-output-clipboard = 文字起こしをクリップボードに入れます（自動ペーストは常にクリップボードを使います）
+label-output-append-files = メモファイルに追記:
+btn-output-append-add = ファイルを追加…
+tooltip-output-append-add = 文字起こしごとにタイムスタンプ行と一緒に追記するファイルを選びます
+tooltip-output-append-remove = このファイルへの追記をやめる
+hint-output-append-files = 各項目は「--- YYYY-MM-DD HH:MM ---」の行から始まります。ファイル名に {"{{"}date{"}}"} を入れると日ごとのメモになります。
+msg-output-no-targets = 出力先が選ばれていません。文字起こしは履歴にのみ残ります。
+chk-restore-clipboard = 貼り付け後に元のクリップボードを戻す
+tooltip-restore-clipboard = 文字起こし結果をコピーする前にクリップボードを保存し、貼り付けの少し後に元へ戻します。復元できるのはテキストのみです。
+chk-review-before-paste = 貼り付け前に確認する
+tooltip-review-before-paste = 文字起こし後、テキストを編集可能なウィンドウに表示します。「貼り付け」を押すまでコピー・貼り付けは行いません。
+chk-compare-before-paste = 貼り付け前に元の文と LLM の結果を比較する
+tooltip-compare-before-paste = LLM 後処理で文字起こしが変わった場合、Whisper の結果と LLM の結果を並べて表示し、選んだ方を貼り付けます。
+label-transcript-prefix = 書き起こしの前に付ける文:
+label-transcript-suffix = 書き起こしの後に付ける文:
+hint-transcript-boilerplate = コピー・貼り付けの前に毎回書き起こしの前後へ追加します（挨拶や署名など）。{"{{"}date{"}}"} と {"{{"}time{"}}"} は現在の日付と時刻に置き換わります。空欄なら追加しません。
+label-remote-transcription = リモートAPIで書き起こす（OpenAI互換）
+tooltip-remote-transcription = このコンピューターでWhisperを実行する代わりに、録音した各チャンクを /audio/transcriptions エンドポイントへ送信します
+label-remote-base-url = APIベースURL:
+label-remote-api-key = APIキー:
+label-remote-model = モデル:
+btn-run-self-test = セルフテストを実行
+tooltip-run-self-test = 同梱のサンプル音声を現在のモデル・辞書・LLM設定で書き起こします（マイク不要）
+title-self-test = セルフテスト
+msg-self-test-running = サンプルを処理しています…
+msg-self-test-empty = （テキストなし）
+msg-self-test-passed = すべての段階が完了しました。出力とサンプル音声の内容を比べて精度を確認してください。
+msg-self-test-failed = 失敗した段階があります。以降の段階は実行していません。
+btn-run-benchmark = モデルを比較
+tooltip-run-benchmark = 同梱のサンプル音声をダウンロード済みの全モデルで書き起こし、速度と精度を比べます（大きいモデルは時間がかかります）
+title-benchmark = モデルのベンチマーク
+msg-benchmark-running = ダウンロード済みのモデルを順に読み込み、サンプルを書き起こしています…
+msg-benchmark-no-models = ダウンロード済みのモデルがありません。
+label-benchmark-model = モデル
+label-benchmark-load = 読み込み
+label-benchmark-transcribe = 書き起こし
+label-benchmark-speed = 速度
+label-benchmark-accuracy = 精度
+hint-benchmark = 速度は音声の長さ ÷ 処理時間です（1× を超えると実時間より速い）。精度はサンプルの正解テキストに対する 1 − 単語誤り率です。カーソルを合わせると書き起こし結果を表示します。
+selftest-stage-sample = サンプル音声
+selftest-stage-transcription = 書き起こし
+selftest-stage-dictionary = 辞書
+selftest-stage-llm = LLM後処理
+selftest-skipped-llm-disabled = スキップ（LLM後処理がオフ）
+hint-remote-transcription = 録音した音声はこのサーバーへアップロードされます。APIキーが空の場合は環境変数 OPENAI_API_KEY を使います。停止フレーズの検出とオフに戻したときのため、ローカルモデルは引き続き読み込まれます。
+btn-review-paste = 貼り付け
+btn-review-paste-raw = Whisper の結果を貼り付け
+btn-review-paste-llm = LLM の結果を貼り付け
+label-review-raw = Whisper
+label-review-llm = LLM
+tooltip-review-paste = 編集したテキストをコピー/貼り付けします（Ctrl+Enter）。Escで破棄します。
+btn-review-discard = 破棄
+btn-review-rerun = 再実行
+tooltip-review-rerun = 同じ録音を現在の設定でもう一度文字起こしします
+label-paste-method = 貼り付け方法:
+option-paste-ctrl-v = Ctrl+V
+option-paste-ctrl-shift-v = Ctrl+Shift+V（ターミナル向け）
+option-paste-middle-click = 中クリック（プライマリ選択）
+option-paste-type-text = 文字を直接入力
+note-paste-middle-click = マウスポインタの位置に貼り付けます。'xdotool'（X11）または 'ydotool'（Wayland）が必要です。
+note-paste-type-text = wtype/xdotool/ydotool で1文字ずつ入力します。遅くなりますが、貼り付けが禁止されたアプリでも使えます。
 heading-llm = LLM後処理
 llm-description = Whisper の書き起こし結果をローカル LLM API に送信し、文章を自然に整えたり特定の変換を適用した結果を活用できます。
 label-llm-enable = LLM による後処理を有効化
@@ -87,6 +265,9 @@ label-llm-api-base = API Base URL:
 placeholder-llm-api-base = 例: http://localhost:11434/v1
 note-llm-api-base-local = Ollama 既定: http://localhost:11434/v1 / LM Studio: http://localhost:1234/v1
 label-llm-model = モデル名:
+label-llm-mode-model = このモードのモデル:
+tooltip-llm-mode-model = 空欄の場合は上のモデルを使用します。
+option-llm-mode-model-global = 全体のモデルを使用
 placeholder-llm-model = 例: llama3.1:8b
 llm-model-dropdown-placeholder = モデル候補を選択
 btn-llm-fetch-models = モデル一覧取得
@@ -95,6 +276,7 @@ msg-llm-fetch-empty = モデル候補は未取得です。必要な場合は「
 label-llm-mode = 出力モード:
 llm-mode-format = 整形 (句読点・敬体化)
 llm-mode-summary = 要約 (短い箇条書き)
+llm-mode-furigana = ふりがな (漢字に読みを付ける)
 llm-mode-custom = カスタムプロンプト
 llm-mode-custom-add = カスタムモードを追加
 llm-mode-custom-draft = カスタムモード（編集中）
@@ -106,6 +288,15 @@ btn-llm-save-custom = 新しいモードとして保存
 tooltip-llm-save-custom = このプロンプトを出力モードとして追加します
 btn-llm-delete-custom = モードを削除
 tooltip-llm-delete-custom = 選択中のカスタムモードを削除します
+llm-mode-chain-suffix = チェーン
+llm-chain-default-name = 整形してから要約
+btn-llm-new-chain = 新しいチェーン
+tooltip-llm-new-chain = 複数のモードを順番に実行するモードを作成します（各ステップは前の出力を処理します）
+label-llm-chain-steps = ステップ（上から順に実行）:
+btn-llm-chain-add-step = ステップを追加
+tooltip-llm-chain-remove-step = このステップを削除
+tooltip-llm-delete-chain = このチェーンを削除（使用しているモードは残ります）
+hint-llm-chain = 途中のステップが失敗した場合は、最後に成功したステップの出力を使い、警告を表示します。
 btn-llm-duplicate-custom = 複製して新規作成
 tooltip-llm-duplicate-custom = 現在の内容を別のカスタムモードとして保存します
 msg-llm-custom-invalid = モード名とプロンプトを入力してください。
@@ -134,11 +325,42 @@ msg-llm-profile-error-profile-missing = プロファイルが見つかりませ
 msg-llm-profile-error-not-editable = このプロファイルは編集できません。
 msg-llm-profile-error-remove-locale = ロケールを削除できませんでした。
 label-llm-max-input = 最大入力文字数:
+label-llm-oversize-input = 上限を超える文字起こし:
+option-llm-oversize-truncate = 上限で切り詰める
+option-llm-oversize-chunk = 複数のリクエストに分割する
+hint-llm-oversize-input = 分割すると断片ごとにリクエストを送り結果をつなげます。要約は最後にもう一度まとめて要約します。
+label-llm-max-output = 出力トークン上限:
+note-llm-max-output-unlimited = 上限を送信しません（応答の長さはサーバー側で決まります）。
+label-llm-temperature = 温度 (temperature):
+tooltip-llm-temperature = 低いほど忠実（厳密な整形は 0.0）、高いほど自由な表現になります。
+btn-llm-preview-prompt = プロンプトをプレビュー
+tooltip-llm-preview-prompt = LLM を呼び出さずに、送信されるメッセージをそのまま表示します
+title-llm-preview-prompt = プロンプトのプレビュー
+llm-preview-sample-transcript = これはプロンプトテンプレートを確認するためのサンプルの文字起こしです。
+msg-llm-preview-transcript-appended = ユーザープロンプトに {"{{"}transcript{"}}"} がないため、文字起こしは末尾に追加されます。
+label-llm-preview-no-system = （システムプロンプトなし）
+label-llm-preview-payload = リクエスト JSON
+btn-llm-preview-copy-payload = JSON をコピー
 label-llm-timeout = タイムアウト:
 note-llm-timeout-unit = （秒）
+label-llm-models-timeout = モデル一覧・テストのタイムアウト:
+tooltip-llm-models-timeout = モデル一覧の取得と接続テストで使用します。サーバーに接続できない場合にすぐ結果が出ます。後処理には上のタイムアウトが使われます。
+label-llm-poll-models = サーバー起動までモデル一覧を再取得する
+label-llm-summary-markdown = Markdownの箇条書きに整える
+tooltip-llm-summary-markdown = 要約モードのみ：モデルがどの記号を使っても、結果を「- 」の箇条書き（前に空行）に整えます。カスタムモードには影響しません。
+label-llm-summary-heading = 日付の見出しを付ける（## YYYY-MM-DD）
+tooltip-llm-poll-models = このタブを開いていてモデル一覧が空のとき（Ollama が未起動など）、間隔を空けながら数秒ごとに一覧を再取得します。取得できるかタブを閉じると止まります。
+msg-llm-waiting-server = LLM サーバーの起動を待っています…
 label-llm-apply-autopaste = LLM 出力を自動ペーストに使用
+label-clipboard-mode = クリップボードの内容:
+option-clipboard-polished = 整形後のテキストのみ
+option-clipboard-raw = 文字起こし原文のみ
+option-clipboard-raw-then-polished = 原文→整形後の順にコピー（原文はクリップボード履歴に残ります）
+option-clipboard-both = 両方（--- で区切る）
 btn-llm-test-connection = 接続テスト
 msg-llm-test-running = テスト中…（数秒かかる場合があります）
+btn-llm-test-cancel = キャンセル
+msg-llm-test-cancelled = 接続テストをキャンセルしました
 label-llm-test-enable = LLM後処理テストを表示
 label-llm-test-no-history = 書き起こし履歴がありません。まず録音を行ってください。
 label-llm-test-select-transcript = テストする書き起こしを選択
@@ -152,6 +374,21 @@ label-llm-test-output = LLM出力
 btn-llm-test-copy-output = コピー
 msg-llm-history-load-failed = 履歴の読み込みに失敗しました
 heading-llm-history = 履歴
+warn-history-stored = 履歴には、LLM後処理を行った各音声入力の文字起こし全文とLLM出力が、このコンピューター上にプレーンテキストで保存されます:
+chk-history-enabled = 履歴を保存する
+label-history-redaction = マスクするパターン
+hint-history-redaction = 正規表現で指定します。一致した部分は保存前に文字起こしとLLM出力の両方で [redacted] に置き換えられます。既存の履歴は変更されません。
+msg-history-redaction-invalid = 無効なパターンです（無視されます）
+btn-history-redaction-add = パターンを追加
+btn-history-redaction-card = カード番号のパターンを追加
+label-history-max-entries = 保持する件数:
+chk-history-retention = 次の日数より古い履歴を削除
+label-history-days = 日
+hint-history-retention = 次の履歴を保存するときに適用されます。範囲外の履歴はすぐに非表示になります。
+btn-history-clear = 履歴を消去
+msg-history-clear-confirm = すべての履歴を削除しますか？
+btn-history-clear-confirm = すべて削除
+msg-history-clear-failed = 履歴を消去できませんでした
 label-llm-history-empty = まだ履歴はありません
 label-llm-history-details = 詳細
 label-llm-history-select-entry = 上の一覧から履歴を選択してください
@@ -162,6 +399,10 @@ label-llm-history-base-url = LLM API Base:
 label-llm-history-language = 言語設定:
 label-llm-history-language-auto = 自動 (Whisper設定)
 label-llm-history-latency = LLM後処理時間:
+label-llm-history-recording = 録音時間:
+label-llm-history-chars = 文字
+label-llm-history-words = 語
+label-llm-history-wpm = 1分あたりの語数:
 label-llm-history-truncated = 入力は最大文字数で切り詰められました
 label-llm-history-transcript = 書き起こしテキスト
 label-llm-history-output = LLM出力
@@ -170,6 +411,19 @@ btn-llm-history-copy-output = LLM出力をコピー
 tooltip-llm-history-copy-transcript = 書き起こしテキストをクリップボードにコピーします
 tooltip-llm-history-copy-output = LLM出力をクリップボードにコピーします
 tooltip-llm-history-select-row = 詳細を表示
+hint-history-search = 書き起こしとLLM出力を検索
+label-history-date = 期間:
+option-history-date-all = すべて
+option-history-date-today = 今日
+option-history-date-7d = 過去7日間
+option-history-date-30d = 過去30日間
+msg-history-no-matches = 条件に一致する履歴はありません
+btn-llm-history-rerun = 再処理
+tooltip-llm-history-rerun = 保存された書き起こしを、左で選んだモードと現在のLLM設定で再処理します。結果はここに表示され、履歴には追加されません
+tooltip-llm-history-rerun-no-transcript = この履歴には書き起こしが保存されていません
+label-llm-history-rerun-output = 再処理の出力
+label-llm-history-diff = LLMによる変更点
+hint-llm-history-diff = 赤の取り消し線: 書き起こしから削除された部分。緑: LLMが追加した部分。
 label-llm-history-duration-column = 処理時間
 label-llm-history-custom-prompts = 使用したカスタムプロンプト
 label-llm-history-custom-system = システムプロンプト
@@ -177,6 +431,21 @@ label-llm-history-custom-user = ユーザープロンプト
 label-dev-tools = 開発ツール:
 btn-launch-setup-wizard = 初回セットアップウィザードを起動
 btn-reset-defaults = 全設定をデフォルトに戻す
+label-config-bundle = 設定ファイル:
+btn-export-config = エクスポート…
+btn-import-config = インポート…
+hint-config-bundle = 設定（カスタムLLMプロンプトを含む）と辞書を1つの.zipにまとめ、別のマシンへ移行できます。マイク/出力デバイス、ウィンドウ位置、モデルのパス、カスタム効果音ファイルは含まれません。
+title-import-config = 設定のインポート
+label-bundle-created = エクスポート日時:
+label-bundle-contents = 内容:
+label-bundle-settings = 設定
+label-bundle-dictionary = 辞書エントリ
+msg-import-config-confirm = 現在の設定と辞書が上書きされます（このマシンのデバイス選択は保持されます）。上書き前に現在のファイルをバックアップします。
+btn-import-config-overwrite = インポートして上書き
+msg-config-exported = エクスポートしました:
+msg-config-export-failed = エクスポートに失敗しました:
+msg-config-imported = インポートしました。以前のファイルのバックアップ:
+msg-config-import-failed = インポートに失敗しました:
 msg-applied-defaults = デフォルト設定を適用しました
 title-reset-defaults = 設定をリセットしますか？
 msg-reset-defaults-confirm = 辞書などのカスタム設定が削除されます。よろしいですか？
@@ -196,8 +465,25 @@ msg-update-download-failed = ダウンロードに失敗しました
 btn-open-downloads = フォルダを開く
 section-dictionary = 辞書
 dict-description = 別名を標準表記に自動置換（長い別名から適用・保存後すぐ反映）
+label-dict-fuzzy-distance = あいまい一致の最大編集数:
+tooltip-dict-fuzzy-distance = あいまい一致を有効にした項目で許容する文字の編集数の上限です。短い別名ほど少なくなります（約4文字につき1）。空白の違いは常に無視されます。
+chk-dict-fuzzy = あいまい一致（近い単語も置換）
+tooltip-dict-fuzzy = 別名とわずかに異なる単語全体も置換します（例: "kuber netes" や複数形）
+label-dict-locales = 言語
+hint-dict-locales = 書き起こし言語（指定または自動検出）がいずれかに一致する場合のみ適用します。未選択の場合はすべての言語に適用します。
 label-save-location = 保存先:
 msg-dict-reloaded = 辞書を再読込しました
+btn-dict-reapply-last = 直前の書き起こしに適用
+tooltip-dict-reapply-last = 保存済みの辞書を直前の書き起こしに再適用し（Whisperは再実行しません）、結果をクリップボードにコピーします
+tooltip-dict-reapply-unavailable = まだ書き起こしがありません
+chk-dict-reapply-llm = LLM後処理も実行する
+msg-dict-reapplied = 直前の書き起こしに辞書を再適用しています。結果はクリップボードにコピーされます
+msg-dict-reapply-busy = 別の文字起こしを処理中です。終わってからもう一度お試しください
+msg-dict-conflicts = 競合しているエントリがあります（どちらの置換が使われるかは不定です）:
+label-dict-conflict-alias = 別名
+label-dict-conflict-canonical = 重複した標準表記
+btn-dict-merge = 統合
+tooltip-dict-merge = これらのエントリを最初の1件に統合します（別名・条件・言語はまとめられます）
 btn-add-entry = 項目を追加
 search-hint = 標準表記・別名・含む語で検索
 btn-clear = クリア
@@ -205,6 +491,8 @@ col-standard = 標準表記
 col-aliases = 別名
 col-include = 適用条件（含む語）
 col-actions = 操作
+col-enabled = 有効
+tooltip-dict-enabled = チェックを外すと、エントリを残したまま置換を止めます
 none = (なし)
 btn-edit = 編集
 btn-delete = 削除
@@ -220,13 +508,46 @@ btn-save-apply = 保存して反映
 msg-dict-saved = [辞書] 変更を保存し反映しました
 msg-dict-save-failed = [辞書] 保存に失敗:
 msg-dict-reload-failed = [辞書] 再読込に失敗:
+btn-dict-import-csv = CSVをインポート
+btn-dict-export-csv = CSVをエクスポート
+msg-dict-import-added = [Dictionary] CSVを取り込みました。追加:
+msg-dict-import-merged = 統合:
+msg-dict-import-skipped = スキップ（標準形が空）:
+msg-dict-import-failed = [Dictionary] CSVの取り込みに失敗しました:
+msg-dict-exported = [Dictionary] CSVを書き出しました:
+msg-dict-export-failed = [Dictionary] CSVの書き出しに失敗しました:
 section-hotkey = ホットキー設定
 label-start-stop-recording = 録音開始/停止:
 hotkey-help-examples = 入力例: Cmd+Shift+R / Ctrl+Alt+F9
 hotkey-help-modifiers = 修飾キー: Ctrl/Control, Shift, Alt/Option, Cmd/Command/Super/Win
 hotkey-help-keys = キー: A–Z, 0–9, F1–F12, Space, Enter/Return, Tab, Escape/Esc
 hotkey-help-separator = 区切りは '+'、大文字小文字どちらでも可（例: cmd+shift+r）
+btn-hotkey-capture = キーを押して設定…
+btn-hotkey-capture-cancel = キャンセル
+tooltip-hotkey-capture = 実際にキーを押してホットキーを記録します。Escape で取り消します。
+label-hotkey-capture = キーの組み合わせを押してください:
+msg-hotkey-capture-invalid = この組み合わせはホットキーとして使用できません
 hotkey-restart-note = ホットキーを変更した場合、アプリ再起動後に有効になります（特にmacOS）。
+chk-ipc-socket = ローカルソケットでコマンドを受け付ける（Waybar・スクリプト）
+tooltip-ipc-socket = PIDを知らなくてもクリック操作などから制御できます。`hootvoice --send toggle` で録音の開始/停止、`hootvoice --send show` で設定を開きます。ソケット:
+note-ipc-socket-restart = ソケットの設定はアプリの再起動後に反映されます。
+label-hotkey-mode = ホットキーの動作:
+label-hotkey-debounce = 連続した押下を無視する間隔:
+tooltip-hotkey-debounce = この間隔内の2回目の開始/停止は無視されるため、誤って2回押しても録音がすぐ止まることはありません。0で無効。
+option-hotkey-toggle = トグル（押すたびに開始/停止）
+option-hotkey-push-to-talk = プッシュトゥトーク（押している間だけ録音）
+hotkey-push-to-talk-note = プッシュトゥトークにはキーを離したイベントが必要です（Windows・macOS・X11）。取得できない環境では2回目の押下で停止します。SIGUSR1 は常にトグル動作です。
+label-hotkey-bindings = 追加のホットキー:
+option-hotkey-action-toggle = 録音開始/停止
+option-hotkey-action-toggle-mode = LLMモードを指定して録音
+option-hotkey-action-settings = 設定を表示
+option-hotkey-action-pause = ホットキーの一時停止/再開
+option-hotkey-action-undo-paste = 直前の貼り付けを取り消す
+option-hotkey-action-paste-last = 直前の文字起こしをもう一度貼り付け
+btn-add-hotkey = ホットキーを追加
+msg-hotkey-invalid = ホットキーの形式が正しくありません
+msg-hotkey-conflict = 他のホットキーと重複しています
+msg-hotkey-conflict-primary = 録音ホットキーが追加のホットキーと重複しています
 
 troubleshoot-hotkey-title = ホットキーが効かない場合
 hotkey-macos-desc = macOS ではグローバルホットキーの取得に ‘入力監視’ 権限が必要です。
@@ -255,6 +576,8 @@ hotkey-windows-check = ・同じショートカットを使用する常駐アプ
 hotkey-windows-try-others = 改善しない場合は、別の組み合わせをお試しください。
 
 troubleshoot-autopaste-title = うまく貼り付かない場合
+label-auto-paste-delay = 貼り付け前の待ち時間:
+tooltip-auto-paste-delay = HootVoiceが隠れてから貼り付けのキー操作を送るまで待つ時間です。貼り付け先アプリのフォーカスが戻る前に貼り付けが失われる場合は大きくしてください。
 autopaste-macos-desc = macOS では、自動ペースト（⌘Vの送信）に以下の権限が必要です。
 autopaste-macos-acc = ・アクセシビリティ: ‘このMacを制御’ を許可（HootVoice を ON）
 autopaste-macos-auto = ・オートメーション: ‘System Events’ の制御を許可（初回に許可ダイアログ）
@@ -281,6 +604,13 @@ autopaste-windows-hints2 = ・管理者権限やグループポリシーの影
 autopaste-windows-note = 代替としてクリップボードコピーのみを利用し、手動で貼り付ける設定もご検討ください。
 
 msg-settings-saved = 設定を保存しました
+msg-settings-reverted = 変更を元に戻しました
+msg-settings-unsaved = 未保存の変更があります
+btn-save = 保存
+btn-revert = 元に戻す
+tooltip-revert-settings = 前回の保存以降の変更を破棄します
+chk-settings-autosave = 設定を自動保存する
+tooltip-settings-autosave = オフにすると、変更はすぐに反映されますが「保存」を押すまで settings.toml に書き込まれません。「元に戻す」で最後に保存した設定に戻ります
 
 wizard-title = 初回セットアップ
 btn-back = 戻る
@@ -319,12 +649,47 @@ status-idle = 待機中
 status-recording = 録音中
 status-processing = 処理中
 status-busy = ビジー
+status-queued = 次の録音を予約済み
+msg-queue-cancelled = 録音の予約を取り消しました
+status-hotkey-paused = ホットキー停止中
+msg-error-model-load = Whisper モデルの読み込みに失敗しました
+msg-model-load-failed-banner = 音声モデルを読み込めませんでした。下でモデルをダウンロードするか選択してください。
+btn-dismiss = 閉じる
+msg-error-no-speech = 音声が検出されませんでした
+msg-error-max-duration = 最大録音時間に達しました
+msg-error-llm = LLM に失敗したため Whisper の結果を使用しました
+msg-error-llm-truncated = LLM への入力を切り詰めました
+msg-error-clipboard = クリップボードにコピーできませんでした
+msg-error-paste = 自動貼り付けに失敗しました
+msg-error-audio-file = 音声ファイルを読み込めませんでした
+msg-error-append-file = メモファイルに文字起こしを追記できませんでした
+msg-error-transcription = 文字起こしに失敗したため貼り付けていません
 status-post-processing = LLM 後処理中
+msg-llm-backoff-active = 失敗が続いたため LLM 後処理を一時停止中です。再試行まで
+btn-llm-retry-now = 今すぐ再試行
 btn-toggle-recording = 録音開始/停止
 btn-show-floating = フローティング表示
+btn-pause-hotkey = ホットキー停止
+tooltip-pause-hotkey = 停止中は録音ホットキーと SIGUSR1 を無視します。このウィンドウからの録音は引き続き使えます。
+btn-undo-paste = 貼り付けを取り消す
+tooltip-undo-paste = 直前に自動貼り付けしたテキストを、文字数分の Backspace で削除します（30秒間有効）。ベストエフォートのため、カーソル移動や入力があると別の文字が消える場合があります。
+btn-retranscribe = 再文字起こし
+tooltip-retranscribe = 直前の録音を現在のモデル・言語・設定でもう一度文字起こしします。結果はクリップボードにコピーされます。
+btn-export-subtitles = 字幕を書き出す…
+tooltip-export-subtitles = 直前の文字起こしをタイムスタンプ付きで SRT または WebVTT として保存します（拡張子 .srt / .vtt で選択）。時刻は録音・ファイルの先頭からの経過時間です。
+msg-subtitles-exported = 字幕を保存しました:
+msg-subtitles-export-failed = 字幕の書き出しに失敗しました:
+msg-transcribing-file = ファイルを文字起こし中:
+msg-drop-not-audio = 音声ファイルではありません（wav, mp3, flac, ogg）:
+hint-drop-audio = 音声ファイルをドロップすると文字起こしします（結果はクリップボードにコピーされます）
 title-debug-log = デバッグログ
 label-auto-scroll = 自動スクロール
 msg-log-cleared = ログをクリアしました
+btn-copy-diagnostics = 診断情報をコピー
+tooltip-copy-diagnostics = 不具合報告用に、バージョン・OS・モデル・デバイス・LLM設定と最近のログをコピーします。APIキーとホームフォルダはマスクされます。
+btn-save-diagnostics = 診断情報を保存…
+msg-diagnostics-copied = 診断情報をクリップボードにコピーしました
+msg-diagnostics-saved = 診断情報を保存しました:
 msg-recording-started = 録音開始
 msg-processing = 処理中...
 msg-recording-stopped = 録音停止
@@ -333,6 +698,11 @@ msg-busy = ビジー状態です。少し待ってください
 # Download status messages
 msg-download-started = ダウンロード開始
 msg-download-completed = ダウンロード完了
+msg-download-verify-failed = ダウンロードしたモデルが破損していたため削除しました。もう一度ダウンロードしてください。
+label-model-mirror = ダウンロードミラー:
+hint-model-mirror = モデルのダウンロード元 huggingface.co を置き換えるベースURL（任意。モデルファイルのパスはそのまま）。空欄で既定のURLを使います。
+label-download-url = ダウンロードURL:
+msg-model-mirror-invalid = ミラーURLが不正です
 
 # Model quality/speed panel
 label-speed = 速度:
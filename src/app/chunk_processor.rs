@@ -5,9 +5,18 @@ use std::time::Instant;
 use unicode_categories::UnicodeCategories;
 use whisper_rs::WhisperContext;
 
-use crate::audio::{SplitDecision, VadStrategy, VoiceActivityDetector};
+use crate::audio::{voiced_range, SplitDecision, VadStrategy, VoiceActivityDetector};
 use crate::core::LogCallback;
-use crate::transcription::{transcribe_with_state, WhisperOptimizationParams};
+use crate::transcription::remote::transcribe_remote;
+use crate::transcription::subtitles::SubtitleCue;
+use crate::transcription::{
+    available_threads, transcribe_with_state, TranscriptionBackend, WhisperOptimizationParams,
+};
+
+/// Upper bound for `parallel_chunks` (each worker holds its own Whisper state)
+pub const MAX_PARALLEL_CHUNKS: usize = 8;
+/// Audio kept around speech when trimming silence, so words aren't clipped
+const TRIM_SILENCE_PAD_MS: u32 = 250;
 
 /// Audio chunk
 #[derive(Clone)]
@@ -26,17 +35,24 @@ pub struct ChunkResult {
     pub start_time: f32,
     pub end_time: f32,
     pub processing_time: f32,
+    // Subtitle timing relative to the whole recording
+    pub cues: Vec<SubtitleCue>,
+    pub language: Option<String>,
 }
 
 /// Chunk-based audio processing (enhanced)
 pub struct ChunkProcessor {
-    ctx: Arc<WhisperContext>,
+    // None when no local model is loaded (remote backend only)
+    ctx: Option<Arc<WhisperContext>>,
     vad: VoiceActivityDetector,
     chunks: Vec<AudioChunk>,
     results: Arc<Mutex<Vec<ChunkResult>>>,
+    // Chunks that could not be transcribed ("chunk N: reason")
+    failures: Arc<Mutex<Vec<String>>>,
     tx: Option<mpsc::Sender<AudioChunk>>,
     rx: Option<mpsc::Receiver<AudioChunk>>,
-    worker_handle: Option<thread::JoinHandle<()>>,
+    worker_handles: Vec<thread::JoinHandle<()>>,
+    workers: usize,
     next_chunk_id: usize,
     current_buffer: Vec<f32>,
     chunk_start_time: f32,
@@ -46,11 +62,15 @@ pub struct ChunkProcessor {
     logger: Option<LogCallback>,
     language: Option<String>,
     optimization_params: Option<WhisperOptimizationParams>,
+    backend: TranscriptionBackend,
+    // Drop leading/trailing silence of each chunk before Whisper
+    trim_silence: bool,
+    silence_threshold: f32,
 }
 
 impl ChunkProcessor {
     pub fn new(
-        ctx: Arc<WhisperContext>,
+        ctx: Option<Arc<WhisperContext>>,
         sample_rate: u32,
         language: Option<String>,
         optimization_params: Option<WhisperOptimizationParams>,
@@ -64,9 +84,11 @@ impl ChunkProcessor {
             vad: VoiceActivityDetector::new_with_strategy(sample_rate, vad_strategy),
             chunks: Vec::new(),
             results,
+            failures: Arc::new(Mutex::new(Vec::new())),
             tx: Some(tx),
             rx: Some(rx),
-            worker_handle: None,
+            worker_handles: Vec::new(),
+            workers: 1,
             next_chunk_id: 0,
             current_buffer: Vec::new(),
             chunk_start_time: 0.0,
@@ -75,9 +97,22 @@ impl ChunkProcessor {
             logger: None,
             language,
             optimization_params,
+            backend: TranscriptionBackend::LocalWhisper,
+            trim_silence: false,
+            silence_threshold: vad_strategy.silence_threshold(),
         }
     }
 
+    /// Local whisper-rs (default) or a remote transcription endpoint
+    pub fn set_backend(&mut self, backend: TranscriptionBackend) {
+        self.backend = backend;
+    }
+
+    /// Cut silence before and after speech in each chunk (with a short pad)
+    pub fn set_trim_silence(&mut self, enabled: bool) {
+        self.trim_silence = enabled;
+    }
+
     /// Set logger (hook to stream logs to GUI)
     pub fn set_logger(&mut self, logger: LogCallback) {
         self.logger = Some(logger);
@@ -95,82 +130,219 @@ impl ChunkProcessor {
         }
     }
 
-    /// Start worker thread
+    /// Transcribe up to `workers` chunks at once (1 = sequential)
+    pub fn set_workers(&mut self, workers: usize) {
+        self.workers = workers.clamp(1, MAX_PARALLEL_CHUNKS);
+    }
+
+    /// Start worker threads
     pub fn start_worker(&mut self) {
-        let ctx = self.ctx.clone();
-        let results = self.results.clone();
-        let rx = self.rx.take().expect("Receiver already taken");
-        let logger = self.logger.clone();
-
-        let lang = self.language.clone();
-        let opt_params = self.optimization_params.clone();
-        let handle = thread::spawn(move || {
-            // Create WhisperState once and reuse it in this worker
-            let mut wstate = match ctx.create_state() {
-                Ok(s) => s,
-                Err(e) => {
-                    eprintln!("[Whisper] Failed to create state: {}", e);
-                    return;
-                }
-            };
+        let rx = Arc::new(Mutex::new(self.rx.take().expect("Receiver already taken")));
+        let workers = self.workers;
+        let mut opt_params = self.optimization_params.clone();
+        let fallback = opt_params
+            .as_ref()
+            .and_then(|o| o.language_fallback.as_ref());
+        if self.backend.is_remote() && self.language.is_none() && fallback.is_some() {
+            self.log_line(
+                "[Warning] Auto-detect fallback needs the local model; \
+                 the remote endpoint picks the language itself",
+            );
+        }
+        if workers > 1 && !self.backend.is_remote() {
+            // Split the CPU between workers instead of oversubscribing it
+            let opt = opt_params.get_or_insert_with(WhisperOptimizationParams::default);
+            if opt.n_threads == 0 {
+                opt.n_threads =
+                    (available_threads(opt.use_physical_cores) / workers as i32).clamp(1, 4);
+            }
+            self.log_line(&format!(
+                "[Whisper] Parallel chunks: {} workers × {} threads",
+                workers, opt.n_threads
+            ));
+        }
 
-            while let Ok(chunk) = rx.recv() {
-                let start_time = Instant::now();
-
-                // Run Whisper inference
-                if let Ok(result) = transcribe_with_state(
-                    &mut wstate,
-                    &chunk.samples,
-                    lang.as_deref(),
-                    opt_params.as_ref(),
-                ) {
-                    // Filter non-speech noise
-                    let text = filter_noise_text(&result.text);
-
-                    if !text.is_empty() {
-                        let chunk_result = ChunkResult {
-                            id: chunk.id,
-                            text,
-                            start_time: chunk.start_time,
-                            end_time: chunk.start_time + chunk.duration,
-                            processing_time: start_time.elapsed().as_secs_f32(),
-                        };
-
-                        // Save result
-                        if let Ok(mut results) = results.lock() {
-                            results.push(chunk_result.clone());
-                            results.sort_by_key(|r| r.id);
-
-                            // Realtime log output
+        for index in 0..workers {
+            let ctx = self.ctx.clone();
+            let results = self.results.clone();
+            let failures = self.failures.clone();
+            let rx = rx.clone();
+            let logger = self.logger.clone();
+            let lang = self.language.clone();
+            let opt_params = opt_params.clone();
+            let backend = self.backend.clone();
+            self.worker_handles.push(thread::spawn(move || {
+                // Each local worker owns a WhisperState (model weights are shared via ctx)
+                let mut wstate = match &backend {
+                    TranscriptionBackend::RemoteHttp { .. } => None,
+                    // Without a model every chunk fails below
+                    TranscriptionBackend::LocalWhisper => match ctx
+                        .as_ref()
+                        .map(|c| c.create_state())
+                    {
+                        None => None,
+                        Some(Ok(s)) => Some(s),
+                        Some(Err(e)) => {
+                            // Remaining workers keep draining the queue
+                            eprintln!("[Whisper] Failed to create state (worker {}): {}", index, e);
+                            return;
+                        }
+                    },
+                };
+
+                loop {
+                    // Lock only while taking the next chunk
+                    let next = rx.lock().unwrap().recv();
+                    let Ok(chunk) = next else {
+                        break;
+                    };
+                    let start_time = Instant::now();
+
+                    // Run Whisper inference (locally or on the remote endpoint)
+                    let result = match (wstate.as_mut(), &backend) {
+                        (Some(state), _) => transcribe_with_state(
+                            state,
+                            &chunk.samples,
+                            lang.as_deref(),
+                            opt_params.as_ref(),
+                        ),
+                        (
+                            None,
+                            TranscriptionBackend::RemoteHttp {
+                                base_url,
+                                api_key,
+                                model,
+                            },
+                        ) => transcribe_remote(
+                            base_url,
+                            api_key,
+                            model,
+                            &chunk.samples,
+                            lang.as_deref(),
+                            opt_params.as_ref().map(|o| o.task).unwrap_or_default(),
+                        ),
+                        (None, TranscriptionBackend::LocalWhisper) => {
+                            Err(anyhow::anyhow!("no local Whisper model loaded"))
+                        }
+                    };
+                    // A missing chunk leaves a hole in the transcript: recorded so
+                    // the caller can discard the whole result
+                    if let Err(e) = &result {
+                        let line =
+                            format!("\n  ❌ [Chunk{}] Transcription failed: {:#}", chunk.id, e);
+                        match logger {
+                            Some(ref lg) => lg(&line),
+                            None => eprintln!("{}", line),
+                        }
+                        if let Ok(mut failures) = failures.lock() {
+                            failures.push(format!("chunk {}: {:#}", chunk.id, e));
+                        }
+                    }
+                    if let Ok(result) = result {
+                        if let Some(d) = &result.detection {
+                            let line = format!(
+                                "\n  🌐 [Chunk{}] Detected language: {} (p={:.2}){}",
+                                chunk.id,
+                                d.detected,
+                                d.confidence,
+                                if d.fell_back {
+                                    format!(
+                                        " → fallback {}",
+                                        result.language.as_deref().unwrap_or("?")
+                                    )
+                                } else {
+                                    String::new()
+                                }
+                            );
+                            match logger {
+                                Some(ref lg) => lg(&line),
+                                None => println!("{}", line),
+                            }
+                        }
+                        for seg in &result.dropped_segments {
                             let line = format!(
-                                "\n  ✅ [Chunk{}] {:.1}s-{:.1}s: {} (proc: {:.2}s)",
-                                chunk_result.id,
-                                chunk_result.start_time,
-                                chunk_result.end_time,
-                                chunk_result.text,
-                                chunk_result.processing_time
+                                "\n  🗑 [Chunk{}] Dropped low-confidence segment (p={:.2}): {}",
+                                chunk.id,
+                                seg.confidence(),
+                                seg.text.trim()
                             );
+                            match logger {
+                                Some(ref lg) => lg(&line),
+                                None => println!("{}", line),
+                            }
+                        }
+                        // Filter non-speech noise
+                        let text = filter_noise_text(&result.text);
+
+                        if !text.is_empty() {
+                            let end_time = chunk.start_time + chunk.duration;
+                            // Segment times are relative to the chunk; shift by its offset
+                            let cues = if result.segments.len() > 1 {
+                                result
+                                    .segments
+                                    .iter()
+                                    .filter_map(|seg| {
+                                        let text = filter_noise_text(&seg.text);
+                                        (!text.is_empty()).then(|| SubtitleCue {
+                                            start: chunk.start_time + seg.start,
+                                            end: (chunk.start_time + seg.end).min(end_time),
+                                            text,
+                                        })
+                                    })
+                                    .collect()
+                            } else {
+                                vec![SubtitleCue {
+                                    start: chunk.start_time,
+                                    end: end_time,
+                                    text: text.clone(),
+                                }]
+                            };
+                            let chunk_result = ChunkResult {
+                                id: chunk.id,
+                                text,
+                                start_time: chunk.start_time,
+                                end_time,
+                                processing_time: start_time.elapsed().as_secs_f32(),
+                                cues,
+                                language: result.language.clone(),
+                            };
+
+                            // Save result (kept in chunk order when workers finish out of order)
+                            if let Ok(mut results) = results.lock() {
+                                results.push(chunk_result.clone());
+                                results.sort_by_key(|r| r.id);
+
+                                // Realtime log output
+                                let line = format!(
+                                    "\n  ✅ [Chunk{}] {:.1}s-{:.1}s: {} (proc: {:.2}s)",
+                                    chunk_result.id,
+                                    chunk_result.start_time,
+                                    chunk_result.end_time,
+                                    chunk_result.text,
+                                    chunk_result.processing_time
+                                );
+                                if let Some(ref lg) = logger {
+                                    lg(&line);
+                                } else {
+                                    println!("{}", line);
+                                    use std::io::Write;
+                                    let _ = std::io::stdout().flush();
+                                }
+                            }
+                        } else {
+                            let line =
+                                format!("\n  ⏭️  [Chunk{}] No speech / noise only", chunk.id);
                             if let Some(ref lg) = logger {
                                 lg(&line);
                             } else {
                                 println!("{}", line);
-                                use std::io::Write;
-                                let _ = std::io::stdout().flush();
                             }
                         }
-                    } else {
-                        let line = format!("\n  ⏭️  [Chunk{}] No speech / noise only", chunk.id);
-                        if let Some(ref lg) = logger {
-                            lg(&line);
-                        } else {
-                            println!("{}", line);
-                        }
                     }
                 }
-            }
-        });
+            }));
+        }
 
-        self.worker_handle = Some(handle);
         self.vad.start_recording();
     }
 
@@ -206,11 +378,35 @@ impl ChunkProcessor {
             return;
         }
 
+        let mut range = 0..self.current_buffer.len();
+        if self.trim_silence {
+            match voiced_range(
+                &self.current_buffer,
+                sample_rate,
+                self.silence_threshold,
+                TRIM_SILENCE_PAD_MS,
+            ) {
+                Some(voiced) => range = voiced,
+                None => {
+                    self.log_line("  ⏭️  Skip silent chunk (trimmed)");
+                    self.reset_buffer();
+                    return;
+                }
+            }
+            let trimmed = self.current_buffer.len() - range.len();
+            if trimmed > 0 {
+                self.log_line(&format!(
+                    "  ✂️  Trimmed {:.1}s of silence",
+                    trimmed as f32 / sample_rate as f32
+                ));
+            }
+        }
+
         let chunk = AudioChunk {
             id: self.next_chunk_id,
-            samples: self.current_buffer.clone(),
-            start_time: self.chunk_start_time,
-            duration: self.current_buffer.len() as f32 / sample_rate as f32,
+            samples: self.current_buffer[range.clone()].to_vec(),
+            start_time: self.chunk_start_time + range.start as f32 / sample_rate as f32,
+            duration: range.len() as f32 / sample_rate as f32,
         };
 
         // Realtime log output
@@ -220,7 +416,7 @@ impl ChunkProcessor {
         );
         self.log_line(&log_line);
 
-        // Send to worker thread
+        // Send to worker threads
         if let Some(tx) = &self.tx {
             let _ = tx.send(chunk.clone());
         }
@@ -252,11 +448,11 @@ impl ChunkProcessor {
             }
         }
 
-        // Stop worker thread
+        // Stop worker threads
         drop(self.tx.take());
 
-        // Wait for worker thread to finish
-        if let Some(handle) = self.worker_handle.take() {
+        // Wait for worker threads to finish
+        for handle in self.worker_handles.drain(..) {
             let _ = handle.join();
         }
 
@@ -264,6 +460,39 @@ impl ChunkProcessor {
         self.results.lock().map(|r| r.clone()).unwrap_or_default()
     }
 
+    /// Chunks that failed to transcribe (complete after `finish`); any failure
+    /// means the combined text has gaps
+    pub fn failures(&self) -> Vec<String> {
+        self.failures.lock().map(|f| f.clone()).unwrap_or_default()
+    }
+
+    /// Stop without transcribing the remaining buffer (results are discarded)
+    pub fn cancel(&mut self) {
+        drop(self.tx.take());
+        for handle in self.worker_handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+
+    /// Language spoken in most of the audio (by chunk duration)
+    pub fn dominant_language(results: &[ChunkResult]) -> Option<String> {
+        let mut totals: Vec<(&str, f32)> = Vec::new();
+        for r in results {
+            let Some(lang) = r.language.as_deref() else {
+                continue;
+            };
+            let secs = r.end_time - r.start_time;
+            match totals.iter_mut().find(|(l, _)| *l == lang) {
+                Some((_, total)) => *total += secs,
+                None => totals.push((lang, secs)),
+            }
+        }
+        totals
+            .into_iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(lang, _)| lang.to_string())
+    }
+
     /// Concatenate all text
     pub fn combine_results(results: &[ChunkResult]) -> String {
         // Remove adjacent overlap while concatenating (respect UTF‑8 boundaries)
@@ -1,5 +1,10 @@
+pub mod device;
+pub mod preroll;
 pub mod stream;
 pub mod vad;
 
 // removed unused re-exports to reduce public surface
-pub use vad::{SplitDecision, VadStrategy, VoiceActivityDetector};
+pub use vad::{
+    calculate_rms, recent_levels, voiced_duration, voiced_range, SplitDecision, VadStrategy,
+    VoiceActivityDetector, VAD_SILENCE_DB_RANGE,
+};
@@ -0,0 +1,75 @@
+/// Stable identifiers for a host's input devices, in enumeration order.
+/// CPAL 0.15 has no persistent device ID, so the device name is used (it
+/// survives replugs, unlike the index); duplicates get "#2", "#3", ...
+pub fn input_device_uids(names: &[String]) -> Vec<String> {
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, n)| {
+            let dup = names[..i].iter().filter(|m| *m == n).count();
+            if dup == 0 {
+                n.clone()
+            } else {
+                format!("{}#{}", n, dup + 1)
+            }
+        })
+        .collect()
+}
+
+/// CPAL names for the devices ("(unknown)" when the backend fails)
+pub fn input_device_names(devices: &[cpal::Device]) -> Vec<String> {
+    use cpal::traits::DeviceTrait;
+    devices
+        .iter()
+        .map(|d| d.name().unwrap_or_else(|_| "(unknown)".to_string()))
+        .collect()
+}
+
+/// Availability change of the preferred input device between two polls
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PresenceChange {
+    Lost,
+    Returned,
+}
+
+/// `was_present` is None before the first poll; a device missing at the first
+/// poll counts as lost.
+pub fn presence_change(was_present: Option<bool>, present: bool) -> Option<PresenceChange> {
+    match (was_present, present) {
+        (Some(true) | None, false) => Some(PresenceChange::Lost),
+        (Some(false), true) => Some(PresenceChange::Returned),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{input_device_uids, presence_change, PresenceChange};
+
+    #[test]
+    fn duplicate_names_get_ordinals() {
+        let names: Vec<String> = ["USB Mic", "Built-in", "USB Mic"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            input_device_uids(&names),
+            vec!["USB Mic", "Built-in", "USB Mic#2"]
+        );
+    }
+
+    #[test]
+    fn presence_transitions() {
+        assert_eq!(presence_change(None, true), None);
+        assert_eq!(presence_change(None, false), Some(PresenceChange::Lost));
+        assert_eq!(
+            presence_change(Some(true), false),
+            Some(PresenceChange::Lost)
+        );
+        assert_eq!(presence_change(Some(false), false), None);
+        assert_eq!(
+            presence_change(Some(false), true),
+            Some(PresenceChange::Returned)
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::transcription::WHISPER_SAMPLE_RATE;
+
+// Samples older than this are treated as stale (monitor stream stopped/failed)
+const STALE_AFTER: Duration = Duration::from_millis(250);
+
+/// Rolling buffer of the most recent 16k mono samples captured before recording starts
+#[derive(Default)]
+pub struct PrerollBuffer {
+    samples: VecDeque<f32>,
+    capacity: usize,
+    last_push: Option<Instant>,
+}
+
+impl PrerollBuffer {
+    /// Resize to hold `ms` milliseconds (0 disables and drops everything)
+    pub fn set_duration_ms(&mut self, ms: u32) {
+        self.capacity = WHISPER_SAMPLE_RATE as usize * ms as usize / 1000;
+        if self.capacity == 0 {
+            self.clear();
+            return;
+        }
+        while self.samples.len() > self.capacity {
+            self.samples.pop_front();
+        }
+    }
+
+    pub fn push(&mut self, data: &[f32]) {
+        if self.capacity == 0 {
+            return;
+        }
+        let data = if data.len() > self.capacity {
+            &data[data.len() - self.capacity..]
+        } else {
+            data
+        };
+        let overflow = (self.samples.len() + data.len()).saturating_sub(self.capacity);
+        self.samples.drain(..overflow);
+        self.samples.extend(data.iter().copied());
+        self.last_push = Some(Instant::now());
+    }
+
+    /// Take the buffered audio and reset; returns nothing when the data is stale
+    pub fn take(&mut self) -> Vec<f32> {
+        let fresh = self
+            .last_push
+            .map(|t| t.elapsed() <= STALE_AFTER)
+            .unwrap_or(false);
+        let out = if fresh {
+            self.samples.drain(..).collect()
+        } else {
+            Vec::new()
+        };
+        self.clear();
+        out
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+        self.last_push = None;
+    }
+}
@@ -1,46 +1,229 @@
 use anyhow::Result;
 use cpal::traits::DeviceTrait;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 pub fn build_input_stream_f32(
     device: &cpal::Device,
     config: cpal::StreamConfig,
     sample_format: cpal::SampleFormat,
     buf: Arc<Mutex<Vec<f32>>>,
+    stats: Arc<StreamStats>,
 ) -> Result<cpal::Stream> {
+    let err_stats = stats.clone();
+    let on_error = move |_: cpal::StreamError| err_stats.record_error();
     Ok(match sample_format {
         cpal::SampleFormat::I16 => device.build_input_stream(
             &config,
-            move |data: &[i16], _| {
+            move |data: &[i16], info| {
+                stats.record_buffer(data.len(), info);
                 let mut b = buf.lock().unwrap();
                 b.reserve(data.len());
                 for &s in data {
                     b.push(s as f32 / 32768.0);
                 }
             },
-            move |e| eprintln!("cpal error: {e}"),
+            on_error,
             None,
         )?,
         cpal::SampleFormat::U16 => device.build_input_stream(
             &config,
-            move |data: &[u16], _| {
+            move |data: &[u16], info| {
+                stats.record_buffer(data.len(), info);
                 let mut b = buf.lock().unwrap();
                 b.reserve(data.len());
                 for &s in data {
                     b.push((s as f32 - 32768.0) / 32768.0);
                 }
             },
-            move |e| eprintln!("cpal error: {e}"),
+            on_error,
             None,
         )?,
         _ => device.build_input_stream(
             &config,
-            move |data: &[f32], _| {
+            move |data: &[f32], info| {
+                stats.record_buffer(data.len(), info);
                 let mut b = buf.lock().unwrap();
                 b.extend_from_slice(data);
             },
-            move |e| eprintln!("cpal error: {e}"),
+            on_error,
             None,
         )?,
     })
 }
+
+/// Capture health for the microphone test: CPAL stream errors (xruns, device
+/// errors), gaps between buffers, capture latency and the real sample rate.
+pub struct StreamStats {
+    nominal_rate: u32,
+    channels: usize,
+    errors: AtomicU64,
+    timing: Mutex<Timing>,
+}
+
+// Callback times jitter by about a buffer; over this much audio that stays well
+// below the drift worth reporting
+const MIN_RATE_WINDOW_SECS: u64 = 5;
+
+#[derive(Default)]
+struct Timing {
+    // First capture timestamp; later buffers are measured from it
+    capture_origin: Option<cpal::StreamInstant>,
+    // Where the next buffer should start if nothing was lost
+    next_capture: Option<Duration>,
+    first_callback: Option<Instant>,
+    last_callback: Option<Instant>,
+    // Frames after the first buffer (the wall-clock span starts at its arrival)
+    frames_after_first: u64,
+    dropouts: u64,
+    latency_sum: Duration,
+    latency_max: Duration,
+    latency_count: u32,
+}
+
+impl Timing {
+    fn record(
+        &mut self,
+        frames: usize,
+        capture_at: Option<Duration>,
+        latency: Option<Duration>,
+        now: Instant,
+        rate: u32,
+    ) {
+        let buffer_len = Duration::from_secs_f64(frames as f64 / rate.max(1) as f64);
+        if let (Some(at), Some(expected)) = (capture_at, self.next_capture) {
+            // Jitter below half a buffer (min 5 ms) is normal scheduling noise
+            let tolerance = (buffer_len / 2).max(Duration::from_millis(5));
+            if at > expected + tolerance {
+                self.dropouts += 1;
+            }
+        }
+        self.next_capture = capture_at.map(|at| at + buffer_len);
+        if let Some(latency) = latency {
+            self.latency_sum += latency;
+            self.latency_max = self.latency_max.max(latency);
+            self.latency_count += 1;
+        }
+        if self.first_callback.is_none() {
+            self.first_callback = Some(now);
+        } else {
+            self.frames_after_first += frames as u64;
+        }
+        self.last_callback = Some(now);
+    }
+
+    fn measured_rate(&self, nominal_rate: u32) -> Option<f64> {
+        if self.frames_after_first < nominal_rate as u64 * MIN_RATE_WINDOW_SECS {
+            return None;
+        }
+        let span = self.last_callback? - self.first_callback?;
+        (!span.is_zero()).then(|| self.frames_after_first as f64 / span.as_secs_f64())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StreamReport {
+    pub stream_errors: u64,
+    pub dropouts: u64,
+    pub avg_latency_ms: Option<f32>,
+    pub max_latency_ms: Option<f32>,
+    pub nominal_rate: u32,
+    /// Frames per wall-clock second (None until `MIN_RATE_WINDOW_SECS` of audio arrived)
+    pub measured_rate: Option<f64>,
+}
+
+impl StreamReport {
+    /// Measured rate vs. nominal in parts per million
+    pub fn drift_ppm(&self) -> Option<f64> {
+        let measured = self.measured_rate?;
+        Some((measured / self.nominal_rate.max(1) as f64 - 1.0) * 1e6)
+    }
+}
+
+impl StreamStats {
+    pub fn new(nominal_rate: u32, channels: u16) -> Self {
+        Self {
+            nominal_rate,
+            channels: channels.max(1) as usize,
+            errors: AtomicU64::new(0),
+            timing: Mutex::new(Timing::default()),
+        }
+    }
+
+    pub fn record_error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Called from the data callback with the interleaved sample count
+    pub fn record_buffer(&self, samples: usize, info: &cpal::InputCallbackInfo) {
+        let ts = info.timestamp();
+        let Ok(mut timing) = self.timing.lock() else {
+            return;
+        };
+        let origin = *timing.capture_origin.get_or_insert(ts.capture);
+        timing.record(
+            samples / self.channels,
+            ts.capture.duration_since(&origin),
+            ts.callback.duration_since(&ts.capture),
+            Instant::now(),
+            self.nominal_rate,
+        );
+    }
+
+    pub fn report(&self) -> StreamReport {
+        let timing = self.timing.lock().unwrap();
+        let ms = |d: Duration| d.as_secs_f32() * 1000.0;
+        StreamReport {
+            stream_errors: self.errors.load(Ordering::Relaxed),
+            dropouts: timing.dropouts,
+            avg_latency_ms: (timing.latency_count > 0)
+                .then(|| ms(timing.latency_sum) / timing.latency_count as f32),
+            max_latency_ms: (timing.latency_count > 0).then(|| ms(timing.latency_max)),
+            nominal_rate: self.nominal_rate,
+            measured_rate: timing.measured_rate(self.nominal_rate),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StreamReport, Timing};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn counts_gaps_and_measures_rate() {
+        let mut timing = Timing::default();
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        // 10 ms buffers at 48 kHz; the fourth arrives 30 ms late
+        for (i, at) in [0, 10, 20, 60, 70].into_iter().enumerate() {
+            let now = start + ms(250 * i as u64);
+            timing.record(480, Some(ms(at)), Some(ms(2 + i as u64)), now, 48_000);
+        }
+        assert_eq!(timing.dropouts, 1);
+        assert_eq!(timing.latency_max, ms(6));
+        assert_eq!(timing.frames_after_first, 4 * 480);
+        // A second of audio is too little to tell drift from callback jitter
+        assert_eq!(timing.measured_rate(48_000), None);
+
+        // 10 ms buffers for 6 s, each callback up to 4 ms late
+        let mut timing = Timing::default();
+        for i in 0..=600u64 {
+            let jitter = Duration::from_micros(i * 7919 % 4000);
+            timing.record(480, None, None, start + ms(10 * i) + jitter, 48_000);
+        }
+        let rate = timing.measured_rate(48_000).expect("long enough");
+        assert!((rate - 48_000.0).abs() < 48.0, "{}", rate);
+
+        let report = StreamReport {
+            stream_errors: 0,
+            dropouts: 1,
+            avg_latency_ms: None,
+            max_latency_ms: None,
+            nominal_rate: 48_000,
+            measured_rate: Some(48_048.0),
+        };
+        assert!((report.drift_ppm().unwrap() - 1000.0).abs() < 1e-6);
+    }
+}
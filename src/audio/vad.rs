@@ -4,11 +4,66 @@ use std::time::Instant;
 
 /// Voice Activity Detection (VAD) for automatic audio segmentation
 /// Ported and refined from a Python version
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "kebab-case")]
 pub enum VadStrategy {
     Normal,
     Aggressive,
+    /// User-tuned thresholds (for noisy rooms where the presets split poorly)
+    Custom {
+        /// Level below which a frame counts as silence (dBFS RMS)
+        silence_db: f32,
+        /// Silence required before splitting
+        min_silence_ms: u32,
+        /// Minimum chunk length before a silence may split it
+        min_speech_ms: u32,
+    },
+}
+
+pub const VAD_SILENCE_DB_RANGE: std::ops::RangeInclusive<f32> = -70.0..=-20.0;
+
+impl VadStrategy {
+    /// Parameters equivalent to this strategy, as (silence_db, min_silence_ms, min_speech_ms)
+    pub fn params(&self) -> (f32, u32, u32) {
+        match *self {
+            VadStrategy::Normal => (amplitude_to_db(0.005), 2000, 3000),
+            VadStrategy::Aggressive => (amplitude_to_db(0.007), 1000, 1500),
+            VadStrategy::Custom {
+                silence_db,
+                min_silence_ms,
+                min_speech_ms,
+            } => (silence_db, min_silence_ms, min_speech_ms),
+        }
+    }
+
+    /// Convert to a Custom strategy starting from the current values
+    pub fn to_custom(self) -> VadStrategy {
+        let (silence_db, min_silence_ms, min_speech_ms) = self.params();
+        VadStrategy::Custom {
+            silence_db,
+            min_silence_ms,
+            min_speech_ms,
+        }
+    }
+
+    /// RMS amplitude used for the silence decision
+    pub fn silence_threshold(&self) -> f32 {
+        match *self {
+            VadStrategy::Normal => 0.005,
+            VadStrategy::Aggressive => 0.007,
+            VadStrategy::Custom { silence_db, .. } => db_to_amplitude(
+                silence_db.clamp(*VAD_SILENCE_DB_RANGE.start(), *VAD_SILENCE_DB_RANGE.end()),
+            ),
+        }
+    }
+}
+
+fn amplitude_to_db(amp: f32) -> f32 {
+    20.0 * amp.max(1e-6).log10()
+}
+
+fn db_to_amplitude(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
 }
 
 /// Voice Activity Detection (VAD) for automatic audio segmentation
@@ -32,8 +87,9 @@ pub struct VoiceActivityDetector {
     recording_started: Option<Instant>,
     /// Start time of current chunk
     chunk_started: Option<Instant>,
-    /// Time when silence started
-    silence_started: Option<Instant>,
+    /// Samples of silence since the last speech frame (counted in audio, not
+    /// wall-clock time, so file input fed in a tight loop splits like live capture)
+    silence_samples: usize,
     /// Total samples processed
     total_samples: usize,
     /// Samples processed in current chunk
@@ -57,7 +113,7 @@ impl VoiceActivityDetector {
             strategy,
             recording_started: None,
             chunk_started: None,
-            silence_started: None,
+            silence_samples: 0,
             total_samples: 0,
             chunk_samples: 0,
             speech_frames: 0,
@@ -82,6 +138,16 @@ impl VoiceActivityDetector {
                 self.min_chunk_duration = 1.5;
                 self.max_chunk_duration = 25.0;
             }
+            VadStrategy::Custom {
+                min_silence_ms,
+                min_speech_ms,
+                ..
+            } => {
+                self.silence_threshold = strategy.silence_threshold();
+                self.base_silence_duration = (min_silence_ms.max(100) as f32) / 1000.0;
+                self.min_chunk_duration = (min_speech_ms as f32) / 1000.0;
+                self.max_chunk_duration = 30.0;
+            }
         }
     }
 
@@ -119,6 +185,8 @@ impl VoiceActivityDetector {
                     0.2
                 }
             }
+            // User asked for a fixed silence length; only the max-length split still applies
+            VadStrategy::Custom { .. } => self.base_silence_duration,
         }
     }
 
@@ -178,45 +246,40 @@ impl VoiceActivityDetector {
         let is_silent = rms < self.silence_threshold;
 
         if is_silent {
-            // Record when silence started
-            if self.silence_started.is_none() {
-                self.silence_started = Some(Instant::now());
-            }
+            self.silence_samples += samples.len();
 
             // Check how long silence has lasted
-            if let Some(silence_start) = self.silence_started {
-                let silence_duration = silence_start.elapsed().as_secs_f32();
-
-                // Debug: print accumulated silence for long chunks
-                if chunk_duration > 8.0 && silence_duration > 0.3 {
-                    println!(
-                        "  [silence: {:.1}s / required: {:.1}s @ {:.1}s]",
-                        silence_duration, required_silence_duration, chunk_duration
-                    );
-                }
+            let silence_duration = self.silence_samples as f32 / self.sample_rate as f32;
+
+            // Debug: print accumulated silence for long chunks
+            if chunk_duration > 8.0 && silence_duration > 0.3 {
+                println!(
+                    "  [silence: {:.1}s / required: {:.1}s @ {:.1}s]",
+                    silence_duration, required_silence_duration, chunk_duration
+                );
+            }
 
-                // If min chunk length is met and required silence exceeded
-                if chunk_duration >= self.min_chunk_duration
-                    && silence_duration >= required_silence_duration
-                {
-                    // Ensure the chunk contains speech
-                    if self.contains_speech() {
-                        let reason = format!(
-                            "detected {:.1}s silence after {:.1}s",
-                            silence_duration, chunk_duration
-                        );
-                        self.reset_chunk();
-                        return SplitDecision::Split { reason };
-                    } else {
-                        println!("  Skipping silence-only chunk");
-                        self.reset_chunk();
-                        return SplitDecision::Skip;
-                    }
+            // If min chunk length is met and required silence exceeded
+            if chunk_duration >= self.min_chunk_duration
+                && silence_duration >= required_silence_duration
+            {
+                // Ensure the chunk contains speech
+                if self.contains_speech() {
+                    let reason = format!(
+                        "detected {:.1}s silence after {:.1}s",
+                        silence_duration, chunk_duration
+                    );
+                    self.reset_chunk();
+                    return SplitDecision::Split { reason };
+                } else {
+                    println!("  Skipping silence-only chunk");
+                    self.reset_chunk();
+                    return SplitDecision::Skip;
                 }
             }
         } else {
             // Reset silence timer when speech is present
-            self.silence_started = None;
+            self.silence_samples = 0;
         }
 
         // Force split at maximum chunk length
@@ -236,7 +299,7 @@ impl VoiceActivityDetector {
     /// Reset chunk tracking
     fn reset_chunk(&mut self) {
         self.chunk_started = Some(Instant::now());
-        self.silence_started = None;
+        self.silence_samples = 0;
         self.chunk_samples = 0;
         self.speech_frames = 0;
         self.total_frames = 0;
@@ -257,8 +320,41 @@ pub enum SplitDecision {
     Skip,
 }
 
+/// Seconds of audio above `threshold`, measured in 100 ms frames like the live VAD
+pub fn voiced_duration(samples: &[f32], sample_rate: u32, threshold: f32) -> f32 {
+    let frame = (sample_rate as usize / 10).max(1);
+    let voiced = samples
+        .chunks(frame)
+        .filter(|f| calculate_rms(f) > threshold)
+        .map(|f| f.len())
+        .sum::<usize>();
+    voiced as f32 / sample_rate.max(1) as f32
+}
+
+/// Samples from the first to the last voiced 100 ms frame, widened by `pad_ms`
+/// on each side so word onsets/tails survive. None when nothing is voiced.
+pub fn voiced_range(
+    samples: &[f32],
+    sample_rate: u32,
+    threshold: f32,
+    pad_ms: u32,
+) -> Option<std::ops::Range<usize>> {
+    let frame = (sample_rate as usize / 10).max(1);
+    let mut voiced = samples
+        .chunks(frame)
+        .enumerate()
+        .filter(|(_, f)| calculate_rms(f) > threshold)
+        .map(|(i, _)| i);
+    let first = voiced.next()?;
+    let last = voiced.last().unwrap_or(first);
+    let pad = (sample_rate as u64 * pad_ms as u64 / 1000) as usize;
+    let start = (first * frame).saturating_sub(pad);
+    let end = ((last + 1) * frame).saturating_add(pad).min(samples.len());
+    Some(start..end)
+}
+
 /// Compute RMS (Root Mean Square)
-fn calculate_rms(samples: &[f32]) -> f32 {
+pub fn calculate_rms(samples: &[f32]) -> f32 {
     if samples.is_empty() {
         return 0.0;
     }
@@ -266,3 +362,83 @@ fn calculate_rms(samples: &[f32]) -> f32 {
     let sum_squares: f32 = samples.iter().map(|s| s * s).sum();
     (sum_squares / samples.len() as f32).sqrt()
 }
+
+/// Levels of the last `count` windows of `window` samples (oldest first) for
+/// live displays: RMS mapped from -60..0 dBFS to 0..1. Missing history is 0.
+pub fn recent_levels(samples: &[f32], window: usize, count: usize) -> Vec<f32> {
+    let window = window.max(1);
+    let mut levels = vec![0.0; count];
+    let full = samples.len() / window;
+    for (slot, chunk_idx) in levels.iter_mut().rev().zip((0..full).rev()) {
+        let chunk = &samples[chunk_idx * window..(chunk_idx + 1) * window];
+        let db = 20.0 * calculate_rms(chunk).max(1e-9).log10();
+        *slot = ((db.clamp(-60.0, 0.0) + 60.0) / 60.0).clamp(0.0, 1.0);
+    }
+    levels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        recent_levels, voiced_duration, voiced_range, SplitDecision, VadStrategy,
+        VoiceActivityDetector,
+    };
+
+    #[test]
+    fn recent_levels_are_newest_last() {
+        // 3 windows: silence, full scale, 0.001 (-60 dBFS); a partial window is ignored
+        let mut samples = vec![0.0; 4];
+        samples.extend([1.0; 4]);
+        samples.extend([0.001; 4]);
+        samples.extend([1.0; 2]);
+        let levels = recent_levels(&samples, 4, 4);
+        assert_eq!(levels.len(), 4);
+        assert_eq!(levels[0], 0.0);
+        assert_eq!(levels[1], 0.0);
+        assert!((levels[2] - 1.0).abs() < 1e-4);
+        assert!(levels[3] < 1e-3);
+        assert_eq!(recent_levels(&[], 4, 2), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn splits_file_input_on_pauses_without_waiting() {
+        // 4 s speech, 2.5 s silence, 2 s speech (16 kHz), fed back to back in 100 ms pieces
+        let mut samples = vec![0.1f32; 64_000];
+        samples.resize(104_000, 0.0);
+        samples.resize(136_000, 0.1);
+        let mut vad = VoiceActivityDetector::new_with_strategy(16_000, VadStrategy::Normal);
+        let mut splits = Vec::new();
+        let mut fed = 0;
+        for piece in samples.chunks(1_600) {
+            fed += piece.len();
+            if let SplitDecision::Split { .. } = vad.process_audio(piece) {
+                splits.push(fed);
+            }
+        }
+        // Normal needs 2 s of silence: the cut lands 2 s into the pause
+        assert_eq!(splits, vec![96_000]);
+    }
+
+    #[test]
+    fn counts_only_frames_above_threshold() {
+        // 1 s of silence, then 0.5 s at 0.1 amplitude (16 kHz)
+        let mut samples = vec![0.0f32; 16_000];
+        samples.resize(24_000, 0.1);
+        let voiced = voiced_duration(&samples, 16_000, 0.01);
+        assert!((voiced - 0.5).abs() < 1e-3, "voiced = {}", voiced);
+        assert_eq!(voiced_duration(&[], 16_000, 0.01), 0.0);
+    }
+
+    #[test]
+    fn voiced_range_trims_silence_with_padding() {
+        // 1 s silence, 0.5 s speech, 2 s silence (16 kHz); 200 ms pad
+        let mut samples = vec![0.0f32; 16_000];
+        samples.resize(24_000, 0.1);
+        samples.resize(56_000, 0.0);
+        assert_eq!(
+            voiced_range(&samples, 16_000, 0.01, 200),
+            Some(12_800..27_200)
+        );
+        assert_eq!(voiced_range(&samples[..16_000], 16_000, 0.01, 200), None);
+    }
+}
@@ -8,6 +8,8 @@ use gtk4 as gtk;
 #[cfg(all(target_os = "linux", feature = "wayland_layer"))]
 use gtk4_layer_shell as gls;
 
+#[cfg(all(target_os = "linux", feature = "wayland_layer"))]
+use hootvoice::gui::settings::StatusColors;
 #[cfg(all(target_os = "linux", feature = "wayland_layer"))]
 use hootvoice::utils::app_config_dir;
 #[cfg(all(target_os = "linux", feature = "wayland_layer"))]
@@ -33,6 +35,17 @@ fn load_floating_position() -> Option<(i32, i32)> {
     None
 }
 
+// Same palette as the main window (read once at startup)
+#[cfg(all(target_os = "linux", feature = "wayland_layer"))]
+fn load_status_colors() -> StatusColors {
+    std::fs::read_to_string(settings_path())
+        .ok()
+        .and_then(|txt| toml::from_str::<toml::Value>(&txt).ok())
+        .and_then(|val| val.get("status_colors").cloned())
+        .and_then(|v| v.try_into().ok())
+        .unwrap_or_default()
+}
+
 #[cfg(all(target_os = "linux", feature = "wayland_layer"))]
 fn save_floating_position(x: i32, y: i32) {
     let path = settings_path();
@@ -113,17 +126,24 @@ fn main() {
 
         // Global CSS (rounded, semi-transparent background)
         let provider = gtk::CssProvider::new();
-        let css = r#"
-            .hv-float {
+        let colors = load_status_colors();
+        let rgb = |[r, g, b]: [u8; 3]| format!("rgb({},{},{})", r, g, b);
+        let css = format!(
+            r#"
+            .hv-float {{
                 border-radius: 6px;
                 background-color: rgba(24,24,24,0.86);
                 border: 1px solid rgba(200,200,200,0.16);
-            }
-            .hv-state-red { color: rgb(220,53,69); }
-            .hv-state-green { color: rgb(40,167,69); }
-            .hv-state-yellow { color: rgb(255,193,7); }
-        "#;
-        provider.load_from_data(css);
+            }}
+            .hv-state-red {{ color: {}; }}
+            .hv-state-green {{ color: {}; }}
+            .hv-state-yellow {{ color: {}; }}
+        "#,
+            rgb(colors.recording),
+            rgb(colors.idle),
+            rgb(colors.processing)
+        );
+        provider.load_from_data(&css);
         if let Some(display) = gtk::gdk::Display::default() {
             gtk::style_context_add_provider_for_display(
                 &display,
@@ -173,6 +193,14 @@ fn main() {
                             btn_toggle_c.set_label("…");
                             btn_toggle_c.add_css_class("hv-state-yellow");
                         }
+                        "error" => {
+                            btn_toggle_c.set_label("!");
+                            btn_toggle_c.add_css_class("hv-state-red");
+                        }
+                        // Hotkey paused: SIGUSR1 from this button is ignored too
+                        "paused" => {
+                            btn_toggle_c.set_label("⏸");
+                        }
                         _ => {
                             btn_toggle_c.set_label("⏺");
                             btn_toggle_c.add_css_class("hv-state-green");
@@ -0,0 +1,231 @@
+//! Headless transcription for scripts:
+//! `hootvoice --transcribe input.wav --model large-v3 --lang ja --llm off --output out.txt`.
+//! Anything not given on the command line comes from the saved settings.toml.
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::core::WhisperCore;
+use crate::gui::launcher::{absolute_model_path_for_settings, read_saved_settings};
+use crate::utils::app_config_dir;
+
+pub const USAGE: &str = "\
+Usage: hootvoice --transcribe <audio file> [options]
+
+Options:
+  --model <name|path>    Model preset (tiny, base, small, medium, large-v3) or a ggml .bin path
+  --lang <code|auto>     Transcription language (default: from settings)
+  --llm <off|on|mode>    LLM post-processing; a mode id also selects the prompt mode
+  --no-dictionary        Skip dictionary replacements
+  --output <path>        Write the result to a file instead of stdout
+  --verbose              Log progress to stderr
+";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LlmFlag {
+    Off,
+    On,
+    Mode(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliArgs {
+    input: PathBuf,
+    model: Option<String>,
+    language: Option<String>,
+    llm: Option<LlmFlag>,
+    dictionary: bool,
+    output: Option<PathBuf>,
+    verbose: bool,
+}
+
+impl CliArgs {
+    /// Parse `std::env::args()`; None when `--transcribe` is absent (normal GUI start)
+    pub fn parse(args: &[String]) -> Result<Option<Self>> {
+        if !args.iter().any(|a| a == "--transcribe") {
+            return Ok(None);
+        }
+        let mut parsed = CliArgs {
+            input: PathBuf::new(),
+            model: None,
+            language: None,
+            llm: None,
+            dictionary: true,
+            output: None,
+            verbose: false,
+        };
+        let mut iter = args.iter().skip(1);
+        while let Some(arg) = iter.next() {
+            let mut value = || {
+                iter.next()
+                    .cloned()
+                    .ok_or_else(|| anyhow!("{} needs a value", arg))
+            };
+            match arg.as_str() {
+                "--transcribe" => parsed.input = PathBuf::from(value()?),
+                "--model" => parsed.model = Some(value()?),
+                "--lang" => parsed.language = Some(value()?),
+                "--llm" => {
+                    parsed.llm = Some(match value()?.as_str() {
+                        "off" => LlmFlag::Off,
+                        "on" => LlmFlag::On,
+                        mode => LlmFlag::Mode(mode.to_string()),
+                    })
+                }
+                "--no-dictionary" => parsed.dictionary = false,
+                "--output" => parsed.output = Some(PathBuf::from(value()?)),
+                "--verbose" => parsed.verbose = true,
+                other => bail!("unknown argument: {}", other),
+            }
+        }
+        Ok(Some(parsed))
+    }
+}
+
+// Preset name ("large-v3" -> models/ggml-large-v3.bin) or an existing file path
+fn resolve_model(name: &str) -> PathBuf {
+    let path = PathBuf::from(name);
+    if path.exists() {
+        return path;
+    }
+    let filename = if name.ends_with(".bin") {
+        name.to_string()
+    } else {
+        format!("ggml-{}.bin", name)
+    };
+    app_config_dir().join("models").join(filename)
+}
+
+/// Transcribe one file and print/write the result
+pub fn run(args: CliArgs) -> Result<()> {
+    // Logs go to stderr so stdout carries only the transcript
+    let filter = tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+        tracing_subscriber::EnvFilter::new(if args.verbose { "info" } else { "warn" })
+    });
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_target(false)
+        .with_writer(std::io::stderr)
+        .init();
+
+    if !args.input.exists() {
+        bail!("input file not found: {}", args.input.display());
+    }
+    let mut settings = read_saved_settings();
+    let model = match args.model.as_deref() {
+        Some(name) => resolve_model(name),
+        None => absolute_model_path_for_settings(&settings),
+    };
+    // WhisperCore::new would start a download; the CLI never does that implicitly.
+    // The remote backend needs no local model.
+    let remote = settings.remote_transcription.enabled;
+    if !model.exists() && !remote {
+        bail!(
+            "model not found: {} (download it from Settings > Model first)",
+            model.display()
+        );
+    }
+
+    crate::utils::sound::set_enabled(false);
+    let core = if model.exists() {
+        WhisperCore::new(&model, settings.model_download_base_url.as_deref())?
+    } else {
+        WhisperCore::without_model(&model)
+    };
+    if args.verbose {
+        core.set_log_callback(Arc::new(|msg: &str| eprintln!("{}", msg)));
+    }
+
+    let language = args
+        .language
+        .unwrap_or_else(|| settings.whisper_language.clone());
+    core.set_language((language != "auto").then_some(language.as_str()));
+    core.set_whisper_optimization(crate::transcription::WhisperOptimizationParams {
+        no_timestamps: settings.whisper_no_timestamps,
+        token_timestamps: settings.whisper_token_timestamps,
+        use_physical_cores: settings.whisper_use_physical_cores,
+        task: settings.whisper_task,
+        n_threads: crate::transcription::threads_param(settings.whisper_threads),
+        language_fallback: settings.language_fallback(),
+        min_segment_confidence: settings.min_segment_confidence,
+        ..Default::default()
+    });
+    core.set_chunk_split_strategy(settings.chunk_split_strategy);
+    core.set_parallel_chunks(settings.parallel_chunks);
+    core.set_transcription_backend(settings.remote_transcription.backend());
+    core.set_normalize_audio(settings.normalize_audio);
+    core.set_trim_silence(settings.trim_silence);
+    core.set_min_speech_secs(settings.min_speech_secs);
+    core.set_hallucination_filter(
+        settings.hallucination_filter,
+        settings.hallucination_phrases.clone(),
+    );
+    core.set_spoken_commands(settings.enable_spoken_commands);
+    core.set_autocapitalize(settings.local_autocapitalize);
+    core.set_history_retention(settings.history_retention());
+    core.set_history_privacy(
+        settings.history_enabled,
+        &settings.history_redaction_patterns,
+    );
+    if args.dictionary {
+        core.set_dictionary_fuzzy_distance(settings.dictionary_fuzzy_max_distance);
+        core.set_dictionary_entries(crate::dictionary::load_or_init_dictionary()?);
+    }
+
+    let llm = &mut settings.llm_postprocess;
+    match args.llm {
+        Some(LlmFlag::Off) => llm.enabled = false,
+        Some(LlmFlag::On) => llm.enabled = true,
+        Some(LlmFlag::Mode(mode)) => {
+            llm.enabled = true;
+            llm.mode_id = mode;
+        }
+        None => {}
+    }
+    // The printed text is the LLM result whenever post-processing runs
+    llm.apply_to_autopaste = true;
+    core.set_llm_postprocess_settings(settings.llm_postprocess.clone());
+
+    let text = core.transcribe_file_to_text(&args.input)?;
+    match args.output {
+        Some(path) => std::fs::write(&path, format!("{}\n", text))
+            .with_context(|| format!("write {}", path.display()))?,
+        None => println!("{}", text),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CliArgs, LlmFlag};
+
+    fn args(list: &[&str]) -> Vec<String> {
+        std::iter::once("hootvoice")
+            .chain(list.iter().copied())
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn parses_transcription_flags() {
+        assert_eq!(CliArgs::parse(&args(&[])).unwrap(), None);
+        let parsed = CliArgs::parse(&args(&[
+            "--transcribe",
+            "in.wav",
+            "--lang",
+            "ja",
+            "--llm",
+            "summary",
+            "--no-dictionary",
+        ]))
+        .unwrap()
+        .unwrap();
+        assert_eq!(parsed.input.to_str(), Some("in.wav"));
+        assert_eq!(parsed.language.as_deref(), Some("ja"));
+        assert_eq!(parsed.llm, Some(LlmFlag::Mode("summary".into())));
+        assert!(!parsed.dictionary);
+        assert!(CliArgs::parse(&args(&["--transcribe", "in.wav", "--bogus"])).is_err());
+        assert!(CliArgs::parse(&args(&["--transcribe"])).is_err());
+    }
+}
@@ -1,27 +1,34 @@
 // Core facade for high-level control; detailed I/O/transcription/output logic
 // lives in submodules under `core/`.
 
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, Instant};
 use whisper_rs::{WhisperContext, WhisperContextParameters};
 
 // use std::time::Instant; // not used in this module
 
 mod audio_io;
+mod benchmark;
 mod output;
 mod postprocess;
+mod selftest;
 mod transcriber;
 use crate::audio::VadStrategy;
 use crate::dictionary::DictionaryEntry;
-use crate::llm::{LlmPostProcessSettings, LlmPostProcessor};
+use crate::llm::{HistoryRetention, LlmPostProcessSettings, LlmPostProcessor};
 use crate::transcription::ensure_model;
-use crate::transcription::WhisperOptimizationParams;
+use crate::transcription::{TranscriptionBackend, WhisperOptimizationParams, WHISPER_SAMPLE_RATE};
+use crate::utils::keyboard::LinuxPasteMethod;
 use crate::utils::sound;
+pub use benchmark::{BenchmarkRun, ModelBenchmark};
 use hound::{SampleFormat as WavSampleFormat, WavSpec, WavWriter};
-pub use output::BehaviorOptions;
+pub use output::{BehaviorOptions, ClipboardMode, OutputTarget, PendingReview, SoundTiming};
+pub use selftest::{SelfTestStage, SelfTestStageKind, StageOutcome};
 use std::sync::atomic::AtomicU32;
+pub use transcriber::{MaxRecordAction, MAX_RECORD_WARNING_SECS};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum SimpleRecState {
@@ -34,9 +41,164 @@ pub enum SimpleRecState {
 
 pub type LogCallback = Arc<dyn Fn(&str) + Send + Sync>;
 
+/// The core was not Idle when a file transcription started; retry once it is
+#[derive(Debug)]
+pub struct CoreBusy(pub SimpleRecState);
+
+impl std::fmt::Display for CoreBusy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "busy ({:?})", self.0)
+    }
+}
+
+impl std::error::Error for CoreBusy {}
+
+// Load a ggml model; errors name the file and the likely cause
+fn load_whisper_context(model_path: &Path) -> Result<WhisperContext> {
+    if !model_path.is_file() {
+        bail!("model file not found: {}", model_path.display());
+    }
+    let model_path_str = model_path
+        .to_str()
+        .ok_or_else(|| anyhow!("invalid model path (non-UTF-8)"))?;
+    WhisperContext::new_with_params(model_path_str, WhisperContextParameters::default())
+        .with_context(|| {
+            format!(
+                "load Whisper model (corrupt or incomplete file?): {}",
+                model_path.display()
+            )
+        })
+}
+
+/// Extensions accepted for file transcription (formats the rodio decoder handles)
+pub const SUPPORTED_AUDIO_EXTENSIONS: &[&str] = &["wav", "mp3", "flac", "ogg"];
+
+pub fn is_supported_audio_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| {
+            SUPPORTED_AUDIO_EXTENSIONS
+                .iter()
+                .any(|s| s.eq_ignore_ascii_case(e))
+        })
+        .unwrap_or(false)
+}
+
+// How long the floating window keeps showing the last error
+const ERROR_FLASH: Duration = Duration::from_secs(5);
+
+/// Failures surfaced to the user (error sound + floating window flash), plus a few
+/// notices that only flash
+#[derive(Clone, Debug, PartialEq)]
+pub enum ErrorEvent {
+    ModelLoad(String),
+    NoSpeech,
+    Llm(String),
+    // Transcript cut to `max_input_chars` before post-processing; a notice, the
+    // LLM output is still used
+    LlmTruncated(String),
+    Clipboard,
+    Paste,
+    AudioFile(String),
+    // An `OutputTarget::AppendFile` could not be written
+    AppendFile(String),
+    // Recording cut off at `max_record_secs` (with `MaxRecordAction::StopWithWarning`);
+    // a notice, the transcript itself is finalized normally
+    MaxDuration,
+    // A chunk could not be transcribed (remote request failed, no local model);
+    // the incomplete transcript is discarded
+    Transcription(String),
+}
+
+impl ErrorEvent {
+    /// i18n key of the short message shown in the UI
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            ErrorEvent::ModelLoad(_) => "msg-error-model-load",
+            ErrorEvent::NoSpeech => "msg-error-no-speech",
+            ErrorEvent::Llm(_) => "msg-error-llm",
+            ErrorEvent::LlmTruncated(_) => "msg-error-llm-truncated",
+            ErrorEvent::Clipboard => "msg-error-clipboard",
+            ErrorEvent::Paste => "msg-error-paste",
+            ErrorEvent::AudioFile(_) => "msg-error-audio-file",
+            ErrorEvent::AppendFile(_) => "msg-error-append-file",
+            ErrorEvent::MaxDuration => "msg-error-max-duration",
+            ErrorEvent::Transcription(_) => "msg-error-transcription",
+        }
+    }
+
+    /// Nothing failed: no error sound, shown in the warning color
+    pub fn is_notice(&self) -> bool {
+        matches!(self, ErrorEvent::MaxDuration | ErrorEvent::LlmTruncated(_))
+    }
+
+    pub fn detail(&self) -> Option<&str> {
+        match self {
+            ErrorEvent::ModelLoad(d)
+            | ErrorEvent::Llm(d)
+            | ErrorEvent::LlmTruncated(d)
+            | ErrorEvent::AudioFile(d)
+            | ErrorEvent::AppendFile(d)
+            | ErrorEvent::Transcription(d) => Some(d.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// Most recent error; components that can fail hold a clone
+#[derive(Clone, Default)]
+pub struct ErrorNotifier {
+    last: Arc<Mutex<Option<(ErrorEvent, Instant)>>>,
+}
+
+impl ErrorNotifier {
+    pub fn notify(&self, event: ErrorEvent) {
+        if !event.is_notice() {
+            sound::play_event_async(sound::SoundEvent::Error);
+        }
+        *self.last.lock().unwrap() = Some((event, Instant::now()));
+    }
+
+    /// Last error if it happened within the flash window
+    pub fn recent(&self) -> Option<ErrorEvent> {
+        let mut guard = self.last.lock().unwrap();
+        match guard.as_ref() {
+            Some((ev, at)) if at.elapsed() < ERROR_FLASH => Some(ev.clone()),
+            Some(_) => {
+                *guard = None;
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn clear(&self) {
+        *self.last.lock().unwrap() = None;
+    }
+}
+
+/// Default for `set_toggle_debounce_ms`
+pub const DEFAULT_TOGGLE_DEBOUNCE_MS: u64 = 200;
+
+// True when `now` falls inside the debounce window after the last toggle
+fn is_debounced(last: Option<Instant>, now: Instant, window: Duration) -> bool {
+    last.is_some_and(|t| now.saturating_duration_since(t) < window)
+}
+
+// Keeps the core `Busy`/`Processing` (recording toggles are refused or queued)
+// until dropped, then Idle; a panic in the guarded work cannot leave it stuck
+struct BusyGuard(Arc<Mutex<SimpleRecState>>);
+
+impl Drop for BusyGuard {
+    fn drop(&mut self) {
+        *self.0.lock().unwrap() = SimpleRecState::Idle;
+    }
+}
+
 #[derive(Clone)]
 pub struct WhisperCore {
-    pub ctx: Arc<Mutex<Arc<WhisperContext>>>,
+    // None until a model is loaded (remote backend without a local model)
+    pub ctx: Arc<Mutex<Option<Arc<WhisperContext>>>>,
     pub state: Arc<Mutex<SimpleRecState>>,
     pub log_callback: Arc<Mutex<Option<LogCallback>>>,
     audio_buffer: Arc<Mutex<Vec<f32>>>,
@@ -45,6 +207,19 @@ pub struct WhisperCore {
     current_model_path: Arc<Mutex<std::path::PathBuf>>,
     preferred_output_device: Arc<Mutex<Option<String>>>,
     llm_settings: Arc<Mutex<LlmPostProcessSettings>>,
+    // LLM mode forced for the current recording (set by mode-specific hotkeys)
+    llm_mode_override: Arc<Mutex<Option<String>>>,
+    llm_processor: Arc<LlmPostProcessor>,
+    errors: ErrorNotifier,
+    // File name while `transcribe_file` runs (shown in the status area)
+    current_file: Arc<Mutex<Option<String>>>,
+    // Raw 16 kHz mono audio of the last recording, kept for re-transcription
+    keep_last_recording: Arc<Mutex<bool>>,
+    last_recording: Arc<Mutex<Option<Vec<f32>>>>,
+    // Toggles closer together than the window are ignored (fumbled double press)
+    toggle_debounce: Arc<Mutex<(Duration, Option<Instant>)>>,
+    // A toggle while processing: start once the current run finishes (LLM mode override)
+    queued_start: Arc<Mutex<Option<Option<String>>>>,
     #[cfg(target_os = "macos")]
     front_app_before_paste: Arc<Mutex<Option<String>>>,
 
@@ -55,19 +230,25 @@ pub struct WhisperCore {
 }
 
 impl WhisperCore {
-    pub fn new(model_path: &Path) -> Result<Self> {
+    /// Load `model_path`, downloading it first when missing (`model_mirror` is the
+    /// optional download base URL from the settings)
+    pub fn new(model_path: &Path, model_mirror: Option<&str>) -> Result<Self> {
         whisper_rs::install_logging_hooks();
-        ensure_model(model_path).context("download Whisper model")?;
+        ensure_model(model_path, model_mirror).context("download Whisper model")?;
+        let ctx = load_whisper_context(model_path)?;
+        Ok(Self::with_context(Some(ctx), model_path))
+    }
 
-        let model_path_str = model_path
-            .to_str()
-            .ok_or_else(|| anyhow!("invalid model path (non-UTF-8)"))?;
-        let ctx =
-            WhisperContext::new_with_params(model_path_str, WhisperContextParameters::default())
-                .with_context(|| format!("load Whisper model: {}", model_path.display()))?;
+    /// Core for the remote backend: nothing is downloaded or loaded. Local
+    /// transcription fails until `reload_model` loads `model_path`.
+    pub fn without_model(model_path: &Path) -> Self {
+        whisper_rs::install_logging_hooks();
+        Self::with_context(None, model_path)
+    }
 
+    fn with_context(ctx: Option<WhisperContext>, model_path: &Path) -> Self {
         // Shared state (Arc/Mutex)
-        let ctx_arc = Arc::new(Mutex::new(Arc::new(ctx)));
+        let ctx_arc = Arc::new(Mutex::new(ctx.map(Arc::new)));
         let state = Arc::new(Mutex::new(SimpleRecState::Idle));
         let log_callback = Arc::new(Mutex::new(None));
 
@@ -81,6 +262,14 @@ impl WhisperCore {
         let behavior = Arc::new(Mutex::new(BehaviorOptions {
             use_clipboard: true,
             auto_paste: true,
+            clipboard_mode: ClipboardMode::PolishedOnly,
+            restore_clipboard: false,
+            review_before_paste: false,
+            compare_before_paste: false,
+            linux_paste_method: Default::default(),
+            paste_delay_ms: 0,
+            complete_sound_timing: SoundTiming::AfterPaste,
+            append_to_files: true,
         }));
         let current_model_path = Arc::new(Mutex::new(model_path.to_path_buf()));
         let language = Arc::new(Mutex::new(None));
@@ -95,12 +284,17 @@ impl WhisperCore {
         let current_session = Arc::new(std::sync::atomic::AtomicU64::new(0));
         let auto_stop_silence_secs = Arc::new(Mutex::new(10.0));
         let max_record_secs = Arc::new(Mutex::new(600.0));
+        let normalize_audio = Arc::new(Mutex::new(false));
         let llm_settings = Arc::new(Mutex::new(LlmPostProcessSettings::default()));
+        let llm_mode_override = Arc::new(Mutex::new(None));
         let llm_processor = Arc::new(LlmPostProcessor::new());
+        let errors = ErrorNotifier::default();
         let postprocess_engine = postprocess::PostProcessEngine::new(
             llm_settings.clone(),
+            llm_mode_override.clone(),
             llm_processor.clone(),
             state.clone(),
+            errors.clone(),
         );
         #[cfg(target_os = "macos")]
         let front_app_before_paste = Arc::new(Mutex::new(None));
@@ -128,17 +322,20 @@ impl WhisperCore {
             dictionary_entries.clone(),
             auto_stop_silence_secs.clone(),
             max_record_secs.clone(),
+            normalize_audio.clone(),
             postprocess_engine.clone(),
             state.clone(),
+            errors.clone(),
         );
         let out = output::OutputBehavior::new(
             behavior.clone(),
             #[cfg(target_os = "macos")]
             front_app_before_paste.clone(),
             log_callback.clone(),
+            errors.clone(),
         );
 
-        Ok(Self {
+        Self {
             ctx: ctx_arc,
             state,
             log_callback,
@@ -148,26 +345,41 @@ impl WhisperCore {
             current_model_path,
             preferred_output_device,
             llm_settings,
+            llm_mode_override,
+            llm_processor,
+            errors,
+            current_file: Arc::new(Mutex::new(None)),
+            keep_last_recording: Arc::new(Mutex::new(false)),
+            last_recording: Arc::new(Mutex::new(None)),
+            toggle_debounce: Arc::new(Mutex::new((
+                Duration::from_millis(DEFAULT_TOGGLE_DEBOUNCE_MS),
+                None,
+            ))),
+            queued_start: Arc::new(Mutex::new(None)),
             #[cfg(target_os = "macos")]
             front_app_before_paste,
             audio,
             trans,
             out,
-        })
+        }
     }
 
-    /// Reload the Whisper model (prefer idle state)
+    /// Reload the Whisper model (prefer idle state). The file must already exist;
+    /// on failure the current model stays loaded.
     pub fn reload_model(&self, model_path: &Path) -> Result<()> {
+        let result = self.reload_model_inner(model_path);
+        if let Err(e) = &result {
+            self.errors
+                .notify(ErrorEvent::ModelLoad(format!("{:#}", e)));
+        }
+        result
+    }
+
+    fn reload_model_inner(&self, model_path: &Path) -> Result<()> {
         self.log("[Whisper] Loading new model...");
-        ensure_model(model_path).context("download Whisper model")?;
-        let model_path_str = model_path
-            .to_str()
-            .ok_or_else(|| anyhow!("invalid model path (non-UTF-8)"))?;
-        let new_ctx =
-            WhisperContext::new_with_params(model_path_str, WhisperContextParameters::default())
-                .with_context(|| format!("load Whisper model: {}", model_path.display()))?;
+        let new_ctx = load_whisper_context(model_path)?;
         let mut guard = self.ctx.lock().unwrap();
-        *guard = Arc::new(new_ctx);
+        *guard = Some(Arc::new(new_ctx));
         *self.current_model_path.lock().unwrap() = model_path.to_path_buf();
         self.log(&format!(
             "[Whisper] Model switched: {}",
@@ -185,19 +397,67 @@ impl WhisperCore {
     }
 
     pub fn toggle_recording(&self) -> SimpleRecState {
-        // Use try_lock for non-blocking access (do not block UI thread)
-        let mut state = match self.state.try_lock() {
-            Ok(s) => s,
-            Err(_) => {
-                self.log("[Warning] Failed to acquire state lock (busy?)");
-                return SimpleRecState::Busy;
+        self.toggle_recording_with_llm_mode(None)
+    }
+
+    /// Toggle recording; when starting, `llm_mode` forces that LLM mode for this recording only.
+    /// A second toggle within the debounce window is ignored and returns the current state.
+    pub fn toggle_recording_with_llm_mode(&self, llm_mode: Option<&str>) -> SimpleRecState {
+        {
+            let mut debounce = self.toggle_debounce.lock().unwrap();
+            let now = Instant::now();
+            if is_debounced(debounce.1, now, debounce.0) {
+                drop(debounce);
+                self.log("[Record] Ignored repeated toggle (debounce)");
+                return self.get_state();
             }
+            debounce.1 = Some(now);
+        }
+        self.toggle_recording_now(llm_mode)
+    }
+
+    // Push-to-talk start/stop bypass the debounce (a quick tap must still stop)
+    fn toggle_recording_now(&self, llm_mode: Option<&str>) -> SimpleRecState {
+        self.toggle_recording_inner(llm_mode, false)
+    }
+
+    // `from_queue`: a start pressed while processing, run once the core is Idle
+    // again. It keeps the previous run's error flash and paste undo
+    // so they are still seen.
+    fn toggle_recording_inner(&self, llm_mode: Option<&str>, from_queue: bool) -> SimpleRecState {
+        let state = if from_queue {
+            Some(self.state.lock().unwrap())
+        } else {
+            // Use try_lock for non-blocking access (do not block UI thread)
+            self.state.try_lock().ok()
         };
+        let Some(mut state) = state else {
+            self.log("[Warning] Failed to acquire state lock (busy?)");
+            return SimpleRecState::Busy;
+        };
+        if from_queue && *state != SimpleRecState::Idle {
+            // Something else started first: keep waiting for the next Idle
+            self.queued_start
+                .lock()
+                .unwrap()
+                .get_or_insert(llm_mode.map(|m| m.to_string()));
+            return *state;
+        }
 
         let _old_state = *state;
         let new_state = match *state {
             SimpleRecState::Idle => {
                 self.log("[Record] Start recording");
+                if !from_queue {
+                    // A new attempt supersedes the previous error flash (and paste undo)
+                    self.errors.clear();
+                    self.out.forget_last_paste();
+                    self.out.discard_review();
+                }
+                *self.llm_mode_override.lock().unwrap() = llm_mode.map(|m| m.to_string());
+                if let Some(mode) = llm_mode {
+                    self.log(&format!("[llm] Using mode {} for this recording", mode));
+                }
                 // Log current model
                 let mp = self.current_model_path.lock().unwrap().clone();
                 self.log(&format!("[Whisper] Using model: {}", mp.display()));
@@ -219,13 +479,16 @@ impl WhisperCore {
                 thread::spawn(move || core.stop_recording_internal());
                 SimpleRecState::Processing
             }
-            SimpleRecState::Processing => {
-                self.log("[Warning] Already processing");
-                SimpleRecState::Processing
-            }
-            SimpleRecState::PostProcessing => {
-                self.log("[Warning] Already processing");
-                SimpleRecState::PostProcessing
+            SimpleRecState::Processing | SimpleRecState::PostProcessing => {
+                // Only one recording runs at a time; a single start waits in line
+                let mut queued = self.queued_start.lock().unwrap();
+                if queued.take().is_some() {
+                    self.log("[Record] Cancelled queued recording");
+                } else {
+                    *queued = Some(llm_mode.map(|m| m.to_string()));
+                    self.log("[Record] Queued next recording (starts after processing)");
+                }
+                *state
             }
             SimpleRecState::Busy => {
                 self.log("[Warning] State busy");
@@ -236,6 +499,454 @@ impl WhisperCore {
         new_state
     }
 
+    /// A recording is queued to start when the current processing finishes
+    pub fn recording_queued(&self) -> bool {
+        self.queued_start.lock().unwrap().is_some()
+    }
+
+    // Back to Idle: honor a start that was pressed while processing. A transcript
+    // held for review would be replaced by the next result, so the start waits
+    // until the review is confirmed or discarded.
+    fn start_queued_recording(&self) {
+        let mut queued = self.queued_start.lock().unwrap();
+        if queued.is_none() {
+            return;
+        }
+        if self.out.pending_review().is_some() {
+            drop(queued);
+            self.log("[Record] Queued recording starts after the review");
+            return;
+        }
+        let Some(llm_mode) = queued.take() else {
+            return;
+        };
+        drop(queued);
+        self.log("[Record] Starting queued recording");
+        self.toggle_recording_inner(llm_mode.as_deref(), true);
+    }
+
+    // Idle -> Busy for a blocking task that must not overlap a recording;
+    // the current state when it is not Idle
+    fn enter_busy(&self) -> std::result::Result<BusyGuard, SimpleRecState> {
+        let mut state = self.state.lock().unwrap();
+        if *state != SimpleRecState::Idle {
+            return Err(*state);
+        }
+        *state = SimpleRecState::Busy;
+        Ok(BusyGuard(self.state.clone()))
+    }
+
+    // `Processing` from the UI thread: None instead of waiting when not Idle
+    fn try_enter_processing(&self) -> Option<BusyGuard> {
+        let mut state = self.state.try_lock().ok()?;
+        if *state != SimpleRecState::Idle {
+            return None;
+        }
+        *state = SimpleRecState::Processing;
+        Some(BusyGuard(self.state.clone()))
+    }
+
+    /// Run the bundled sample through transcription, dictionary and (when enabled)
+    /// the LLM. Blocks (the core is `Busy` meanwhile); nothing is copied, pasted or
+    /// written to history.
+    pub fn run_self_test(&self) -> Vec<SelfTestStage> {
+        use SelfTestStageKind as Kind;
+        let _busy = match self.enter_busy() {
+            Ok(guard) => guard,
+            Err(state) => {
+                return vec![SelfTestStage::run(Kind::Sample, || {
+                    Err(format!("busy ({:?})", state))
+                })];
+            }
+        };
+        self.log("[SelfTest] Started");
+        let mut stages = Vec::new();
+
+        let mut samples = Vec::new();
+        stages.push(SelfTestStage::run(Kind::Sample, || {
+            let path = selftest::sample_path()?;
+            samples = audio_io::AudioIO::decode_file_16k_mono(&path)
+                .map_err(|e| format!("{:#}", e))?
+                .samples;
+            Ok(format!(
+                "{:.1}s ({})",
+                samples.len() as f32 / WHISPER_SAMPLE_RATE as f32,
+                path.display()
+            ))
+        }));
+
+        let mut detected = None;
+        let mut raw = String::new();
+        if stages.iter().all(SelfTestStage::passed) {
+            stages.push(SelfTestStage::run(Kind::Transcription, || {
+                let result = self
+                    .trans
+                    .transcribe_clip(&samples)
+                    .map_err(|e| format!("{:#}", e))?;
+                detected = result.language;
+                raw = result.text.trim().to_string();
+                Ok(raw.clone())
+            }));
+        }
+
+        let language = self.trans.output_language().or(detected);
+        let mut corrected = String::new();
+        if stages.iter().all(SelfTestStage::passed) {
+            stages.push(SelfTestStage::run(Kind::Dictionary, || {
+                corrected = self.trans.apply_dictionary(&raw, language.as_deref());
+                Ok(corrected.clone())
+            }));
+        }
+
+        let llm = self.llm_settings.lock().unwrap().clone();
+        if !stages.iter().all(SelfTestStage::passed) {
+            // Earlier failure: later stages have no input
+        } else if !llm.enabled {
+            stages.push(SelfTestStage::skipped(
+                Kind::Llm,
+                "selftest-skipped-llm-disabled",
+            ));
+        } else {
+            let entries = self.trans.dictionary_entries.lock().unwrap().clone();
+            let hint = crate::dictionary::llm_prompt_hint(&entries);
+            stages.push(SelfTestStage::run(Kind::Llm, || {
+                self.llm_processor
+                    .process(&llm, &corrected, hint.as_deref(), language.as_deref())
+                    .map(|outcome| outcome.content)
+                    .map_err(|e| e.message)
+            }));
+        }
+
+        for stage in &stages {
+            self.log(&format!(
+                "[SelfTest] {:?}: {:.2}s {:?}",
+                stage.kind,
+                stage.elapsed.as_secs_f32(),
+                stage.outcome
+            ));
+        }
+        stages
+    }
+
+    /// Time the self-test clip with every downloaded model in `SUPPORTED_MODELS`.
+    /// Blocks (each model is loaded in turn, the core is `Busy` meanwhile); the
+    /// active model is unchanged.
+    pub fn benchmark_models(&self) -> Result<Vec<ModelBenchmark>> {
+        let _busy = self
+            .enter_busy()
+            .map_err(|state| anyhow!("busy ({:?})", state))?;
+        let path = selftest::sample_path().map_err(|e| anyhow!(e))?;
+        let samples = audio_io::AudioIO::decode_file_16k_mono(&path)?.samples;
+        let audio_secs = samples.len() as f32 / WHISPER_SAMPLE_RATE as f32;
+        let reference = crate::utils::paths::resolve_resource(benchmark::BENCHMARK_REFERENCE)
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .filter(|text| !text.trim().is_empty());
+        self.log(&format!("[Benchmark] Started ({:.1}s clip)", audio_secs));
+
+        let models_dir = crate::utils::app_config_dir().join("models");
+        let mut results = Vec::new();
+        for info in crate::transcription::SUPPORTED_MODELS {
+            let model_path = models_dir.join(info.filename);
+            if !model_path.is_file() {
+                continue;
+            }
+            let load_start = Instant::now();
+            let outcome = load_whisper_context(&model_path).and_then(|ctx| {
+                let load = load_start.elapsed();
+                let start = Instant::now();
+                let result = self.trans.transcribe_clip_with(&ctx, &samples)?;
+                let transcribe = start.elapsed();
+                let text = result.text.trim().to_string();
+                Ok(BenchmarkRun {
+                    load,
+                    transcribe,
+                    real_time_factor: audio_secs / transcribe.as_secs_f32().max(0.001),
+                    accuracy: reference
+                        .as_deref()
+                        .map(|r| (1.0 - benchmark::word_error_rate(r, &text)).clamp(0.0, 1.0)),
+                    text,
+                })
+            });
+            match &outcome {
+                Ok(run) => self.log(&format!(
+                    "[Benchmark] {}: {:.2}s ({:.1}x real time)",
+                    info.filename,
+                    run.transcribe.as_secs_f32(),
+                    run.real_time_factor
+                )),
+                Err(e) => self.log(&format!("[Benchmark] {} failed: {:#}", info.filename, e)),
+            }
+            results.push(ModelBenchmark {
+                filename: info.filename,
+                label_key: info.label_key,
+                outcome: outcome.map_err(|e| format!("{:#}", e)),
+            });
+        }
+        Ok(results)
+    }
+
+    /// Transcribe an audio file like a recording (dictionary, LLM, history, clipboard).
+    /// Blocks until done; the result is copied but never auto-pasted.
+    pub fn transcribe_file(&self, path: &Path) -> Result<()> {
+        self.transcribe_file_with(path, &self.out.without_auto_paste())
+    }
+
+    /// Headless variant for the CLI: nothing is copied or pasted. Returns the final
+    /// text (empty when no speech was recognized).
+    pub fn transcribe_file_to_text(&self, path: &Path) -> Result<String> {
+        self.transcribe_file_with(path, &self.out.silent())?;
+        // Failed chunks discard the transcript; do not print an empty one as success
+        if let Some(ErrorEvent::Transcription(detail)) = self.errors.recent() {
+            return Err(anyhow!("transcription failed: {}", detail));
+        }
+        Ok(self.trans.last_text().unwrap_or_default())
+    }
+
+    fn transcribe_file_with(&self, path: &Path, output: &output::OutputBehavior) -> Result<()> {
+        {
+            let mut state = self
+                .state
+                .try_lock()
+                .map_err(|_| CoreBusy(SimpleRecState::Busy))?;
+            if *state != SimpleRecState::Idle {
+                return Err(CoreBusy(*state).into());
+            }
+            *state = SimpleRecState::Processing;
+        }
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        self.errors.clear();
+        *self.llm_mode_override.lock().unwrap() = None;
+        *self.current_file.lock().unwrap() = Some(name.clone());
+        self.log(&format!("[File] Transcribing {}", path.display()));
+        crate::utils::sound::start_loop("processing", "sounds/processing.mp3", 1000);
+
+        let result = match audio_io::AudioIO::decode_file_16k_mono(path) {
+            Ok(decoded) => {
+                self.log(&format!(
+                    "[File] Decoded {:.1}s of audio ({} Hz, {} ch)",
+                    decoded.samples.len() as f32 / WHISPER_SAMPLE_RATE as f32,
+                    decoded.source_rate,
+                    decoded.source_channels
+                ));
+                // e.g. 8 kHz telephony audio: upsampling cannot add the missing bandwidth
+                if decoded.source_rate < WHISPER_SAMPLE_RATE {
+                    self.log(&format!(
+                        "[Warning] {} Hz source upsampled to {} Hz; accuracy is limited by the recording",
+                        decoded.source_rate, WHISPER_SAMPLE_RATE
+                    ));
+                }
+                self.trans
+                    .transcribe_samples(decoded.samples, &self.log_callback, output);
+                Ok(())
+            }
+            Err(e) => {
+                crate::utils::sound::stop_loop("processing");
+                self.log(&format!("[Error] Failed to read {}: {:#}", name, e));
+                self.errors
+                    .notify(ErrorEvent::AudioFile(format!("{}: {:#}", name, e)));
+                Err(e)
+            }
+        };
+        *self.current_file.lock().unwrap() = None;
+        *self.state.lock().unwrap() = SimpleRecState::Idle;
+        self.start_queued_recording();
+        result
+    }
+
+    /// Undo the last auto-paste by sending Backspaces (best-effort, see `OutputBehavior`)
+    pub fn undo_last_paste(&self) -> bool {
+        self.out.undo_last_paste()
+    }
+
+    pub fn can_undo_paste(&self) -> bool {
+        self.out.can_undo_paste()
+    }
+
+    /// Paste the most recent transcript again without re-recording
+    pub fn paste_last_transcription(&self) -> bool {
+        self.out.paste_last_output()
+    }
+
+    /// Transcript held for review before paste (`set_review_before_paste`)
+    pub fn pending_review(&self) -> Option<PendingReview> {
+        self.out.pending_review()
+    }
+
+    /// Paste/copy the reviewed (possibly edited) text
+    pub fn confirm_review(&self, text: String) {
+        self.out.confirm_review(text);
+        self.start_queued_recording();
+    }
+
+    pub fn discard_review(&self) {
+        self.out.discard_review();
+        self.start_queued_recording();
+    }
+
+    /// Transcribe the last recording again (current settings); the result is reviewed again
+    pub fn rerun_review(&self) -> bool {
+        self.transcribe_last_recording(
+            self.out.clone(),
+            "[Review] Re-running transcription of the last recording",
+        )
+    }
+
+    /// Re-run the kept recording with the current model/language/settings.
+    /// The result goes to the clipboard (no auto-paste: the app has focus).
+    pub fn retranscribe_last(&self) -> bool {
+        self.transcribe_last_recording(
+            self.out.without_auto_paste(),
+            "[Record] Re-transcribing the last recording with current settings",
+        )
+    }
+
+    /// The last transcription can be corrected again with `reapply_dictionary_to_last`
+    pub fn has_last_transcript(&self) -> bool {
+        self.trans.has_last_raw()
+    }
+
+    /// Re-apply the current dictionary (and with `with_llm` the LLM) to the last
+    /// transcript without re-recording. The result goes to the clipboard.
+    pub fn reapply_dictionary_to_last(&self, with_llm: bool) -> bool {
+        if !self.trans.has_last_raw() {
+            return false;
+        }
+        let Some(processing) = self.try_enter_processing() else {
+            return false;
+        };
+        self.out.discard_review();
+        let core = self.clone();
+        thread::spawn(move || {
+            let output = core.out.without_auto_paste().without_notes();
+            core.trans
+                .reapply_dictionary(with_llm, &core.log_callback, &output);
+            drop(processing);
+            core.start_queued_recording();
+        });
+        true
+    }
+
+    /// The last transcription has timed cues for `export_subtitles`
+    pub fn has_subtitles(&self) -> bool {
+        self.trans.has_subtitles()
+    }
+
+    /// Write the last transcription as SRT or WebVTT (chosen by the `.vtt`/`.srt` extension).
+    /// Returns the number of cues written.
+    pub fn export_subtitles(&self, path: &std::path::Path) -> Result<usize> {
+        use crate::transcription::subtitles::{render, SubtitleFormat};
+        let cues = self.trans.last_subtitles();
+        if cues.is_empty() {
+            return Err(anyhow!("no transcription with timestamps to export"));
+        }
+        let body = render(&cues, SubtitleFormat::from_path(path));
+        std::fs::write(path, body)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        self.log(&format!(
+            "[File] Exported {} subtitle cues to {}",
+            cues.len(),
+            path.display()
+        ));
+        Ok(cues.len())
+    }
+
+    /// A recording is kept for `retranscribe_last`
+    pub fn has_last_recording(&self) -> bool {
+        self.last_recording.lock().unwrap().is_some()
+    }
+
+    // Keep the raw audio of each recording after it is transcribed (off frees it)
+    pub fn set_keep_last_recording(&self, enabled: bool) {
+        *self.keep_last_recording.lock().unwrap() = enabled;
+        if !enabled {
+            *self.last_recording.lock().unwrap() = None;
+        }
+    }
+
+    fn transcribe_last_recording(&self, output: output::OutputBehavior, message: &str) -> bool {
+        let samples = {
+            let Ok(mut state) = self.state.try_lock() else {
+                return false;
+            };
+            if *state != SimpleRecState::Idle {
+                return false;
+            }
+            let kept = self.last_recording.lock().unwrap().clone();
+            let samples = kept.unwrap_or_else(|| self.audio_buffer.lock().unwrap().clone());
+            if samples.is_empty() {
+                return false;
+            }
+            *state = SimpleRecState::Processing;
+            samples
+        };
+        self.out.discard_review();
+        self.log(message);
+        crate::utils::sound::start_loop("processing", "sounds/processing.mp3", 1000);
+        let core = self.clone();
+        thread::spawn(move || {
+            core.trans
+                .transcribe_samples(samples, &core.log_callback, &output);
+            *core.state.lock().unwrap() = SimpleRecState::Idle;
+            core.start_queued_recording();
+        });
+        true
+    }
+
+    // Time since recording started (None unless recording)
+    pub fn recording_elapsed(&self) -> Option<std::time::Duration> {
+        if self.get_state() != SimpleRecState::Recording {
+            return None;
+        }
+        self.trans
+            .record_started_at
+            .lock()
+            .unwrap()
+            .map(|t| t.elapsed())
+    }
+
+    /// Input levels (0..1, newest last) of the last `count` 50 ms windows while
+    /// recording; empty otherwise
+    pub fn recent_input_levels(&self, count: usize) -> Vec<f32> {
+        if self.get_state() != SimpleRecState::Recording {
+            return Vec::new();
+        }
+        const WINDOW: usize = 800; // 50 ms at 16 kHz
+        let buf = self.audio_buffer.lock().unwrap();
+        let tail = buf.len().saturating_sub(WINDOW * count);
+        // Align to the window grid so bars don't jitter between frames
+        let tail = tail - tail % WINDOW;
+        crate::audio::recent_levels(&buf[tail..], WINDOW, count)
+    }
+
+    /// File being transcribed by `transcribe_file`, if any
+    pub fn current_file(&self) -> Option<String> {
+        self.current_file.lock().unwrap().clone()
+    }
+
+    /// Start recording only when idle (push-to-talk key down, `start` command)
+    pub fn start_recording(&self, llm_mode: Option<&str>) -> SimpleRecState {
+        match self.get_state() {
+            SimpleRecState::Idle => self.toggle_recording_now(llm_mode),
+            other => other,
+        }
+    }
+
+    /// Stop recording only when currently recording (push-to-talk key up, `stop` command)
+    pub fn stop_recording(&self) -> SimpleRecState {
+        match self.get_state() {
+            SimpleRecState::Recording => self.toggle_recording_now(None),
+            other => other,
+        }
+    }
+
+    /// Minimum interval between toggles in milliseconds (0 disables)
+    pub fn set_toggle_debounce_ms(&self, ms: u64) {
+        self.toggle_debounce.lock().unwrap().0 = Duration::from_millis(ms);
+    }
+
     pub fn set_log_callback(&self, callback: LogCallback) {
         *self.log_callback.lock().unwrap() = Some(callback);
     }
@@ -260,14 +971,46 @@ impl WhisperCore {
         self.audio.set_input_device_index(idx);
     }
 
-    pub fn set_input_device_host_and_index(&self, host: Option<&str>, idx: Option<usize>) {
-        self.audio.set_input_device_host_and_index(host, idx);
+    // `uid` (stable device ID) wins over `idx`, which shifts when devices are replugged
+    pub fn set_input_device_host_and_index(
+        &self,
+        host: Option<&str>,
+        idx: Option<usize>,
+        uid: Option<&str>,
+    ) {
+        self.audio.set_input_device_host_and_index(host, idx, uid);
     }
 
     pub fn set_input_gain(&self, gain: f32) {
         self.audio.set_input_gain(gain);
     }
 
+    // Pre-roll kept before the hotkey press (0 disables)
+    pub fn set_preroll_ms(&self, ms: u32) {
+        self.audio.set_preroll_ms(ms, self.log_callback.clone());
+    }
+
+    // Watch for the chosen microphone being unplugged (falls back to the default);
+    // `auto_reselect` switches back when it is plugged in again
+    pub fn set_input_device_auto_reselect(&self, auto_reselect: bool) {
+        self.audio
+            .set_device_auto_reselect(auto_reselect, self.log_callback.clone());
+    }
+
+    /// True while recording from the default device because the chosen one is gone
+    pub fn input_device_fallback(&self) -> bool {
+        self.audio.input_device_fallback()
+    }
+
+    /// Input stream errors (buffer overruns, device errors) since launch
+    pub fn input_stream_errors(&self) -> u64 {
+        self.audio.stream_error_count()
+    }
+
+    pub fn reselect_input_device(&self) {
+        self.audio.reselect_input_device();
+    }
+
     pub fn set_whisper_optimization(&self, params: WhisperOptimizationParams) {
         self.trans.set_whisper_optimization(params);
     }
@@ -278,24 +1021,162 @@ impl WhisperCore {
         self.log("[Dictionary] Updated user dictionary");
     }
 
+    // Spoken punctuation commands on the raw transcript (before the dictionary)
+    pub fn set_spoken_commands(&self, enabled: bool) {
+        self.trans.set_spoken_commands(enabled);
+    }
+
+    // Local sentence casing after the dictionary (skipped when the LLM runs)
+    pub fn set_autocapitalize(&self, enabled: bool) {
+        self.trans.set_autocapitalize(enabled);
+    }
+
+    // Speak the final transcript through the OS text-to-speech engine
+    pub fn set_readback_tts(&self, enabled: bool) {
+        self.trans.set_readback_tts(enabled);
+    }
+
+    // Spoken phrase that ends the recording (None/empty disables)
+    pub fn set_stop_phrase(&self, phrase: Option<&str>) {
+        self.trans.set_stop_phrase(phrase);
+    }
+
+    // Edit budget for fuzzy dictionary entries
+    pub fn set_dictionary_fuzzy_distance(&self, max_edits: u32) {
+        self.trans.set_dictionary_fuzzy_distance(max_edits);
+    }
+
     // Chunk splitting strategy (VAD)
     pub fn set_chunk_split_strategy(&self, strategy: VadStrategy) {
         self.trans.set_chunk_split_strategy(strategy);
     }
 
+    // Chunks transcribed concurrently; applies from the next recording
+    pub fn set_parallel_chunks(&self, workers: usize) {
+        self.trans.set_parallel_chunks(workers);
+    }
+
+    /// Applies from the next recording/file; the local model stays loaded either way
+    pub fn set_transcription_backend(&self, backend: TranscriptionBackend) {
+        self.trans.set_transcription_backend(backend);
+    }
+
     // Auto-stop by silence/max duration (0 disables each)
     pub fn set_auto_stop_params(&self, silence_secs: f32, max_secs: f32) {
         self.trans.set_auto_stop_params(silence_secs, max_secs);
     }
 
+    pub fn set_max_record_action(&self, action: MaxRecordAction) {
+        self.trans.set_max_record_action(action);
+    }
+
+    /// Time left before `max_record_secs` stops the recording (None when not
+    /// recording or no cap is set)
+    pub fn max_record_remaining(&self) -> Option<Duration> {
+        let max_secs = *self.trans.max_record_secs.lock().unwrap();
+        if max_secs <= 0.0 {
+            return None;
+        }
+        let elapsed = self.recording_elapsed()?;
+        Some(Duration::from_secs_f32(max_secs).saturating_sub(elapsed))
+    }
+
+    // Skip transcription when the recording has less voiced audio than this (0 disables)
+    pub fn set_min_speech_secs(&self, secs: f32) {
+        self.trans.set_min_speech_secs(secs);
+    }
+
+    // Suppress transcripts that are only a known hallucination phrase
+    pub fn set_hallucination_filter(&self, builtin: bool, phrases: Vec<String>) {
+        self.trans.set_hallucination_filter(builtin, phrases);
+    }
+
+    // Peak-normalize the captured buffer once before transcription
+    pub fn set_normalize_audio(&self, enabled: bool) {
+        self.trans.set_normalize_audio(enabled);
+    }
+
+    // Drop silence before/after speech so Whisper sees less dead air
+    pub fn set_trim_silence(&self, enabled: bool) {
+        self.trans.set_trim_silence(enabled);
+    }
+
     pub fn set_llm_postprocess_settings(&self, settings: LlmPostProcessSettings) {
         *self.llm_settings.lock().unwrap() = settings.clone();
         self.trans.set_llm_settings(settings);
     }
 
-    // Behavior options reflected from GUI settings
-    pub fn set_behavior_options(&self, use_clipboard: bool, auto_paste: bool) {
-        self.out.set_behavior_options(use_clipboard, auto_paste);
+    // History size/age limits applied when an entry is written
+    pub fn set_history_retention(&self, retention: HistoryRetention) {
+        self.trans.postprocess.set_history_retention(retention);
+    }
+
+    // Whether history is written at all, and regex masks applied before writing
+    pub fn set_history_privacy(&self, enabled: bool, redaction_patterns: &[String]) {
+        self.trans
+            .postprocess
+            .set_history_privacy(enabled, redaction_patterns);
+    }
+
+    /// Remaining LLM backoff after repeated failures (None when not backing off)
+    pub fn llm_backoff_remaining_secs(&self) -> Option<u64> {
+        self.llm_processor.backoff_remaining_secs()
+    }
+
+    pub fn reset_llm_backoff(&self) {
+        self.llm_processor.reset_backoff();
+        self.log("[llm] Backoff reset; next recording will call the LLM again");
+    }
+
+    /// Error to flash in the floating window (None once it has expired)
+    pub fn recent_error(&self) -> Option<ErrorEvent> {
+        self.errors.recent()
+    }
+
+    // Clipboard/auto-paste/append-file targets from the output settings
+    pub fn set_output_targets(&self, targets: &[OutputTarget]) {
+        self.out.set_output_targets(targets);
+    }
+
+    // Raw/polished clipboard contents when the LLM produced output
+    pub fn set_clipboard_mode(&self, mode: ClipboardMode) {
+        self.out.set_clipboard_mode(mode);
+    }
+
+    pub fn set_complete_sound_timing(&self, timing: SoundTiming) {
+        self.out.set_complete_sound_timing(timing);
+    }
+
+    // Put the user's previous clipboard back after auto-paste
+    pub fn set_restore_clipboard(&self, enabled: bool) {
+        self.out.set_restore_clipboard(enabled);
+    }
+
+    // Linux auto-paste mechanism (Ctrl+V, Ctrl+Shift+V, middle click, typing)
+    pub fn set_linux_paste_method(&self, method: LinuxPasteMethod) {
+        self.out.set_linux_paste_method(method);
+    }
+
+    // Fixed text around every transcript (history, clipboard/paste/review)
+    pub fn set_transcript_boilerplate(&self, prefix: &str, suffix: &str) {
+        self.trans
+            .postprocess
+            .set_boilerplate(prefix.to_string(), suffix.to_string());
+    }
+
+    // Hold transcripts for editing in the floating window until confirmed
+    pub fn set_review_before_paste(&self, enabled: bool) {
+        self.out.set_review_before_paste(enabled);
+    }
+
+    /// Show raw and LLM text side by side and paste the one picked
+    pub fn set_compare_before_paste(&self, enabled: bool) {
+        self.out.set_compare_before_paste(enabled);
+    }
+
+    // Extra wait before the paste keystroke (target app regaining focus)
+    pub fn set_auto_paste_delay_ms(&self, ms: u32) {
+        self.out.set_paste_delay_ms(ms);
     }
 
     pub fn log(&self, message: &str) {
@@ -316,6 +1197,10 @@ impl WhisperCore {
     // Output behavior lives in `core/output.rs`
 
     fn start_recording_internal(&self) {
+        // A readback of the previous transcript would be picked up by the mic
+        if self.trans.readback_tts() {
+            crate::utils::tts::stop();
+        }
         // Delegate to components
         self.audio.start_capture(self.log_callback.clone());
         // Start chunk processing loop
@@ -337,6 +1222,8 @@ impl WhisperCore {
     fn stop_recording_internal(&self) {
         // Stop audio capture and join thread
         self.audio.stop_capture();
+        // Resume the pre-roll monitor (fresh buffer; nothing from this recording)
+        self.audio.start_preroll_monitor(self.log_callback.clone());
         if let Some(handle) = self.processing_thread.lock().unwrap().take() {
             let _ = handle.join();
         }
@@ -349,7 +1236,7 @@ impl WhisperCore {
                     path,
                     WavSpec {
                         channels: 1,
-                        sample_rate: 16_000,
+                        sample_rate: WHISPER_SAMPLE_RATE,
                         bits_per_sample: 32,
                         sample_format: WavSampleFormat::Float,
                     },
@@ -369,8 +1256,175 @@ impl WhisperCore {
         // Finalize transcription and apply output behavior
         self.trans
             .finalize_and_output(self.audio_buffer.clone(), &self.log_callback, &self.out);
+        if *self.keep_last_recording.lock().unwrap() {
+            let samples = std::mem::take(&mut *self.audio_buffer.lock().unwrap());
+            *self.last_recording.lock().unwrap() = Some(samples);
+        } else if self.out.pending_review().is_none() {
+            // Free the buffer (a pending review keeps it for "Re-run")
+            *self.audio_buffer.lock().unwrap() = Vec::new();
+        }
         *self.state.lock().unwrap() = SimpleRecState::Idle;
+        if self.trans.take_max_record_hit() {
+            self.handle_max_record_hit();
+        }
+        self.start_queued_recording();
+    }
+
+    fn handle_max_record_hit(&self) {
+        // Same LLM mode as the recording that was cut off
+        let llm_mode = self.llm_mode_override.lock().unwrap().clone();
+        let restart = apply_max_record_action(
+            self.trans.max_record_action(),
+            llm_mode,
+            &mut self.queued_start.lock().unwrap(),
+            &self.errors,
+        );
+        if restart {
+            self.log("[Record] Max duration reached; continuing with a new recording");
+        }
     }
 
     // removed: old helper `log_with_callback` (unused)
 }
+
+// Returns true when a new recording was queued
+fn apply_max_record_action(
+    action: MaxRecordAction,
+    llm_mode: Option<String>,
+    queued_start: &mut Option<Option<String>>,
+    errors: &ErrorNotifier,
+) -> bool {
+    match action {
+        MaxRecordAction::FinalizeAndStop => false,
+        MaxRecordAction::FinalizeAndAutoRestart => {
+            queued_start.get_or_insert(llm_mode);
+            true
+        }
+        MaxRecordAction::StopWithWarning => {
+            errors.notify(ErrorEvent::MaxDuration);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        apply_max_record_action, is_debounced, load_whisper_context, BusyGuard, ErrorEvent,
+        ErrorNotifier, MaxRecordAction, SimpleRecState, WhisperCore,
+    };
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn missing_model_error_names_the_file() {
+        let path = Path::new("/nonexistent/hootvoice/ggml-missing.bin");
+        let err = load_whisper_context(path).err().expect("must fail");
+        let msg = format!("{:#}", err);
+        assert!(msg.contains("not found"), "{}", msg);
+        assert!(msg.contains("ggml-missing.bin"), "{}", msg);
+    }
+
+    #[test]
+    fn failed_reload_keeps_the_model_path_and_reports_it() {
+        let current = Path::new("/nonexistent/hootvoice/ggml-base.bin");
+        let core = WhisperCore::without_model(current);
+        let missing = Path::new("/nonexistent/hootvoice/ggml-missing.bin");
+        let err = core.reload_model(missing).expect_err("must fail");
+        assert!(format!("{:#}", err).contains("not found"), "{:#}", err);
+        assert_eq!(core.get_model_path(), current);
+        match core.recent_error() {
+            Some(ErrorEvent::ModelLoad(detail)) => {
+                assert!(detail.contains("ggml-missing.bin"), "{}", detail)
+            }
+            other => panic!("expected a model load error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn toggles_inside_window_are_debounced() {
+        let t0 = Instant::now();
+        let window = Duration::from_millis(200);
+        assert!(!is_debounced(None, t0, window));
+        assert!(is_debounced(
+            Some(t0),
+            t0 + Duration::from_millis(150),
+            window
+        ));
+        assert!(!is_debounced(
+            Some(t0),
+            t0 + Duration::from_millis(200),
+            window
+        ));
+        assert!(!is_debounced(Some(t0), t0, Duration::ZERO));
+    }
+
+    #[test]
+    fn busy_guard_returns_to_idle() {
+        let state = Arc::new(Mutex::new(SimpleRecState::Busy));
+        drop(BusyGuard(state.clone()));
+        assert_eq!(*state.lock().unwrap(), SimpleRecState::Idle);
+    }
+
+    #[test]
+    fn processing_guard_is_exclusive_and_survives_a_panic() {
+        let core = WhisperCore::without_model(Path::new("/nonexistent/hootvoice/ggml-base.bin"));
+        let processing = core.try_enter_processing().expect("idle core");
+        assert_eq!(core.get_state(), SimpleRecState::Processing);
+        assert!(core.try_enter_processing().is_none());
+        let worker = std::thread::spawn(move || {
+            let _processing = processing;
+            panic!("dictionary step failed");
+        });
+        assert!(worker.join().is_err());
+        assert_eq!(core.get_state(), SimpleRecState::Idle);
+    }
+
+    #[test]
+    fn max_record_actions() {
+        let errors = ErrorNotifier::default();
+        let mut queued = None;
+        let mode = Some("polish".to_string());
+
+        assert!(!apply_max_record_action(
+            MaxRecordAction::FinalizeAndStop,
+            mode.clone(),
+            &mut queued,
+            &errors
+        ));
+        assert_eq!(queued, None);
+        assert_eq!(errors.recent(), None);
+
+        assert!(apply_max_record_action(
+            MaxRecordAction::FinalizeAndAutoRestart,
+            mode.clone(),
+            &mut queued,
+            &errors
+        ));
+        assert_eq!(queued, Some(mode.clone()));
+        // A start the user already queued keeps its own LLM mode
+        apply_max_record_action(
+            MaxRecordAction::FinalizeAndAutoRestart,
+            None,
+            &mut queued,
+            &errors,
+        );
+        assert_eq!(queued, Some(mode.clone()));
+        assert_eq!(errors.recent(), None);
+
+        let mut queued = None;
+        assert!(!apply_max_record_action(
+            MaxRecordAction::StopWithWarning,
+            mode,
+            &mut queued,
+            &errors
+        ));
+        assert_eq!(queued, None);
+        let notice = errors.recent().expect("notice shown");
+        assert_eq!(notice, ErrorEvent::MaxDuration);
+        assert!(notice.is_notice());
+        assert!(ErrorEvent::LlmTruncated(String::new()).is_notice());
+        assert!(!ErrorEvent::Paste.is_notice());
+    }
+}
@@ -1,9 +1,16 @@
 use std::cell::RefCell;
-use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
-use std::sync::{Arc, Mutex};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
 use std::thread;
+use std::time::Duration;
 
+use crate::audio::device::{
+    input_device_names, input_device_uids, presence_change, PresenceChange,
+};
+use crate::audio::preroll::PrerollBuffer;
 use crate::core::LogCallback;
+use crate::transcription::WHISPER_SAMPLE_RATE;
 
 thread_local! {
     static MONO_BUFFER: RefCell<Vec<f32>> = const { RefCell::new(Vec::new()) };
@@ -14,6 +21,27 @@ thread_local! {
 static ACTIVE_CORE_INPUT_STREAMS: std::sync::atomic::AtomicUsize =
     std::sync::atomic::AtomicUsize::new(0);
 
+/// Decoded file audio plus what the file itself contained (for logging)
+pub struct DecodedAudio {
+    /// Mono at `WHISPER_SAMPLE_RATE`
+    pub samples: Vec<f32>,
+    pub source_rate: u32,
+    pub source_channels: usize,
+}
+
+// How often the idle watcher re-enumerates input devices
+const DEVICE_POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+// Hot-plug state of the preferred input device
+#[derive(Default)]
+struct DeviceWatch {
+    started: AtomicBool,
+    auto_reselect: AtomicBool,
+    // The preferred device disappeared: capture uses the system default. Stays set
+    // after it returns unless auto-reselect is on or the user picks it again.
+    using_fallback: AtomicBool,
+}
+
 // Minimal device settings and capture lifecycle grouped here
 #[derive(Clone)]
 pub struct AudioIO {
@@ -24,9 +52,21 @@ pub struct AudioIO {
     pub preferred_input_device: Arc<Mutex<Option<String>>>,
     pub preferred_input_device_index: Arc<Mutex<Option<usize>>>,
     pub preferred_input_host: Arc<Mutex<Option<String>>>,
+    // Stable device ID within the host (preferred over the index, which shifts on replug)
+    preferred_input_uid: Arc<Mutex<Option<String>>>,
     pub input_gain: Arc<AtomicU32>, // f32 bits
 
     pub current_session: Arc<AtomicU64>,
+
+    // Pre-roll: lightweight monitor stream keeps the last N ms while idle
+    pub preroll: Arc<Mutex<PrerollBuffer>>,
+    preroll_ms: Arc<AtomicU32>,
+    capturing: Arc<AtomicBool>,
+    monitor_stop: Arc<AtomicBool>,
+    monitor_thread: Arc<Mutex<Option<thread::JoinHandle<()>>>>,
+    device_watch: Arc<DeviceWatch>,
+    // CPAL error callbacks of recording streams since launch (xruns, device errors)
+    stream_errors: Arc<AtomicU64>,
 }
 
 impl AudioIO {
@@ -48,8 +88,16 @@ impl AudioIO {
             preferred_input_device,
             preferred_input_device_index,
             preferred_input_host,
+            preferred_input_uid: Arc::new(Mutex::new(None)),
             input_gain,
             current_session,
+            preroll: Arc::new(Mutex::new(PrerollBuffer::default())),
+            preroll_ms: Arc::new(AtomicU32::new(0)),
+            capturing: Arc::new(AtomicBool::new(false)),
+            monitor_stop: Arc::new(AtomicBool::new(false)),
+            monitor_thread: Arc::new(Mutex::new(None)),
+            device_watch: Arc::new(DeviceWatch::default()),
+            stream_errors: Arc::new(AtomicU64::new(0)),
         }
     }
 
@@ -61,9 +109,136 @@ impl AudioIO {
         *self.preferred_input_device_index.lock().unwrap() = idx;
     }
 
-    pub fn set_input_device_host_and_index(&self, host: Option<&str>, idx: Option<usize>) {
+    pub fn set_input_device_host_and_index(
+        &self,
+        host: Option<&str>,
+        idx: Option<usize>,
+        uid: Option<&str>,
+    ) {
         *self.preferred_input_host.lock().unwrap() = host.map(|s| s.to_string());
         *self.preferred_input_device_index.lock().unwrap() = idx;
+        let mut current = self.preferred_input_uid.lock().unwrap();
+        if current.as_deref() != uid {
+            *current = uid.map(|s| s.to_string());
+            self.device_watch
+                .using_fallback
+                .store(false, Ordering::SeqCst);
+        }
+    }
+
+    /// True while capture uses the system default because the chosen device is gone
+    pub fn input_device_fallback(&self) -> bool {
+        self.device_watch.using_fallback.load(Ordering::SeqCst)
+    }
+
+    pub fn stream_error_count(&self) -> u64 {
+        self.stream_errors.load(Ordering::Relaxed)
+    }
+
+    /// Go back to the chosen device (after it was reconnected)
+    pub fn reselect_input_device(&self) {
+        self.device_watch
+            .using_fallback
+            .store(false, Ordering::SeqCst);
+    }
+
+    // Start the hot-plug watcher (once). It only holds a weak reference to the
+    // watch state, so it exits when the last AudioIO clone is dropped.
+    pub fn set_device_auto_reselect(
+        &self,
+        auto_reselect: bool,
+        log_callback: Arc<Mutex<Option<LogCallback>>>,
+    ) {
+        self.device_watch
+            .auto_reselect
+            .store(auto_reselect, Ordering::SeqCst);
+        if self.device_watch.started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let watch = Arc::downgrade(&self.device_watch);
+        let capturing = self.capturing.clone();
+        let preferred_host = self.preferred_input_host.clone();
+        let preferred_uid = self.preferred_input_uid.clone();
+        thread::spawn(move || {
+            Self::watch_input_device(
+                watch,
+                capturing,
+                preferred_host,
+                preferred_uid,
+                log_callback,
+            )
+        });
+    }
+
+    fn watch_input_device(
+        watch: Weak<DeviceWatch>,
+        capturing: Arc<AtomicBool>,
+        preferred_host: Arc<Mutex<Option<String>>>,
+        preferred_uid: Arc<Mutex<Option<String>>>,
+        log_callback: Arc<Mutex<Option<LogCallback>>>,
+    ) {
+        use cpal::traits::HostTrait;
+
+        let mut watched: Option<String> = None;
+        let mut was_present: Option<bool> = None;
+        loop {
+            thread::sleep(DEVICE_POLL_INTERVAL);
+            let Some(watch) = watch.upgrade() else {
+                break;
+            };
+            // Enumeration can disturb an open stream on some backends; wait until idle
+            if capturing.load(Ordering::SeqCst) {
+                continue;
+            }
+            let uid = preferred_uid.lock().unwrap().clone();
+            if uid != watched {
+                watched = uid.clone();
+                was_present = None;
+            }
+            let Some(uid) = uid else {
+                continue;
+            };
+            let host = Self::resolve_host(&preferred_host.lock().unwrap());
+            let devices: Vec<cpal::Device> = host
+                .input_devices()
+                .map(|it| it.collect())
+                .unwrap_or_default();
+            let present = input_device_uids(&input_device_names(&devices)).contains(&uid);
+            match presence_change(was_present, present) {
+                Some(PresenceChange::Lost) => {
+                    watch.using_fallback.store(true, Ordering::SeqCst);
+                    Self::log_with_callback(
+                        &log_callback,
+                        &format!(
+                            "[Warning] Input device '{}' disconnected; recording from the system default",
+                            uid
+                        ),
+                    );
+                }
+                Some(PresenceChange::Returned) => {
+                    if watch.auto_reselect.load(Ordering::SeqCst) {
+                        watch.using_fallback.store(false, Ordering::SeqCst);
+                        Self::log_with_callback(
+                            &log_callback,
+                            &format!(
+                                "[Record] Input device '{}' reconnected; using it again",
+                                uid
+                            ),
+                        );
+                    } else {
+                        Self::log_with_callback(
+                            &log_callback,
+                            &format!(
+                                "[Record] Input device '{}' reconnected; still using the system default until it is selected again",
+                                uid
+                            ),
+                        );
+                    }
+                }
+                None => {}
+            }
+            was_present = Some(present);
+        }
     }
 
     pub fn set_input_gain(&self, gain: f32) {
@@ -71,6 +246,102 @@ impl AudioIO {
             .store(gain.max(0.0).to_bits(), Ordering::Relaxed);
     }
 
+    // Pre-roll length in ms (0 disables and closes the monitor stream)
+    pub fn set_preroll_ms(&self, ms: u32, log_callback: Arc<Mutex<Option<LogCallback>>>) {
+        let prev = self.preroll_ms.swap(ms, Ordering::SeqCst);
+        if prev == ms {
+            return;
+        }
+        self.preroll.lock().unwrap().set_duration_ms(ms);
+        if ms == 0 {
+            self.stop_preroll_monitor();
+            Self::log_with_callback(&log_callback, "[Record] Pre-roll disabled");
+        } else {
+            self.start_preroll_monitor(log_callback);
+        }
+    }
+
+    // Open the monitor stream that feeds the pre-roll buffer (no-op while capturing)
+    pub fn start_preroll_monitor(&self, log_callback: Arc<Mutex<Option<LogCallback>>>) {
+        use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+
+        let mut slot = self.monitor_thread.lock().unwrap();
+        if self.preroll_ms.load(Ordering::SeqCst) == 0 || self.capturing.load(Ordering::SeqCst) {
+            return;
+        }
+        if slot.as_ref().is_some_and(|h| !h.is_finished()) {
+            return;
+        }
+        self.monitor_stop.store(false, Ordering::SeqCst);
+
+        let preroll = self.preroll.clone();
+        let monitor_stop = self.monitor_stop.clone();
+        let preferred_in = self.preferred_input_device.clone();
+        let preferred_in_idx = self.preferred_input_device_index.clone();
+        let preferred_host = self.preferred_input_host.clone();
+        let preferred_uid = self.preferred_input_uid.clone();
+        let device_watch = self.device_watch.clone();
+        let handle = thread::spawn(move || {
+            let read_key = || {
+                (
+                    preferred_host.lock().unwrap().clone(),
+                    *preferred_in_idx.lock().unwrap(),
+                    preferred_in.lock().unwrap().clone(),
+                    preferred_uid.lock().unwrap().clone(),
+                    device_watch.using_fallback.load(Ordering::SeqCst),
+                )
+            };
+            while !monitor_stop.load(Ordering::SeqCst) {
+                let key = read_key();
+                let device = if key.4 {
+                    Self::resolve_host(&key.0).default_input_device()
+                } else {
+                    Self::find_input_device(&key.0, key.1, &key.2, &key.3)
+                };
+                let stream = device.and_then(|device| {
+                    let supported = device.default_input_config().ok()?;
+                    let config: cpal::StreamConfig = supported.config();
+                    let sample_format = supported.sample_format();
+                    Self::build_preroll_stream(&device, &config, sample_format, preroll.clone())
+                        .ok()
+                        .filter(|s| s.play().is_ok())
+                });
+                let Some(stream) = stream else {
+                    Self::log_with_callback(
+                        &log_callback,
+                        "[Warning] Pre-roll monitor could not open the input device",
+                    );
+                    // Retry later (device may come back)
+                    for _ in 0..20 {
+                        if monitor_stop.load(Ordering::SeqCst) {
+                            break;
+                        }
+                        thread::sleep(std::time::Duration::from_millis(100));
+                    }
+                    continue;
+                };
+                Self::log_with_callback(&log_callback, "[Record] Pre-roll monitor started");
+                // Reopen when the preferred device changes
+                while !monitor_stop.load(Ordering::SeqCst) && read_key() == key {
+                    thread::sleep(std::time::Duration::from_millis(50));
+                }
+                drop(stream);
+                preroll.lock().unwrap().clear();
+            }
+        });
+        *slot = Some(handle);
+    }
+
+    pub fn stop_preroll_monitor(&self) {
+        let mut slot = self.monitor_thread.lock().unwrap();
+        self.monitor_stop.store(true, Ordering::SeqCst);
+        if let Some(h) = slot.take() {
+            let _ = h.join();
+        }
+        // Never keep idle audio around once the monitor is closed
+        self.preroll.lock().unwrap().clear();
+    }
+
     // Start CPAL input stream and push 16k mono f32 into audio_buffer
     pub fn start_capture(&self, log_callback: Arc<Mutex<Option<LogCallback>>>) {
         use cpal::{
@@ -78,8 +349,27 @@ impl AudioIO {
             StreamConfig,
         };
 
-        // Clear audio buffer and flags
-        self.audio_buffer.lock().unwrap().clear();
+        // Hand the device over from the pre-roll monitor, keeping what it captured
+        self.capturing.store(true, Ordering::SeqCst);
+        let preroll = self.preroll.lock().unwrap().take();
+        self.stop_preroll_monitor();
+
+        // Clear audio buffer and flags (pre-roll goes first)
+        {
+            let mut buf = self.audio_buffer.lock().unwrap();
+            buf.clear();
+            if !preroll.is_empty() {
+                let g = f32::from_bits(self.input_gain.load(Ordering::Relaxed));
+                buf.extend(preroll.iter().map(|s| s * g));
+                Self::log_with_callback(
+                    &log_callback,
+                    &format!(
+                        "[Record] Prepended pre-roll: {} ms",
+                        preroll.len() * 1000 / WHISPER_SAMPLE_RATE as usize
+                    ),
+                );
+            }
+        }
         *self.stop_flag.lock().unwrap() = false;
 
         let buffer = self.audio_buffer.clone();
@@ -87,7 +377,10 @@ impl AudioIO {
         let preferred_in = self.preferred_input_device.lock().unwrap().clone();
         let preferred_in_idx = *self.preferred_input_device_index.lock().unwrap();
         let preferred_host = self.preferred_input_host.lock().unwrap().clone();
+        let preferred_uid = self.preferred_input_uid.lock().unwrap().clone();
+        let use_default = self.device_watch.using_fallback.load(Ordering::SeqCst);
         let input_gain_for_thread = self.input_gain.clone();
+        let stream_errors = self.stream_errors.clone();
         // Bump session ID
         let session_id = self.current_session.fetch_add(1, Ordering::SeqCst) + 1;
         let session_guard = self.current_session.clone();
@@ -119,15 +412,13 @@ impl AudioIO {
                 .input_devices()
                 .map(|it| it.collect())
                 .unwrap_or_default();
-            let mut names: Vec<String> = Vec::new();
-            for d in &device_list {
-                names.push(d.name().unwrap_or_else(|_| "(unknown)".to_string()));
-            }
+            let names = input_device_names(&device_list);
+            let uids = input_device_uids(&names);
             Self::log_with_callback(
                 &log_callback,
                 &format!(
-                    "[Record] Desired input: host={:?}, name={:?}, index={:?}",
-                    preferred_host, preferred_in, preferred_in_idx
+                    "[Record] Desired input: host={:?}, uid={:?}, name={:?}, index={:?}",
+                    preferred_host, preferred_uid, preferred_in, preferred_in_idx
                 ),
             );
             if names.is_empty() {
@@ -139,9 +430,33 @@ impl AudioIO {
                 }
             }
 
-            // Selection order: host index, then name match, then default
+            // Selection order: device UID, then host index, then name match, then default
             let mut chosen: Option<cpal::Device> = None;
-            if let Some(idx) = preferred_in_idx {
+            if use_default {
+                Self::log_with_callback(
+                    &log_callback,
+                    "[Warning] Selected input device is disconnected; using the system default",
+                );
+            } else if let Some(ref uid) = preferred_uid {
+                if let Some(pos) = uids.iter().position(|u| u == uid) {
+                    chosen = device_list.get(pos).cloned();
+                    Self::log_with_callback(
+                        &log_callback,
+                        &format!("[Record] Selected by device ID: '{}' (index={})", uid, pos),
+                    );
+                } else {
+                    // The saved index now points at whatever took its place
+                    Self::log_with_callback(
+                        &log_callback,
+                        &format!(
+                            "[Warning] Device ID not found: '{}'; using the system default",
+                            uid
+                        ),
+                    );
+                }
+            }
+            let by_id = use_default || preferred_uid.is_some();
+            if let Some(idx) = preferred_in_idx.filter(|_| chosen.is_none() && !by_id) {
                 if let Some(d) = device_list.get(idx).cloned() {
                     chosen = Some(d);
                     Self::log_with_callback(
@@ -159,7 +474,7 @@ impl AudioIO {
                     );
                 }
             }
-            if chosen.is_none() {
+            if chosen.is_none() && !by_id {
                 if let Some(ref want) = preferred_in {
                     if let Some(pos) = names.iter().position(|n| n == want) {
                         chosen = device_list.get(pos).cloned();
@@ -206,7 +521,11 @@ impl AudioIO {
 
             let config: StreamConfig = supported_config.clone().into();
             let sample_format = supported_config.sample_format();
-            let err_fn = |err| eprintln!("[Error] Stream error: {}", err);
+            let err_log = log_callback.clone();
+            let err_fn = move |err: cpal::StreamError| {
+                stream_errors.fetch_add(1, Ordering::Relaxed);
+                Self::log_with_callback(&err_log, &format!("[Warning] Stream error: {}", err));
+            };
 
             let sr_for_cb = config.sample_rate.0;
             let ch_for_cb = config.channels as usize;
@@ -264,11 +583,11 @@ impl AudioIO {
                                 }
                                 RESAMPLE_BUFFER.with(|res_buf| {
                                     let mut resampled = res_buf.borrow_mut();
-                                    if sr_for_cb != 16_000 {
+                                    if sr_for_cb != WHISPER_SAMPLE_RATE {
                                         Self::resample_into(
                                             &mono,
                                             sr_for_cb,
-                                            16_000,
+                                            WHISPER_SAMPLE_RATE,
                                             &mut resampled,
                                         );
                                     } else {
@@ -332,11 +651,11 @@ impl AudioIO {
                                 }
                                 RESAMPLE_BUFFER.with(|res_buf| {
                                     let mut resampled = res_buf.borrow_mut();
-                                    if sr_for_cb != 16_000 {
+                                    if sr_for_cb != WHISPER_SAMPLE_RATE {
                                         Self::resample_into(
                                             &mono,
                                             sr_for_cb,
-                                            16_000,
+                                            WHISPER_SAMPLE_RATE,
                                             &mut resampled,
                                         );
                                     } else {
@@ -393,11 +712,11 @@ impl AudioIO {
                                 }
                                 RESAMPLE_BUFFER.with(|res_buf| {
                                     let mut resampled = res_buf.borrow_mut();
-                                    if sr_for_cb != 16_000 {
+                                    if sr_for_cb != WHISPER_SAMPLE_RATE {
                                         Self::resample_into(
                                             &mono,
                                             sr_for_cb,
-                                            16_000,
+                                            WHISPER_SAMPLE_RATE,
                                             &mut resampled,
                                         );
                                     } else {
@@ -459,7 +778,7 @@ impl AudioIO {
                         return;
                     }
                     Self::log_with_callback(&log_callback, "[Record] Recording started");
-                    crate::utils::sound::play_sound_async("sounds/start.mp3");
+                    crate::utils::sound::play_event_async(crate::utils::sound::SoundEvent::Start);
 
                     // Busy-wait loop to keep the thread alive until stop_flag becomes true
                     while !*stop_flag.lock().unwrap() {
@@ -497,6 +816,155 @@ impl AudioIO {
         if let Some(h) = self.recording_thread.lock().unwrap().take() {
             let _ = h.join();
         }
+        self.capturing.store(false, Ordering::SeqCst);
+    }
+
+    // Same selection order as capture (device ID, host index, name, default), without logs
+    fn find_input_device(
+        preferred_host: &Option<String>,
+        preferred_in_idx: Option<usize>,
+        preferred_in: &Option<String>,
+        preferred_uid: &Option<String>,
+    ) -> Option<cpal::Device> {
+        use cpal::traits::{DeviceTrait, HostTrait};
+
+        let host = Self::resolve_host(preferred_host);
+        let devices: Vec<cpal::Device> = host
+            .input_devices()
+            .map(|it| it.collect())
+            .unwrap_or_default();
+        if let Some(uid) = preferred_uid {
+            let uids = input_device_uids(&input_device_names(&devices));
+            // A missing ID means the device is unplugged; the index would pick a stranger
+            return match uids.iter().position(|u| u == uid) {
+                Some(pos) => devices.get(pos).cloned(),
+                None => host.default_input_device(),
+            };
+        }
+        if let Some(d) = preferred_in_idx.and_then(|i| devices.get(i)) {
+            return Some(d.clone());
+        }
+        if let Some(want) = preferred_in {
+            if let Some(d) = devices
+                .iter()
+                .find(|d| d.name().ok().as_deref() == Some(want.as_str()))
+            {
+                return Some(d.clone());
+            }
+        }
+        host.default_input_device()
+    }
+
+    // Host by lowercased name; the default host when unset or unavailable
+    fn resolve_host(preferred_host: &Option<String>) -> cpal::Host {
+        preferred_host
+            .as_ref()
+            .and_then(|h| {
+                cpal::available_hosts()
+                    .into_iter()
+                    .find(|id| format!("{:?}", id).to_lowercase() == h.to_lowercase())
+            })
+            .and_then(|id| cpal::host_from_id(id).ok())
+            .unwrap_or_else(cpal::default_host)
+    }
+
+    fn build_preroll_stream(
+        device: &cpal::Device,
+        config: &cpal::StreamConfig,
+        sample_format: cpal::SampleFormat,
+        preroll: Arc<Mutex<PrerollBuffer>>,
+    ) -> Result<cpal::Stream, cpal::BuildStreamError> {
+        use cpal::traits::DeviceTrait;
+
+        let sr = config.sample_rate.0;
+        let ch = config.channels as usize;
+        let err_fn = |err| eprintln!("[Error] Pre-roll stream error: {}", err);
+        match sample_format {
+            cpal::SampleFormat::I16 => device.build_input_stream(
+                config,
+                move |data: &[i16], _: &_| {
+                    Self::push_preroll(data, ch, sr, |s| s as f32 / 32768.0, &preroll)
+                },
+                err_fn,
+                None,
+            ),
+            cpal::SampleFormat::U16 => device.build_input_stream(
+                config,
+                move |data: &[u16], _: &_| {
+                    Self::push_preroll(data, ch, sr, |s| (s as f32 - 32768.0) / 32768.0, &preroll)
+                },
+                err_fn,
+                None,
+            ),
+            _ => device.build_input_stream(
+                config,
+                move |data: &[f32], _: &_| Self::push_preroll(data, ch, sr, |s| s, &preroll),
+                err_fn,
+                None,
+            ),
+        }
+    }
+
+    // Downmix + resample to 16k mono, then append to the pre-roll ring (gain applied on take)
+    fn push_preroll<T: Copy>(
+        data: &[T],
+        channels: usize,
+        sample_rate: u32,
+        to_f32: impl Fn(T) -> f32,
+        preroll: &Mutex<PrerollBuffer>,
+    ) {
+        MONO_BUFFER.with(|mono_buf| {
+            let mut mono = mono_buf.borrow_mut();
+            mono.clear();
+            for chunk in data.chunks(channels.max(1)) {
+                let sum: f32 = chunk.iter().map(|&s| to_f32(s)).sum();
+                mono.push(sum / chunk.len() as f32);
+            }
+            RESAMPLE_BUFFER.with(|res_buf| {
+                let mut resampled = res_buf.borrow_mut();
+                Self::resample_into(&mono, sample_rate, WHISPER_SAMPLE_RATE, &mut resampled);
+                if let Ok(mut p) = preroll.lock() {
+                    p.push(&resampled);
+                }
+            });
+        });
+    }
+
+    /// Decode an audio file (wav/mp3/flac/ogg) to 16 kHz mono for Whisper
+    pub fn decode_file_16k_mono(path: &Path) -> anyhow::Result<DecodedAudio> {
+        use rodio::Source;
+        let file = std::fs::File::open(path)?;
+        let decoder = rodio::Decoder::new(std::io::BufReader::new(file))
+            .map_err(|e| anyhow::anyhow!("unsupported or corrupt audio: {}", e))?;
+        let channels = decoder.channels().max(1) as usize;
+        let sample_rate = decoder.sample_rate();
+        if sample_rate == 0 {
+            anyhow::bail!("invalid sample rate 0 Hz");
+        }
+        let interleaved: Vec<f32> = decoder.map(|s| s as f32 / i16::MAX as f32).collect();
+        let samples = Self::to_whisper_mono(interleaved, channels, sample_rate);
+        if samples.is_empty() {
+            anyhow::bail!("no audio samples");
+        }
+        Ok(DecodedAudio {
+            samples,
+            source_rate: sample_rate,
+            source_channels: channels,
+        })
+    }
+
+    // Downmix + resample; 16 kHz mono input is returned as is (no extra pass)
+    fn to_whisper_mono(interleaved: Vec<f32>, channels: usize, sample_rate: u32) -> Vec<f32> {
+        if channels == 1 && sample_rate == WHISPER_SAMPLE_RATE {
+            return interleaved;
+        }
+        let mono: Vec<f32> = interleaved
+            .chunks(channels.max(1))
+            .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+            .collect();
+        let mut out = Vec::new();
+        Self::resample_into(&mono, sample_rate, WHISPER_SAMPLE_RATE, &mut out);
+        out
     }
 
     // TODO: Consider replacing with a low-cost FIR resampler (e.g., rubato / speexdsp).
@@ -0,0 +1,82 @@
+//! "Benchmark": the self-test clip through every downloaded model, reporting
+//! speed (real-time factor) and accuracy against the clip's reference text.
+
+use std::time::Duration;
+
+use crate::utils::diff::is_cjk;
+
+/// Reference transcript of `SELF_TEST_SAMPLE`; accuracy is left out without it
+pub const BENCHMARK_REFERENCE: &str = "sounds/selftest.txt";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchmarkRun {
+    pub load: Duration,
+    pub transcribe: Duration,
+    /// Audio seconds per processing second (above 1 = faster than real time)
+    pub real_time_factor: f32,
+    /// 1 - word error rate, clamped to 0..=1
+    pub accuracy: Option<f32>,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModelBenchmark {
+    pub filename: &'static str,
+    pub label_key: &'static str,
+    pub outcome: Result<BenchmarkRun, String>,
+}
+
+// Lowercased words without punctuation; CJK characters are words of their own
+fn words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for c in text.chars() {
+        if c.is_alphanumeric() && !is_cjk(c) {
+            current.extend(c.to_lowercase());
+            continue;
+        }
+        if !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        if is_cjk(c) {
+            words.push(c.to_string());
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// Word-level edit distance divided by the reference length
+pub fn word_error_rate(reference: &str, hypothesis: &str) -> f32 {
+    let (r, h) = (words(reference), words(hypothesis));
+    if r.is_empty() {
+        return if h.is_empty() { 0.0 } else { 1.0 };
+    }
+    let mut prev: Vec<usize> = (0..=h.len()).collect();
+    for (i, rw) in r.iter().enumerate() {
+        let mut row = vec![i + 1; h.len() + 1];
+        for (j, hw) in h.iter().enumerate() {
+            let substitute = prev[j] + usize::from(rw != hw);
+            row[j + 1] = substitute.min(prev[j + 1] + 1).min(row[j] + 1);
+        }
+        prev = row;
+    }
+    prev[h.len()] as f32 / r.len() as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::word_error_rate;
+
+    #[test]
+    fn word_error_rate_ignores_case_and_punctuation() {
+        assert_eq!(word_error_rate("Hello, world.", "hello world"), 0.0);
+        assert_eq!(word_error_rate("the cat sat", "the bat sat"), 1.0 / 3.0);
+        assert_eq!(word_error_rate("one two", "one two three four"), 1.0);
+        // CJK is compared per character
+        assert_eq!(word_error_rate("今日は晴れ", "今日は雨"), 0.4);
+        assert_eq!(word_error_rate("", ""), 0.0);
+    }
+}
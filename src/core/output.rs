@@ -1,4 +1,9 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use unicode_segmentation::UnicodeSegmentation;
 
 #[cfg(target_os = "macos")]
 mod macos_helpers {
@@ -45,6 +50,35 @@ mod macos_helpers {
         }
     }
 
+    /// Plain-text clipboard contents (None when there is no string flavor)
+    pub fn get_clipboard() -> Option<String> {
+        unsafe {
+            let pb: id = msg_send![class!(NSPasteboard), generalPasteboard];
+            let ty = NSString::alloc(nil).init_str("public.utf8-plain-text");
+            let s: id = msg_send![pb, stringForType: ty];
+            if s == nil {
+                return None;
+            }
+            let ptr: *const std::os::raw::c_char = msg_send![s, UTF8String];
+            if ptr.is_null() {
+                return None;
+            }
+            Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+        }
+    }
+
+    pub fn clipboard_is_empty() -> bool {
+        unsafe {
+            let pb: id = msg_send![class!(NSPasteboard), generalPasteboard];
+            let types: id = msg_send![pb, types];
+            if types == nil {
+                return true;
+            }
+            let n: usize = msg_send![types, count];
+            n == 0
+        }
+    }
+
     pub fn activate_bundle_id(bundle_id: &str) -> bool {
         let script = format!(
             "try\n tell application id \"{}\" to activate\nend try",
@@ -58,12 +92,103 @@ mod macos_helpers {
     }
 }
 
-use crate::core::LogCallback;
+use crate::core::{ErrorEvent, ErrorNotifier, LogCallback};
+use crate::utils::keyboard::LinuxPasteMethod;
+use serde::{Deserialize, Serialize};
+
+// Pause so clipboard managers record the raw text before it is replaced. Deliberate:
+// managers that poll (or coalesce quick changes) would otherwise only see the final text
+const CLIPBOARD_HISTORY_GAP_MS: u64 = 300;
+// Wait for the target app to read the pasted text before putting the old clipboard back
+const CLIPBOARD_RESTORE_DELAY_MS: u64 = 600;
+// Undo is offered only shortly after the paste (the cursor is less likely to have moved)
+const UNDO_PASTE_WINDOW: Duration = Duration::from_secs(30);
+// Let the hotkey's modifiers be released so Backspace isn't sent as Ctrl/Alt+Backspace
+const UNDO_KEY_RELEASE_DELAY_MS: u64 = 300;
+// Let the review window close (and focus return to the target app) before pasting
+const REVIEW_PASTE_DELAY_MS: u64 = 250;
+// `SoundTiming::BeforePaste` never holds the paste longer than this (long custom sounds)
+const COMPLETE_SOUND_MAX_WAIT_MS: u64 = 3_000;
+
+// Clipboard contents captured before auto-paste overwrites them
+enum ClipboardSnapshot {
+    Text(String),
+    Empty,
+    // Image/file/etc.: cannot be saved through the text-only clipboard helpers
+    NonText,
+}
+
+/// What goes to the clipboard when LLM post-processing produced text
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ClipboardMode {
+    #[default]
+    PolishedOnly,
+    RawOnly,
+    // Copy raw first (kept by clipboard history), then polished (pasted)
+    RawThenPolished,
+    BothWithSeparator,
+}
+
+/// When the completion sound plays relative to auto-paste
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SoundTiming {
+    /// After the paste keystroke succeeded
+    #[default]
+    AfterPaste,
+    /// Played to the end before the window hides and the paste is sent
+    BeforePaste,
+    /// Not played when auto-paste is on (clipboard-only output still plays it)
+    SilentWithAutoPaste,
+}
+
+/// Where a finished transcript goes; several targets can be active at once
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum OutputTarget {
+    Clipboard,
+    AutoPaste,
+    /// Appended with a timestamp separator; `{{date}}` in the path gives daily notes
+    AppendFile {
+        path: PathBuf,
+    },
+}
 
 #[derive(Clone, Copy, Debug)]
 pub struct BehaviorOptions {
     pub use_clipboard: bool,
     pub auto_paste: bool,
+    pub clipboard_mode: ClipboardMode,
+    pub restore_clipboard: bool,
+    // Hold the text for review/editing in the floating window until confirmed
+    pub review_before_paste: bool,
+    // Hold raw and LLM text side by side until one is picked (only when they differ)
+    pub compare_before_paste: bool,
+    // Ignored on macOS/Windows
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    pub linux_paste_method: LinuxPasteMethod,
+    // Wait after hiding/refocusing before the paste keystroke
+    pub paste_delay_ms: u32,
+    pub complete_sound_timing: SoundTiming,
+    // Write to the `OutputTarget::AppendFile` paths (off for CLI and re-pastes)
+    pub append_to_files: bool,
+}
+
+struct HeldReview {
+    id: u64,
+    texts: Vec<String>,
+    compare: Option<(String, String)>,
+}
+
+/// Transcript waiting for confirmation (`review_before_paste`)
+#[derive(Clone, Debug)]
+pub struct PendingReview {
+    // Changes with every new transcript so the editor can reset its buffer
+    pub id: u64,
+    pub text: String,
+    /// Raw and polished text for the side-by-side view (`compare_before_paste`)
+    pub compare: Option<(String, String)>,
 }
 
 #[derive(Clone)]
@@ -72,6 +197,17 @@ pub struct OutputBehavior {
     #[cfg(target_os = "macos")]
     pub front_app_before_paste: Arc<Mutex<Option<String>>>,
     pub log_callback: Arc<Mutex<Option<LogCallback>>>,
+    errors: ErrorNotifier,
+    // Characters (grapheme clusters) inserted by the last auto-paste and when
+    last_paste: Arc<Mutex<Option<(usize, Instant)>>>,
+    // Last text sent to the clipboard/paste, for pasting it again by hand
+    last_output: Arc<Mutex<Option<String>>>,
+    // Clipboard texts held for review (the last one is the editable/pasted one),
+    // plus raw/polished when they are shown side by side
+    review: Arc<Mutex<Option<HeldReview>>>,
+    review_seq: Arc<AtomicU64>,
+    // `OutputTarget::AppendFile` paths, unexpanded
+    append_files: Arc<Mutex<Vec<PathBuf>>>,
 }
 
 impl OutputBehavior {
@@ -79,20 +215,210 @@ impl OutputBehavior {
         behavior: Arc<Mutex<BehaviorOptions>>,
         #[cfg(target_os = "macos")] front_app_before_paste: Arc<Mutex<Option<String>>>,
         log_callback: Arc<Mutex<Option<LogCallback>>>,
+        errors: ErrorNotifier,
     ) -> Self {
         Self {
             behavior,
             #[cfg(target_os = "macos")]
             front_app_before_paste,
             log_callback,
+            errors,
+            last_paste: Arc::new(Mutex::new(None)),
+            last_output: Arc::new(Mutex::new(None)),
+            review: Arc::new(Mutex::new(None)),
+            review_seq: Arc::new(AtomicU64::new(0)),
+            append_files: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
     pub fn set_behavior_options(&self, use_clipboard: bool, auto_paste: bool) {
-        *self.behavior.lock().unwrap() = BehaviorOptions {
-            use_clipboard,
-            auto_paste,
+        let mut behavior = self.behavior.lock().unwrap();
+        behavior.use_clipboard = use_clipboard;
+        behavior.auto_paste = auto_paste;
+    }
+
+    pub fn set_output_targets(&self, targets: &[OutputTarget]) {
+        let auto_paste = targets.contains(&OutputTarget::AutoPaste);
+        let use_clipboard = auto_paste || targets.contains(&OutputTarget::Clipboard);
+        self.set_behavior_options(use_clipboard, auto_paste);
+        *self.append_files.lock().unwrap() = targets
+            .iter()
+            .filter_map(|target| match target {
+                OutputTarget::AppendFile { path } => Some(path.clone()),
+                _ => None,
+            })
+            .collect();
+    }
+
+    pub fn set_clipboard_mode(&self, mode: ClipboardMode) {
+        self.behavior.lock().unwrap().clipboard_mode = mode;
+    }
+
+    pub fn set_complete_sound_timing(&self, timing: SoundTiming) {
+        self.behavior.lock().unwrap().complete_sound_timing = timing;
+    }
+
+    pub fn set_restore_clipboard(&self, enabled: bool) {
+        self.behavior.lock().unwrap().restore_clipboard = enabled;
+    }
+
+    pub fn set_linux_paste_method(&self, method: LinuxPasteMethod) {
+        self.behavior.lock().unwrap().linux_paste_method = method;
+    }
+
+    pub fn set_review_before_paste(&self, enabled: bool) {
+        self.behavior.lock().unwrap().review_before_paste = enabled;
+    }
+
+    pub fn set_compare_before_paste(&self, enabled: bool) {
+        self.behavior.lock().unwrap().compare_before_paste = enabled;
+    }
+
+    pub fn set_paste_delay_ms(&self, ms: u32) {
+        self.behavior.lock().unwrap().paste_delay_ms = ms;
+    }
+
+    /// Same output with auto-paste off (file transcription: the app itself has focus)
+    pub fn without_auto_paste(&self) -> Self {
+        let mut behavior = *self.behavior.lock().unwrap();
+        behavior.auto_paste = false;
+        behavior.use_clipboard = true;
+        behavior.review_before_paste = false;
+        behavior.compare_before_paste = false;
+        self.with_behavior(behavior)
+    }
+
+    /// Same output without a notes entry (re-applying to a transcript already appended)
+    pub fn without_notes(&self) -> Self {
+        let mut behavior = *self.behavior.lock().unwrap();
+        behavior.append_to_files = false;
+        self.with_behavior(behavior)
+    }
+
+    /// No clipboard, paste or review (headless CLI: the caller prints the text)
+    pub fn silent(&self) -> Self {
+        let mut behavior = *self.behavior.lock().unwrap();
+        behavior.auto_paste = false;
+        behavior.use_clipboard = false;
+        behavior.review_before_paste = false;
+        behavior.compare_before_paste = false;
+        behavior.append_to_files = false;
+        self.with_behavior(behavior)
+    }
+
+    fn with_behavior(&self, behavior: BehaviorOptions) -> Self {
+        Self {
+            behavior: Arc::new(Mutex::new(behavior)),
+            #[cfg(target_os = "macos")]
+            front_app_before_paste: self.front_app_before_paste.clone(),
+            log_callback: self.log_callback.clone(),
+            errors: self.errors.clone(),
+            last_paste: self.last_paste.clone(),
+            last_output: self.last_output.clone(),
+            review: self.review.clone(),
+            review_seq: self.review_seq.clone(),
+            append_files: self.append_files.clone(),
+        }
+    }
+
+    /// Transcript waiting for review, if any
+    pub fn pending_review(&self) -> Option<PendingReview> {
+        self.review
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|held| PendingReview {
+                id: held.id,
+                text: held.texts.last().cloned().unwrap_or_default(),
+                compare: held.compare.clone(),
+            })
+    }
+
+    /// Output the reviewed transcript with the user's edits (normal clipboard/paste path,
+    /// on a background thread)
+    pub fn confirm_review(&self, edited: String) {
+        let Some(HeldReview { mut texts, .. }) = self.review.lock().unwrap().take() else {
+            return;
+        };
+        if let Some(last) = texts.last_mut() {
+            *last = edited;
+        }
+        Self::log_with_callback(&self.log_callback, "[Review] Confirmed; pasting");
+        let out = self.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(REVIEW_PASTE_DELAY_MS));
+            let behavior = *out.behavior.lock().unwrap();
+            out.output_texts(behavior, texts);
+        });
+    }
+
+    pub fn discard_review(&self) -> bool {
+        let dropped = self.review.lock().unwrap().take().is_some();
+        if dropped {
+            Self::log_with_callback(&self.log_callback, "[Review] Discarded transcript");
+        }
+        dropped
+    }
+
+    /// Whether the last auto-paste can still be undone
+    pub fn can_undo_paste(&self) -> bool {
+        matches!(*self.last_paste.lock().unwrap(), Some((_, at)) if at.elapsed() < UNDO_PASTE_WINDOW)
+    }
+
+    // A new recording/output supersedes the previous paste
+    pub fn forget_last_paste(&self) {
+        *self.last_paste.lock().unwrap() = None;
+    }
+
+    /// Best-effort undo: press Backspace once per pasted character in the focused app.
+    /// Wrong if the cursor moved or the user typed since the paste.
+    pub fn undo_last_paste(&self) -> bool {
+        let last = self.last_paste.lock().unwrap().take();
+        let count = match last {
+            Some((count, at)) if at.elapsed() < UNDO_PASTE_WINDOW => count,
+            _ => {
+                Self::log_with_callback(&self.log_callback, "[Undo] Nothing to undo");
+                return false;
+            }
+        };
+        #[cfg(target_os = "macos")]
+        if let Some(bid) = self.front_app_before_paste.lock().unwrap().clone() {
+            let _ = macos_helpers::activate_bundle_id(&bid);
+        }
+        std::thread::sleep(Duration::from_millis(UNDO_KEY_RELEASE_DELAY_MS));
+        let ok = crate::utils::keyboard::send_backspaces(count);
+        if ok {
+            Self::log_with_callback(
+                &self.log_callback,
+                &format!("[Undo] Removed last paste ({} characters)", count),
+            );
+        } else {
+            Self::log_with_callback(
+                &self.log_callback,
+                "[Warning] Undo paste failed (could not send Backspace)",
+            );
+            self.errors.notify(ErrorEvent::Paste);
+        }
+        ok
+    }
+
+    /// Paste the last output again into the app focused now (after auto-paste
+    /// landed in the wrong window). Always pastes, whatever the output settings.
+    pub fn paste_last_output(&self) -> bool {
+        let Some(text) = self.last_output.lock().unwrap().clone() else {
+            Self::log_with_callback(&self.log_callback, "[Paste] No transcript to paste yet");
+            return false;
         };
+        // The target is wherever focus is now, not where the original paste went
+        self.remember_front_app();
+        std::thread::sleep(Duration::from_millis(UNDO_KEY_RELEASE_DELAY_MS));
+        let mut behavior = *self.behavior.lock().unwrap();
+        behavior.auto_paste = true;
+        behavior.use_clipboard = true;
+        behavior.append_to_files = false;
+        Self::log_with_callback(&self.log_callback, "[Paste] Pasting last transcript again");
+        self.output_texts(behavior, vec![text]);
+        true
     }
 
     pub fn remember_front_app(&self) {
@@ -103,11 +429,69 @@ impl OutputBehavior {
         }
     }
 
-    pub fn apply_output(&self, text: &str) {
+    // `raw` is the Whisper text, `final_text` what post-processing chose to paste,
+    // `llm_output` the LLM result (if any) regardless of that choice. `wrap` adds
+    // the transcript prefix/suffix, once per clipboard text.
+    pub fn apply_output(
+        &self,
+        raw: &str,
+        final_text: &str,
+        llm_output: Option<&str>,
+        wrap: &dyn Fn(&str) -> String,
+    ) {
         let behavior = *self.behavior.lock().unwrap();
+        let texts = clipboard_texts(behavior.clipboard_mode, raw, final_text, llm_output, wrap);
+        let compare = llm_output
+            .filter(|p| behavior.compare_before_paste && p.trim() != raw.trim())
+            .map(|polished| (wrap(raw), wrap(polished)));
+        if (behavior.review_before_paste || compare.is_some())
+            && (behavior.auto_paste || behavior.use_clipboard)
+        {
+            self.forget_last_paste();
+            let id = self.review_seq.fetch_add(1, Ordering::SeqCst) + 1;
+            *self.review.lock().unwrap() = Some(HeldReview { id, texts, compare });
+            crate::utils::sound::stop_loop("processing");
+            Self::log_with_callback(
+                &self.log_callback,
+                "[Review] Waiting for confirmation before paste",
+            );
+            return;
+        }
+        self.output_texts(behavior, texts);
+    }
+
+    // Clipboard copies (all but the last kept for clipboard history), then paste the last
+    fn output_texts(&self, behavior: BehaviorOptions, mut texts: Vec<String>) {
+        let text = texts.pop().unwrap_or_default();
+        *self.last_output.lock().unwrap() = Some(text.clone());
+        let text = text.as_str();
+        self.forget_last_paste();
+        let appended = behavior.append_to_files && self.append_to_note_files(text);
+        // Snapshot before anything (including raw-first copies) touches the clipboard
+        let previous = if behavior.auto_paste && behavior.restore_clipboard {
+            Some(Self::read_clipboard(&self.log_callback))
+        } else {
+            None
+        };
+        if behavior.auto_paste || behavior.use_clipboard {
+            // Only `ClipboardMode::RawThenPolished` has earlier texts. The gap blocks
+            // on purpose: the paste below must find the final text on the clipboard,
+            // so it cannot be copied from another thread meanwhile.
+            for earlier in &texts {
+                Self::copy_to_clipboard_only(earlier, &self.log_callback);
+                std::thread::sleep(std::time::Duration::from_millis(CLIPBOARD_HISTORY_GAP_MS));
+            }
+        }
         if behavior.auto_paste {
             // 1) Copy to clipboard
-            Self::copy_to_clipboard_only(text, &self.log_callback);
+            let copied = Self::copy_to_clipboard_only(text, &self.log_callback);
+            if behavior.complete_sound_timing == SoundTiming::BeforePaste {
+                crate::utils::sound::stop_loop("processing");
+                crate::utils::sound::play_event_blocking(
+                    crate::utils::sound::SoundEvent::Complete,
+                    Duration::from_millis(COMPLETE_SOUND_MAX_WAIT_MS),
+                );
+            }
             // 2) Auto-paste
             #[cfg(target_os = "macos")]
             macos_helpers::hide_app();
@@ -130,6 +514,16 @@ impl OutputBehavior {
                 }
                 std::thread::sleep(std::time::Duration::from_millis(120));
             }
+            #[cfg(target_os = "linux")]
+            if behavior.linux_paste_method == LinuxPasteMethod::MiddleClickPrimary {
+                Self::copy_to_primary(text, &self.log_callback);
+            }
+            if behavior.paste_delay_ms > 0 {
+                std::thread::sleep(Duration::from_millis(behavior.paste_delay_ms.into()));
+            }
+            #[cfg(target_os = "linux")]
+            let ok = crate::utils::keyboard::auto_paste_linux(behavior.linux_paste_method, text);
+            #[cfg(not(target_os = "linux"))]
             let ok = crate::utils::keyboard::auto_paste();
             if ok {
                 Self::log_with_callback(&self.log_callback, "[Keyboard] Sent paste");
@@ -140,7 +534,11 @@ impl OutputBehavior {
                 }
                 #[cfg(target_os = "linux")]
                 {
-                    Self::log_with_callback(&self.log_callback, "[Warning] Auto paste failed. On Wayland install 'wtype'; on X11 install 'xdotool'.");
+                    if behavior.linux_paste_method == LinuxPasteMethod::MiddleClickPrimary {
+                        Self::log_with_callback(&self.log_callback, "[Warning] Middle-click paste failed. Install 'ydotool' (Wayland) or 'xdotool' (X11).");
+                    } else {
+                        Self::log_with_callback(&self.log_callback, "[Warning] Auto paste failed. On Wayland install 'wtype'; on X11 install 'xdotool'.");
+                    }
                 }
                 #[cfg(target_os = "windows")]
                 {
@@ -151,11 +549,34 @@ impl OutputBehavior {
                 }
             }
             crate::utils::sound::stop_loop("processing");
-            crate::utils::sound::play_sound_async("sounds/complete.mp3");
+            if !copied {
+                self.errors.notify(ErrorEvent::Clipboard);
+            } else if !ok {
+                // Leave the transcription on the clipboard so it can be pasted by hand
+                self.errors.notify(ErrorEvent::Paste);
+            } else {
+                if behavior.complete_sound_timing == SoundTiming::AfterPaste {
+                    crate::utils::sound::play_event_async(
+                        crate::utils::sound::SoundEvent::Complete,
+                    );
+                }
+                *self.last_paste.lock().unwrap() =
+                    Some((text.graphemes(true).count(), Instant::now()));
+                if let Some(previous) = previous {
+                    self.restore_clipboard_later(previous);
+                }
+            }
         } else if behavior.use_clipboard {
-            Self::copy_to_clipboard_only(text, &self.log_callback);
+            let copied = Self::copy_to_clipboard_only(text, &self.log_callback);
             crate::utils::sound::stop_loop("processing");
-            crate::utils::sound::play_sound_async("sounds/complete.mp3");
+            if copied {
+                crate::utils::sound::play_event_async(crate::utils::sound::SoundEvent::Complete);
+            } else {
+                self.errors.notify(ErrorEvent::Clipboard);
+            }
+        } else if appended {
+            crate::utils::sound::stop_loop("processing");
+            crate::utils::sound::play_event_async(crate::utils::sound::SoundEvent::Complete);
         } else {
             Self::log_with_callback(
                 &self.log_callback,
@@ -164,6 +585,191 @@ impl OutputBehavior {
         }
     }
 
+    // Append `text` to every `AppendFile` target; true when at least one was written
+    fn append_to_note_files(&self, text: &str) -> bool {
+        let paths = self.append_files.lock().unwrap().clone();
+        if paths.is_empty() || text.trim().is_empty() {
+            return false;
+        }
+        let now = chrono::Local::now();
+        let entry = note_entry(text, now);
+        let mut any = false;
+        for path in &paths {
+            let path = note_path(path, now);
+            match append_note(&path, &entry) {
+                Ok(()) => {
+                    any = true;
+                    Self::log_with_callback(
+                        &self.log_callback,
+                        &format!("[Output] Appended to {}", path.display()),
+                    );
+                }
+                Err(e) => {
+                    let detail = format!("{}: {}", path.display(), e);
+                    Self::log_with_callback(
+                        &self.log_callback,
+                        &format!("[Error] Append to file failed: {}", detail),
+                    );
+                    self.errors.notify(ErrorEvent::AppendFile(detail));
+                }
+            }
+        }
+        any
+    }
+
+    fn restore_clipboard_later(&self, previous: ClipboardSnapshot) {
+        let log_callback = self.log_callback.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(CLIPBOARD_RESTORE_DELAY_MS));
+            match previous {
+                ClipboardSnapshot::Text(text) => {
+                    if Self::copy_to_clipboard_only(&text, &log_callback) {
+                        Self::log_with_callback(
+                            &log_callback,
+                            "[Clipboard] Restored previous contents",
+                        );
+                    }
+                }
+                ClipboardSnapshot::Empty => {
+                    Self::log_with_callback(
+                        &log_callback,
+                        "[Clipboard] Previous clipboard was empty; nothing to restore",
+                    );
+                }
+                ClipboardSnapshot::NonText => {
+                    Self::log_with_callback(
+                        &log_callback,
+                        "[Clipboard] Previous contents were not text; not restored",
+                    );
+                }
+            }
+        });
+    }
+
+    // PRIMARY selection for middle-click paste (X11 xclip, Wayland wl-copy --primary)
+    #[cfg(target_os = "linux")]
+    fn copy_to_primary(text: &str, log_callback: &Arc<Mutex<Option<LogCallback>>>) {
+        use std::io::Write;
+        use std::process::{Command, Stdio};
+        for (program, args) in [
+            ("xclip", &["-selection", "primary"][..]),
+            ("wl-copy", &["--primary"][..]),
+        ] {
+            if let Ok(mut child) = Command::new(program)
+                .args(args)
+                .stdin(Stdio::piped())
+                .spawn()
+            {
+                if let Some(stdin) = child.stdin.as_mut() {
+                    let _ = stdin.write_all(text.as_bytes());
+                }
+                if child.wait().map(|s| s.success()).unwrap_or(false) {
+                    Self::log_with_callback(log_callback, "[Clipboard] Set primary selection");
+                    return;
+                }
+            }
+        }
+        Self::log_with_callback(
+            log_callback,
+            "[Warning] Failed to set primary selection (xclip/wl-copy not available)",
+        );
+    }
+
+    // Read the current clipboard as text (best effort, per platform)
+    fn read_clipboard(log_callback: &Arc<Mutex<Option<LogCallback>>>) -> ClipboardSnapshot {
+        #[cfg(target_os = "linux")]
+        {
+            use std::process::Command;
+            let is_text = |types: &str| {
+                types.lines().any(|t| {
+                    let t = t.trim();
+                    t.starts_with("text/plain")
+                        || t == "UTF8_STRING"
+                        || t == "STRING"
+                        || t == "TEXT"
+                })
+            };
+            // X11 (xclip), then Wayland (wl-paste) — same order as copying
+            // xclip fails when nothing owns the clipboard (or without an X server)
+            let xclip = Command::new("xclip")
+                .args(["-selection", "clipboard", "-t", "TARGETS", "-o"])
+                .output();
+            let xclip_ran = xclip.is_ok();
+            if let Some(out) = xclip.as_ref().ok().filter(|o| o.status.success()) {
+                let types = String::from_utf8_lossy(&out.stdout);
+                if types.trim().is_empty() {
+                    return ClipboardSnapshot::Empty;
+                }
+                if !is_text(&types) {
+                    return ClipboardSnapshot::NonText;
+                }
+                return match Command::new("xclip")
+                    .args(["-selection", "clipboard", "-t", "UTF8_STRING", "-o"])
+                    .output()
+                {
+                    Ok(o) if o.status.success() => {
+                        ClipboardSnapshot::Text(String::from_utf8_lossy(&o.stdout).into_owned())
+                    }
+                    _ => ClipboardSnapshot::NonText,
+                };
+            }
+            match Command::new("wl-paste").arg("--list-types").output() {
+                Ok(out) => {
+                    let types = String::from_utf8_lossy(&out.stdout);
+                    if !out.status.success() || types.trim().is_empty() {
+                        return ClipboardSnapshot::Empty;
+                    }
+                    if !is_text(&types) {
+                        return ClipboardSnapshot::NonText;
+                    }
+                    match Command::new("wl-paste").arg("--no-newline").output() {
+                        Ok(o) if o.status.success() => {
+                            ClipboardSnapshot::Text(String::from_utf8_lossy(&o.stdout).into_owned())
+                        }
+                        _ => ClipboardSnapshot::NonText,
+                    }
+                }
+                Err(_) if xclip_ran => ClipboardSnapshot::Empty,
+                Err(e) => {
+                    Self::log_with_callback(
+                        log_callback,
+                        &format!("[Warning] Cannot read clipboard (xclip/wl-paste): {e}"),
+                    );
+                    ClipboardSnapshot::NonText
+                }
+            }
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            let _ = log_callback;
+            match macos_helpers::get_clipboard() {
+                Some(text) => ClipboardSnapshot::Text(text),
+                None if macos_helpers::clipboard_is_empty() => ClipboardSnapshot::Empty,
+                None => ClipboardSnapshot::NonText,
+            }
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            if !clipboard_win::is_format_avail(clipboard_win::formats::CF_UNICODETEXT) {
+                return ClipboardSnapshot::NonText;
+            }
+            let text: Result<String, _> =
+                clipboard_win::get_clipboard(clipboard_win::formats::Unicode);
+            match text {
+                Ok(text) => ClipboardSnapshot::Text(text),
+                Err(e) => {
+                    Self::log_with_callback(
+                        log_callback,
+                        &format!("[Warning] Cannot read clipboard: {e}"),
+                    );
+                    ClipboardSnapshot::NonText
+                }
+            }
+        }
+    }
+
     fn log_with_callback(log_callback: &Arc<Mutex<Option<LogCallback>>>, message: &str) {
         if let Some(ref callback) = *log_callback.lock().unwrap() {
             callback(message);
@@ -179,9 +785,10 @@ impl OutputBehavior {
         }
     }
 
-    // Copy to clipboard only (no auto‑paste/sounds)
-    fn copy_to_clipboard_only(text: &str, log_callback: &Arc<Mutex<Option<LogCallback>>>) {
+    // Copy to clipboard only (no auto‑paste/sounds); false when every method failed
+    fn copy_to_clipboard_only(text: &str, log_callback: &Arc<Mutex<Option<LogCallback>>>) -> bool {
         use std::process::Command;
+        let mut copied = false;
 
         #[cfg(target_os = "linux")]
         {
@@ -197,6 +804,7 @@ impl OutputBehavior {
                         let _ = stdin.write_all(text.as_bytes());
                     }
                     let _ = child.wait();
+                    copied = true;
                     Self::log_with_callback(log_callback, "[Clipboard] Copied text");
                 }
                 Err(e) => {
@@ -221,6 +829,7 @@ impl OutputBehavior {
                                 let _ = stdin.write_all(text.as_bytes());
                             }
                             let _ = child.wait();
+                            copied = true;
                             Self::log_with_callback(
                                 log_callback,
                                 "[Clipboard] Copied text (Wayland)",
@@ -247,6 +856,7 @@ impl OutputBehavior {
         #[cfg(target_os = "macos")]
         {
             if macos_helpers::set_clipboard(text) {
+                copied = true;
                 Self::log_with_callback(log_callback, "[Clipboard] Copied text");
             } else {
                 let status = std::process::Command::new("/usr/bin/pbcopy")
@@ -261,6 +871,7 @@ impl OutputBehavior {
                     });
                 match status {
                     Ok(st) if st.success() => {
+                        copied = true;
                         Self::log_with_callback(log_callback, "[Clipboard] Copied text")
                     }
                     Ok(st) => Self::log_with_callback(
@@ -284,6 +895,7 @@ impl OutputBehavior {
             // Avoid piping to `clip.exe` which expects the current ANSI/OEM codepage and causes mojibake for UTF‑8.
             match clipboard_win::set_clipboard(clipboard_win::formats::Unicode, text) {
                 Ok(()) => {
+                    copied = true;
                     Self::log_with_callback(log_callback, "[Clipboard] Copied text");
                 }
                 Err(e) => {
@@ -301,6 +913,7 @@ impl OutputBehavior {
                         .status();
                     match ps {
                         Ok(st) if st.success() => {
+                            copied = true;
                             Self::log_with_callback(
                                 log_callback,
                                 "[Clipboard] Copied text (PowerShell)",
@@ -328,5 +941,138 @@ impl OutputBehavior {
                 }
             }
         }
+        copied
+    }
+}
+
+/// `prefix + text + suffix` with `{{date}}` (YYYY-MM-DD) and `{{time}}` (HH:MM) expanded.
+/// Empty text stays empty.
+pub fn wrap_transcript(
+    text: &str,
+    prefix: &str,
+    suffix: &str,
+    now: chrono::DateTime<chrono::Local>,
+) -> String {
+    if text.is_empty() || (prefix.is_empty() && suffix.is_empty()) {
+        return text.to_string();
+    }
+    let expand = |s: &str| {
+        s.replace("{{date}}", &now.format("%Y-%m-%d").to_string())
+            .replace("{{time}}", &now.format("%H:%M").to_string())
+    };
+    format!("{}{}{}", expand(prefix), text, expand(suffix))
+}
+
+/// One notes-file entry: a `--- YYYY-MM-DD HH:MM ---` separator line, then the text
+pub fn note_entry(text: &str, now: chrono::DateTime<chrono::Local>) -> String {
+    format!(
+        "\n--- {} ---\n{}\n",
+        now.format("%Y-%m-%d %H:%M"),
+        text.trim_end()
+    )
+}
+
+/// Target path with `{{date}}` (YYYY-MM-DD) expanded
+pub fn note_path(path: &Path, now: chrono::DateTime<chrono::Local>) -> PathBuf {
+    let raw = path.to_string_lossy();
+    if !raw.contains("{{date}}") {
+        return path.to_path_buf();
+    }
+    PathBuf::from(raw.replace("{{date}}", &now.format("%Y-%m-%d").to_string()))
+}
+
+fn append_note(path: &Path, entry: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(entry.as_bytes())
+}
+
+// Clipboard contents in order; the last one is pasted
+fn clipboard_texts(
+    mode: ClipboardMode,
+    raw: &str,
+    final_text: &str,
+    llm_output: Option<&str>,
+    wrap: &dyn Fn(&str) -> String,
+) -> Vec<String> {
+    let Some(polished) = llm_output.filter(|p| p.trim() != raw.trim()) else {
+        return vec![wrap(final_text)];
+    };
+    match mode {
+        ClipboardMode::PolishedOnly => vec![wrap(final_text)],
+        ClipboardMode::RawOnly => vec![wrap(raw)],
+        ClipboardMode::RawThenPolished => vec![wrap(raw), wrap(polished)],
+        // One greeting/signature around both
+        ClipboardMode::BothWithSeparator => {
+            vec![wrap(&format!("{}\n\n---\n\n{}", raw, polished))]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clipboard_texts, note_entry, note_path, wrap_transcript, ClipboardMode};
+    use chrono::TimeZone;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn wraps_with_placeholders() {
+        let now = chrono::Local.with_ymd_and_hms(2024, 3, 9, 8, 5, 0).unwrap();
+        assert_eq!(
+            wrap_transcript("Body", "Hi,\n", "\n-- {{date}} {{time}}", now),
+            "Hi,\nBody\n-- 2024-03-09 08:05"
+        );
+        assert_eq!(wrap_transcript("", "Hi ", "", now), "");
+        assert_eq!(wrap_transcript("Body", "", "", now), "Body");
+    }
+
+    #[test]
+    fn both_with_separator_wraps_once() {
+        let wrap = |text: &str| format!("Hi,\n{}\n-- Me", text);
+        assert_eq!(
+            clipboard_texts(
+                ClipboardMode::BothWithSeparator,
+                "raw text",
+                "Polished text.",
+                Some("Polished text."),
+                &wrap
+            ),
+            vec!["Hi,\nraw text\n\n---\n\nPolished text.\n-- Me".to_string()]
+        );
+        assert_eq!(
+            clipboard_texts(
+                ClipboardMode::RawThenPolished,
+                "raw text",
+                "Polished text.",
+                Some("Polished text."),
+                &wrap
+            ),
+            vec![
+                "Hi,\nraw text\n-- Me".to_string(),
+                "Hi,\nPolished text.\n-- Me".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn notes_entries_and_daily_paths() {
+        let now = chrono::Local.with_ymd_and_hms(2024, 3, 9, 8, 5, 0).unwrap();
+        assert_eq!(
+            note_entry("Buy milk\n", now),
+            "\n--- 2024-03-09 08:05 ---\nBuy milk\n"
+        );
+        assert_eq!(
+            note_path(Path::new("/notes/{{date}}.md"), now),
+            PathBuf::from("/notes/2024-03-09.md")
+        );
+        assert_eq!(
+            note_path(Path::new("/notes/inbox.md"), now),
+            PathBuf::from("/notes/inbox.md")
+        );
     }
 }
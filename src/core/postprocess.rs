@@ -1,50 +1,148 @@
-use crate::core::{LogCallback, SimpleRecState};
+use crate::core::output::wrap_transcript;
+use crate::core::{ErrorEvent, ErrorNotifier, LogCallback, SimpleRecState};
 use crate::llm::{
-    history_file_path, record_history, LlmPostProcessSettings, LlmPostProcessor,
-    MAX_HISTORY_ENTRIES,
+    history_file_path, record_history, HistoryRedaction, HistoryRetention, LlmPostProcessSettings,
+    LlmPostProcessor,
 };
 use std::sync::{Arc, Mutex};
 
 pub struct PostProcessResult {
     pub final_text: String,
+    // LLM result even when `apply_to_autopaste` kept the Whisper text
+    pub llm_output: Option<String>,
     pub llm_latency_secs: f32,
 }
 
 #[derive(Clone)]
 pub struct PostProcessEngine {
     settings: Arc<Mutex<LlmPostProcessSettings>>,
+    mode_override: Arc<Mutex<Option<String>>>,
     processor: Arc<LlmPostProcessor>,
     state: Arc<Mutex<SimpleRecState>>,
+    errors: ErrorNotifier,
+    history_retention: Arc<Mutex<HistoryRetention>>,
+    history_enabled: Arc<Mutex<bool>>,
+    history_redaction: Arc<Mutex<HistoryRedaction>>,
+    // Fixed text around every transcript (prefix, suffix); {{date}}/{{time}} expanded
+    boilerplate: Arc<Mutex<(String, String)>>,
 }
 
 impl PostProcessEngine {
     pub fn new(
         settings: Arc<Mutex<LlmPostProcessSettings>>,
+        mode_override: Arc<Mutex<Option<String>>>,
         processor: Arc<LlmPostProcessor>,
         state: Arc<Mutex<SimpleRecState>>,
+        errors: ErrorNotifier,
     ) -> Self {
         Self {
             settings,
+            mode_override,
             processor,
             state,
+            errors,
+            history_retention: Arc::new(Mutex::new(HistoryRetention::default())),
+            history_enabled: Arc::new(Mutex::new(true)),
+            history_redaction: Arc::new(Mutex::new(HistoryRedaction::default())),
+            boilerplate: Arc::new(Mutex::new((String::new(), String::new()))),
         }
     }
 
+    /// Whether `process` will call the LLM (enabled, or a mode hotkey forces it)
+    pub fn will_run(&self) -> bool {
+        self.settings.lock().unwrap().enabled || self.mode_override.lock().unwrap().is_some()
+    }
+
+    /// Whether the LLM result replaces the Whisper text in the output (it runs and
+    /// `apply_to_autopaste` is on)
+    pub fn replaces_text(&self) -> bool {
+        self.will_run() && self.settings.lock().unwrap().apply_to_autopaste
+    }
+
     pub fn set_settings(&self, settings: LlmPostProcessSettings) {
         *self.settings.lock().unwrap() = settings;
     }
 
+    pub fn set_history_retention(&self, retention: HistoryRetention) {
+        *self.history_retention.lock().unwrap() = retention;
+    }
+
+    pub fn set_history_privacy(&self, enabled: bool, redaction_patterns: &[String]) {
+        *self.history_enabled.lock().unwrap() = enabled;
+        // Called every frame: recompile only when the patterns changed
+        let mut redaction = self.history_redaction.lock().unwrap();
+        if redaction.patterns() != redaction_patterns {
+            *redaction = HistoryRedaction::new(redaction_patterns);
+        }
+    }
+
+    pub fn set_boilerplate(&self, prefix: String, suffix: String) {
+        *self.boilerplate.lock().unwrap() = (prefix, suffix);
+    }
+
+    /// `text` with the transcript prefix/suffix around it (what history and output see)
+    pub fn wrap(&self, text: &str, now: chrono::DateTime<chrono::Local>) -> String {
+        let (prefix, suffix) = self.boilerplate.lock().unwrap().clone();
+        wrap_transcript(text, &prefix, &suffix, now)
+    }
+
     pub fn process(
         &self,
         base_text: &str,
         dictionary_hint: Option<&str>,
         language_hint: Option<&str>,
+        recording_duration_secs: f32,
+        log: &Arc<Mutex<Option<LogCallback>>>,
+    ) -> PostProcessResult {
+        self.run(
+            base_text,
+            dictionary_hint,
+            language_hint,
+            recording_duration_secs,
+            log,
+            true,
+        )
+    }
+
+    /// `process` for a transcript that already has its history entry (re-applying
+    /// the dictionary): nothing new is written to the history
+    pub fn reprocess(
+        &self,
+        base_text: &str,
+        dictionary_hint: Option<&str>,
+        language_hint: Option<&str>,
+        recording_duration_secs: f32,
+        log: &Arc<Mutex<Option<LogCallback>>>,
+    ) -> PostProcessResult {
+        self.run(
+            base_text,
+            dictionary_hint,
+            language_hint,
+            recording_duration_secs,
+            log,
+            false,
+        )
+    }
+
+    fn run(
+        &self,
+        base_text: &str,
+        dictionary_hint: Option<&str>,
+        language_hint: Option<&str>,
+        recording_duration_secs: f32,
         log: &Arc<Mutex<Option<LogCallback>>>,
+        record: bool,
     ) -> PostProcessResult {
-        let snapshot = self.settings.lock().unwrap().clone();
+        let mut snapshot = self.settings.lock().unwrap().clone();
+        // Mode-specific hotkeys force their mode even when post-processing is off
+        if let Some(mode_id) = self.mode_override.lock().unwrap().clone() {
+            snapshot.enabled = true;
+            snapshot.mode_id = mode_id;
+        }
         if !snapshot.enabled {
             return PostProcessResult {
                 final_text: base_text.to_string(),
+                llm_output: None,
                 llm_latency_secs: 0.0,
             };
         }
@@ -55,7 +153,7 @@ impl PostProcessEngine {
             &format!(
                 "[llm] Processing via {} (model: {}).",
                 snapshot.effective_base_url(),
-                snapshot.model
+                snapshot.effective_model()
             ),
         );
 
@@ -78,13 +176,9 @@ impl PostProcessEngine {
                 let latency_ms = outcome.latency_ms;
                 history_payload = Some((content.clone(), truncated_input, latency_ms));
                 if outcome.truncated_input {
-                    log_message(
-                        log,
-                        &format!(
-                            "[llm] Input truncated to {} chars.",
-                            snapshot.max_input_chars
-                        ),
-                    );
+                    let detail = format!("Input truncated to {} chars.", snapshot.max_input_chars);
+                    log_message(log, &format!("[llm] {}", detail));
+                    self.errors.notify(ErrorEvent::LlmTruncated(detail));
                 }
                 llm_latency_secs = latency_ms as f32 / 1000.0;
                 log_message(
@@ -92,6 +186,10 @@ impl PostProcessEngine {
                     &format!("[llm] Completed in {:.2}s.", llm_latency_secs),
                 );
                 log_message(log, &format!("[llm][output] {}", content));
+                if let Some(warning) = outcome.warning {
+                    log_message(log, &format!("[llm][warning] {}", warning));
+                    self.errors.notify(ErrorEvent::Llm(warning));
+                }
                 llm_output_for_log = Some(content.clone());
                 if snapshot.apply_to_autopaste {
                     final_text = content.clone();
@@ -112,16 +210,28 @@ impl PostProcessEngine {
                 }
                 log_message(log, &format!("[llm][error] {}", message));
                 log_message(log, "[llm] Falling back to Whisper text.");
+                self.errors.notify(ErrorEvent::Llm(message));
             }
         }
 
-        if let Some((llm_output, truncated_input, latency_ms)) = history_payload {
+        if history_payload.is_some() && !*self.history_enabled.lock().unwrap() {
+            log_message(log, "[llm][history] History is disabled; entry not saved");
+            history_payload = None;
+        }
+        if let Some((llm_output, truncated_input, latency_ms)) = history_payload.filter(|_| record)
+        {
+            let retention = *self.history_retention.lock().unwrap();
+            let redaction = self.history_redaction.lock().unwrap().clone();
+            let now = chrono::Local::now();
             match record_history(
-                base_text,
-                &llm_output,
+                &self.wrap(base_text, now),
+                &self.wrap(&llm_output, now),
                 truncated_input,
                 latency_ms,
+                recording_duration_secs,
                 &snapshot,
+                retention,
+                &redaction,
             ) {
                 Ok(outcome) => {
                     log_message(
@@ -129,7 +239,7 @@ impl PostProcessEngine {
                         &format!(
                             "[llm][history] Saved entry {}/{} → {}",
                             outcome.total_entries,
-                            MAX_HISTORY_ENTRIES,
+                            retention.max_entries,
                             history_file_path().display()
                         ),
                     );
@@ -157,6 +267,7 @@ impl PostProcessEngine {
 
         PostProcessResult {
             final_text,
+            llm_output: llm_output_for_log,
             llm_latency_secs,
         }
     }
@@ -0,0 +1,106 @@
+//! "Run self-test": a bundled clip goes through the current model, dictionary
+//! and LLM settings without a microphone; each stage reports timing and output.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::utils::{app_config_dir, paths::resolve_resource};
+
+/// Sample clip shipped next to the sound effects (16 kHz mono WAV)
+pub const SELF_TEST_SAMPLE: &str = "sounds/selftest.wav";
+/// Spoken for a missing sample when `sounds/selftest.txt` cannot be read
+const SELF_TEST_SENTENCE: &str = "HootVoice self test. One two three.";
+
+/// The bundled clip. Without one (e.g. a source build) the text of `selftest.txt`
+/// is spoken once by the OS text-to-speech into the config dir, where
+/// `resolve_resource` looks first.
+pub fn sample_path() -> Result<PathBuf, String> {
+    if let Some(path) = resolve_resource(SELF_TEST_SAMPLE) {
+        return Ok(path);
+    }
+    let path = app_config_dir().join(SELF_TEST_SAMPLE);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    crate::utils::tts::synthesize_to_file(&sample_sentence(), Some("en"), &path).map_err(|e| {
+        let _ = std::fs::remove_file(&path);
+        format!(
+            "{} not found and could not be generated: {:#}",
+            SELF_TEST_SAMPLE, e
+        )
+    })?;
+    Ok(path)
+}
+
+// The benchmark scores against `selftest.txt`, so a generated sample speaks the same words
+fn sample_sentence() -> String {
+    resolve_resource(super::benchmark::BENCHMARK_REFERENCE)
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+        .unwrap_or_else(|| SELF_TEST_SENTENCE.to_string())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelfTestStageKind {
+    Sample,
+    Transcription,
+    Dictionary,
+    Llm,
+}
+
+impl SelfTestStageKind {
+    /// i18n key of the stage name
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Self::Sample => "selftest-stage-sample",
+            Self::Transcription => "selftest-stage-transcription",
+            Self::Dictionary => "selftest-stage-dictionary",
+            Self::Llm => "selftest-stage-llm",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StageOutcome {
+    /// Output of the stage (transcript, corrected text, ...)
+    Passed(String),
+    /// Not run (e.g. LLM disabled); the reason is an i18n key
+    Skipped(&'static str),
+    Failed(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelfTestStage {
+    pub kind: SelfTestStageKind,
+    pub elapsed: Duration,
+    pub outcome: StageOutcome,
+}
+
+impl SelfTestStage {
+    /// Run one stage and time it
+    pub(super) fn run(kind: SelfTestStageKind, f: impl FnOnce() -> Result<String, String>) -> Self {
+        let start = Instant::now();
+        let outcome = match f() {
+            Ok(text) => StageOutcome::Passed(text),
+            Err(e) => StageOutcome::Failed(e),
+        };
+        Self {
+            kind,
+            elapsed: start.elapsed(),
+            outcome,
+        }
+    }
+
+    pub(super) fn skipped(kind: SelfTestStageKind, reason_key: &'static str) -> Self {
+        Self {
+            kind,
+            elapsed: Duration::ZERO,
+            outcome: StageOutcome::Skipped(reason_key),
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        !matches!(self.outcome, StageOutcome::Failed(_))
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::utils::app_config_dir;
 
@@ -12,6 +12,28 @@ pub struct DictionaryEntry {
     /// If omitted or empty, the entry always applies.
     #[serde(default)]
     pub include: Vec<String>,
+    /// Also match aliases within a small edit distance (whole words only).
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub fuzzy: bool,
+    /// Whisper language codes ("ja", "en", ...) this entry applies to.
+    /// If omitted or empty, the entry applies to every language.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locales: Vec<String>,
+    /// Disabled entries are kept in the file but never applied.
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub enabled: bool,
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn is_true(b: &bool) -> bool {
+    *b
 }
 
 pub type Dictionary = Vec<DictionaryEntry>;
@@ -54,11 +76,51 @@ pub fn save_dictionary(dict: &Dictionary) -> anyhow::Result<()> {
 // Build flattened (alias -> canonical) pairs sorted by alias length descending.
 // removed: flatten_sorted (unused)
 // Note: kept as a normal comment to avoid doc-confusion lint
-/// Filter by `include` terms and return alias -> canonical pairs sorted by longest alias first
-pub fn flatten_sorted_with_context(dict: &Dictionary, context_text: &str) -> Vec<(String, String)> {
+/// Filter by `include` terms and `locales` (`language` is the selected or detected
+/// transcription language; None = unknown, locales are not checked) and return
+/// alias -> canonical pairs sorted by longest alias first
+pub fn flatten_sorted_with_context(
+    dict: &Dictionary,
+    context_text: &str,
+    language: Option<&str>,
+) -> Vec<(String, String)> {
+    flatten_applicable(dict, context_text, language, |_| true)
+}
+
+/// Same as `flatten_sorted_with_context`, limited to entries with `fuzzy` enabled
+pub fn fuzzy_pairs_with_context(
+    dict: &Dictionary,
+    context_text: &str,
+    language: Option<&str>,
+) -> Vec<(String, String)> {
+    flatten_applicable(dict, context_text, language, |e| e.fuzzy)
+}
+
+// "en" matches "en" and "en-US"; case-insensitive
+fn locale_matches(entry: &DictionaryEntry, language: Option<&str>) -> bool {
+    let Some(lang) = language else {
+        return true;
+    };
+    let primary = lang.split(['-', '_']).next().unwrap_or(lang);
+    entry.locales.is_empty()
+        || entry.locales.iter().any(|l| {
+            let l = l.trim();
+            l.eq_ignore_ascii_case(lang) || l.eq_ignore_ascii_case(primary)
+        })
+}
+
+fn flatten_applicable(
+    dict: &Dictionary,
+    context_text: &str,
+    language: Option<&str>,
+    keep: impl Fn(&DictionaryEntry) -> bool,
+) -> Vec<(String, String)> {
     let ctx_lower = context_text.to_lowercase();
     let mut pairs: Vec<(String, String)> = Vec::new();
-    for entry in dict.iter() {
+    for entry in dict
+        .iter()
+        .filter(|e| e.enabled && keep(e) && locale_matches(e, language))
+    {
         // If `include` is empty apply unconditionally; otherwise require any term to match
         let applicable = if entry.include.is_empty() {
             true
@@ -83,7 +145,18 @@ pub fn flatten_sorted_with_context(dict: &Dictionary, context_text: &str) -> Vec
 
 /// Apply dictionary pairs to text using longest-first replacement.
 pub fn apply_pairs(text: &str, pairs: &[(String, String)]) -> String {
-    if pairs.is_empty() || text.is_empty() {
+    apply_pairs_with_fuzzy(text, pairs, &[], 0)
+}
+
+/// Exact replacement first, then whole-word fuzzy matches of `fuzzy_pairs` within
+/// `max_distance` edits (spacing differences are free) on the remaining text.
+pub fn apply_pairs_with_fuzzy(
+    text: &str,
+    pairs: &[(String, String)],
+    fuzzy_pairs: &[(String, String)],
+    max_distance: usize,
+) -> String {
+    if (pairs.is_empty() && fuzzy_pairs.is_empty()) || text.is_empty() {
         return text.to_string();
     }
 
@@ -103,6 +176,9 @@ pub fn apply_pairs(text: &str, pairs: &[(String, String)]) -> String {
             ranges.push((start, end, canon));
         }
     }
+    if !fuzzy_pairs.is_empty() {
+        collect_fuzzy_ranges(text, fuzzy_pairs, max_distance, &mut ranges);
+    }
     if ranges.is_empty() {
         return text.to_string();
     }
@@ -119,6 +195,424 @@ pub fn apply_pairs(text: &str, pairs: &[(String, String)]) -> String {
     out
 }
 
+// Word tokens as byte ranges (runs of alphanumerics; apostrophes stay inside a word)
+fn word_tokens(text: &str) -> Vec<(usize, usize)> {
+    let mut tokens = Vec::new();
+    let mut start: Option<usize> = None;
+    for (i, c) in text.char_indices() {
+        let in_word = c.is_alphanumeric() || (c == '\'' && start.is_some());
+        match (in_word, start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                tokens.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        tokens.push((s, text.len()));
+    }
+    tokens
+}
+
+// Lowercase with whitespace/hyphens removed so "kuber netes" == "Kubernetes"
+fn compact_lower(s: &str) -> Vec<char> {
+    s.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+// Levenshtein distance, giving up (None) once it exceeds `max`
+fn edit_distance_within(a: &[char], b: &[char], max: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        let mut row_min = cur[0];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+            row_min = row_min.min(cur[j + 1]);
+        }
+        if row_min > max {
+            return None;
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    let d = prev[b.len()];
+    (d <= max).then_some(d)
+}
+
+// Allowed edits scale with alias length so short words are never "corrected"
+fn fuzzy_budget(alias_len: usize, max_distance: usize) -> usize {
+    max_distance.min(alias_len / 4)
+}
+
+fn collect_fuzzy_ranges<'a>(
+    text: &str,
+    fuzzy_pairs: &'a [(String, String)],
+    max_distance: usize,
+    ranges: &mut Vec<(usize, usize, &'a str)>,
+) {
+    let tokens = word_tokens(text);
+    for (alias, canon) in fuzzy_pairs.iter() {
+        let target = compact_lower(alias);
+        if target.is_empty() {
+            continue;
+        }
+        let budget = fuzzy_budget(target.len(), max_distance);
+        // A spoken alias may be split into one more word than it is written with
+        let max_words = alias.split_whitespace().count() + 1;
+        let mut i = 0;
+        while i < tokens.len() {
+            let mut matched = None;
+            for n in (1..=max_words.min(tokens.len() - i)).rev() {
+                let (start, end) = (tokens[i].0, tokens[i + n - 1].1);
+                // Only join words separated by spaces/hyphens (never across punctuation)
+                let joined_ok = tokens[i..i + n].windows(2).all(|w| {
+                    text[w[0].1..w[1].0]
+                        .chars()
+                        .all(|c| c.is_whitespace() || c == '-')
+                });
+                if !joined_ok || ranges.iter().any(|&(s, e, _)| start < e && s < end) {
+                    continue;
+                }
+                let candidate = &text[start..end];
+                if candidate == canon {
+                    continue;
+                }
+                if edit_distance_within(&compact_lower(candidate), &target, budget).is_some() {
+                    matched = Some((n, start, end));
+                    break;
+                }
+            }
+            match matched {
+                Some((n, start, end)) => {
+                    ranges.push((start, end, canon));
+                    i += n;
+                }
+                None => i += 1,
+            }
+        }
+    }
+}
+
+/// Dictionary summary passed to the LLM as `{{dictionary}}`.
+pub fn llm_prompt_hint(entries: &[DictionaryEntry]) -> Option<String> {
+    const MAX_LINES: usize = 40;
+    let mut lines = Vec::new();
+    for entry in entries {
+        if !entry.enabled || entry.aliases.is_empty() {
+            continue;
+        }
+        let mut line = format!("- {}: {}", entry.canonical, entry.aliases.join(", "));
+        if !entry.include.is_empty() {
+            line.push_str(" (context: ");
+            line.push_str(&entry.include.join(", "));
+            line.push(')');
+        }
+        lines.push(line);
+        if lines.len() >= MAX_LINES {
+            break;
+        }
+    }
+    if lines.is_empty() {
+        None
+    } else {
+        let mut prompt = String::from("User dictionary replacements:\n");
+        prompt.push_str(&lines.join("\n"));
+        Some(prompt)
+    }
+}
+
+const CSV_HEADER: [&str; 6] = [
+    "canonical",
+    "aliases",
+    "include",
+    "fuzzy",
+    "locales",
+    "enabled",
+];
+
+/// Result of merging imported entries into the dictionary.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub added: usize,
+    pub merged: usize,
+    /// Rows dropped because the canonical form was empty
+    pub skipped: usize,
+}
+
+/// Export as CSV rows: `canonical,aliases(;-joined),include(;-joined),fuzzy,locales,enabled` with a header line.
+pub fn export_csv(dict: &Dictionary, path: &Path) -> anyhow::Result<()> {
+    let mut out = String::new();
+    out.push_str(&CSV_HEADER.join(","));
+    out.push('\n');
+    for entry in dict.iter() {
+        let fields = [
+            entry.canonical.clone(),
+            entry.aliases.join(";"),
+            entry.include.join(";"),
+            if entry.fuzzy { "true" } else { "" }.to_string(),
+            entry.locales.join(";"),
+            if entry.enabled { "" } else { "false" }.to_string(),
+        ];
+        let row: Vec<String> = fields.iter().map(|f| csv_escape(f)).collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    fs::write(path, out)?;
+    Ok(())
+}
+
+/// Import CSV rows and merge them into `dict` by canonical form (aliases/includes unioned).
+pub fn import_csv(dict: &mut Dictionary, path: &Path) -> anyhow::Result<ImportSummary> {
+    let text = fs::read_to_string(path)?;
+    let (entries, skipped) = parse_csv_entries(&text);
+    let mut summary = merge_entries(dict, entries);
+    summary.skipped = skipped;
+    Ok(summary)
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// Minimal RFC 4180 reader: quoted fields may contain commas, quotes ("") and newlines
+fn parse_csv_rows(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+fn split_list(field: &str) -> Vec<String> {
+    field
+        .split(';')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+// Returns parsed entries and the number of rows skipped for an empty canonical
+fn parse_csv_entries(text: &str) -> (Vec<DictionaryEntry>, usize) {
+    let mut entries = Vec::new();
+    let mut skipped = 0;
+    for (idx, row) in parse_csv_rows(text).into_iter().enumerate() {
+        if row.iter().all(|f| f.trim().is_empty()) {
+            continue;
+        }
+        let canonical = row.first().map(|s| s.trim()).unwrap_or_default();
+        if idx == 0 && canonical.eq_ignore_ascii_case(CSV_HEADER[0]) {
+            continue;
+        }
+        if canonical.is_empty() {
+            skipped += 1;
+            continue;
+        }
+        entries.push(DictionaryEntry {
+            canonical: canonical.to_string(),
+            aliases: row.get(1).map(|f| split_list(f)).unwrap_or_default(),
+            include: row.get(2).map(|f| split_list(f)).unwrap_or_default(),
+            // Older 3-column files have no fuzzy column
+            fuzzy: row
+                .get(3)
+                .map(|f| matches!(f.trim().to_lowercase().as_str(), "true" | "1" | "yes"))
+                .unwrap_or(false),
+            locales: row.get(4).map(|f| split_list(f)).unwrap_or_default(),
+            // Empty or missing means enabled
+            enabled: row
+                .get(5)
+                .is_none_or(|f| !matches!(f.trim().to_lowercase().as_str(), "false" | "0" | "no")),
+        });
+    }
+    (entries, skipped)
+}
+
+/// Entries that can apply to the same transcript yet disagree (indices into the dictionary)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DictionaryConflict {
+    /// One alias maps to different canonical forms; which one wins is arbitrary
+    AmbiguousAlias { alias: String, entries: Vec<usize> },
+    /// Several entries share a canonical form (can be merged into the first)
+    DuplicateCanonical {
+        canonical: String,
+        entries: Vec<usize>,
+    },
+}
+
+impl DictionaryConflict {
+    pub fn entries(&self) -> &[usize] {
+        match self {
+            Self::AmbiguousAlias { entries, .. } | Self::DuplicateCanonical { entries, .. } => {
+                entries
+            }
+        }
+    }
+}
+
+// Both entries can be active for one language (no locales = every language)
+fn locales_overlap(a: &DictionaryEntry, b: &DictionaryEntry) -> bool {
+    a.locales.is_empty()
+        || b.locales.is_empty()
+        || a.locales.iter().any(|l| {
+            b.locales
+                .iter()
+                .any(|m| l.trim().eq_ignore_ascii_case(m.trim()))
+        })
+}
+
+// Groups of enabled entries sharing `key`, where some pair also shares a language
+fn overlapping_groups<'a>(
+    dict: &'a Dictionary,
+    keys: impl Fn(&'a DictionaryEntry) -> Vec<&'a str>,
+) -> Vec<(&'a str, Vec<usize>)> {
+    let mut groups: Vec<(&str, Vec<usize>)> = Vec::new();
+    for (i, entry) in dict.iter().enumerate().filter(|(_, e)| e.enabled) {
+        for key in keys(entry) {
+            match groups.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, members)) if !members.contains(&i) => members.push(i),
+                Some(_) => {}
+                None => groups.push((key, vec![i])),
+            }
+        }
+    }
+    groups.retain(|(_, members)| {
+        members.iter().enumerate().any(|(n, &a)| {
+            members[n + 1..]
+                .iter()
+                .any(|&b| locales_overlap(&dict[a], &dict[b]))
+        })
+    });
+    groups
+}
+
+/// Aliases claimed by entries with different canonical forms, and canonical
+/// forms used by more than one entry. Disabled entries and entries for
+/// disjoint languages never conflict.
+pub fn find_conflicts(dict: &Dictionary) -> Vec<DictionaryConflict> {
+    let mut conflicts: Vec<DictionaryConflict> =
+        overlapping_groups(dict, |e| vec![e.canonical.trim()])
+            .into_iter()
+            .filter(|(canonical, _)| !canonical.is_empty())
+            .map(
+                |(canonical, entries)| DictionaryConflict::DuplicateCanonical {
+                    canonical: canonical.to_string(),
+                    entries,
+                },
+            )
+            .collect();
+    let aliases = overlapping_groups(dict, |e| {
+        e.aliases
+            .iter()
+            .map(|a| a.trim())
+            .filter(|a| !a.is_empty())
+            .collect()
+    });
+    for (alias, entries) in aliases {
+        let first = dict[entries[0]].canonical.trim();
+        if entries.iter().any(|&i| dict[i].canonical.trim() != first) {
+            conflicts.push(DictionaryConflict::AmbiguousAlias {
+                alias: alias.to_string(),
+                entries,
+            });
+        }
+    }
+    conflicts
+}
+
+/// Merge the entries at `indices` into the first of them (as CSV import does)
+pub fn merge_into_first(dict: &mut Dictionary, indices: &[usize]) {
+    let Some((&first, rest)) = indices.split_first() else {
+        return;
+    };
+    let mut rest: Vec<usize> = rest
+        .iter()
+        .copied()
+        .filter(|&i| i != first && i < dict.len())
+        .collect();
+    rest.sort_unstable();
+    rest.dedup();
+    let mut target = first;
+    for &i in rest.iter().rev() {
+        let entry = dict.remove(i);
+        if i < target {
+            target -= 1;
+        }
+        let existing = &mut dict[target];
+        union_into(&mut existing.aliases, entry.aliases);
+        union_into(&mut existing.include, entry.include);
+        existing.fuzzy |= entry.fuzzy;
+        union_into(&mut existing.locales, entry.locales);
+    }
+}
+
+fn union_into(target: &mut Vec<String>, extra: Vec<String>) {
+    for item in extra {
+        if !target.contains(&item) {
+            target.push(item);
+        }
+    }
+}
+
+/// Merge entries by canonical form; returns how many were added vs merged.
+pub fn merge_entries(dict: &mut Dictionary, incoming: Vec<DictionaryEntry>) -> ImportSummary {
+    let mut summary = ImportSummary::default();
+    for entry in incoming {
+        if let Some(existing) = dict.iter_mut().find(|e| e.canonical == entry.canonical) {
+            union_into(&mut existing.aliases, entry.aliases);
+            union_into(&mut existing.include, entry.include);
+            existing.fuzzy |= entry.fuzzy;
+            union_into(&mut existing.locales, entry.locales);
+            summary.merged += 1;
+        } else {
+            dict.push(entry);
+            summary.added += 1;
+        }
+    }
+    summary
+}
+
 fn default_sample_yaml() -> String {
     // Provide a simple readable YAML sample with common tech words
     // Users can edit via the Settings > Dictionary tab.
@@ -136,7 +630,11 @@ fn default_sample_yaml() -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{apply_pairs, flatten_sorted_with_context, DictionaryEntry};
+    use super::{
+        apply_pairs, apply_pairs_with_fuzzy, find_conflicts, flatten_sorted_with_context,
+        llm_prompt_hint, merge_entries, merge_into_first, parse_csv_entries, Dictionary,
+        DictionaryConflict, DictionaryEntry,
+    };
 
     #[test]
     fn no_recursive_replacement() {
@@ -145,20 +643,194 @@ mod tests {
                 canonical: "1".into(),
                 aliases: vec!["12".into()],
                 include: vec![],
+                fuzzy: false,
+                locales: vec![],
+                enabled: true,
             },
             DictionaryEntry {
                 canonical: "one".into(),
                 aliases: vec!["1".into()],
                 include: vec![],
+                fuzzy: false,
+                locales: vec![],
+                enabled: true,
             },
         ];
-        let pairs = flatten_sorted_with_context(&dict, "");
+        let pairs = flatten_sorted_with_context(&dict, "", None);
         assert_eq!(apply_pairs("12 1", &pairs), "1 one");
     }
 
+    #[test]
+    fn locales_gate_entries() {
+        let dict = vec![
+            DictionaryEntry {
+                canonical: "Kubernetes".into(),
+                aliases: vec!["kube".into()],
+                include: vec![],
+                fuzzy: false,
+                locales: vec!["en".into()],
+                enabled: true,
+            },
+            DictionaryEntry {
+                canonical: "API".into(),
+                aliases: vec!["エーピーアイ".into(), "api".into()],
+                include: vec![],
+                fuzzy: false,
+                locales: vec![],
+                enabled: true,
+            },
+        ];
+        let en = flatten_sorted_with_context(&dict, "", Some("en"));
+        assert_eq!(apply_pairs("kube api", &en), "Kubernetes API");
+        let ja = flatten_sorted_with_context(&dict, "", Some("ja"));
+        assert_eq!(apply_pairs("kube エーピーアイ", &ja), "kube API");
+        // Unknown language applies everything (previous behavior)
+        let any = flatten_sorted_with_context(&dict, "", None);
+        assert_eq!(apply_pairs("kube", &any), "Kubernetes");
+    }
+
+    #[test]
+    fn disabled_entries_are_skipped() {
+        let yaml = "- canonical: API\n  aliases: [api]\n  enabled: false\n- canonical: Rust\n  aliases: [rustlang]\n";
+        let dict: Dictionary = serde_yaml::from_str(yaml).unwrap();
+        assert!(!dict[0].enabled);
+        // Older files without the field load as enabled
+        assert!(dict[1].enabled);
+        let pairs = flatten_sorted_with_context(&dict, "", None);
+        assert_eq!(apply_pairs("api rustlang", &pairs), "api Rust");
+        assert_eq!(
+            llm_prompt_hint(&dict).as_deref(),
+            Some("User dictionary replacements:\n- Rust: rustlang")
+        );
+        let (entries, _) = parse_csv_entries("API,api,,,,false\nRust,rustlang\n");
+        assert!(!entries[0].enabled);
+        assert!(entries[1].enabled);
+    }
+
     #[test]
     fn longest_first_without_overlap() {
         let pairs = vec![("foobar".into(), "X".into()), ("foo".into(), "Y".into())];
         assert_eq!(apply_pairs("foobar foo", &pairs), "X Y");
     }
+
+    #[test]
+    fn csv_rows_with_quotes_and_header() {
+        let csv = "canonical,aliases,include\n\"Foo, Inc.\",\"foo;\"\"F\"\"\",\n,orphan,\nRust,rustlang,cargo;crate\n";
+        let (entries, skipped) = parse_csv_entries(csv);
+        assert_eq!(skipped, 1);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].canonical, "Foo, Inc.");
+        assert_eq!(entries[0].aliases, vec!["foo", "\"F\""]);
+        assert_eq!(entries[1].include, vec!["cargo", "crate"]);
+    }
+
+    #[test]
+    fn merge_unions_by_canonical() {
+        let mut dict = vec![DictionaryEntry {
+            canonical: "Rust".into(),
+            aliases: vec!["rustlang".into()],
+            include: vec![],
+            fuzzy: false,
+            locales: vec![],
+            enabled: true,
+        }];
+        let incoming = vec![
+            DictionaryEntry {
+                canonical: "Rust".into(),
+                aliases: vec!["rustlang".into(), "Rust language".into()],
+                include: vec!["cargo".into()],
+                fuzzy: false,
+                locales: vec![],
+                enabled: true,
+            },
+            DictionaryEntry {
+                canonical: "Whisper".into(),
+                aliases: vec!["whisper-rs".into()],
+                include: vec![],
+                fuzzy: false,
+                locales: vec![],
+                enabled: true,
+            },
+        ];
+        let summary = merge_entries(&mut dict, incoming);
+        assert_eq!((summary.added, summary.merged), (1, 1));
+        assert_eq!(dict[0].aliases, vec!["rustlang", "Rust language"]);
+        assert_eq!(dict[0].include, vec!["cargo"]);
+    }
+
+    #[test]
+    fn conflicts_need_a_shared_language() {
+        let entry = |canonical: &str, aliases: &[&str], locales: &[&str]| DictionaryEntry {
+            canonical: canonical.into(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+            include: vec![],
+            fuzzy: false,
+            locales: locales.iter().map(|l| l.to_string()).collect(),
+            enabled: true,
+        };
+        let mut dict: Dictionary = vec![
+            entry("Kubernetes", &["kube", "k8s"], &[]),
+            entry("Rust", &["rustlang"], &["en"]),
+            entry("Kubectl", &["kube"], &[]),
+            // Same alias, but only one entry applies per language
+            entry("ラスト", &["rustlang"], &["ja"]),
+            entry("Kubernetes", &["K8S"], &[]),
+        ];
+        assert_eq!(
+            find_conflicts(&dict),
+            vec![
+                DictionaryConflict::DuplicateCanonical {
+                    canonical: "Kubernetes".into(),
+                    entries: vec![0, 4],
+                },
+                DictionaryConflict::AmbiguousAlias {
+                    alias: "kube".into(),
+                    entries: vec![0, 2],
+                },
+            ]
+        );
+
+        merge_into_first(&mut dict, &[4, 0]);
+        assert_eq!(dict.len(), 4);
+        assert_eq!(dict[3].aliases, vec!["K8S", "kube", "k8s"]);
+        dict[1].enabled = false;
+        dict[2].enabled = false;
+        assert!(find_conflicts(&dict).is_empty());
+    }
+
+    #[test]
+    fn fuzzy_matches_whole_words_only() {
+        let fuzzy = vec![("kubernetes".into(), "Kubernetes".into())];
+        assert_eq!(
+            apply_pairs_with_fuzzy("deploy to kuber netes now", &[], &fuzzy, 1),
+            "deploy to Kubernetes now"
+        );
+        assert_eq!(
+            apply_pairs_with_fuzzy("kubernete, cluster", &[], &fuzzy, 1),
+            "Kubernetes, cluster"
+        );
+        // Substring of a longer word is left alone
+        assert_eq!(
+            apply_pairs_with_fuzzy("superkubernetesx", &[], &fuzzy, 1),
+            "superkubernetesx"
+        );
+    }
+
+    #[test]
+    fn fuzzy_budget_limits_short_aliases() {
+        let fuzzy = vec![
+            ("api".into(), "API".into()),
+            ("postgres".into(), "PostgreSQL".into()),
+        ];
+        // 3-letter alias allows no edits even with a larger global threshold
+        assert_eq!(apply_pairs_with_fuzzy("ape", &[], &fuzzy, 3), "ape");
+        assert_eq!(
+            apply_pairs_with_fuzzy("postgress", &[], &fuzzy, 1),
+            "PostgreSQL"
+        );
+        assert_eq!(
+            apply_pairs_with_fuzzy("postgrezzz", &[], &fuzzy, 1),
+            "postgrezzz"
+        );
+    }
 }
@@ -1,20 +1,27 @@
 use eframe::egui;
 use std::sync::{Arc, Mutex};
 // no cross-thread command channel needed; handle hotkey/SIGUSR1 inline
-use crate::utils::logfile::{push_log_and_persist, trim_log_file_startup};
-use chrono::Local;
+use crate::utils::logfile::{push_log_and_persist, trim_log_file_startup, LogEntry, LogLevel};
 use std::collections::VecDeque;
 use std::fs::OpenOptions;
+use std::path::PathBuf;
 
 use super::floating::FloatingWindow;
-use super::settings::SettingsWindow;
+use super::settings::{SettingsWindow, StatusColors, ThemeMode};
 use super::waybar;
 use crate::audio::VadStrategy;
-use crate::core::{SimpleRecState, WhisperCore};
-use crate::hotkey::HotkeyManager;
+use crate::core::{
+    ClipboardMode, CoreBusy, MaxRecordAction, OutputTarget, SimpleRecState, SoundTiming,
+    WhisperCore,
+};
+use crate::hotkey::{HotkeyAction, HotkeyManager, HotkeyMode, KeyEdge};
 use crate::i18n;
-use crate::llm::LlmPostProcessSettings;
+use crate::llm::{HistoryRetention, LlmPostProcessSettings};
+use crate::transcription::{TranscriptionBackend, WhisperTask};
 use crate::utils::app_config_dir;
+#[cfg(unix)]
+use crate::utils::ipc::IpcCommand;
+use crate::utils::keyboard::LinuxPasteMethod;
 use egui::FontFamily;
 use lucide_icons::Icon;
 // UNIX-only: import signal handling for SIGUSR1/SIGUSR2
@@ -24,7 +31,7 @@ use signal_hook::{
     iterator::Signals,
 };
 
-#[derive(PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 enum TabView {
     General,
     Devices,
@@ -35,6 +42,54 @@ enum TabView {
     Logs,
 }
 
+impl TabView {
+    // Tab bar order (Ctrl/Cmd+1..7)
+    const ALL: [TabView; 7] = [
+        TabView::General,
+        TabView::Devices,
+        TabView::SpeechModel,
+        TabView::Dictionary,
+        TabView::Llm,
+        TabView::History,
+        TabView::Logs,
+    ];
+    const DIGIT_KEYS: [egui::Key; 7] = [
+        egui::Key::Num1,
+        egui::Key::Num2,
+        egui::Key::Num3,
+        egui::Key::Num4,
+        egui::Key::Num5,
+        egui::Key::Num6,
+        egui::Key::Num7,
+    ];
+
+    fn shortcut(self) -> egui::KeyboardShortcut {
+        let pos = Self::ALL.iter().position(|t| *t == self).unwrap_or(0);
+        egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, Self::DIGIT_KEYS[pos])
+    }
+
+    // Ctrl/Cmd+1..7 jumps to a tab; Ctrl+Tab / Ctrl+Shift+Tab cycles
+    fn from_input(input: &mut egui::InputState, current: TabView) -> Option<TabView> {
+        use egui::{Key, Modifiers};
+        if let Some(tab) = Self::ALL
+            .into_iter()
+            .find(|tab| input.consume_shortcut(&tab.shortcut()))
+        {
+            return Some(tab);
+        }
+        let n = Self::ALL.len();
+        let pos = Self::ALL.iter().position(|t| *t == current).unwrap_or(0);
+        // Shift variant first: the plain pattern also matches with Shift held
+        if input.consume_key(Modifiers::CTRL | Modifiers::SHIFT, Key::Tab) {
+            Some(Self::ALL[(pos + n - 1) % n])
+        } else if input.consume_key(Modifiers::CTRL, Key::Tab) {
+            Some(Self::ALL[(pos + 1) % n])
+        } else {
+            None
+        }
+    }
+}
+
 pub struct WhisperApp {
     core: Arc<WhisperCore>,
     pub(crate) settings_window: SettingsWindow,
@@ -42,9 +97,9 @@ pub struct WhisperApp {
     show_settings: bool,
     show_floating: bool,
     status_message: String,
-    debug_logs: Arc<Mutex<VecDeque<String>>>,
+    debug_logs: Arc<Mutex<VecDeque<LogEntry>>>,
     auto_scroll: bool,
-    last_waybar_state: Option<SimpleRecState>,
+    last_waybar_state: Option<(SimpleRecState, bool, bool, bool)>,
     active_tab: TabView,
     live_settings: Arc<Mutex<LiveSettingsSnapshot>>, // for hotkey/SIGUSR1
     settings_requested: Arc<std::sync::atomic::AtomicBool>,
@@ -52,32 +107,143 @@ pub struct WhisperApp {
     is_wayland: bool,
     main_hidden_by_app: bool,
     main_minimized_by_app: bool,
+    // Started hidden (`start_hidden`): no window until the first recording
+    background: bool,
     // Keep global hotkey manager alive for app lifetime
     hotkey_manager: Option<HotkeyManager>,
     llm_was_enabled: bool,
+    // Recording hotkeys/SIGUSR1 are ignored while set (not persisted)
+    hotkey_paused: Arc<std::sync::atomic::AtomicBool>,
+    // Audio files dropped onto the window, transcribed one at a time
+    file_queue: Arc<Mutex<VecDeque<PathBuf>>>,
+    file_worker_running: Arc<std::sync::atomic::AtomicBool>,
+    // Report of a self-test running on a background thread
+    self_test_result: Arc<Mutex<Option<Vec<crate::core::SelfTestStage>>>>,
+    // Model benchmark report, likewise from a background thread
+    benchmark_result: Arc<Mutex<Option<Result<Vec<crate::core::ModelBenchmark>, String>>>>,
+    // Short notice for rejected drops (shown for a few seconds)
+    drop_notice: Option<(String, std::time::Instant)>,
+    // Theme/UI scale last pushed to egui (settings can also change through a bundle import)
+    applied_appearance: Option<(ThemeMode, f32)>,
 }
 
 #[derive(Clone, Debug)]
 struct LiveSettingsSnapshot {
     whisper_language: String,
+    whisper_task: WhisperTask,
+    language_fallback: Option<crate::transcription::LanguageFallback>,
+    min_segment_confidence: Option<f32>,
     input_device: Option<String>,
     input_host: Option<String>,
     input_device_index_in_host: Option<usize>,
+    input_device_uid: Option<String>,
     input_device_index: Option<usize>,
     output_device: Option<String>,
     input_gain_percent: f32,
-    auto_paste: bool,
+    preroll_ms: u32,
+    input_auto_reselect: bool,
+    output_targets: Vec<OutputTarget>,
+    restore_clipboard_after_paste: bool,
+    review_before_paste: bool,
+    compare_before_paste: bool,
+    auto_paste_delay_ms: u32,
+    complete_sound_timing: SoundTiming,
+    linux_paste_method: LinuxPasteMethod,
+    clipboard_mode: ClipboardMode,
     whisper_no_timestamps: bool,
     whisper_token_timestamps: bool,
     whisper_use_physical_cores: bool,
+    whisper_threads: Option<usize>,
     chunk_split_strategy: VadStrategy,
+    parallel_chunks: usize,
+    hotkey_debounce_ms: u64,
+    transcription_backend: TranscriptionBackend,
     auto_stop_silence_secs: f32,
     max_record_secs: f32,
+    max_record_action: MaxRecordAction,
+    stop_phrase: Option<String>,
+    normalize_audio: bool,
+    trim_silence: bool,
+    min_speech_secs: f32,
+    keep_last_recording: bool,
+    hallucination_filter: bool,
+    hallucination_phrases: Vec<String>,
+    transcript_prefix: String,
+    transcript_suffix: String,
+    enable_spoken_commands: bool,
+    local_autocapitalize: bool,
+    readback_tts: bool,
+    history_retention: HistoryRetention,
+    history_enabled: bool,
+    history_redaction_patterns: Vec<String>,
+    dictionary_fuzzy_max_distance: u32,
     sound_enabled: bool,
     sound_volume_percent: f32,
     llm_postprocess: LlmPostProcessSettings,
 }
 
+// Apply the settings snapshot from non-UI threads (hotkey/SIGUSR1)
+fn apply_snapshot_to_core(core: &WhisperCore, s: &LiveSettingsSnapshot) {
+    core.set_output_targets(&s.output_targets);
+    core.set_clipboard_mode(s.clipboard_mode);
+    core.set_restore_clipboard(s.restore_clipboard_after_paste);
+    core.set_review_before_paste(s.review_before_paste);
+    core.set_compare_before_paste(s.compare_before_paste);
+    core.set_auto_paste_delay_ms(s.auto_paste_delay_ms);
+    core.set_complete_sound_timing(s.complete_sound_timing);
+    core.set_linux_paste_method(s.linux_paste_method);
+    let lang_opt = if s.whisper_language == "auto" {
+        None
+    } else {
+        Some(s.whisper_language.as_str())
+    };
+    core.set_language(lang_opt);
+    core.set_audio_devices(s.input_device.as_deref(), s.output_device.as_deref());
+    core.set_input_device_host_and_index(
+        s.input_host.as_deref(),
+        s.input_device_index_in_host,
+        s.input_device_uid.as_deref(),
+    );
+    core.set_input_device_index(s.input_device_index);
+    core.set_input_gain((s.input_gain_percent / 100.0).clamp(0.0, 2.0));
+    core.set_preroll_ms(s.preroll_ms);
+    core.set_input_device_auto_reselect(s.input_auto_reselect);
+    crate::utils::sound::set_enabled(s.sound_enabled);
+    crate::utils::sound::set_volume_percent(s.sound_volume_percent);
+    // Whisper最適化設定を反映
+    use crate::transcription::WhisperOptimizationParams;
+    core.set_whisper_optimization(WhisperOptimizationParams {
+        no_timestamps: s.whisper_no_timestamps,
+        token_timestamps: s.whisper_token_timestamps,
+        use_physical_cores: s.whisper_use_physical_cores,
+        task: s.whisper_task,
+        n_threads: crate::transcription::threads_param(s.whisper_threads),
+        language_fallback: s.language_fallback.clone(),
+        min_segment_confidence: s.min_segment_confidence,
+        ..Default::default()
+    });
+    core.set_chunk_split_strategy(s.chunk_split_strategy);
+    core.set_parallel_chunks(s.parallel_chunks);
+    core.set_toggle_debounce_ms(s.hotkey_debounce_ms);
+    core.set_transcription_backend(s.transcription_backend.clone());
+    core.set_auto_stop_params(s.auto_stop_silence_secs, s.max_record_secs);
+    core.set_stop_phrase(s.stop_phrase.as_deref());
+    core.set_max_record_action(s.max_record_action);
+    core.set_normalize_audio(s.normalize_audio);
+    core.set_trim_silence(s.trim_silence);
+    core.set_min_speech_secs(s.min_speech_secs);
+    core.set_keep_last_recording(s.keep_last_recording);
+    core.set_hallucination_filter(s.hallucination_filter, s.hallucination_phrases.clone());
+    core.set_transcript_boilerplate(&s.transcript_prefix, &s.transcript_suffix);
+    core.set_spoken_commands(s.enable_spoken_commands);
+    core.set_autocapitalize(s.local_autocapitalize);
+    core.set_readback_tts(s.readback_tts);
+    core.set_history_retention(s.history_retention);
+    core.set_history_privacy(s.history_enabled, &s.history_redaction_patterns);
+    core.set_dictionary_fuzzy_distance(s.dictionary_fuzzy_max_distance);
+    core.set_llm_postprocess_settings(s.llm_postprocess.clone());
+}
+
 // File I/O helpers moved to utils::logfile
 
 impl WhisperApp {
@@ -88,25 +254,60 @@ impl WhisperApp {
         let s0 = settings_window.get_settings().clone();
         let live_settings = Arc::new(Mutex::new(LiveSettingsSnapshot {
             whisper_language: s0.whisper_language.clone(),
+            whisper_task: s0.whisper_task,
+            language_fallback: s0.language_fallback(),
+            min_segment_confidence: s0.min_segment_confidence,
             input_device: s0.input_device.clone(),
             input_host: s0.input_host.clone(),
             input_device_index_in_host: s0.input_device_index_in_host,
+            input_device_uid: s0.input_device_uid.clone(),
             input_device_index: s0.input_device_index,
             output_device: s0.output_device.clone(),
             input_gain_percent: s0.input_gain_percent,
-            auto_paste: s0.auto_paste,
+            preroll_ms: s0.preroll_ms,
+            input_auto_reselect: s0.input_auto_reselect,
+            output_targets: s0.output_targets.clone(),
+            restore_clipboard_after_paste: s0.restore_clipboard_after_paste,
+            review_before_paste: s0.review_before_paste,
+            compare_before_paste: s0.compare_before_paste,
+            auto_paste_delay_ms: s0.auto_paste_delay_ms,
+            complete_sound_timing: s0.complete_sound_timing,
+            linux_paste_method: s0.linux_paste_method,
+            clipboard_mode: s0.clipboard_mode,
             whisper_no_timestamps: s0.whisper_no_timestamps,
             whisper_token_timestamps: s0.whisper_token_timestamps,
             whisper_use_physical_cores: s0.whisper_use_physical_cores,
+            whisper_threads: s0.whisper_threads,
             chunk_split_strategy: s0.chunk_split_strategy,
+            parallel_chunks: s0.parallel_chunks,
+            hotkey_debounce_ms: s0.hotkey_debounce_ms,
+            transcription_backend: s0.remote_transcription.backend(),
             auto_stop_silence_secs: s0.auto_stop_silence_secs,
             max_record_secs: s0.max_record_secs,
+            max_record_action: s0.max_record_action,
+            stop_phrase: s0.stop_phrase.clone(),
+            normalize_audio: s0.normalize_audio,
+            trim_silence: s0.trim_silence,
+            min_speech_secs: s0.min_speech_secs,
+            keep_last_recording: s0.keep_last_recording,
+            hallucination_filter: s0.hallucination_filter,
+            hallucination_phrases: s0.hallucination_phrases.clone(),
+            transcript_prefix: s0.transcript_prefix.clone(),
+            transcript_suffix: s0.transcript_suffix.clone(),
+            enable_spoken_commands: s0.enable_spoken_commands,
+            local_autocapitalize: s0.local_autocapitalize,
+            readback_tts: s0.readback_tts,
+            history_retention: s0.history_retention(),
+            history_enabled: s0.history_enabled,
+            history_redaction_patterns: s0.history_redaction_patterns.clone(),
+            dictionary_fuzzy_max_distance: s0.dictionary_fuzzy_max_distance,
             sound_enabled: s0.sound_enabled,
             sound_volume_percent: s0.sound_volume_percent,
             llm_postprocess: s0.llm_postprocess.clone(),
         }));
         crate::utils::sound::set_enabled(s0.sound_enabled);
         crate::utils::sound::set_volume_percent(s0.sound_volume_percent);
+        s0.apply_event_sounds();
 
         // Prepare debug log file under the app's config directory
         let log_path = app_config_dir().join("debug.log");
@@ -122,11 +323,12 @@ impl WhisperApp {
         let logs_for_callback = debug_logs.clone();
         let log_path_for_callback = log_path.clone();
         let log_callback = Arc::new(move |message: &str| {
-            let timestamp = Local::now().format("%H:%M:%S%.3f");
-            let log_line = format!("[{}] {}", timestamp, message);
-
             // Update in-memory logs and persist to file
-            push_log_and_persist(&logs_for_callback, &log_path_for_callback, &log_line);
+            push_log_and_persist(
+                &logs_for_callback,
+                &log_path_for_callback,
+                LogEntry::new(message),
+            );
         }) as crate::core::LogCallback;
 
         core.set_log_callback(log_callback.clone());
@@ -161,8 +363,16 @@ impl WhisperApp {
                 || std::env::var("WAYLAND_DISPLAY").is_ok(),
             main_hidden_by_app: false,
             main_minimized_by_app: false,
+            background: false,
             hotkey_manager: None,
             llm_was_enabled: s0.llm_postprocess.enabled,
+            hotkey_paused: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            file_queue: Arc::new(Mutex::new(VecDeque::new())),
+            file_worker_running: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            self_test_result: Arc::new(Mutex::new(None)),
+            benchmark_result: Arc::new(Mutex::new(None)),
+            drop_notice: None,
+            applied_appearance: None,
         };
 
         // removed: system tray
@@ -173,8 +383,12 @@ impl WhisperApp {
         // Log current model
         let mp = app.core.get_model_path();
         app.add_log(&format!("[Whisper] Using model: {}", mp.display()));
-        let hk = app.settings_window.get_settings().hotkey_recording.clone();
-        app.add_log(&format!("[Startup] Hotkey: {}", hk));
+        for binding in app.settings_window.get_settings().hotkey_bindings.clone() {
+            app.add_log(&format!(
+                "[Startup] Hotkey: {} → {:?}",
+                binding.hotkey, binding.action
+            ));
+        }
         // Advertise signal usage only on UNIX
         #[cfg(unix)]
         app.add_log("[Startup] Signal: pkill -USR1 hootvoice");
@@ -195,50 +409,75 @@ impl WhisperApp {
                 Ok(mut hotkey_manager) => {
                     let core_for_hotkey = app.core.clone();
                     let live_for_hotkey = live_settings.clone();
-                    let initial_hotkey =
-                        app.settings_window.get_settings().hotkey_recording.clone();
-                    if let Err(e) = hotkey_manager.register_hotkey(&initial_hotkey, move || {
-                        // Apply latest settings snapshot before toggling
-                        if let Ok(s) = live_for_hotkey.lock() {
-                            core_for_hotkey.set_behavior_options(true, s.auto_paste);
-                            let lang_opt = if s.whisper_language == "auto" {
-                                None
-                            } else {
-                                Some(s.whisper_language.as_str())
+                    let settings_flag = settings_requested.clone();
+                    let paused_for_hotkey = app.hotkey_paused.clone();
+                    let bindings = app.settings_window.get_settings().hotkey_bindings.clone();
+                    let hotkey_mode = app.settings_window.get_settings().hotkey_mode;
+                    let errors = hotkey_manager.register_bindings(
+                        &bindings,
+                        hotkey_mode,
+                        move |action, edge| {
+                            let llm_mode = match action {
+                                HotkeyAction::ShowSettings => {
+                                    settings_flag.store(true, std::sync::atomic::Ordering::SeqCst);
+                                    return;
+                                }
+                                HotkeyAction::TogglePause => {
+                                    paused_for_hotkey
+                                        .fetch_xor(true, std::sync::atomic::Ordering::SeqCst);
+                                    return;
+                                }
+                                HotkeyAction::UndoPaste => {
+                                    let core = core_for_hotkey.clone();
+                                    std::thread::spawn(move || {
+                                        core.undo_last_paste();
+                                    });
+                                    return;
+                                }
+                                HotkeyAction::PasteLast => {
+                                    let core = core_for_hotkey.clone();
+                                    std::thread::spawn(move || {
+                                        core.paste_last_transcription();
+                                    });
+                                    return;
+                                }
+                                HotkeyAction::ToggleRecord => None,
+                                HotkeyAction::ToggleRecordWithMode(mode_id) => {
+                                    Some(mode_id.as_str())
+                                }
                             };
-                            core_for_hotkey.set_language(lang_opt);
-                            core_for_hotkey.set_audio_devices(
-                                s.input_device.as_deref(),
-                                s.output_device.as_deref(),
-                            );
-                            core_for_hotkey.set_input_device_host_and_index(
-                                s.input_host.as_deref(),
-                                s.input_device_index_in_host,
-                            );
-                            core_for_hotkey.set_input_device_index(s.input_device_index);
-                            core_for_hotkey
-                                .set_input_gain((s.input_gain_percent / 100.0).clamp(0.0, 2.0));
-                            crate::utils::sound::set_enabled(s.sound_enabled);
-                            crate::utils::sound::set_volume_percent(s.sound_volume_percent);
-                            // Whisper最適化設定を反映
-                            use crate::transcription::WhisperOptimizationParams;
-                            core_for_hotkey.set_whisper_optimization(WhisperOptimizationParams {
-                                no_timestamps: s.whisper_no_timestamps,
-                                token_timestamps: s.whisper_token_timestamps,
-                                use_physical_cores: s.whisper_use_physical_cores,
-                                ..Default::default()
-                            });
-                            core_for_hotkey.set_chunk_split_strategy(s.chunk_split_strategy);
-                            core_for_hotkey
-                                .set_auto_stop_params(s.auto_stop_silence_secs, s.max_record_secs);
-                            core_for_hotkey.set_llm_postprocess_settings(s.llm_postprocess.clone());
-                        }
-                        core_for_hotkey.toggle_recording();
-                    }) {
-                        app.add_log(&format!("[Warning] Failed to register hotkey: {}", e));
-                    } else {
+                            if edge == KeyEdge::Up {
+                                core_for_hotkey.stop_recording();
+                                return;
+                            }
+                            // Paused: ignore starts (a release above still ends a recording)
+                            if paused_for_hotkey.load(std::sync::atomic::Ordering::SeqCst) {
+                                return;
+                            }
+                            // Apply latest settings snapshot before toggling
+                            if let Ok(s) = live_for_hotkey.lock() {
+                                apply_snapshot_to_core(&core_for_hotkey, &s);
+                            }
+                            if hotkey_mode == HotkeyMode::PushToTalk {
+                                core_for_hotkey.start_recording(llm_mode);
+                            } else {
+                                core_for_hotkey.toggle_recording_with_llm_mode(llm_mode);
+                            }
+                        },
+                    );
+                    for (hotkey, e) in &errors {
+                        app.add_log(&format!(
+                            "[Warning] Failed to register hotkey {}: {}",
+                            hotkey, e
+                        ));
+                    }
+                    if errors.len() < bindings.len() {
                         hotkey_manager.spawn_event_thread();
-                        app.add_log("[Startup] Registered global hotkey");
+                        app.add_log(&format!(
+                            "[Startup] Registered {} global hotkey(s) ({:?})",
+                            bindings.len() - errors.len(),
+                            hotkey_mode
+                        ));
                         // Hold manager to keep registration alive
                         // (GlobalHotKeyManager unregisters on drop)
                         // Safe to store; callbacks are in an internal Arc
@@ -256,6 +495,7 @@ impl WhisperApp {
             let core_for_signal = app.core.clone();
             let live_for_signal = live_settings.clone();
             let settings_flag = settings_requested.clone();
+            let paused_for_signal = app.hotkey_paused.clone();
             std::thread::spawn(move || {
                 let mut signals = match Signals::new([SIGUSR1, SIGUSR2]) {
                     Ok(sigs) => sigs,
@@ -266,39 +506,11 @@ impl WhisperApp {
                 };
                 for sig in signals.forever() {
                     if sig == SIGUSR1 {
+                        if paused_for_signal.load(std::sync::atomic::Ordering::SeqCst) {
+                            continue;
+                        }
                         if let Ok(s) = live_for_signal.lock() {
-                            core_for_signal.set_behavior_options(true, s.auto_paste);
-                            let lang_opt = if s.whisper_language == "auto" {
-                                None
-                            } else {
-                                Some(s.whisper_language.as_str())
-                            };
-                            core_for_signal.set_language(lang_opt);
-                            core_for_signal.set_audio_devices(
-                                s.input_device.as_deref(),
-                                s.output_device.as_deref(),
-                            );
-                            core_for_signal.set_input_device_host_and_index(
-                                s.input_host.as_deref(),
-                                s.input_device_index_in_host,
-                            );
-                            core_for_signal.set_input_device_index(s.input_device_index);
-                            core_for_signal
-                                .set_input_gain((s.input_gain_percent / 100.0).clamp(0.0, 2.0));
-                            crate::utils::sound::set_enabled(s.sound_enabled);
-                            crate::utils::sound::set_volume_percent(s.sound_volume_percent);
-                            // Apply Whisper optimization settings
-                            use crate::transcription::WhisperOptimizationParams;
-                            core_for_signal.set_whisper_optimization(WhisperOptimizationParams {
-                                no_timestamps: s.whisper_no_timestamps,
-                                token_timestamps: s.whisper_token_timestamps,
-                                use_physical_cores: s.whisper_use_physical_cores,
-                                ..Default::default()
-                            });
-                            core_for_signal.set_chunk_split_strategy(s.chunk_split_strategy);
-                            core_for_signal
-                                .set_auto_stop_params(s.auto_stop_silence_secs, s.max_record_secs);
-                            core_for_signal.set_llm_postprocess_settings(s.llm_postprocess.clone());
+                            apply_snapshot_to_core(&core_for_signal, &s);
                         }
                         core_for_signal.toggle_recording();
                     } else if sig == SIGUSR2 {
@@ -307,6 +519,43 @@ impl WhisperApp {
                 }
             });
         }
+        // Local command socket (Waybar click handlers: `hootvoice --send toggle`)
+        #[cfg(unix)]
+        if app.settings_window.get_settings().ipc_socket {
+            let core_for_ipc = app.core.clone();
+            let live_for_ipc = live_settings.clone();
+            let settings_flag = settings_requested.clone();
+            let paused_for_ipc = app.hotkey_paused.clone();
+            let result = crate::utils::ipc::spawn_listener(move |cmd| match cmd {
+                IpcCommand::Toggle | IpcCommand::Start => {
+                    if paused_for_ipc.load(std::sync::atomic::Ordering::SeqCst) {
+                        return Err("paused");
+                    }
+                    if let Ok(s) = live_for_ipc.lock() {
+                        apply_snapshot_to_core(&core_for_ipc, &s);
+                    }
+                    if cmd == IpcCommand::Start {
+                        core_for_ipc.start_recording(None);
+                    } else {
+                        core_for_ipc.toggle_recording();
+                    }
+                    Ok(())
+                }
+                // Stopping is allowed while paused (nothing new starts)
+                IpcCommand::Stop => {
+                    core_for_ipc.stop_recording();
+                    Ok(())
+                }
+                IpcCommand::Show => {
+                    settings_flag.store(true, std::sync::atomic::Ordering::SeqCst);
+                    Ok(())
+                }
+            });
+            match result {
+                Ok(path) => app.add_log(&format!("[Startup] Command socket: {}", path.display())),
+                Err(e) => app.add_log(&format!("[Warning] Failed to open command socket: {}", e)),
+            }
+        }
 
         app
     }
@@ -314,7 +563,15 @@ impl WhisperApp {
     fn apply_live_settings_to_core(&self) {
         let s = self.settings_window.get_settings();
         // Clipboard usage always enabled; only auto-paste toggles
-        self.core.set_behavior_options(true, s.auto_paste);
+        self.core.set_output_targets(&s.output_targets);
+        self.core.set_clipboard_mode(s.clipboard_mode);
+        self.core
+            .set_restore_clipboard(s.restore_clipboard_after_paste);
+        self.core.set_review_before_paste(s.review_before_paste);
+        self.core.set_compare_before_paste(s.compare_before_paste);
+        self.core.set_auto_paste_delay_ms(s.auto_paste_delay_ms);
+        self.core.set_complete_sound_timing(s.complete_sound_timing);
+        self.core.set_linux_paste_method(s.linux_paste_method);
         // 言語
         let lang_opt = if s.whisper_language == "auto" {
             None
@@ -325,14 +582,21 @@ impl WhisperApp {
         // デバイス
         self.core
             .set_audio_devices(s.input_device.as_deref(), s.output_device.as_deref());
-        self.core
-            .set_input_device_host_and_index(s.input_host.as_deref(), s.input_device_index_in_host);
+        self.core.set_input_device_host_and_index(
+            s.input_host.as_deref(),
+            s.input_device_index_in_host,
+            s.input_device_uid.as_deref(),
+        );
         self.core.set_input_device_index(s.input_device_index);
         // 入力ゲイン
         self.core
             .set_input_gain((s.input_gain_percent / 100.0).clamp(0.0, 2.0));
+        self.core.set_preroll_ms(s.preroll_ms);
+        self.core
+            .set_input_device_auto_reselect(s.input_auto_reselect);
         crate::utils::sound::set_enabled(s.sound_enabled);
         crate::utils::sound::set_volume_percent(s.sound_volume_percent);
+        s.apply_event_sounds();
         // Whisper最適化設定
         use crate::transcription::WhisperOptimizationParams;
         self.core
@@ -340,35 +604,251 @@ impl WhisperApp {
                 no_timestamps: s.whisper_no_timestamps,
                 token_timestamps: s.whisper_token_timestamps,
                 use_physical_cores: s.whisper_use_physical_cores,
+                task: s.whisper_task,
+                n_threads: crate::transcription::threads_param(s.whisper_threads),
+                language_fallback: s.language_fallback(),
+                min_segment_confidence: s.min_segment_confidence,
                 ..Default::default()
             });
         // 分割戦略
         self.core.set_chunk_split_strategy(s.chunk_split_strategy);
+        self.core.set_parallel_chunks(s.parallel_chunks);
+        self.core.set_toggle_debounce_ms(s.hotkey_debounce_ms);
+        self.core
+            .set_transcription_backend(s.remote_transcription.backend());
         // 自動停止
         self.core
             .set_auto_stop_params(s.auto_stop_silence_secs, s.max_record_secs);
+        self.core.set_stop_phrase(s.stop_phrase.as_deref());
+        self.core.set_max_record_action(s.max_record_action);
+        self.core.set_normalize_audio(s.normalize_audio);
+        self.core.set_trim_silence(s.trim_silence);
+        self.core.set_min_speech_secs(s.min_speech_secs);
+        self.core.set_keep_last_recording(s.keep_last_recording);
+        self.core
+            .set_hallucination_filter(s.hallucination_filter, s.hallucination_phrases.clone());
+        self.core
+            .set_transcript_boilerplate(&s.transcript_prefix, &s.transcript_suffix);
+        self.core.set_spoken_commands(s.enable_spoken_commands);
+        self.core.set_autocapitalize(s.local_autocapitalize);
+        self.core.set_readback_tts(s.readback_tts);
+        self.core.set_history_retention(s.history_retention());
+        self.core
+            .set_history_privacy(s.history_enabled, &s.history_redaction_patterns);
+        self.core
+            .set_dictionary_fuzzy_distance(s.dictionary_fuzzy_max_distance);
         self.core
             .set_llm_postprocess_settings(s.llm_postprocess.clone());
     }
 
+    // Settings and the floating window stay up together (`keep_main_window_visible`)
+    fn keep_main_window(&self) -> bool {
+        self.settings_window.get_settings().keep_main_window_visible
+    }
+
+    // Off: recording leaves the windows as they are (feedback from sounds/Waybar)
+    fn show_floating_on_record(&self) -> bool {
+        self.settings_window.get_settings().show_floating_on_record
+    }
+
     pub fn show_floating_window(&mut self) {
         self.show_floating = true;
-        self.show_settings = false;
+        // Settings still shown means the main window is never hidden (`want_hidden`)
+        if !self.keep_main_window() {
+            self.show_settings = false;
+        }
         self.settings_window.set_last_ui_mode("floating");
     }
 
     pub fn show_settings_window(&mut self) {
         self.show_settings = true;
-        self.show_floating = false;
+        if !self.keep_main_window() {
+            self.show_floating = false;
+        }
         self.settings_window.set_last_ui_mode("settings");
     }
 
+    /// Startup model load failed: open the Speech Model tab with the error banner
+    /// (the core runs without a model until one loads)
+    pub fn show_model_load_error(&mut self, error: String) {
+        self.add_log(&format!("[Error] Failed to load model: {}", error));
+        self.settings_window.set_model_load_error(Some(error));
+        self.active_tab = TabView::SpeechModel;
+        self.show_settings_window();
+    }
+
+    /// Hide the main window; the floating window appears once recording starts
+    pub fn start_in_background(&mut self) {
+        self.background = true;
+        self.show_settings = false;
+        self.show_floating = false;
+        self.add_log("[Startup] Started hidden; waiting for the recording hotkey");
+    }
+
+    // Queue dropped audio files; anything else is rejected with a short notice
+    fn handle_dropped_files(&mut self, paths: Vec<PathBuf>) {
+        let (audio, rejected): (Vec<PathBuf>, Vec<PathBuf>) = paths
+            .into_iter()
+            .partition(|p| crate::core::is_supported_audio_file(p));
+        if !rejected.is_empty() {
+            let names: Vec<String> = rejected
+                .iter()
+                .map(|p| {
+                    p.file_name()
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| p.display().to_string())
+                })
+                .collect();
+            self.add_log(&format!(
+                "[File] Ignored non-audio drop: {}",
+                names.join(", ")
+            ));
+            self.drop_notice = Some((
+                format!("{} {}", i18n::tr("msg-drop-not-audio"), names.join(", ")),
+                std::time::Instant::now(),
+            ));
+        }
+        if audio.is_empty() {
+            return;
+        }
+        self.apply_live_settings_to_core();
+        self.file_queue.lock().unwrap().extend(audio);
+        if self
+            .file_worker_running
+            .swap(true, std::sync::atomic::Ordering::SeqCst)
+        {
+            return;
+        }
+        let core = self.core.clone();
+        let queue = self.file_queue.clone();
+        let running = self.file_worker_running.clone();
+        std::thread::spawn(move || {
+            loop {
+                let next = queue.lock().unwrap().pop_front();
+                let Some(path) = next else { break };
+                // Wait for a recording in progress to finish before taking the file
+                while core.get_state() != SimpleRecState::Idle {
+                    std::thread::sleep(std::time::Duration::from_millis(200));
+                }
+                if let Err(e) = core.transcribe_file(&path) {
+                    if e.downcast_ref::<CoreBusy>().is_some() {
+                        // A recording started between the check and the file: retry it
+                        queue.lock().unwrap().push_front(path);
+                        continue;
+                    }
+                    core.log(&format!("[Warning] File transcription failed: {:#}", e));
+                }
+            }
+            running.store(false, std::sync::atomic::Ordering::SeqCst);
+        });
+    }
+
+    fn export_subtitles(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("SubRip (.srt)", &["srt"])
+            .add_filter("WebVTT (.vtt)", &["vtt"])
+            .set_file_name("transcript.srt")
+            .save_file()
+        else {
+            return;
+        };
+        let notice = match self.core.export_subtitles(&path) {
+            Ok(_) => format!("{} {}", i18n::tr("msg-subtitles-exported"), path.display()),
+            Err(e) => {
+                self.add_log(&format!("[Error] Subtitle export failed: {:#}", e));
+                format!("{} {:#}", i18n::tr("msg-subtitles-export-failed"), e)
+            }
+        };
+        self.drop_notice = Some((notice, std::time::Instant::now()));
+    }
+
+    // Redacted summary + recent log for bug reports
+    fn diagnostics_report(&self) -> String {
+        let s = self.settings_window.get_settings();
+        let llm = &s.llm_postprocess;
+        let remote = &s.remote_transcription;
+        let or_default = |v: &Option<String>| v.clone().unwrap_or_else(|| "(default)".into());
+        let mut summary = vec![
+            ("version", env!("CARGO_PKG_VERSION").to_string()),
+            (
+                "os",
+                format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+            ),
+            ("model", self.core.get_model_path().display().to_string()),
+            ("language", s.whisper_language.clone()),
+            ("input device", or_default(&s.input_device)),
+            ("input host", or_default(&s.input_host)),
+            (
+                "input fallback",
+                self.core.input_device_fallback().to_string(),
+            ),
+            (
+                "input stream errors",
+                self.core.input_stream_errors().to_string(),
+            ),
+            ("output device", or_default(&s.output_device)),
+            (
+                "hotkeys",
+                s.hotkey_bindings
+                    .iter()
+                    .map(|b| b.hotkey.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+            ("llm enabled", llm.enabled.to_string()),
+            (
+                "llm base url",
+                crate::utils::diagnostics::mask_url(&llm.effective_base_url()),
+            ),
+            ("llm model", llm.model.clone()),
+            ("llm mode", llm.mode_id.clone()),
+        ];
+        if cfg!(target_os = "linux") {
+            let session = std::env::var("XDG_SESSION_TYPE").unwrap_or_default();
+            summary.insert(2, ("session", session));
+        }
+        if remote.enabled {
+            summary.push((
+                "remote transcription",
+                crate::utils::diagnostics::mask_url(&remote.base_url),
+            ));
+        }
+        let lines: Vec<String> = self
+            .debug_logs
+            .lock()
+            .map(|logs| logs.iter().map(|e| e.line.clone()).collect())
+            .unwrap_or_default();
+        let home = directories::UserDirs::new().map(|d| d.home_dir().to_path_buf());
+        let env_key = std::env::var("OPENAI_API_KEY").unwrap_or_default();
+        crate::utils::diagnostics::build_report(
+            &summary,
+            &lines,
+            home.as_deref(),
+            &[remote.api_key.as_str(), env_key.as_str()],
+        )
+    }
+
+    fn save_diagnostics(&self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Text (.txt)", &["txt"])
+            .set_file_name("hootvoice-diagnostics.txt")
+            .save_file()
+        else {
+            return;
+        };
+        match std::fs::write(&path, self.diagnostics_report()) {
+            Ok(_) => self.add_log(&format!(
+                "[system] {} {}",
+                i18n::tr("msg-diagnostics-saved"),
+                path.display()
+            )),
+            Err(e) => self.add_log(&format!("[Error] Saving diagnostics failed: {}", e)),
+        }
+    }
+
     pub fn add_log(&self, message: &str) {
-        let timestamp = Local::now().format("%H:%M:%S%.3f");
-        let log_line = format!("[{}] {}", timestamp, message);
         let log_path = app_config_dir().join("debug.log");
-
-        push_log_and_persist(&self.debug_logs, &log_path, &log_line);
+        push_log_and_persist(&self.debug_logs, &log_path, LogEntry::new(message));
     }
 }
 
@@ -379,11 +859,31 @@ impl eframe::App for WhisperApp {
     }
 
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        let appearance = {
+            let s = self.settings_window.get_settings();
+            (s.theme, s.ui_scale_percent)
+        };
+        if self.applied_appearance != Some(appearance) {
+            self.settings_window.get_settings().apply_appearance(ctx);
+            self.applied_appearance = Some(appearance);
+        }
+
         // Visibility control of the main window
         // - When floating-only: hide/minimize the main window
         // - When switching back to Settings: only restore if we hid it programmatically
         // Do NOT fight user-initiated minimize; respect the OS minimize button.
-        let want_hidden = self.show_floating && !self.show_settings;
+        if self.show_settings {
+            self.background = false;
+        } else if self.background {
+            if self.core.get_state() != SimpleRecState::Idle && self.show_floating_on_record() {
+                self.background = false;
+                self.show_floating = true;
+            } else {
+                // No visible viewport drives repaints while waiting for the hotkey
+                ctx.request_repaint_after(std::time::Duration::from_millis(250));
+            }
+        }
+        let want_hidden = (self.show_floating || self.background) && !self.show_settings;
         if self.is_wayland {
             // Wayland: toggle Visible; avoid using Minimized.
             if want_hidden {
@@ -414,7 +914,27 @@ impl eframe::App for WhisperApp {
             .swap(false, std::sync::atomic::Ordering::SeqCst)
         {
             self.show_settings = true;
-            self.show_floating = false;
+            if !self.keep_main_window() {
+                self.show_floating = false;
+            }
+        }
+        // Keyboard tab switching (settings screen only)
+        if self.show_settings && !self.settings_window.is_capturing_hotkey() {
+            let current = self.active_tab;
+            if let Some(tab) = ctx.input_mut(|i| TabView::from_input(i, current)) {
+                self.active_tab = tab;
+            }
+        }
+        // Drag-and-drop: transcribe dropped audio files
+        let dropped: Vec<PathBuf> = ctx.input(|i| {
+            i.raw
+                .dropped_files
+                .iter()
+                .filter_map(|f| f.path.clone())
+                .collect()
+        });
+        if !dropped.is_empty() {
+            self.handle_dropped_files(dropped);
         }
         // Each frame, reflect latest UI settings to a snapshot
         {
@@ -422,19 +942,53 @@ impl eframe::App for WhisperApp {
             let llm_enabled_now = s.llm_postprocess.enabled;
             if let Ok(mut snap) = self.live_settings.lock() {
                 snap.whisper_language = s.whisper_language.clone();
+                snap.whisper_task = s.whisper_task;
+                snap.language_fallback = s.language_fallback();
+                snap.min_segment_confidence = s.min_segment_confidence;
                 snap.input_device = s.input_device.clone();
                 snap.input_host = s.input_host.clone();
                 snap.input_device_index_in_host = s.input_device_index_in_host;
+                snap.input_device_uid = s.input_device_uid.clone();
                 snap.input_device_index = s.input_device_index;
                 snap.output_device = s.output_device.clone();
                 snap.input_gain_percent = s.input_gain_percent;
-                snap.auto_paste = s.auto_paste;
+                snap.preroll_ms = s.preroll_ms;
+                snap.input_auto_reselect = s.input_auto_reselect;
+                snap.output_targets = s.output_targets.clone();
+                snap.restore_clipboard_after_paste = s.restore_clipboard_after_paste;
+                snap.review_before_paste = s.review_before_paste;
+                snap.compare_before_paste = s.compare_before_paste;
+                snap.auto_paste_delay_ms = s.auto_paste_delay_ms;
+                snap.complete_sound_timing = s.complete_sound_timing;
+                snap.linux_paste_method = s.linux_paste_method;
+                snap.clipboard_mode = s.clipboard_mode;
                 snap.whisper_no_timestamps = s.whisper_no_timestamps;
                 snap.whisper_token_timestamps = s.whisper_token_timestamps;
                 snap.whisper_use_physical_cores = s.whisper_use_physical_cores;
+                snap.whisper_threads = s.whisper_threads;
                 snap.chunk_split_strategy = s.chunk_split_strategy;
+                snap.parallel_chunks = s.parallel_chunks;
+                snap.hotkey_debounce_ms = s.hotkey_debounce_ms;
+                snap.transcription_backend = s.remote_transcription.backend();
                 snap.auto_stop_silence_secs = s.auto_stop_silence_secs;
                 snap.max_record_secs = s.max_record_secs;
+                snap.max_record_action = s.max_record_action;
+                snap.stop_phrase = s.stop_phrase.clone();
+                snap.normalize_audio = s.normalize_audio;
+                snap.trim_silence = s.trim_silence;
+                snap.min_speech_secs = s.min_speech_secs;
+                snap.keep_last_recording = s.keep_last_recording;
+                snap.hallucination_filter = s.hallucination_filter;
+                snap.hallucination_phrases = s.hallucination_phrases.clone();
+                snap.transcript_prefix = s.transcript_prefix.clone();
+                snap.transcript_suffix = s.transcript_suffix.clone();
+                snap.enable_spoken_commands = s.enable_spoken_commands;
+                snap.local_autocapitalize = s.local_autocapitalize;
+                snap.readback_tts = s.readback_tts;
+                snap.history_retention = s.history_retention();
+                snap.history_enabled = s.history_enabled;
+                snap.history_redaction_patterns = s.history_redaction_patterns.clone();
+                snap.dictionary_fuzzy_max_distance = s.dictionary_fuzzy_max_distance;
                 snap.sound_enabled = s.sound_enabled;
                 snap.sound_volume_percent = s.sound_volume_percent;
                 snap.llm_postprocess = s.llm_postprocess.clone();
@@ -452,9 +1006,17 @@ impl eframe::App for WhisperApp {
         // システムトレイ機能は削除済み
 
         // Update Waybar custom module status file (on change)
-        if self.last_waybar_state != Some(state) {
-            waybar::write_status(state);
-            self.last_waybar_state = Some(state);
+        let hotkey_paused = self.hotkey_paused.load(std::sync::atomic::Ordering::SeqCst);
+        let recent_error = self.core.recent_error();
+        let queued = self.core.recording_queued();
+        let waybar_key = (state, hotkey_paused, recent_error.is_some(), queued);
+        if self.last_waybar_state != Some(waybar_key) {
+            waybar::write_status(state, hotkey_paused, recent_error.as_ref(), queued);
+            self.last_waybar_state = Some(waybar_key);
+        }
+        if recent_error.is_some() {
+            // Clear the error status once the flash window has passed
+            ctx.request_repaint_after(std::time::Duration::from_secs(1));
         }
 
         match state {
@@ -497,19 +1059,23 @@ impl eframe::App for WhisperApp {
                         let state = self.core.get_state();
 
                         // Show status as a badge (use the same Lucide icon as floating)
-                        let (status_text, bg_color, text_color, icon_glyph) = match state {
+                        let colors = self.settings_window.get_settings().status_colors;
+                        let (status_text, bg_color, icon_glyph) = match state {
+                            SimpleRecState::Idle if hotkey_paused => (
+                                i18n::tr("status-hotkey-paused"),
+                                colors.color(SimpleRecState::Busy),
+                                Icon::MicOff.unicode(),
+                            ),
                             SimpleRecState::Idle => (
                                 i18n::tr("status-idle"),
-                                egui::Color32::from_rgb(40, 167, 69),
-                                egui::Color32::WHITE,
+                                colors.color(state),
                                 Icon::Pause.unicode(),
                             ),
                             SimpleRecState::Recording => {
                                 ctx.request_repaint_after(std::time::Duration::from_millis(500));
                                 (
                                     i18n::tr("status-recording"),
-                                    egui::Color32::from_rgb(220, 53, 69),
-                                    egui::Color32::WHITE,
+                                    colors.color(state),
                                     Icon::Mic.unicode(),
                                 )
                             }
@@ -517,8 +1083,7 @@ impl eframe::App for WhisperApp {
                                 ctx.request_repaint_after(std::time::Duration::from_millis(500));
                                 (
                                     i18n::tr("status-processing"),
-                                    egui::Color32::from_rgb(255, 193, 7),
-                                    egui::Color32::BLACK,
+                                    colors.color(state),
                                     Icon::Loader.unicode(),
                                 )
                             }
@@ -526,8 +1091,7 @@ impl eframe::App for WhisperApp {
                                 ctx.request_repaint_after(std::time::Duration::from_millis(500));
                                 (
                                     i18n::tr("status-post-processing"),
-                                    egui::Color32::from_rgb(75, 154, 242),
-                                    egui::Color32::WHITE,
+                                    colors.color(state),
                                     Icon::Loader.unicode(),
                                 )
                             }
@@ -535,12 +1099,22 @@ impl eframe::App for WhisperApp {
                                 ctx.request_repaint_after(std::time::Duration::from_millis(500));
                                 (
                                     i18n::tr("status-busy"),
-                                    egui::Color32::from_rgb(108, 117, 125),
-                                    egui::Color32::WHITE,
+                                    colors.color(state),
                                     Icon::Loader.unicode(),
                                 )
                             }
                         };
+                        // A start pressed while processing waits in line
+                        let status_text = if self.core.recording_queued()
+                            && matches!(
+                                state,
+                                SimpleRecState::Processing | SimpleRecState::PostProcessing
+                            ) {
+                            format!("{} · {}", status_text, i18n::tr("status-queued"))
+                        } else {
+                            status_text
+                        };
+                        let text_color = StatusColors::text_on(bg_color);
 
                         // ステータスバッジを表示
                         egui::Frame::default()
@@ -573,12 +1147,28 @@ impl eframe::App for WhisperApp {
                             // 直前の設定を反映してから録音切替
                             self.apply_live_settings_to_core();
                             self.settings_window.stop_input_meter();
-                            crate::utils::sound::stop_loop("processing");
+                            let was_processing = matches!(
+                                self.core.get_state(),
+                                SimpleRecState::Processing | SimpleRecState::PostProcessing
+                            );
+                            if !was_processing {
+                                crate::utils::sound::stop_loop("processing");
+                            }
                             let new_state = self.core.toggle_recording();
                             self.status_message = match new_state {
+                                // Pressed while processing: queued or un-queued
+                                _ if was_processing => {
+                                    if self.core.recording_queued() {
+                                        i18n::tr("status-queued")
+                                    } else {
+                                        i18n::tr("msg-queue-cancelled")
+                                    }
+                                }
                                 SimpleRecState::Recording => {
                                     self.add_log("[Record] Recording started");
-                                    self.show_floating_window();
+                                    if self.show_floating_on_record() {
+                                        self.show_floating_window();
+                                    }
                                     i18n::tr("msg-recording-started")
                                 }
                                 SimpleRecState::Processing => {
@@ -611,8 +1201,119 @@ impl eframe::App for WhisperApp {
                         {
                             self.show_floating_window();
                         }
+
+                        // Best-effort: the main window is minimized so focus returns to the
+                        // app that received the paste before Backspace is sent
+                        if ui
+                            .add_enabled(
+                                self.core.can_undo_paste(),
+                                egui::Button::new(i18n::tr("btn-undo-paste")),
+                            )
+                            .on_hover_text(i18n::tr("tooltip-undo-paste"))
+                            .on_disabled_hover_text(i18n::tr("tooltip-undo-paste"))
+                            .clicked()
+                        {
+                            ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(true));
+                            let core = self.core.clone();
+                            std::thread::spawn(move || {
+                                core.undo_last_paste();
+                            });
+                        }
+
+                        if self.settings_window.get_settings().keep_last_recording
+                            && ui
+                                .add_enabled(
+                                    self.core.has_last_recording()
+                                        && self.core.get_state() == SimpleRecState::Idle,
+                                    egui::Button::new(i18n::tr("btn-retranscribe")),
+                                )
+                                .on_hover_text(i18n::tr("tooltip-retranscribe"))
+                                .clicked()
+                        {
+                            // Model/language changes may not have been pushed to the core yet
+                            self.apply_live_settings_to_core();
+                            self.core.retranscribe_last();
+                        }
+
+                        let timestamps = {
+                            let s = self.settings_window.get_settings();
+                            !s.whisper_no_timestamps || s.whisper_token_timestamps
+                        };
+                        if timestamps
+                            && ui
+                                .add_enabled(
+                                    self.core.has_subtitles(),
+                                    egui::Button::new(i18n::tr("btn-export-subtitles")),
+                                )
+                                .on_hover_text(i18n::tr("tooltip-export-subtitles"))
+                                .clicked()
+                        {
+                            self.export_subtitles();
+                        }
+
+                        let mut paused = hotkey_paused;
+                        if ui
+                            .toggle_value(&mut paused, i18n::tr("btn-pause-hotkey"))
+                            .on_hover_text(i18n::tr("tooltip-pause-hotkey"))
+                            .changed()
+                        {
+                            self.hotkey_paused
+                                .store(paused, std::sync::atomic::Ordering::SeqCst);
+                            self.add_log(if paused {
+                                "[Hotkey] Paused"
+                            } else {
+                                "[Hotkey] Resumed"
+                            });
+                        }
                     });
 
+                    // File transcription progress / drop hints
+                    let queued = self.file_queue.lock().unwrap().len();
+                    if let Some(name) = self.core.current_file() {
+                        ui.add_space(6.0);
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            let mut text =
+                                format!("{} {}", i18n::tr("msg-transcribing-file"), name);
+                            if queued > 0 {
+                                text.push_str(&format!(" (+{})", queued));
+                            }
+                            ui.label(text);
+                        });
+                    }
+                    if let Some((notice, at)) = &self.drop_notice {
+                        if at.elapsed() < std::time::Duration::from_secs(5) {
+                            ctx.request_repaint_after(std::time::Duration::from_secs(1));
+                            ui.add_space(6.0);
+                            ui.colored_label(ui.visuals().warn_fg_color, notice);
+                        } else {
+                            self.drop_notice = None;
+                        }
+                    }
+                    if ctx.input(|i| !i.raw.hovered_files.is_empty()) {
+                        ui.add_space(6.0);
+                        ui.label(
+                            egui::RichText::new(i18n::tr("hint-drop-audio"))
+                                .color(ui.visuals().selection.stroke.color)
+                                .strong(),
+                        );
+                    }
+
+                    // LLM backoff after repeated failures: explain and allow retrying now
+                    if let Some(wait) = self.core.llm_backoff_remaining_secs() {
+                        ctx.request_repaint_after(std::time::Duration::from_secs(1));
+                        ui.add_space(6.0);
+                        ui.horizontal(|ui| {
+                            ui.colored_label(
+                                ui.visuals().warn_fg_color,
+                                format!("{} {}s", i18n::tr("msg-llm-backoff-active"), wait),
+                            );
+                            if ui.button(i18n::tr("btn-llm-retry-now")).clicked() {
+                                self.core.reset_llm_backoff();
+                            }
+                        });
+                    }
+
                     ui.add_space(8.0);
                     ui.separator();
                     ui.add_space(8.0);
@@ -642,7 +1343,8 @@ impl eframe::App for WhisperApp {
                                         unselected_fill
                                     })
                                     .corner_radius(rounding);
-                                if ui.add(btn).clicked() {
+                                let shortcut = ui.ctx().format_shortcut(&tab.shortcut());
+                                if ui.add(btn).on_hover_text(shortcut).clicked() {
                                     self.active_tab = tab;
                                 }
                             };
@@ -700,8 +1402,10 @@ impl eframe::App for WhisperApp {
                     // タブとコンテンツの間に少し余白を入れる
                     ui.add_space(6.0);
 
-                    // タブコンテンツ
-                    let available_h = ui.available_height();
+                    // タブコンテンツ（手動保存モードでは下に保存バーを残す）
+                    let save_bar = !self.settings_window.autosave_enabled();
+                    let save_bar_h = if save_bar { 40.0 } else { 0.0 };
+                    let available_h = (ui.available_height() - save_bar_h).max(0.0);
                     egui::ScrollArea::vertical()
                         .max_height(available_h)
                         .auto_shrink([false; 2])
@@ -750,6 +1454,20 @@ impl eframe::App for WhisperApp {
                                                 i18n::tr("msg-log-cleared")
                                             ));
                                         }
+                                        if ui
+                                            .button(i18n::tr("btn-copy-diagnostics"))
+                                            .on_hover_text(i18n::tr("tooltip-copy-diagnostics"))
+                                            .clicked()
+                                        {
+                                            ui.ctx().copy_text(self.diagnostics_report());
+                                            self.add_log(&format!(
+                                                "[system] {}",
+                                                i18n::tr("msg-diagnostics-copied")
+                                            ));
+                                        }
+                                        if ui.button(i18n::tr("btn-save-diagnostics")).clicked() {
+                                            self.save_diagnostics();
+                                        }
                                     });
 
                                     ui.separator();
@@ -765,7 +1483,7 @@ impl eframe::App for WhisperApp {
                                             ui.style_mut().override_text_style =
                                                 Some(egui::TextStyle::Monospace);
                                             // スナップショット方式で読み取り時間を最小化
-                                            let snapshot: Option<Vec<String>> = {
+                                            let snapshot: Option<Vec<LogEntry>> = {
                                                 if let Ok(logs) = self.debug_logs.lock() {
                                                     Some(logs.iter().cloned().collect())
                                                 } else {
@@ -774,27 +1492,39 @@ impl eframe::App for WhisperApp {
                                             };
                                             if let Some(lines) = snapshot {
                                                 for log in lines.iter() {
-                                                    let color = if log.contains("[Error]") {
-                                                        egui::Color32::from_rgb(255, 100, 100)
-                                                    } else if log.contains("[Warning]") {
-                                                        egui::Color32::from_rgb(255, 200, 100)
-                                                    } else if log.contains("[Record]") {
-                                                        egui::Color32::from_rgb(100, 200, 255)
-                                                    } else if log.contains("[Process]") {
-                                                        egui::Color32::from_rgb(255, 255, 100)
-                                                    } else if log.contains("[Whisper]")
-                                                        || log.contains("[llm]")
-                                                    {
-                                                        egui::Color32::from_rgb(200, 150, 255)
-                                                    } else if log.contains("[Startup]")
-                                                        || log.contains("[Tray]")
-                                                        || log.contains("[Info]")
-                                                    {
-                                                        egui::Color32::from_rgb(100, 255, 200)
-                                                    } else {
-                                                        egui::Color32::from_rgb(200, 200, 200)
+                                                    let color = match log.level {
+                                                        LogLevel::Error => {
+                                                            egui::Color32::from_rgb(255, 100, 100)
+                                                        }
+                                                        LogLevel::Warning => {
+                                                            egui::Color32::from_rgb(255, 200, 100)
+                                                        }
+                                                        LogLevel::Info => match log
+                                                            .category
+                                                            .as_str()
+                                                        {
+                                                            "Record" => egui::Color32::from_rgb(
+                                                                100, 200, 255,
+                                                            ),
+                                                            "Process" => egui::Color32::from_rgb(
+                                                                255, 255, 100,
+                                                            ),
+                                                            "Whisper" | "llm" => {
+                                                                egui::Color32::from_rgb(
+                                                                    200, 150, 255,
+                                                                )
+                                                            }
+                                                            "Startup" | "Tray" | "Info" => {
+                                                                egui::Color32::from_rgb(
+                                                                    100, 255, 200,
+                                                                )
+                                                            }
+                                                            _ => egui::Color32::from_rgb(
+                                                                200, 200, 200,
+                                                            ),
+                                                        },
                                                     };
-                                                    ui.colored_label(color, log);
+                                                    ui.colored_label(color, &log.line);
                                                 }
                                             }
                                         });
@@ -805,6 +1535,9 @@ impl eframe::App for WhisperApp {
                                 }
                             }
                         });
+                    if save_bar {
+                        self.settings_window.ui_save_bar(ui);
+                    }
                 });
         }
 
@@ -814,7 +1547,15 @@ impl eframe::App for WhisperApp {
         if self.active_tab != TabView::Logs {
             let s = self.settings_window.get_settings();
             // Clipboard always enabled; toggle only auto-paste
-            self.core.set_behavior_options(true, s.auto_paste);
+            self.core.set_output_targets(&s.output_targets);
+            self.core.set_clipboard_mode(s.clipboard_mode);
+            self.core
+                .set_restore_clipboard(s.restore_clipboard_after_paste);
+            self.core.set_review_before_paste(s.review_before_paste);
+            self.core.set_compare_before_paste(s.compare_before_paste);
+            self.core.set_auto_paste_delay_ms(s.auto_paste_delay_ms);
+            self.core.set_complete_sound_timing(s.complete_sound_timing);
+            self.core.set_linux_paste_method(s.linux_paste_method);
             let llm_settings_snapshot = s.llm_postprocess.clone();
             // Apply Whisper language (auto: None)
             let lang_opt = if s.whisper_language == "auto" {
@@ -829,8 +1570,12 @@ impl eframe::App for WhisperApp {
             // Apply input gain (0..200% → 0.0..2.0)
             self.core
                 .set_input_gain((s.input_gain_percent / 100.0).clamp(0.0, 2.0));
+            self.core.set_preroll_ms(s.preroll_ms);
+            self.core
+                .set_input_device_auto_reselect(s.input_auto_reselect);
             crate::utils::sound::set_enabled(s.sound_enabled);
             crate::utils::sound::set_volume_percent(s.sound_volume_percent);
+            s.apply_event_sounds();
             // Reflect Whisper optimization settings
             use crate::transcription::WhisperOptimizationParams;
             self.core
@@ -838,21 +1583,48 @@ impl eframe::App for WhisperApp {
                     no_timestamps: s.whisper_no_timestamps,
                     token_timestamps: s.whisper_token_timestamps,
                     use_physical_cores: s.whisper_use_physical_cores,
+                    task: s.whisper_task,
+                    n_threads: crate::transcription::threads_param(s.whisper_threads),
+                    language_fallback: s.language_fallback(),
+                    min_segment_confidence: s.min_segment_confidence,
                     ..Default::default()
                 });
             self.core.set_chunk_split_strategy(s.chunk_split_strategy);
+            self.core.set_parallel_chunks(s.parallel_chunks);
+            self.core.set_toggle_debounce_ms(s.hotkey_debounce_ms);
+            self.core
+                .set_transcription_backend(s.remote_transcription.backend());
+            self.core.set_normalize_audio(s.normalize_audio);
+            self.core.set_trim_silence(s.trim_silence);
+            self.core.set_min_speech_secs(s.min_speech_secs);
+            self.core.set_keep_last_recording(s.keep_last_recording);
+            self.core
+                .set_hallucination_filter(s.hallucination_filter, s.hallucination_phrases.clone());
+            self.core
+                .set_transcript_boilerplate(&s.transcript_prefix, &s.transcript_suffix);
+            self.core.set_spoken_commands(s.enable_spoken_commands);
+            self.core.set_autocapitalize(s.local_autocapitalize);
+            self.core.set_readback_tts(s.readback_tts);
+            self.core.set_history_retention(s.history_retention());
+            self.core
+                .set_history_privacy(s.history_enabled, &s.history_redaction_patterns);
+            self.core
+                .set_dictionary_fuzzy_distance(s.dictionary_fuzzy_max_distance);
 
             // Apply model if requested
             if let Some(new_path) = self.settings_window.take_model_to_apply() {
                 match self.core.reload_model(&new_path) {
                     Ok(()) => {
+                        self.settings_window.set_model_load_error(None);
                         self.add_log(&format!(
                             "[Settings] Model switched: {}",
                             new_path.display()
                         ));
                     }
                     Err(e) => {
-                        self.add_log(&format!("[Error] Failed to switch model: {}", e));
+                        self.settings_window
+                            .set_model_load_error(Some(format!("{:#}", e)));
+                        self.add_log(&format!("[Error] Failed to switch model: {:#}", e));
                     }
                 }
             }
@@ -860,6 +1632,44 @@ impl eframe::App for WhisperApp {
             if let Some(entries) = self.settings_window.take_dictionary_to_apply() {
                 self.core.set_dictionary_entries(entries);
             }
+            // After the apply above, so the latest dictionary is used
+            if let Some(with_llm) = self.settings_window.take_dictionary_reapply_request() {
+                let accepted = self.core.reapply_dictionary_to_last(with_llm);
+                self.settings_window
+                    .set_dictionary_reapply_accepted(accepted);
+            }
+            self.settings_window
+                .set_last_transcript_available(self.core.has_last_transcript());
+            // Microphone hot-plug notice and "use it again"
+            if self.settings_window.take_input_reselect_request() {
+                self.core.reselect_input_device();
+            }
+            self.settings_window
+                .set_input_device_fallback(self.core.input_device_fallback());
+            // Self-test (blocks on Whisper/LLM, so off the UI thread)
+            if self.settings_window.take_self_test_request() {
+                let core = self.core.clone();
+                let slot = self.self_test_result.clone();
+                std::thread::spawn(move || {
+                    let stages = core.run_self_test();
+                    *slot.lock().unwrap() = Some(stages);
+                });
+            }
+            if let Some(stages) = self.self_test_result.lock().unwrap().take() {
+                self.settings_window.set_self_test_results(stages);
+            }
+            // Benchmark loads every downloaded model in turn: minutes for large ones
+            if self.settings_window.take_benchmark_request() {
+                let core = self.core.clone();
+                let slot = self.benchmark_result.clone();
+                std::thread::spawn(move || {
+                    let results = core.benchmark_models().map_err(|e| format!("{:#}", e));
+                    *slot.lock().unwrap() = Some(results);
+                });
+            }
+            if let Some(results) = self.benchmark_result.lock().unwrap().take() {
+                self.settings_window.set_benchmark_results(results);
+            }
 
             self.core
                 .set_llm_postprocess_settings(llm_settings_snapshot);
@@ -878,8 +1688,11 @@ impl eframe::App for WhisperApp {
             // Show settings only when Settings button pressed
             if !self.show_floating && open_settings {
                 self.show_settings = true;
+                self.show_floating = self.keep_main_window();
                 self.settings_window.set_last_ui_mode("settings");
             }
         }
+        // Review-before-paste editor (also when the floating window is hidden)
+        self.floating_window.show_review(ctx, &self.settings_window);
     }
 }
@@ -1,14 +1,53 @@
 use eframe::egui;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use crate::core::{SimpleRecState, WhisperCore};
+use crate::core::{SimpleRecState, WhisperCore, MAX_RECORD_WARNING_SECS};
+use crate::i18n;
 // removed unused icon-loading paths
 use egui::FontFamily;
 use lucide_icons::Icon;
 
+/// How the floating window is drawn
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FloatingMode {
+    /// Record/settings buttons (and error message)
+    #[default]
+    Full,
+    /// State-colored dot (plus elapsed time while recording); expands on hover/click
+    Mini,
+}
+
+// Hover this long over the mini HUD before it expands (a quick drag stays mini)
+const MINI_HOVER_EXPAND_DELAY: Duration = Duration::from_millis(600);
+
+// Live input level while recording: one bar per 50 ms window
+const LEVEL_BARS: usize = 12;
+const LEVEL_BARS_MINI: usize = 6;
+const LEVEL_BAR_STEP: f32 = 4.0;
+
+// A restored window keeps its position while at least this much of it is on screen
+const MIN_VISIBLE_PX: f32 = 24.0;
+
 pub struct FloatingWindow {
     core: Arc<WhisperCore>,
     size: egui::Vec2,
+    // Mini mode: expanded by click (stays open until collapsed)
+    expanded: bool,
+    // Mini mode: pointer over the viewport since
+    hover_since: Option<Instant>,
+    // Cleared after a manual collapse until the pointer leaves
+    hover_armed: bool,
+    // Review-before-paste: transcript being edited (reset when a new one arrives)
+    review_id: Option<u64>,
+    review_text: String,
+    // Compare view: raw Whisper text (`review_text` holds the LLM result)
+    review_raw: String,
+    // Saved positions found off the current monitors, and where they are shown
+    // instead (display only, never saved). None until the monitor size is known.
+    restore_overrides: Option<Vec<(egui::Pos2, egui::Pos2)>>,
     #[cfg(all(target_os = "linux", feature = "wayland_layer"))]
     is_wayland: bool,
     #[cfg(all(target_os = "linux", feature = "wayland_layer"))]
@@ -22,6 +61,13 @@ impl FloatingWindow {
         Self {
             core,
             size: egui::Vec2::new(280.0, 96.0),
+            expanded: false,
+            hover_since: None,
+            hover_armed: true,
+            review_id: None,
+            review_text: String::new(),
+            review_raw: String::new(),
+            restore_overrides: None,
             #[cfg(all(target_os = "linux", feature = "wayland_layer"))]
             is_wayland: std::env::var("XDG_SESSION_TYPE")
                 .map(|v| v == "wayland")
@@ -64,8 +110,46 @@ impl FloatingWindow {
         }
 
         let id = egui::ViewportId::from_hash_of("floating_viewport");
+        // Recent failure: widen the window to show the message for a few seconds
+        let error = self.core.recent_error();
+        let mode = settings.get_settings().floating_mode;
+        let colors = settings.get_settings().status_colors;
+        if mode == FloatingMode::Full {
+            self.expanded = false;
+        }
+        let hover_expanded = self
+            .hover_since
+            .is_some_and(|t| t.elapsed() >= MINI_HOVER_EXPAND_DELAY);
+        // Errors always get the full layout so the message is readable
+        let collapsed =
+            mode == FloatingMode::Mini && !self.expanded && !hover_expanded && error.is_none();
+        let recording = self.core.get_state() == SimpleRecState::Recording;
+        // Seconds left when the max recording time is close
+        let countdown_secs = cap_countdown(&self.core);
         // Tiny floating window dimensions
-        self.size = egui::vec2(120.0, 28.0);
+        self.size = if collapsed {
+            if recording {
+                egui::vec2(72.0 + LEVEL_BARS_MINI as f32 * LEVEL_BAR_STEP, 22.0)
+            } else {
+                egui::vec2(22.0, 22.0)
+            }
+        } else if error.is_some() {
+            egui::vec2(300.0, 28.0)
+        } else {
+            // Mini mode has an extra collapse button
+            let base = if mode == FloatingMode::Mini {
+                150.0
+            } else {
+                120.0
+            };
+            let meter = if recording {
+                LEVEL_BARS as f32 * LEVEL_BAR_STEP + 4.0
+            } else {
+                0.0
+            };
+            let countdown = if countdown_secs.is_some() { 32.0 } else { 0.0 };
+            egui::vec2(base + meter + countdown, 28.0)
+        };
         let mut builder = egui::ViewportBuilder::default()
             .with_title("HootVoice - Floating")
             .with_inner_size(self.size)
@@ -75,12 +159,33 @@ impl FloatingWindow {
             .with_transparent(true)
             .with_app_id("HootVoice-Floating");
 
-        // Restore last saved position
-        if let Some(pos) = settings.get_floating_position() {
-            builder = builder.with_position(pos);
+        if self.restore_overrides.is_none() {
+            self.restore_overrides = self.off_screen_overrides(ctx, settings);
+        }
+        // Restore last saved position (the mini HUD keeps its own)
+        if let Some(pos) = saved_position(settings, mode) {
+            builder = builder.with_position(self.restore_position(pos));
         }
 
         ctx.show_viewport_immediate(id, builder, |ctx2, _class| {
+            // Hover tracking for the mini HUD (applied from the next frame)
+            if ctx2.input(|i| i.pointer.hover_pos().is_some()) {
+                if self.hover_since.is_none() && self.hover_armed {
+                    self.hover_since = Some(Instant::now());
+                }
+            } else {
+                self.hover_since = None;
+                self.hover_armed = true;
+            }
+            if collapsed {
+                self.show_mini(ctx2, settings, mode);
+                ctx2.request_repaint_after(std::time::Duration::from_millis(if recording {
+                    50
+                } else {
+                    200
+                }));
+                return;
+            }
             // Pick background color based on current theme for better readability
             let bg_fill = ctx2.style().visuals.window_fill();
             egui::CentralPanel::default()
@@ -107,8 +212,7 @@ impl FloatingWindow {
                     if drag_resp.dragged() {
                         // Persist window position continuously while dragging
                         if let Some(outer) = ctx2.input(|i| i.viewport().outer_rect) {
-                            let pos = outer.min;
-                            settings.set_floating_position(pos);
+                            save_position(settings, mode, outer.min);
                         }
                     }
 
@@ -126,33 +230,22 @@ impl FloatingWindow {
                                 SimpleRecState::Busy => Icon::Loader,
                             }
                             .unicode();
-                            // State color (match settings badge colors)
-                            let rec_color = match state {
-                                SimpleRecState::Idle => egui::Color32::from_rgb(40, 167, 69), // green
-                                SimpleRecState::Recording => egui::Color32::from_rgb(220, 53, 69), // red
-                                SimpleRecState::Processing => egui::Color32::from_rgb(255, 193, 7), // yellow
-                                SimpleRecState::PostProcessing => {
-                                    egui::Color32::from_rgb(75, 154, 242)
-                                } // blue
-                                SimpleRecState::Busy => egui::Color32::from_rgb(108, 117, 125), // gray
-                            };
-
-                            let rec_clicked = ui
-                                .add(
-                                    egui::Button::new(
-                                        egui::RichText::new(rec_glyph)
-                                            .family(FontFamily::Name("lucide".into()))
-                                            .size(16.0)
-                                            .color(rec_color),
-                                    )
-                                    .min_size(egui::vec2(24.0, 20.0)),
+                            let rec_color = colors.color(state);
+
+                            let mut rec_button = ui.add(
+                                egui::Button::new(
+                                    egui::RichText::new(rec_glyph)
+                                        .family(FontFamily::Name("lucide".into()))
+                                        .size(16.0)
+                                        .color(rec_color),
                                 )
-                                .clicked();
-                            if rec_clicked
-                                && state != SimpleRecState::Processing
-                                && state != SimpleRecState::PostProcessing
-                                && state != SimpleRecState::Busy
-                            {
+                                .min_size(egui::vec2(24.0, 20.0)),
+                            );
+                            if self.core.recording_queued() {
+                                rec_button = rec_button.on_hover_text(i18n::tr("status-queued"));
+                            }
+                            // While processing a click queues (or un-queues) the next recording
+                            if rec_button.clicked() && state != SimpleRecState::Busy {
                                 self.core.toggle_recording();
                             }
 
@@ -168,22 +261,93 @@ impl FloatingWindow {
                                     .min_size(egui::vec2(24.0, 20.0)),
                                 )
                                 .clicked();
+                            if mode == FloatingMode::Mini {
+                                ui.add_space(4.0);
+                                let collapse_clicked = ui
+                                    .add(
+                                        egui::Button::new(
+                                            egui::RichText::new(Icon::Minimize2.unicode())
+                                                .family(FontFamily::Name("lucide".into()))
+                                                .size(16.0),
+                                        )
+                                        .min_size(egui::vec2(24.0, 20.0)),
+                                    )
+                                    .on_hover_text(i18n::tr("tooltip-floating-collapse"))
+                                    .clicked();
+                                if collapse_clicked {
+                                    self.expanded = false;
+                                    self.hover_since = None;
+                                    self.hover_armed = false;
+                                }
+                            }
                             if settings_clicked {
                                 // Save current position
                                 if let Some(outer) = ctx2.input(|i| i.viewport().outer_rect) {
-                                    let pos = outer.min;
-                                    settings.set_floating_position(pos);
+                                    save_position(settings, mode, outer.min);
                                 }
                                 requested_settings = true;
                                 // Close the viewport
                                 ctx2.send_viewport_cmd(egui::ViewportCommand::Close);
                             }
+
+                            if error.is_none() && state == SimpleRecState::Recording {
+                                ui.add_space(4.0);
+                                let (rect, _) = ui.allocate_exact_size(
+                                    egui::vec2(LEVEL_BARS as f32 * LEVEL_BAR_STEP, 18.0),
+                                    egui::Sense::hover(),
+                                );
+                                paint_level_bars(
+                                    ui.painter(),
+                                    rect,
+                                    &self.core.recent_input_levels(LEVEL_BARS),
+                                    rec_color,
+                                );
+                                if let Some(secs) = countdown_secs {
+                                    ui.add_space(4.0);
+                                    ui.label(
+                                        egui::RichText::new(format!("{}s", secs))
+                                            .color(ui.visuals().warn_fg_color)
+                                            .strong(),
+                                    )
+                                    .on_hover_text(i18n::tr("tooltip-max-record-countdown"));
+                                }
+                            }
+
+                            if let Some(err) = &error {
+                                ui.add_space(4.0);
+                                let color = if err.is_notice() {
+                                    ui.visuals().warn_fg_color
+                                } else {
+                                    egui::Color32::from_rgb(220, 53, 69)
+                                };
+                                ui.label(
+                                    egui::RichText::new(Icon::TriangleAlert.unicode())
+                                        .family(FontFamily::Name("lucide".into()))
+                                        .size(16.0)
+                                        .color(color),
+                                );
+                                let resp = ui.add(
+                                    egui::Label::new(
+                                        egui::RichText::new(i18n::tr(err.message_key()))
+                                            .color(color)
+                                            .strong(),
+                                    )
+                                    .truncate(),
+                                );
+                                if let Some(detail) = err.detail() {
+                                    resp.on_hover_text(detail);
+                                }
+                            }
                         });
                     });
                 });
 
-            // Light refresh
-            ctx2.request_repaint_after(std::time::Duration::from_millis(200));
+            // Light refresh (faster while the level bars move)
+            ctx2.request_repaint_after(std::time::Duration::from_millis(if recording {
+                50
+            } else {
+                200
+            }));
         });
 
         if requested_settings {
@@ -192,6 +356,204 @@ impl FloatingWindow {
         requested_settings
     }
 
+    // Mini HUD: state dot (+ elapsed time while recording). Drag to move, click to expand.
+    fn show_mini(
+        &mut self,
+        ctx2: &egui::Context,
+        settings: &mut crate::gui::settings::SettingsWindow,
+        mode: FloatingMode,
+    ) {
+        let state = self.core.get_state();
+        let colors = settings.get_settings().status_colors;
+        let bg_fill = ctx2.style().visuals.window_fill();
+        egui::CentralPanel::default()
+            .frame(
+                egui::Frame::new()
+                    .fill(bg_fill)
+                    .corner_radius(egui::CornerRadius::same(11))
+                    .stroke(egui::Stroke::new(
+                        1.0,
+                        egui::Color32::from_rgba_unmultiplied(200, 200, 200, 40),
+                    )),
+            )
+            .show(ctx2, |ui| {
+                let rect = ui.max_rect();
+                let resp = ui.allocate_rect(rect, egui::Sense::click_and_drag());
+                if resp.drag_started() {
+                    ctx2.send_viewport_cmd(egui::ViewportCommand::StartDrag);
+                }
+                if resp.dragged() {
+                    if let Some(outer) = ctx2.input(|i| i.viewport().outer_rect) {
+                        save_position(settings, mode, outer.min);
+                    }
+                }
+                if resp.clicked() {
+                    self.expanded = true;
+                }
+                let dot = egui::pos2(rect.left() + 11.0, rect.center().y);
+                ui.painter().circle_filled(dot, 5.0, colors.color(state));
+                if let Some(elapsed) = self.core.recording_elapsed() {
+                    let secs = elapsed.as_secs();
+                    // The timer turns to the warning color close to the max recording time
+                    let text_color = if cap_countdown(&self.core).is_some() {
+                        ui.visuals().warn_fg_color
+                    } else {
+                        ui.visuals().strong_text_color()
+                    };
+                    let text_rect = ui.painter().text(
+                        egui::pos2(dot.x + 10.0, rect.center().y),
+                        egui::Align2::LEFT_CENTER,
+                        format!("{:02}:{:02}", secs / 60, secs % 60),
+                        egui::FontId::monospace(12.0),
+                        text_color,
+                    );
+                    let bars = egui::Rect::from_min_size(
+                        egui::pos2(text_rect.right() + 6.0, rect.top() + 4.0),
+                        egui::vec2(LEVEL_BARS_MINI as f32 * LEVEL_BAR_STEP, rect.height() - 8.0),
+                    );
+                    paint_level_bars(
+                        ui.painter(),
+                        bars,
+                        &self.core.recent_input_levels(LEVEL_BARS_MINI),
+                        colors.color(state),
+                    );
+                }
+            });
+    }
+
+    /// Editor for a transcript held by `review_before_paste` (Paste / Discard / Re-run);
+    /// with `compare_before_paste`, raw and LLM text side by side, each pasteable.
+    /// A separate viewport so it can take keyboard focus and hand it back when closed.
+    pub fn show_review(
+        &mut self,
+        ctx: &egui::Context,
+        settings: &crate::gui::settings::SettingsWindow,
+    ) {
+        let id = egui::ViewportId::from_hash_of("floating_review_viewport");
+        let Some(review) = self.core.pending_review() else {
+            if self.review_id.take().is_some() {
+                ctx.send_viewport_cmd_to(id, egui::ViewportCommand::Close);
+            }
+            return;
+        };
+        if self.review_id != Some(review.id) {
+            self.review_id = Some(review.id);
+            match review.compare.clone() {
+                Some((raw, polished)) => {
+                    self.review_raw = raw;
+                    self.review_text = polished;
+                }
+                None => {
+                    self.review_raw.clear();
+                    self.review_text = review.text;
+                }
+            }
+        }
+        let comparing = review.compare.is_some();
+
+        let mut builder = egui::ViewportBuilder::default()
+            .with_title("HootVoice - Review")
+            .with_inner_size(if comparing {
+                egui::vec2(640.0, 220.0)
+            } else {
+                egui::vec2(380.0, 180.0)
+            })
+            .with_min_inner_size(egui::vec2(260.0, 120.0))
+            .with_always_on_top()
+            .with_app_id("HootVoice-Review");
+        // Just below the floating window
+        if let Some(pos) = saved_position(settings, settings.get_settings().floating_mode) {
+            builder = builder.with_position(pos + egui::vec2(0.0, 36.0));
+        }
+
+        ctx.show_viewport_immediate(id, builder, |ctx2, _class| {
+            let idle = self.core.get_state() == SimpleRecState::Idle;
+            let mut confirm = false;
+            let mut confirm_raw = false;
+            let mut discard = ctx2.input(|i| i.viewport().close_requested());
+            let mut rerun = false;
+            egui::TopBottomPanel::bottom("review_buttons").show(ctx2, |ui| {
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    if comparing {
+                        confirm_raw = ui.button(i18n::tr("btn-review-paste-raw")).clicked();
+                        confirm = ui
+                            .button(i18n::tr("btn-review-paste-llm"))
+                            .on_hover_text(i18n::tr("tooltip-review-paste"))
+                            .clicked();
+                    } else {
+                        confirm = ui
+                            .button(i18n::tr("btn-review-paste"))
+                            .on_hover_text(i18n::tr("tooltip-review-paste"))
+                            .clicked();
+                    }
+                    discard |= ui.button(i18n::tr("btn-review-discard")).clicked();
+                    rerun = ui
+                        .add_enabled(idle, egui::Button::new(i18n::tr("btn-review-rerun")))
+                        .on_hover_text(i18n::tr("tooltip-review-rerun"))
+                        .clicked();
+                });
+                ui.add_space(2.0);
+            });
+            egui::CentralPanel::default().show(ctx2, |ui| {
+                if !comparing {
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        let resp = ui.add(
+                            egui::TextEdit::multiline(&mut self.review_text)
+                                .desired_rows(5)
+                                .desired_width(f32::INFINITY),
+                        );
+                        if ui.memory(|m| m.focused().is_none()) {
+                            resp.request_focus();
+                        }
+                    });
+                    return;
+                }
+                ui.columns(2, |cols| {
+                    let panes = [
+                        (&mut self.review_raw, "label-review-raw"),
+                        (&mut self.review_text, "label-review-llm"),
+                    ];
+                    for (ui, (text, key)) in cols.iter_mut().zip(panes) {
+                        ui.label(
+                            egui::RichText::new(i18n::tr(key))
+                                .small()
+                                .color(ui.visuals().weak_text_color()),
+                        );
+                        egui::ScrollArea::vertical().id_salt(key).show(ui, |ui| {
+                            ui.add(
+                                egui::TextEdit::multiline(text)
+                                    .desired_rows(6)
+                                    .desired_width(f32::INFINITY),
+                            );
+                        });
+                    }
+                });
+            });
+            // Ctrl/Cmd+Enter pastes, Escape discards
+            confirm |= ctx2.input(|i| i.modifiers.command && i.key_pressed(egui::Key::Enter));
+            discard |= ctx2.input(|i| i.key_pressed(egui::Key::Escape));
+
+            if confirm_raw {
+                self.core
+                    .confirm_review(std::mem::take(&mut self.review_raw));
+            } else if confirm {
+                self.core
+                    .confirm_review(std::mem::take(&mut self.review_text));
+            } else if discard {
+                self.core.discard_review();
+            } else if rerun {
+                // Not idle after all: keep the editor open
+                rerun = self.core.rerun_review();
+            }
+            if confirm || confirm_raw || discard || rerun {
+                self.review_id = None;
+                ctx2.send_viewport_cmd(egui::ViewportCommand::Close);
+            }
+            ctx2.request_repaint_after(std::time::Duration::from_millis(200));
+        });
+    }
+
     #[cfg(all(target_os = "linux", feature = "wayland_layer"))]
     fn show_wayland_sidecar(
         &mut self,
@@ -259,4 +621,161 @@ impl Drop for FloatingWindow {
     }
 }
 
+// Vertical bars centered on `rect`, oldest on the left; a flat line means no input
+fn paint_level_bars(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    levels: &[f32],
+    color: egui::Color32,
+) {
+    for (i, level) in levels.iter().enumerate() {
+        let x = rect.left() + i as f32 * LEVEL_BAR_STEP + LEVEL_BAR_STEP / 2.0;
+        let half = (rect.height() / 2.0 * level.clamp(0.0, 1.0)).max(1.0);
+        painter.line_segment(
+            [
+                egui::pos2(x, rect.center().y - half),
+                egui::pos2(x, rect.center().y + half),
+            ],
+            egui::Stroke::new(LEVEL_BAR_STEP - 1.0, color),
+        );
+    }
+}
+
+impl FloatingWindow {
+    // Where to show positions saved on a since-disconnected monitor. The saved
+    // value stays untouched: the monitor layout is only a guess (see
+    // `screen_bounds`), so the window goes back there once that monitor returns.
+    // None until the monitor size is known.
+    fn off_screen_overrides(
+        &self,
+        ctx: &egui::Context,
+        settings: &crate::gui::settings::SettingsWindow,
+    ) -> Option<Vec<(egui::Pos2, egui::Pos2)>> {
+        let screen = ctx.input(|i| screen_bounds(i.viewport()))?;
+        let mut overrides = Vec::new();
+        for mode in [FloatingMode::Full, FloatingMode::Mini] {
+            let Some(pos) = saved_position(settings, mode) else {
+                continue;
+            };
+            let shown = clamp_to_screen(pos, self.size, screen);
+            if shown != pos {
+                self.core.log(&format!(
+                    "[Floating] Saved position ({:.0}, {:.0}) looks off screen; showing at ({:.0}, {:.0})",
+                    pos.x, pos.y, shown.x, shown.y
+                ));
+                overrides.push((pos, shown));
+            }
+        }
+        Some(overrides)
+    }
+
+    // Saved position, or its on-screen stand-in until the user moves the window
+    fn restore_position(&self, saved: egui::Pos2) -> egui::Pos2 {
+        self.restore_overrides
+            .iter()
+            .flatten()
+            .find(|(pos, _)| *pos == saved)
+            .map_or(saved, |(_, shown)| *shown)
+    }
+}
+
+// Area a window can be restored to: the primary monitor at the origin plus the
+// monitor holding the main window (assumed to be the same size, side by side).
+// egui only reports the size of the current monitor, not the full layout.
+fn screen_bounds(viewport: &egui::ViewportInfo) -> Option<egui::Rect> {
+    let monitor = viewport.monitor_size.filter(|m| m.x > 0.0 && m.y > 0.0)?;
+    let primary = egui::Rect::from_min_size(egui::Pos2::ZERO, monitor);
+    let Some(main) = viewport.outer_rect else {
+        return Some(primary);
+    };
+    let origin = egui::pos2(
+        (main.center().x / monitor.x).floor() * monitor.x,
+        (main.center().y / monitor.y).floor() * monitor.y,
+    );
+    Some(primary.union(egui::Rect::from_min_size(origin, monitor)))
+}
+
+// Keep `pos` when enough of the window is visible, otherwise clamp it inside `screen`
+fn clamp_to_screen(pos: egui::Pos2, size: egui::Vec2, screen: egui::Rect) -> egui::Pos2 {
+    let window = egui::Rect::from_min_size(pos, size);
+    let visible = window.intersect(screen);
+    if visible.width() >= MIN_VISIBLE_PX.min(size.x)
+        && visible.height() >= MIN_VISIBLE_PX.min(size.y)
+    {
+        return pos;
+    }
+    egui::pos2(
+        pos.x
+            .clamp(screen.min.x, (screen.max.x - size.x).max(screen.min.x)),
+        pos.y
+            .clamp(screen.min.y, (screen.max.y - size.y).max(screen.min.y)),
+    )
+}
+
+// Whole seconds left before `max_record_secs` once inside the warning lead time
+fn cap_countdown(core: &WhisperCore) -> Option<u64> {
+    core.max_record_remaining()
+        .filter(|left| left.as_secs_f32() <= MAX_RECORD_WARNING_SECS)
+        .map(|left| left.as_secs_f32().ceil() as u64)
+}
+
+fn saved_position(
+    settings: &crate::gui::settings::SettingsWindow,
+    mode: FloatingMode,
+) -> Option<egui::Pos2> {
+    match mode {
+        FloatingMode::Full => settings.get_floating_position(),
+        FloatingMode::Mini => settings.get_floating_mini_position(),
+    }
+}
+
+fn save_position(
+    settings: &mut crate::gui::settings::SettingsWindow,
+    mode: FloatingMode,
+    pos: egui::Pos2,
+) {
+    match mode {
+        FloatingMode::Full => settings.set_floating_position(pos),
+        FloatingMode::Mini => settings.set_floating_mini_position(pos),
+    }
+}
+
 // OverlayIcons and PNG-based icon loading have been removed (unused).
+
+#[cfg(test)]
+mod tests {
+    use super::{clamp_to_screen, screen_bounds};
+    use eframe::egui;
+
+    #[test]
+    fn off_screen_position_is_clamped() {
+        let screen = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(1920.0, 1080.0));
+        let size = egui::vec2(120.0, 28.0);
+        // Partly visible: kept as is
+        let edge = egui::pos2(1850.0, 500.0);
+        assert_eq!(clamp_to_screen(edge, size, screen), edge);
+        // Saved on a disconnected right-hand monitor
+        assert_eq!(
+            clamp_to_screen(egui::pos2(2500.0, 300.0), size, screen),
+            egui::pos2(1800.0, 300.0)
+        );
+        assert_eq!(
+            clamp_to_screen(egui::pos2(-400.0, -90.0), size, screen),
+            egui::pos2(0.0, 0.0)
+        );
+
+        // Main window on a second monitor to the right widens the area
+        let info = egui::ViewportInfo {
+            monitor_size: Some(egui::vec2(1920.0, 1080.0)),
+            outer_rect: Some(egui::Rect::from_min_size(
+                egui::pos2(2200.0, 100.0),
+                egui::vec2(800.0, 600.0),
+            )),
+            ..Default::default()
+        };
+        let bounds = screen_bounds(&info).unwrap();
+        assert_eq!(bounds.max, egui::pos2(3840.0, 1080.0));
+        let right = egui::pos2(2500.0, 300.0);
+        assert_eq!(clamp_to_screen(right, size, bounds), right);
+    }
+}
@@ -36,6 +36,8 @@ struct SetupState {
 pub struct RootApp {
     state: RootState,
     unmaximize_once: bool,
+    // `window_maximized` from the saved settings
+    start_maximized: bool,
     // For in-app testing (dev builds), allow opening the wizard anytime
     wizard_in_app: Option<FirstRunWizard>,
     // Run mic permission preflight once at launch (macOS only)
@@ -47,15 +49,22 @@ impl RootApp {
     pub fn new() -> Self {
         // Resolve absolute path for the expected model (from default/settings)
         let (expected_abs, _rel) = resolve_expected_model_path();
-        if expected_abs.exists() {
+        let saved = read_saved_settings();
+        let start_maximized = saved.window_maximized;
+        // The remote backend transcribes without a local model: no setup needed
+        if expected_abs.exists() || saved.remote_transcription.enabled {
             // Even if it exists, model loading is heavy — start with async loading and show UI first
             let result: LoadResultSlot = Arc::new(Mutex::new(None));
             let path = expected_abs.clone();
+            let mirror = saved.model_download_base_url.clone();
             let result_clone = result.clone();
             std::thread::spawn(move || {
-                let loaded = WhisperCore::new(&path)
-                    .map(Arc::new)
-                    .map_err(|e| format!("{}", e));
+                let loaded = if path.exists() {
+                    WhisperCore::new(&path, mirror.as_deref())
+                } else {
+                    Ok(WhisperCore::without_model(&path))
+                };
+                let loaded = loaded.map(Arc::new).map_err(|e| format!("{:#}", e));
                 if let Ok(mut guard) = result_clone.lock() {
                     *guard = Some(loaded);
                 }
@@ -67,6 +76,7 @@ impl RootApp {
             Self {
                 state: RootState::Loading(state),
                 unmaximize_once: true,
+                start_maximized,
                 wizard_in_app: None,
                 #[cfg(target_os = "macos")]
                 mic_preflight_started: false,
@@ -84,12 +94,21 @@ impl RootApp {
             Self {
                 state: RootState::Setup(setup),
                 unmaximize_once: true,
+                start_maximized,
                 wizard_in_app: None,
                 #[cfg(target_os = "macos")]
                 mic_preflight_started: false,
             }
         }
     }
+
+    fn settings_window_mut(&mut self) -> Option<&mut SettingsWindow> {
+        match &mut self.state {
+            RootState::Loading(_) => None,
+            RootState::Setup(setup) => Some(&mut setup.settings_window),
+            RootState::Running(app) => Some(&mut app.settings_window),
+        }
+    }
 }
 
 impl Default for RootApp {
@@ -104,9 +123,10 @@ impl eframe::App for RootApp {
     }
 
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // On startup, ensure not maximized/fullscreen (avoid carry-over on macOS)
+        // On startup, ensure not fullscreen and only maximized when saved that way
+        // (avoid carry-over on macOS)
         if self.unmaximize_once {
-            ctx.send_viewport_cmd(egui::ViewportCommand::Maximized(false));
+            ctx.send_viewport_cmd(egui::ViewportCommand::Maximized(self.start_maximized));
             ctx.send_viewport_cmd(egui::ViewportCommand::Fullscreen(false));
             self.unmaximize_once = false;
         }
@@ -133,21 +153,23 @@ impl eframe::App for RootApp {
                     match res {
                         Ok(core) => {
                             let mut app = WhisperApp::new(core);
-                            // On first display, force open Settings (avoid invisible window issues)
-                            app.show_settings_window();
+                            if app.settings_window.get_settings().start_hidden {
+                                // Setup is complete (model loaded); the wizard path never hides
+                                app.start_in_background();
+                            } else {
+                                // On first display, force open Settings (avoid invisible window issues)
+                                app.show_settings_window();
+                            }
                             next_state = Some(RootState::Running(app));
                         }
-                        Err(_e) => {
-                            // Load failed: fallback to setup
-                            let settings_window = SettingsWindow::new();
-                            let setup = SetupState {
-                                settings_window,
-                                auto_prompted: false,
-                                expected_abs_model: state.expected_abs_model.clone(),
-                                wizard: FirstRunWizard::new(),
-                                applied_model: None,
-                            };
-                            next_state = Some(RootState::Setup(setup));
+                        Err(e) => {
+                            // Setup is already done: stay in the app and say why, so the
+                            // user can re-download or pick another model from the banner
+                            eprintln!("[Whisper] Model load failed: {}", e);
+                            let core = WhisperCore::without_model(&state.expected_abs_model);
+                            let mut app = WhisperApp::new(Arc::new(core));
+                            app.show_model_load_error(e);
+                            next_state = Some(RootState::Running(app));
                         }
                     }
                 } else {
@@ -242,10 +264,29 @@ impl eframe::App for RootApp {
                         .unwrap_or_else(|| {
                             absolute_model_path_for_settings(setup.settings_window.get_settings())
                         });
-                    if model_path_abs.exists() {
-                        if let Ok(core) = WhisperCore::new(&model_path_abs) {
-                            let app = WhisperApp::new(Arc::new(core));
-                            next_state = Some(RootState::Running(app));
+                    let settings = setup.settings_window.get_settings();
+                    let remote = settings.remote_transcription.enabled;
+                    if model_path_abs.exists() || remote {
+                        let mirror = settings.model_download_base_url.clone();
+                        let loaded = if model_path_abs.exists() {
+                            WhisperCore::new(&model_path_abs, mirror.as_deref())
+                        } else {
+                            Ok(WhisperCore::without_model(&model_path_abs))
+                        };
+                        match loaded {
+                            Ok(core) => {
+                                // WhisperApp reloads settings.toml: write queued wizard edits
+                                setup.settings_window.flush_pending_save();
+                                let app = WhisperApp::new(Arc::new(core));
+                                next_state = Some(RootState::Running(app));
+                            }
+                            Err(e) => {
+                                // Stay in setup; the banner explains what went wrong
+                                setup
+                                    .settings_window
+                                    .set_model_load_error(Some(format!("{:#}", e)));
+                                setup.wizard = FirstRunWizard::new();
+                            }
                         }
                     }
                 }
@@ -255,24 +296,61 @@ impl eframe::App for RootApp {
             }
         }
 
+        let (inner_rect, maximized, minimized) = ctx.input(|i| {
+            let v = i.viewport();
+            (v.inner_rect, v.maximized, v.minimized)
+        });
+        if let Some(sw) = self.settings_window_mut() {
+            // Remember the main window geometry (not while minimized)
+            if let (Some(rect), false) = (inner_rect, minimized.unwrap_or(false)) {
+                sw.remember_window_geometry(rect.size(), maximized.unwrap_or(false));
+            }
+            // Throttled settings writes: flush when due, and right away when the
+            // window loses focus or is being closed
+            let leaving = ctx
+                .input(|i| i.viewport().focused == Some(false) || i.viewport().close_requested());
+            if leaving {
+                sw.flush_pending_save();
+            } else if let Some(left) = sw.flush_pending_save_if_due() {
+                ctx.request_repaint_after(left);
+            }
+        }
+
         // After closing the UI, transition state safely
         if let Some(ns) = next_state {
             self.state = ns;
         }
     }
+
+    fn on_exit(&mut self) {
+        // Never lose an edit still waiting for the throttled write
+        if let Some(sw) = self.settings_window_mut() {
+            sw.flush_pending_save();
+        }
+    }
 }
 
-fn resolve_expected_model_path() -> (PathBuf, PathBuf) {
-    // Load whisper model path from settings.toml (same logic as SettingsWindow)
+// settings.toml as saved (defaults when missing/invalid), read before the UI exists
+pub(crate) fn read_saved_settings() -> super::settings::Settings {
     let settings_path = app_config_dir().join("settings.toml");
     let settings_str = std::fs::read_to_string(&settings_path).unwrap_or_default();
-    let settings: super::settings::Settings = toml::from_str(&settings_str).unwrap_or_default();
+    toml::from_str(&settings_str).unwrap_or_default()
+}
+
+/// Apply the saved theme and UI scale before the first frame (no flash/resize)
+pub fn apply_startup_appearance(ctx: &egui::Context) {
+    read_saved_settings().apply_appearance(ctx);
+}
+
+fn resolve_expected_model_path() -> (PathBuf, PathBuf) {
+    // Load whisper model path from settings.toml (same logic as SettingsWindow)
+    let settings = read_saved_settings();
 
     let abs = absolute_model_path_for_settings(&settings);
     (abs, settings.whisper_model_path.clone())
 }
 
-fn absolute_model_path_for_settings(settings: &super::settings::Settings) -> PathBuf {
+pub(crate) fn absolute_model_path_for_settings(settings: &super::settings::Settings) -> PathBuf {
     let p = &settings.whisper_model_path;
     if p.is_absolute() {
         p.clone()
@@ -1,23 +1,37 @@
 use eframe::egui;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 // ProjectDirs and utility imports moved to submodules
 use crate::i18n;
 use crate::llm::{
-    builtin_prompt_preview, history_modified_time, load_history_entries, ConnectionTestOutcome,
-    LlmHistoryEntry, LlmModelInfo, LlmPostProcessSettings, LlmPostProcessor, PostProcessOutcome,
-    DEFAULT_LOCAL_BASE_URL, MODE_ID_CUSTOM_DRAFT, PRESET_ID_FORMAT, PRESET_ID_SUMMARY,
+    builtin_prompt_preview, clear_history, history_entry_matches_filter, history_file_path,
+    history_modified_time, load_history_entries, preview_prompt, redaction_pattern_error,
+    ConnectionTestOutcome, HistoryDateFilter, HistoryRetention, LlmHistoryEntry, LlmModelInfo,
+    LlmPostProcessSettings, LlmPostProcessor, OversizeInput, PostProcessOutcome,
+    CARD_NUMBER_PATTERN, DEFAULT_HISTORY_MAX_ENTRIES, DEFAULT_LOCAL_BASE_URL,
+    DEFAULT_MAX_OUTPUT_TOKENS, HISTORY_MAX_ENTRIES_LIMIT, MAX_MAX_OUTPUT_TOKENS,
+    MIN_MAX_OUTPUT_TOKENS, MODE_ID_CUSTOM_DRAFT, PRESET_ID_FORMAT, PRESET_ID_FURIGANA,
+    PRESET_ID_SUMMARY,
 };
-use crate::transcription::SUPPORTED_MODELS;
+use crate::transcription::remote::RemoteTranscriptionSettings;
+use crate::transcription::{LanguageFallback, WhisperTask, SUPPORTED_MODELS};
+use crate::utils::diff::{word_diff, DiffSpan};
 use crate::utils::update::{releases_latest_url, spawn_check_update, AvailableUpdate, UpdateState};
 use crate::utils::{open::open_url, update};
 use std::sync::{mpsc, Arc, Mutex};
 // (kept above) use std::sync::atomic::{AtomicBool, Ordering};
+use crate::audio::stream::{StreamReport, StreamStats};
 use crate::audio::VadStrategy;
+use crate::core::{ClipboardMode, MaxRecordAction, OutputTarget, SimpleRecState, SoundTiming};
+use crate::gui::floating::FloatingMode;
+use crate::hotkey::{HotkeyAction, HotkeyBinding, HotkeyMode};
+use crate::utils::keyboard::LinuxPasteMethod;
 use std::sync::atomic::AtomicBool;
 // device trait usage moved to submodules
 use chrono::Local;
-use std::time::{Instant, SystemTime};
+use lucide_icons::Icon;
+use std::time::{Duration, Instant, SystemTime};
 // moved audio test helpers into submodule; keep imports local there
 
 // removed: correction feature
@@ -31,7 +45,11 @@ mod audio_meter;
 mod hotkey;
 mod whisper_models;
 // removed: Ollama support
+mod benchmark;
+mod llm_chain;
+mod output_targets;
 mod persistence;
+mod self_test;
 
 // Bundle third-party licenses as Markdown at build time
 const THIRD_PARTY_LICENSES_MD: &str = include_str!(concat!(
@@ -56,48 +74,310 @@ struct LlmPromptTestState {
     error: Option<String>,
 }
 
+// Model list retry backoff while the LLM server is not up yet: 2s, 4s, 8s, ... capped at 30s
+fn model_poll_delay(attempt: u32) -> Duration {
+    Duration::from_secs((2u64 << attempt.min(4)).min(30))
+}
+
 fn is_builtin_mode_id(id: &str) -> bool {
-    matches!(id, PRESET_ID_FORMAT | PRESET_ID_SUMMARY)
+    matches!(
+        id,
+        PRESET_ID_FORMAT | PRESET_ID_SUMMARY | PRESET_ID_FURIGANA
+    )
 }
 
 enum LlmUiMessage {
-    TestResult(Result<ConnectionTestOutcome, String>),
+    // Tagged with the test id so a cancelled run's late result is dropped
+    TestResult(u64, Result<ConnectionTestOutcome, String>),
     ModelList(Result<Vec<LlmModelInfo>, String>),
     PromptTest(Result<PostProcessOutcome, String>),
+    // Tagged with the history entry index
+    HistoryRerun(usize, Result<PostProcessOutcome, String>),
 }
 
 // Legacy tab enum removed (unused)
 
+/// Light/dark appearance of all windows
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeMode {
+    /// Follow the OS setting (when the platform reports it)
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+/// VSync of the main window, read once at startup
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum VsyncMode {
+    /// Off on Linux (some drivers time out waiting for vsync), on elsewhere
+    #[default]
+    Auto,
+    On,
+    Off,
+}
+
+impl VsyncMode {
+    pub const ALL: [Self; 3] = [Self::Auto, Self::On, Self::Off];
+
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Self::Auto => "option-vsync-auto",
+            Self::On => "option-vsync-on",
+            Self::Off => "option-vsync-off",
+        }
+    }
+
+    /// `HOOTVOICE_VSYNC` (0/1) still wins over the setting
+    pub fn resolve(self, env: Option<&str>) -> bool {
+        if let Some(v) = env {
+            return matches!(v, "1" | "true" | "TRUE" | "on" | "ON");
+        }
+        match self {
+            Self::On => true,
+            Self::Off => false,
+            Self::Auto => !cfg!(target_os = "linux"),
+        }
+    }
+}
+
+/// Status colors (sRGB) per recording state: main-window badge and floating window
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatusColors {
+    pub idle: [u8; 3],
+    pub recording: [u8; 3],
+    pub processing: [u8; 3],
+    pub post_processing: [u8; 3],
+    // Also used for "hotkeys paused"
+    pub busy: [u8; 3],
+}
+
+impl Default for StatusColors {
+    fn default() -> Self {
+        Self {
+            idle: [40, 167, 69],
+            recording: [220, 53, 69],
+            processing: [255, 193, 7],
+            post_processing: [75, 154, 242],
+            busy: [108, 117, 125],
+        }
+    }
+}
+
+impl StatusColors {
+    /// Saturated colors with stronger contrast between states
+    pub const HIGH_CONTRAST: Self = Self {
+        idle: [0, 128, 0],
+        recording: [204, 0, 0],
+        processing: [255, 215, 0],
+        post_processing: [0, 82, 204],
+        busy: [64, 64, 64],
+    };
+    /// Okabe–Ito palette (distinguishable with red–green color blindness)
+    pub const COLORBLIND: Self = Self {
+        idle: [0, 158, 115],
+        recording: [213, 94, 0],
+        processing: [240, 228, 66],
+        post_processing: [0, 114, 178],
+        busy: [128, 128, 128],
+    };
+
+    /// Built-in palettes with their i18n label keys
+    pub fn palettes() -> [(Self, &'static str); 3] {
+        [
+            (Self::default(), "option-status-colors-default"),
+            (Self::HIGH_CONTRAST, "option-status-colors-high-contrast"),
+            (Self::COLORBLIND, "option-status-colors-colorblind"),
+        ]
+    }
+
+    pub fn color(&self, state: SimpleRecState) -> egui::Color32 {
+        let [r, g, b] = match state {
+            SimpleRecState::Idle => self.idle,
+            SimpleRecState::Recording => self.recording,
+            SimpleRecState::Processing => self.processing,
+            SimpleRecState::PostProcessing => self.post_processing,
+            SimpleRecState::Busy => self.busy,
+        };
+        egui::Color32::from_rgb(r, g, b)
+    }
+
+    /// Black or white, whichever reads better on `bg`
+    pub fn text_on(bg: egui::Color32) -> egui::Color32 {
+        let luma = 0.2126 * bg.r() as f32 + 0.7152 * bg.g() as f32 + 0.0722 * bg.b() as f32;
+        if luma > 150.0 {
+            egui::Color32::BLACK
+        } else {
+            egui::Color32::WHITE
+        }
+    }
+}
+
+// Languages offered in the language combos besides "auto" (code, i18n key)
+const WHISPER_LANGUAGES: [(&str, &str); 10] = [
+    ("ja", "option-japanese-ja"),
+    ("en", "option-english-en"),
+    ("zh", "option-chinese-zh"),
+    ("es", "option-spanish-es"),
+    ("fr", "option-french-fr"),
+    ("de", "option-german-de"),
+    ("ko", "option-korean-ko"),
+    ("pt", "option-portuguese-pt"),
+    ("ru", "option-russian-ru"),
+    ("hi", "option-hindi-hi"),
+];
+
+// Display name of a `whisper_language` value; unknown codes are shown as-is
+fn whisper_language_label(code: &str) -> String {
+    if code == "auto" {
+        return i18n::tr("option-auto-detect");
+    }
+    WHISPER_LANGUAGES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map_or_else(|| code.to_string(), |(_, key)| i18n::tr(key))
+}
+
+/// Allowed `ui_scale_percent` values (100% = OS scale; 110% is the default)
+pub const UI_SCALE_RANGE: std::ops::RangeInclusive<f32> = 80.0..=200.0;
+
+impl ThemeMode {
+    pub fn apply(self, ctx: &egui::Context) {
+        ctx.set_theme(match self {
+            ThemeMode::System => egui::ThemePreference::System,
+            ThemeMode::Light => egui::ThemePreference::Light,
+            ThemeMode::Dark => egui::ThemePreference::Dark,
+        });
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(default)]
 pub struct Settings {
     pub hotkey_recording: String,
+    // Toggle (press to start/stop) or push-to-talk (hold to record)
+    pub hotkey_mode: HotkeyMode,
+    // Repeated toggles within this many ms are ignored (0 disables)
+    pub hotkey_debounce_ms: u64,
+    // All global hotkeys; the first entry mirrors `hotkey_recording`.
+    // Field-level default keeps old configs empty so the legacy hotkey migrates in.
+    #[serde(default)]
+    pub hotkey_bindings: Vec<HotkeyBinding>,
+    // Unix only, opt-in: accept toggle/show commands on a local socket (read at startup)
+    pub ipc_socket: bool,
     pub whisper_model_path: PathBuf,
+    // Mirror replacing the Hugging Face host for model downloads (None = default)
+    pub model_download_base_url: Option<String>,
     pub whisper_language: String,
+    // Used when auto-detect is less confident than `auto_detect_min_confidence`
+    pub auto_detect_fallback: Option<String>,
+    pub auto_detect_min_confidence: f32,
+    // Transcribe, or translate to English (`whisper_language` is then the source)
+    pub whisper_task: WhisperTask,
     // UI language (auto/ja/en)
     pub ui_language: String,
+    pub theme: ThemeMode,
+    // UI size relative to the OS scale factor (percent)
+    pub ui_scale_percent: f32,
+    // Applied at startup (restart required)
+    pub vsync_mode: VsyncMode,
     pub input_device: Option<String>,
     pub input_host: Option<String>,
     pub input_device_index_in_host: Option<usize>,
+    // Stable device ID within the host (matched before the index on capture)
+    pub input_device_uid: Option<String>,
     pub input_device_index: Option<usize>,
     pub output_device: Option<String>,
     pub input_gain_percent: f32,
+    // Sensitivity remembered per input (key: `input_gain_key`)
+    pub device_gains: HashMap<String, f32>,
+    // Audio kept from just before recording starts (0 disables)
+    pub preroll_ms: u32,
+    // Switch back to the chosen microphone when it is plugged in again
+    pub input_auto_reselect: bool,
+    // Mirrors `OutputTarget::AutoPaste` in `output_targets` (older configs only have this)
     pub auto_paste: bool,
+    // Where transcripts go: clipboard, auto-paste, notes files
+    pub output_targets: Vec<OutputTarget>,
+    // Put the previous clipboard back after auto-paste
+    pub restore_clipboard_after_paste: bool,
+    // Wait between hiding the window and the paste keystroke (slow-focusing apps)
+    pub auto_paste_delay_ms: u32,
+    // Edit/confirm the transcript in the floating window before it is pasted
+    pub review_before_paste: bool,
+    // Pick raw or LLM text side by side before paste (when post-processing changed it)
+    pub compare_before_paste: bool,
+    // Fixed text before/after every transcript ({{date}}/{{time}} expanded)
+    pub transcript_prefix: String,
+    pub transcript_suffix: String,
+    // Linux only: how auto-paste delivers the text
+    pub linux_paste_method: LinuxPasteMethod,
     pub use_clipboard: bool,
+    // Raw vs LLM-polished text on the clipboard
+    pub clipboard_mode: ClipboardMode,
     pub floating_opacity: f32,
     pub floating_always_on_top: bool,
     pub llm_postprocess: LlmPostProcessSettings,
     // Last floating window position (screen coords after OS scale)
     pub floating_position: Option<[f32; 2]>,
+    // Floating window style (full controls or mini HUD)
+    pub floating_mode: FloatingMode,
+    // State colors of the status badge and floating window
+    pub status_colors: StatusColors,
+    // Last mini HUD position (kept apart from the full window)
+    pub floating_mini_position: Option<[f32; 2]>,
+    // Main window inner size in points (None = default) and maximized state,
+    // restored at startup
+    pub window_size: Option<[f32; 2]>,
+    pub window_maximized: bool,
     pub whisper_no_timestamps: bool,
     pub whisper_token_timestamps: bool,
     pub whisper_use_physical_cores: bool,
+    // Threads per Whisper inference (None = auto from `whisper_use_physical_cores`)
+    pub whisper_threads: Option<usize>,
     pub chunk_split_strategy: VadStrategy,
+    // Chunks transcribed concurrently (1 = sequential; each extra worker adds a Whisper state)
+    pub parallel_chunks: usize,
+    // OpenAI-compatible HTTP endpoint instead of local whisper-rs (off by default)
+    pub remote_transcription: RemoteTranscriptionSettings,
     // Auto stop (0 disables)
     pub auto_stop_silence_secs: f32, // 0 disables
     pub max_record_secs: f32,        // 0 disables
+    // What happens at `max_record_secs` (stop, continue in a new recording, or warn)
+    pub max_record_action: MaxRecordAction,
+    // Recordings with less voiced audio (VAD) skip transcription (0 disables)
+    pub min_speech_secs: f32,
+    // Drop transcripts that are only a built-in hallucination phrase ("Thanks for watching")
+    pub hallucination_filter: bool,
+    // User's own recurring artifacts (whole-transcript match)
+    pub hallucination_phrases: Vec<String>,
+    // Leave out Whisper segments whose average token probability is below this (local only)
+    pub min_segment_confidence: Option<f32>,
+    // Stop recording when this phrase is spoken (None disables)
+    pub stop_phrase: Option<String>,
+    // Peak-normalize the recording (-3 dBFS) before transcription
+    pub normalize_audio: bool,
+    // Trim leading/trailing silence (keeping a short pad) before transcription
+    pub trim_silence: bool,
+    // Keep the last recording's audio in memory for "Re-transcribe"
+    pub keep_last_recording: bool,
+    // Replace spoken "comma"/"new line"/"改行"... in the raw transcript
+    pub enable_spoken_commands: bool,
+    // Capitalize sentence starts (and English "I") when no LLM formats the text
+    pub local_autocapitalize: bool,
+    // Max edits for dictionary entries with fuzzy matching (also capped by alias length)
+    pub dictionary_fuzzy_max_distance: u32,
     // Last shown UI mode ("settings" | "floating")
     pub last_ui_mode: String,
+    // Launch with no window; only the floating window appears on the hotkey
+    pub start_hidden: bool,
+    // Bring up the floating window when recording starts
+    pub show_floating_on_record: bool,
+    // Switching to the floating window leaves the main window (Settings) open
+    pub keep_main_window_visible: bool,
     // Prompt mic permission shortly after launch (macOS)
     pub preflight_mic_on_launch: bool,
     // Whether mic preflight succeeded
@@ -105,51 +385,238 @@ pub struct Settings {
     // Status sound options
     pub sound_enabled: bool,
     pub sound_volume_percent: f32,
+    // Per-event sounds (None = bundled file)
+    pub sound_start_enabled: bool,
+    pub sound_complete_enabled: bool,
+    pub sound_error_enabled: bool,
+    pub sound_start: Option<PathBuf>,
+    pub sound_complete: Option<PathBuf>,
+    pub sound_error: Option<PathBuf>,
+    // Completion sound before/after the auto-paste, or none while auto-pasting
+    pub complete_sound_timing: SoundTiming,
+    // Read the final transcript aloud (OS text-to-speech, sound device and volume)
+    pub readback_tts: bool,
+    // History kept on disk: newest N entries, optionally only the last N days
+    pub history_max_entries: usize,
+    pub history_retention_days: Option<u32>,
+    // Off: nothing is written to the history file
+    pub history_enabled: bool,
+    // Regexes masked in history entries before they are written
+    pub history_redaction_patterns: Vec<String>,
+    // Off: UI changes stay pending until Save (Revert restores the saved settings)
+    pub settings_autosave: bool,
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
             hotkey_recording: "Ctrl+Shift+R".to_string(),
+            hotkey_mode: HotkeyMode::Toggle,
+            hotkey_debounce_ms: crate::core::DEFAULT_TOGGLE_DEBOUNCE_MS,
+            ipc_socket: false,
+            hotkey_bindings: vec![HotkeyBinding {
+                hotkey: "Ctrl+Shift+R".to_string(),
+                action: HotkeyAction::ToggleRecord,
+            }],
             whisper_model_path: PathBuf::from("models/ggml-large-v3.bin"),
+            model_download_base_url: None,
             // Default: auto-detect
             whisper_language: "auto".to_string(),
+            auto_detect_fallback: None,
+            auto_detect_min_confidence: 0.5,
+            whisper_task: WhisperTask::Transcribe,
             // UI language follows OS/env
             ui_language: "auto".to_string(),
+            theme: ThemeMode::System,
+            // Slightly larger than the OS scale for readability
+            ui_scale_percent: 110.0,
+            vsync_mode: VsyncMode::Auto,
             input_device: None,
             input_host: None,
             input_device_index_in_host: None,
+            input_device_uid: None,
             input_device_index: None,
             output_device: None,
             input_gain_percent: 100.0,
+            device_gains: HashMap::new(),
+            preroll_ms: 0,
+            input_auto_reselect: true,
             auto_paste: true,
+            output_targets: vec![OutputTarget::Clipboard, OutputTarget::AutoPaste],
+            restore_clipboard_after_paste: false,
+            auto_paste_delay_ms: 0,
+            review_before_paste: false,
+            compare_before_paste: false,
+            transcript_prefix: String::new(),
+            transcript_suffix: String::new(),
+            linux_paste_method: LinuxPasteMethod::CtrlV,
             use_clipboard: true,
+            clipboard_mode: ClipboardMode::PolishedOnly,
             floating_opacity: 1.0,
             floating_always_on_top: true,
             llm_postprocess: LlmPostProcessSettings::default(),
             floating_position: None,
+            floating_mode: FloatingMode::Full,
+            status_colors: StatusColors::default(),
+            floating_mini_position: None,
+            window_size: None,
+            window_maximized: false,
             whisper_no_timestamps: true,
             whisper_token_timestamps: false,
             whisper_use_physical_cores: true,
+            whisper_threads: None,
             // Default: aggressive VAD (earlier splits)
             chunk_split_strategy: VadStrategy::Aggressive,
+            parallel_chunks: 1,
+            remote_transcription: RemoteTranscriptionSettings::default(),
             auto_stop_silence_secs: 10.0,
             max_record_secs: 600.0,
+            max_record_action: MaxRecordAction::FinalizeAndStop,
+            min_speech_secs: 0.3,
+            hallucination_filter: true,
+            hallucination_phrases: Vec::new(),
+            min_segment_confidence: None,
+            stop_phrase: None,
+            normalize_audio: false,
+            trim_silence: false,
+            keep_last_recording: false,
+            enable_spoken_commands: false,
+            local_autocapitalize: false,
+            dictionary_fuzzy_max_distance: 1,
             // Start at Settings by default
             last_ui_mode: "settings".to_string(),
+            start_hidden: false,
+            show_floating_on_record: true,
+            keep_main_window_visible: false,
             preflight_mic_on_launch: true,
             preflight_mic_done: false,
             sound_enabled: true,
             sound_volume_percent: 100.0,
+            sound_start_enabled: true,
+            sound_complete_enabled: true,
+            sound_error_enabled: true,
+            sound_start: None,
+            sound_complete: None,
+            complete_sound_timing: SoundTiming::AfterPaste,
+            readback_tts: false,
+            sound_error: None,
+            history_max_entries: DEFAULT_HISTORY_MAX_ENTRIES,
+            history_retention_days: None,
+            history_enabled: true,
+            history_redaction_patterns: Vec::new(),
+            settings_autosave: true,
+        }
+    }
+}
+
+impl Settings {
+    /// Push theme and UI scale to egui (startup, settings changes, bundle import)
+    pub fn apply_appearance(&self, ctx: &egui::Context) {
+        self.theme.apply(ctx);
+        let percent = self
+            .ui_scale_percent
+            .clamp(*UI_SCALE_RANGE.start(), *UI_SCALE_RANGE.end());
+        ctx.set_zoom_factor(percent / 100.0);
+    }
+
+    /// Move the legacy single `hotkey_recording` into `hotkey_bindings`.
+    pub fn migrate_hotkey_bindings(&mut self) {
+        if self.hotkey_bindings.is_empty() {
+            self.hotkey_bindings.push(HotkeyBinding {
+                hotkey: self.hotkey_recording.clone(),
+                action: HotkeyAction::ToggleRecord,
+            });
+        }
+    }
+
+    /// Sync `output_targets` with the legacy `auto_paste` flag.
+    pub fn migrate_output_targets(&mut self) {
+        self.set_output_target(OutputTarget::AutoPaste, self.auto_paste);
+    }
+
+    pub fn has_output_target(&self, target: &OutputTarget) -> bool {
+        self.output_targets.contains(target)
+    }
+
+    /// Add or remove a target; `auto_paste` follows `OutputTarget::AutoPaste`
+    pub fn set_output_target(&mut self, target: OutputTarget, on: bool) {
+        if target == OutputTarget::AutoPaste {
+            self.auto_paste = on;
+        }
+        if !on {
+            self.output_targets.retain(|t| *t != target);
+        } else if !self.has_output_target(&target) {
+            self.output_targets.push(target);
+        }
+    }
+
+    /// Selected input in `device_gains`: stable ID, else name ("" = system default)
+    pub fn input_gain_key(&self) -> String {
+        self.input_device_uid
+            .clone()
+            .or_else(|| self.input_device.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn remember_input_gain(&mut self) {
+        self.device_gains
+            .insert(self.input_gain_key(), self.input_gain_percent);
+    }
+
+    /// Apply the selected input's remembered sensitivity (kept as is when unknown)
+    pub fn recall_input_gain(&mut self) {
+        if let Some(gain) = self.device_gains.get(&self.input_gain_key()) {
+            self.input_gain_percent = gain.clamp(0.0, 200.0);
         }
     }
+
+    /// Fallback for auto-detect (None when a language is selected or no fallback is set)
+    pub fn language_fallback(&self) -> Option<LanguageFallback> {
+        let language = self.auto_detect_fallback.as_ref()?;
+        (self.whisper_language == "auto").then(|| LanguageFallback {
+            language: language.clone(),
+            min_confidence: self.auto_detect_min_confidence.clamp(0.0, 1.0),
+        })
+    }
+
+    pub fn history_retention(&self) -> HistoryRetention {
+        HistoryRetention {
+            max_entries: self.history_max_entries,
+            retention_days: self.history_retention_days,
+        }
+    }
+
+    /// Push the per-event sound choices to the (process-wide) sound player
+    pub fn apply_event_sounds(&self) {
+        use crate::utils::sound::{set_event_sound, SoundEvent};
+        set_event_sound(
+            SoundEvent::Start,
+            self.sound_start_enabled,
+            self.sound_start.as_deref(),
+        );
+        set_event_sound(
+            SoundEvent::Complete,
+            self.sound_complete_enabled,
+            self.sound_complete.as_deref(),
+        );
+        set_event_sound(
+            SoundEvent::Error,
+            self.sound_error_enabled,
+            self.sound_error.as_deref(),
+        );
+    }
 }
 
 pub struct SettingsWindow {
     settings: Settings,
     original_settings: Settings, // keep original settings
     hotkey_input: String,
+    // "Press keys" recorder for the recording hotkey is listening
+    hotkey_capturing: bool,
+    hotkey_capture_error: Option<String>,
     has_unsaved_changes: bool,
+    // Auto-saved change waiting for the throttled write (see persistence.rs)
+    save_throttle: persistence::SaveThrottle,
     save_status_message: Option<String>,
     // Whisper model management
     selected_model_index: usize,
@@ -158,22 +625,42 @@ pub struct SettingsWindow {
     download_message: Arc<Mutex<Option<String>>>,
     pending_apply_model: Arc<Mutex<Option<PathBuf>>>,
     show_download_confirm: bool,
+    // Last Whisper model load failure (banner in the Speech Model section)
+    model_load_error: Option<String>,
+    // Physical memory, queried once for the model RAM warning
+    system_memory_bytes: Option<u64>,
     show_reset_confirm: bool,
     download_cancel_flag: Arc<AtomicBool>,
     current_used_model: Option<PathBuf>,
+    // Size class detected from the loaded model file (header, else name)
+    current_used_preset: Option<&'static crate::transcription::model::ModelInfo>,
     // Cached I/O device lists
     // Flattened input devices (display names)
     input_devices: Vec<String>,
+    // Core is recording from the default device because the chosen one is unplugged
+    input_device_fallback: bool,
+    input_reselect_requested: bool,
+    // "Run self-test": request for the app, running flag and last report
+    self_test_requested: bool,
+    self_test_running: bool,
+    self_test_results: Option<Vec<crate::core::SelfTestStage>>,
+    // "Benchmark": same flow, one result per downloaded model
+    benchmark_requested: bool,
+    benchmark_running: bool,
+    benchmark_results: Option<Result<Vec<crate::core::ModelBenchmark>, String>>,
     // Flattened -> (host_id, per-host index)
     input_map: Vec<(String, usize)>,
     // Flattened -> raw device name (without host)
     input_names: Vec<String>,
+    // Flattened -> stable device ID within the host
+    input_uids: Vec<String>,
     // Available host names (strings)
     input_hosts: Vec<String>,
     output_devices: Vec<String>,
     // Input level meter
     meter_stream: Option<cpal::Stream>,
     meter_level: Arc<Mutex<f32>>, // 0.0..=1.0
+    meter_peak: Arc<Mutex<audio_meter::PeakHold>>,
     meter_device_name: Option<String>,
     is_meter_active: bool, // Whether the meter is running
     // Gemma3 fixed policy (no selection index)
@@ -182,6 +669,9 @@ pub struct SettingsWindow {
     test_buffer: Option<Arc<Mutex<Vec<f32>>>>,
     test_sample_rate: u32,
     test_channels: u16,
+    test_stats: Option<Arc<StreamStats>>,
+    // Stream health of the last test recording
+    test_report: Option<StreamReport>,
     is_test_recording: bool,
     test_started_at: Option<Instant>,
     // License dialog
@@ -191,19 +681,41 @@ pub struct SettingsWindow {
     pub(crate) dict_entries: Vec<crate::dictionary::DictionaryEntry>,
     pub(crate) dict_dirty: bool,
     pub(crate) pending_apply_dictionary: bool,
+    // Ambiguous aliases / duplicate canonicals, refreshed on load and every save
+    pub(crate) dict_conflicts: Vec<crate::dictionary::DictionaryConflict>,
+    // "Apply to last transcript": core has one, include the LLM, pending request
+    last_transcript_available: bool,
+    dict_reapply_with_llm: bool,
+    dict_reapply_requested: Option<bool>,
     // Dictionary editor dialog state
     pub(crate) dict_editor_open: bool,
     pub(crate) dict_editor_edit_index: Option<usize>,
     pub(crate) dict_editor_canonical: String,
     pub(crate) dict_editor_aliases: Vec<String>,
     pub(crate) dict_editor_includes: Vec<String>,
+    pub(crate) dict_editor_fuzzy: bool,
+    pub(crate) dict_editor_locales: Vec<String>,
+    // Field to focus on the next frame (dialog open / Enter / added alias)
+    pub(crate) dict_editor_focus: Option<dictionary_tab::DictEditorFocus>,
     // Dictionary list search filter
     pub(crate) dict_filter_text: String,
+    // Result of the last CSV import/export
+    pub(crate) dict_csv_message: Option<String>,
+    // Settings bundle picked for import (waiting for confirmation)
+    config_import_pending: Option<(PathBuf, crate::utils::config::BundleInfo)>,
+    // Result of the last settings export/import
+    config_bundle_message: Option<String>,
+    // (pattern, compile error) per history redaction pattern; recompiled only when edited
+    redaction_errors: Vec<(String, Option<String>)>,
     // LLM post-processing UI state
     llm_model_options: Vec<LlmModelOption>,
     llm_fetching_models: bool,
     llm_fetch_error: Option<String>,
+    // Automatic model list retry: (failed attempts, next attempt due)
+    llm_model_poll: Option<(u32, Instant)>,
     llm_test_in_progress: bool,
+    llm_test_started_at: Option<Instant>,
+    llm_test_id: u64,
     llm_test_message: Option<String>,
     llm_test_error: Option<String>,
     llm_async_tx: mpsc::Sender<LlmUiMessage>,
@@ -211,10 +723,20 @@ pub struct SettingsWindow {
     llm_custom_error: Option<String>,
     llm_mode_loaded_id: Option<String>,
     llm_prompt_test: LlmPromptTestState,
+    // Prompt preview dialog (None = closed); holds the sample transcript
+    llm_prompt_preview: Option<String>,
     llm_history_entries: Vec<LlmHistoryEntry>,
     llm_history_error: Option<String>,
     llm_history_last_modified: Option<SystemTime>,
     llm_history_selected: Option<usize>,
+    llm_history_confirm_clear: bool,
+    llm_history_filter_text: String,
+    llm_history_date_filter: HistoryDateFilter,
+    // "Reprocess" of a history entry (selected_entry = entry index) and its mode
+    llm_history_rerun: LlmPromptTestState,
+    llm_history_rerun_mode: String,
+    // Raw vs. LLM output diff of the shown entry (timestamp, spans)
+    llm_history_diff: Option<(String, Vec<DiffSpan>)>,
     // Update check state (GitHub Releases)
     update_state: Arc<Mutex<UpdateState>>,
     update_downloading: Arc<Mutex<bool>>,
@@ -227,13 +749,18 @@ pub struct SettingsWindow {
 
 impl SettingsWindow {
     pub fn new() -> Self {
-        let settings = Self::load_settings().unwrap_or_default();
+        let mut settings = Self::load_settings().unwrap_or_default();
+        settings.migrate_hotkey_bindings();
+        settings.migrate_output_targets();
         let (llm_async_tx, llm_async_rx) = mpsc::channel();
         let mut this = Self {
             hotkey_input: settings.hotkey_recording.clone(),
+            hotkey_capturing: false,
+            hotkey_capture_error: None,
             original_settings: settings.clone(),
             settings,
             has_unsaved_changes: false,
+            save_throttle: Default::default(),
             save_status_message: None,
             selected_model_index: 4, // large-v3 (default)
             download_progress: Arc::new(Mutex::new(None)),
@@ -241,16 +768,29 @@ impl SettingsWindow {
             download_message: Arc::new(Mutex::new(None)),
             pending_apply_model: Arc::new(Mutex::new(None)),
             show_download_confirm: false,
+            model_load_error: None,
+            system_memory_bytes: crate::utils::memory::total_memory_bytes(),
             show_reset_confirm: false,
             download_cancel_flag: Arc::new(AtomicBool::new(false)),
             current_used_model: None,
+            current_used_preset: None,
             input_devices: Vec::new(),
+            input_device_fallback: false,
+            input_reselect_requested: false,
+            self_test_requested: false,
+            self_test_running: false,
+            self_test_results: None,
+            benchmark_requested: false,
+            benchmark_running: false,
+            benchmark_results: None,
             input_map: Vec::new(),
             input_names: Vec::new(),
+            input_uids: Vec::new(),
             input_hosts: Vec::new(),
             output_devices: Vec::new(),
             meter_stream: None,
             meter_level: Arc::new(Mutex::new(0.0)),
+            meter_peak: Arc::new(Mutex::new(audio_meter::PeakHold::default())),
             meter_device_name: None,
             is_meter_active: false,
             // Initialize test recording
@@ -258,6 +798,8 @@ impl SettingsWindow {
             test_buffer: None,
             test_sample_rate: 16_000,
             test_channels: 1,
+            test_stats: None,
+            test_report: None,
             is_test_recording: false,
             test_started_at: None,
             show_licenses: false,
@@ -265,16 +807,30 @@ impl SettingsWindow {
             dict_entries: Vec::new(),
             dict_dirty: false,
             pending_apply_dictionary: false,
+            dict_conflicts: Vec::new(),
+            last_transcript_available: false,
+            dict_reapply_with_llm: false,
+            dict_reapply_requested: None,
             dict_editor_open: false,
             dict_editor_edit_index: None,
             dict_editor_canonical: String::new(),
             dict_editor_aliases: Vec::new(),
             dict_editor_includes: Vec::new(),
+            dict_editor_fuzzy: false,
+            dict_editor_locales: Vec::new(),
+            dict_editor_focus: None,
             dict_filter_text: String::new(),
+            dict_csv_message: None,
+            config_import_pending: None,
+            config_bundle_message: None,
+            redaction_errors: Vec::new(),
             llm_model_options: Vec::new(),
             llm_fetching_models: false,
             llm_fetch_error: None,
+            llm_model_poll: None,
             llm_test_in_progress: false,
+            llm_test_started_at: None,
+            llm_test_id: 0,
             llm_test_message: None,
             llm_test_error: None,
             llm_async_tx,
@@ -282,10 +838,17 @@ impl SettingsWindow {
             llm_custom_error: None,
             llm_mode_loaded_id: None,
             llm_prompt_test: LlmPromptTestState::default(),
+            llm_prompt_preview: None,
             llm_history_entries: Vec::new(),
             llm_history_error: None,
             llm_history_last_modified: None,
             llm_history_selected: None,
+            llm_history_filter_text: String::new(),
+            llm_history_date_filter: HistoryDateFilter::All,
+            llm_history_rerun: LlmPromptTestState::default(),
+            llm_history_rerun_mode: String::new(),
+            llm_history_diff: None,
+            llm_history_confirm_clear: false,
             update_state: Arc::new(Mutex::new(UpdateState::Checking)),
             update_downloading: Arc::new(Mutex::new(false)),
             update_progress: Arc::new(Mutex::new(None)),
@@ -297,6 +860,7 @@ impl SettingsWindow {
 
         crate::utils::sound::set_enabled(this.settings.sound_enabled);
         crate::utils::sound::set_volume_percent(this.settings.sound_volume_percent);
+        this.settings.apply_event_sounds();
 
         // Infer preset from the current model filename
         if let Some(name) = this
@@ -326,6 +890,7 @@ impl SettingsWindow {
                 this.dict_dirty = false;
             }
         }
+        this.refresh_dict_conflicts();
         this.settings.llm_postprocess.ensure_mode_valid();
         this.sync_llm_custom_editor();
         // Initialize UI language (switch Fluent based on the setting)
@@ -339,19 +904,24 @@ impl SettingsWindow {
     fn poll_llm_messages(&mut self) {
         loop {
             match self.llm_async_rx.try_recv() {
-                Ok(LlmUiMessage::TestResult(result)) => {
+                Ok(LlmUiMessage::TestResult(id, result)) => {
+                    if id != self.llm_test_id {
+                        continue;
+                    }
                     self.llm_test_in_progress = false;
+                    self.llm_test_started_at = None;
                     match result {
                         Ok(outcome) => {
                             let seconds = outcome.duration_ms as f32 / 1000.0;
                             self.llm_test_message = Some(format!(
-                                "{} (status: {}, {:.2}s)",
+                                "{} (status: {}, {:.2}s)\n{}",
                                 outcome.message,
                                 outcome
                                     .status
                                     .map(|s| s.to_string())
                                     .unwrap_or_else(|| "n/a".to_string()),
-                                seconds
+                                seconds,
+                                outcome.url
                             ));
                             self.llm_test_error = None;
                         }
@@ -373,9 +943,16 @@ impl SettingsWindow {
                                 })
                                 .collect();
                             self.llm_fetch_error = None;
+                            if self.llm_model_options.is_empty() {
+                                // Server up but no model loaded yet: back off like a failure
+                                self.back_off_llm_model_poll();
+                            } else {
+                                self.llm_model_poll = None;
+                            }
                         }
                         Err(err) => {
                             self.llm_fetch_error = Some(err);
+                            self.back_off_llm_model_poll();
                         }
                     }
                 }
@@ -386,7 +963,7 @@ impl SettingsWindow {
                             self.llm_prompt_test.output = Some(outcome.content);
                             self.llm_prompt_test.latency_ms = Some(outcome.latency_ms);
                             self.llm_prompt_test.truncated_input = outcome.truncated_input;
-                            self.llm_prompt_test.error = None;
+                            self.llm_prompt_test.error = outcome.warning;
                         }
                         Err(err) => {
                             self.llm_prompt_test.error = Some(err);
@@ -396,6 +973,22 @@ impl SettingsWindow {
                         }
                     }
                 }
+                Ok(LlmUiMessage::HistoryRerun(index, result)) => {
+                    // Dropped once another entry was re-run
+                    if self.llm_history_rerun.selected_entry != Some(index) {
+                        continue;
+                    }
+                    self.llm_history_rerun.in_progress = false;
+                    match result {
+                        Ok(outcome) => {
+                            self.llm_history_rerun.output = Some(outcome.content);
+                            self.llm_history_rerun.latency_ms = Some(outcome.latency_ms);
+                            self.llm_history_rerun.truncated_input = outcome.truncated_input;
+                            self.llm_history_rerun.error = outcome.warning;
+                        }
+                        Err(err) => self.llm_history_rerun.error = Some(err),
+                    }
+                }
                 Err(std::sync::mpsc::TryRecvError::Empty) => break,
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => break,
             }
@@ -403,7 +996,7 @@ impl SettingsWindow {
     }
 
     fn reload_llm_history(&mut self) {
-        match load_history_entries() {
+        match load_history_entries(self.settings.history_retention()) {
             Ok(entries) => {
                 self.llm_history_entries = entries;
                 self.llm_history_error = None;
@@ -419,6 +1012,13 @@ impl SettingsWindow {
         }
         self.llm_history_last_modified = history_modified_time();
         let len = self.llm_history_entries.len();
+        if self
+            .llm_history_rerun
+            .selected_entry
+            .is_some_and(|i| i >= len)
+        {
+            self.llm_history_rerun = LlmPromptTestState::default();
+        }
         if len == 0 {
             self.llm_history_selected = None;
             self.llm_prompt_test.selected_entry = None;
@@ -494,9 +1094,7 @@ impl SettingsWindow {
     #[cfg(target_os = "macos")]
     pub fn mark_mic_preflight_done(&mut self) {
         if !self.settings.preflight_mic_done {
-            self.settings.preflight_mic_done = true;
-            self.save_settings();
-            self.original_settings = self.settings.clone();
+            self.persist_now(|s| s.preflight_mic_done = true);
         }
     }
 
@@ -510,6 +1108,24 @@ impl SettingsWindow {
         }
     }
 
+    // One-shot "apply to last transcript" request; Some(with_llm)
+    pub fn take_dictionary_reapply_request(&mut self) -> Option<bool> {
+        self.dict_reapply_requested.take()
+    }
+
+    // Status line once the core took (or, while busy, refused) the reapply request
+    pub fn set_dictionary_reapply_accepted(&mut self, accepted: bool) {
+        self.save_status_message = Some(i18n::tr(if accepted {
+            "msg-dict-reapplied"
+        } else {
+            "msg-dict-reapply-busy"
+        }));
+    }
+
+    pub fn set_last_transcript_available(&mut self, available: bool) {
+        self.last_transcript_available = available;
+    }
+
     // Log callback integration and LLM test feature removed
 
     // ui_hotkey_section moved to hotkey.rs
@@ -549,6 +1165,8 @@ impl SettingsWindow {
                     }
                     SettingsTab::Model => {
                         self.ui_speech_model_section(ui);
+                        self.ui_self_test_button(ui);
+                        self.ui_benchmark_button(ui);
                     }
                     SettingsTab::Behavior => {
                         self.ui_appearance_section(ui);
@@ -587,6 +1205,8 @@ impl SettingsWindow {
                         });
                 });
         }
+        self.ui_self_test_window(ui.ctx());
+        self.ui_benchmark_window(ui.ctx());
         return;
 
         #[allow(unreachable_code)]
@@ -710,12 +1330,14 @@ impl SettingsWindow {
                                     }
                                     if let Some((h, oi, oname)) = chosen {
                                         if let Some(i) = oi {
+                                            self.settings.input_device_uid = self.input_uid_for(&h, i);
                                             self.settings.input_host = Some(h);
                                             self.settings.input_device_index_in_host = Some(i);
                                             self.settings.input_device = oname; // 表示名を暫定保存
                                         } else {
                                             self.settings.input_host = None;
                                             self.settings.input_device_index_in_host = None;
+                                            self.settings.input_device_uid = None;
                                             self.settings.input_device = None;
                                         }
                                         self.check_changes();
@@ -864,67 +1486,16 @@ impl SettingsWindow {
                         ui.add_space(6.0);
                         ui.horizontal(|ui| {
                             ui.label(i18n::tr("label-language"));
-                            let display = match self.settings.whisper_language.as_str() {
-                                "auto" => i18n::tr("option-auto-detect"),
-                                "ja" => i18n::tr("option-japanese-ja"),
-                                "en" => i18n::tr("option-english-en"),
-                                "zh" => i18n::tr("option-chinese-zh"),
-                                "es" => i18n::tr("option-spanish-es"),
-                                "fr" => i18n::tr("option-french-fr"),
-                                "de" => i18n::tr("option-german-de"),
-                                "ko" => i18n::tr("option-korean-ko"),
-                                "pt" => i18n::tr("option-portuguese-pt"),
-                                "ru" => i18n::tr("option-russian-ru"),
-                                "hi" => i18n::tr("option-hindi-hi"),
-                                _ => self.settings.whisper_language.clone(),
-                            };
                             let mut changed = false;
                             egui::ComboBox::from_id_salt("whisper_lang_combo")
-                                .selected_text(display)
+                                .selected_text(whisper_language_label(&self.settings.whisper_language))
                                 .show_ui(ui, |ui| {
-                                    if ui.selectable_label(self.settings.whisper_language == "auto", i18n::tr("option-auto-detect")).clicked() {
-                                        self.settings.whisper_language = "auto".to_string();
-                                        changed = true;
-                                    }
-                                    if ui.selectable_label(self.settings.whisper_language == "ja", i18n::tr("option-japanese-ja")).clicked() {
-                                        self.settings.whisper_language = "ja".to_string();
-                                        changed = true;
-                                    }
-                                    if ui.selectable_label(self.settings.whisper_language == "en", i18n::tr("option-english-en")).clicked() {
-                                        self.settings.whisper_language = "en".to_string();
-                                        changed = true;
-                                    }
-                                    if ui.selectable_label(self.settings.whisper_language == "zh", i18n::tr("option-chinese-zh")).clicked() {
-                                        self.settings.whisper_language = "zh".to_string();
-                                        changed = true;
-                                    }
-                                    if ui.selectable_label(self.settings.whisper_language == "es", i18n::tr("option-spanish-es")).clicked() {
-                                        self.settings.whisper_language = "es".to_string();
-                                        changed = true;
-                                    }
-                                    if ui.selectable_label(self.settings.whisper_language == "fr", i18n::tr("option-french-fr")).clicked() {
-                                        self.settings.whisper_language = "fr".to_string();
-                                        changed = true;
-                                    }
-                                    if ui.selectable_label(self.settings.whisper_language == "de", i18n::tr("option-german-de")).clicked() {
-                                        self.settings.whisper_language = "de".to_string();
-                                        changed = true;
-                                    }
-                                    if ui.selectable_label(self.settings.whisper_language == "ko", i18n::tr("option-korean-ko")).clicked() {
-                                        self.settings.whisper_language = "ko".to_string();
-                                        changed = true;
-                                    }
-                                    if ui.selectable_label(self.settings.whisper_language == "pt", i18n::tr("option-portuguese-pt")).clicked() {
-                                        self.settings.whisper_language = "pt".to_string();
-                                        changed = true;
-                                    }
-                                    if ui.selectable_label(self.settings.whisper_language == "ru", i18n::tr("option-russian-ru")).clicked() {
-                                        self.settings.whisper_language = "ru".to_string();
-                                        changed = true;
-                                    }
-                                    if ui.selectable_label(self.settings.whisper_language == "hi", i18n::tr("option-hindi-hi")).clicked() {
-                                        self.settings.whisper_language = "hi".to_string();
-                                        changed = true;
+                                    let codes = std::iter::once("auto").chain(WHISPER_LANGUAGES.iter().map(|(code, _)| *code));
+                                    for code in codes {
+                                        if ui.selectable_label(self.settings.whisper_language == code, whisper_language_label(code)).clicked() {
+                                            self.settings.whisper_language = code.to_string();
+                                            changed = true;
+                                        }
                                     }
                                 });
                             if changed { self.check_changes(); }
@@ -977,6 +1548,7 @@ impl SettingsWindow {
                             let display = match self.settings.chunk_split_strategy {
                                 VadStrategy::Normal => "Normal".to_string(),
                                 VadStrategy::Aggressive => "Aggressive (earlier splits)".to_string(),
+                                VadStrategy::Custom { .. } => "Custom".to_string(),
                             };
                             let mut changed = false;
                             egui::ComboBox::from_id_salt("chunk_split_strategy_combo")
@@ -1124,12 +1696,167 @@ impl SettingsWindow {
                     }
                 });
                 ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("label-theme"));
+                    let label = |m: ThemeMode| match m {
+                        ThemeMode::System => i18n::tr("option-theme-system"),
+                        ThemeMode::Light => i18n::tr("option-theme-light"),
+                        ThemeMode::Dark => i18n::tr("option-theme-dark"),
+                    };
+                    let before = self.settings.theme;
+                    egui::ComboBox::from_id_salt("theme_combo")
+                        .selected_text(label(before))
+                        .show_ui(ui, |ui| {
+                            for m in [ThemeMode::System, ThemeMode::Light, ThemeMode::Dark] {
+                                ui.selectable_value(&mut self.settings.theme, m, label(m));
+                            }
+                        });
+                    if self.settings.theme != before {
+                        self.settings.apply_appearance(ui.ctx());
+                        self.check_changes();
+                    }
+                });
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("label-ui-scale"));
+                    let resp = ui.add(
+                        egui::Slider::new(&mut self.settings.ui_scale_percent, UI_SCALE_RANGE)
+                            .step_by(5.0)
+                            .clamping(egui::SliderClamping::Always)
+                            .suffix("%"),
+                    );
+                    if resp.changed() {
+                        self.settings.apply_appearance(ui.ctx());
+                        self.check_changes();
+                    }
+                    if ui.small_button(i18n::tr("btn-ui-scale-reset")).clicked() {
+                        self.settings.ui_scale_percent = Settings::default().ui_scale_percent;
+                        self.settings.apply_appearance(ui.ctx());
+                        self.check_changes();
+                    }
+                });
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("label-vsync"));
+                    let before = self.settings.vsync_mode;
+                    egui::ComboBox::from_id_salt("vsync_mode_combo")
+                        .selected_text(i18n::tr(before.label_key()))
+                        .show_ui(ui, |ui| {
+                            for m in VsyncMode::ALL {
+                                ui.selectable_value(
+                                    &mut self.settings.vsync_mode,
+                                    m,
+                                    i18n::tr(m.label_key()),
+                                );
+                            }
+                        })
+                        .response
+                        .on_hover_text(i18n::tr("tooltip-vsync"));
+                    if self.settings.vsync_mode != before {
+                        self.check_changes();
+                    }
+                });
+                ui.label(
+                    egui::RichText::new(i18n::tr("note-vsync-restart"))
+                        .small()
+                        .color(ui.visuals().weak_text_color()),
+                );
+                ui.add_space(6.0);
+                // フローティングウィンドウの表示形式
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("label-floating-style"));
+                    let label = |m: FloatingMode| match m {
+                        FloatingMode::Full => i18n::tr("option-floating-full"),
+                        FloatingMode::Mini => i18n::tr("option-floating-mini"),
+                    };
+                    let before = self.settings.floating_mode;
+                    egui::ComboBox::from_id_salt("floating_mode_combo")
+                        .selected_text(label(before))
+                        .show_ui(ui, |ui| {
+                            for m in [FloatingMode::Full, FloatingMode::Mini] {
+                                ui.selectable_value(&mut self.settings.floating_mode, m, label(m));
+                            }
+                        })
+                        .response
+                        .on_hover_text(i18n::tr("tooltip-floating-style"));
+                    if self.settings.floating_mode != before {
+                        self.check_changes();
+                    }
+                });
+                ui.add_space(6.0);
+                if ui
+                    .checkbox(
+                        &mut self.settings.show_floating_on_record,
+                        i18n::tr("chk-show-floating-on-record"),
+                    )
+                    .on_hover_text(i18n::tr("tooltip-show-floating-on-record"))
+                    .changed()
+                {
+                    self.check_changes();
+                }
+                if ui
+                    .checkbox(
+                        &mut self.settings.start_hidden,
+                        i18n::tr("label-start-hidden"),
+                    )
+                    .on_hover_text(i18n::tr("tooltip-start-hidden"))
+                    .changed()
+                {
+                    self.check_changes();
+                }
+                if ui
+                    .checkbox(
+                        &mut self.settings.keep_main_window_visible,
+                        i18n::tr("label-keep-main-window"),
+                    )
+                    .on_hover_text(i18n::tr("tooltip-keep-main-window"))
+                    .changed()
+                {
+                    self.check_changes();
+                }
+                ui.add_space(6.0);
+                self.ui_status_colors(ui);
+                ui.add_space(6.0);
+                self.ui_output_targets(ui);
+                ui.indent("auto_paste_options_indent", |ui| {
+                    ui.add_enabled_ui(self.settings.auto_paste, |ui| {
+                        if ui
+                            .checkbox(
+                                &mut self.settings.restore_clipboard_after_paste,
+                                i18n::tr("chk-restore-clipboard"),
+                            )
+                            .on_hover_text(i18n::tr("tooltip-restore-clipboard"))
+                            .changed()
+                        {
+                            self.check_changes();
+                        }
+                        if cfg!(target_os = "linux") {
+                            self.ui_linux_paste_method(ui);
+                        }
+                    });
+                });
+                if ui
+                    .checkbox(
+                        &mut self.settings.review_before_paste,
+                        i18n::tr("chk-review-before-paste"),
+                    )
+                    .on_hover_text(i18n::tr("tooltip-review-before-paste"))
+                    .changed()
+                {
+                    self.check_changes();
+                }
                 if ui
-                    .checkbox(&mut self.settings.auto_paste, i18n::tr("label-auto-paste"))
+                    .checkbox(
+                        &mut self.settings.compare_before_paste,
+                        i18n::tr("chk-compare-before-paste"),
+                    )
+                    .on_hover_text(i18n::tr("tooltip-compare-before-paste"))
                     .changed()
                 {
                     self.check_changes();
                 }
+                ui.add_space(6.0);
+                self.ui_transcript_boilerplate(ui);
             });
 
         // Auto‑paste troubleshooting (collapsible)
@@ -1159,6 +1886,13 @@ impl SettingsWindow {
                 });
         }
 
+        // Settings export/import (portable bundle)
+        ui.add_space(10.0);
+        self.ui_config_bundle(ui);
+
+        ui.add_space(10.0);
+        self.ui_settings_autosave(ui);
+
         // Show “Reset all to defaults” at the end of the General tab
         ui.add_space(10.0);
         egui::Frame::default()
@@ -1409,8 +2143,39 @@ impl SettingsWindow {
             }
             if self.llm_test_in_progress {
                 ui.spinner();
+                if let Some(started) = self.llm_test_started_at {
+                    ui.label(format!(
+                        "{:.1}s / {}s",
+                        started.elapsed().as_secs_f32(),
+                        self.settings.llm_postprocess.models_timeout_secs
+                    ));
+                    ui.ctx()
+                        .request_repaint_after(std::time::Duration::from_millis(100));
+                }
+                if ui.button(i18n::tr("btn-llm-test-cancel")).clicked() {
+                    self.cancel_llm_connection_test();
+                }
             }
         });
+        self.poll_llm_model_list(ui.ctx());
+        // Only after a failed attempt; the first fetch shows the usual spinner
+        if let Some((_, next)) = self.llm_model_poll.filter(|(attempts, _)| *attempts > 0) {
+            let wait = next.saturating_duration_since(Instant::now()).as_secs();
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(i18n::tr("msg-llm-waiting-server"))
+                        .small()
+                        .color(ui.visuals().weak_text_color()),
+                );
+                if !self.llm_fetching_models {
+                    ui.label(
+                        egui::RichText::new(format!("({}s)", wait + 1))
+                            .small()
+                            .color(ui.visuals().weak_text_color()),
+                    );
+                }
+            });
+        }
         if let Some(err) = &self.llm_fetch_error {
             ui.colored_label(egui::Color32::YELLOW, err);
         } else if self.llm_model_options.is_empty() && !self.llm_fetching_models {
@@ -1427,13 +2192,8 @@ impl SettingsWindow {
         let original_mode_id = mode_id.clone();
         let mut mode_changed = false;
         let mut new_custom_requested = false;
-        let mut mode_options = vec![
-            (PRESET_ID_FORMAT.to_string(), i18n::tr("llm-mode-format")),
-            (PRESET_ID_SUMMARY.to_string(), i18n::tr("llm-mode-summary")),
-        ];
-        for custom in &self.settings.llm_postprocess.custom_prompts {
-            mode_options.push((custom.id.clone(), custom.name.clone()));
-        }
+        let mut new_chain_requested = false;
+        let mut mode_options = self.llm_mode_options();
         mode_options.push((
             MODE_ID_CUSTOM_DRAFT.to_string(),
             i18n::tr("llm-mode-custom-add"),
@@ -1467,6 +2227,10 @@ impl SettingsWindow {
                         }
                     }
                 });
+            new_chain_requested = ui
+                .button(i18n::tr("btn-llm-new-chain"))
+                .on_hover_text(i18n::tr("tooltip-llm-new-chain"))
+                .clicked();
             if self.settings.llm_postprocess.custom_prompts.is_empty() {
                 ui.label(
                     egui::RichText::new(i18n::tr("llm-mode-custom-empty-hint"))
@@ -1494,9 +2258,24 @@ impl SettingsWindow {
         } else {
             self.sync_llm_custom_editor();
         }
+        if new_chain_requested {
+            self.llm_custom_error = None;
+            mode_id = self.create_llm_chain();
+            self.sync_llm_custom_editor();
+        }
 
-        let is_custom_mode = !is_builtin_mode_id(&mode_id);
-        if !is_custom_mode {
+        if mode_id == PRESET_ID_SUMMARY {
+            self.ui_llm_summary_markdown(ui);
+        }
+
+        let is_chain_mode = self.settings.llm_postprocess.chain_mode(&mode_id).is_some();
+        let is_custom_mode = !is_builtin_mode_id(&mode_id) && !is_chain_mode;
+        if !is_chain_mode && mode_id != MODE_ID_CUSTOM_DRAFT {
+            self.ui_llm_mode_model(ui, &mode_id);
+        }
+        if is_chain_mode {
+            self.ui_llm_chain_editor(ui, &mode_id);
+        } else if !is_custom_mode {
             let locales = self
                 .settings
                 .llm_postprocess
@@ -1676,6 +2455,15 @@ impl SettingsWindow {
                                 system_trimmed.as_str(),
                                 user_trimmed.as_str(),
                             );
+                            let model = self
+                                .settings
+                                .llm_postprocess
+                                .mode_model(&mode_id)
+                                .unwrap_or_default()
+                                .to_string();
+                            self.settings
+                                .llm_postprocess
+                                .set_mode_model(&new_id, &model);
                             self.llm_custom_error = None;
                             self.llm_mode_loaded_id = Some(new_id.clone());
                             self.settings.llm_postprocess.mode_id = new_id;
@@ -1689,6 +2477,16 @@ impl SettingsWindow {
             self.llm_custom_error = None;
         }
 
+        ui.add_space(6.0);
+        if ui
+            .button(i18n::tr("btn-llm-preview-prompt"))
+            .on_hover_text(i18n::tr("tooltip-llm-preview-prompt"))
+            .clicked()
+        {
+            self.open_llm_prompt_preview();
+        }
+        self.ui_llm_prompt_preview_window(ui);
+
         ui.add_space(6.0);
         let mut max_chars = self.settings.llm_postprocess.max_input_chars as i32;
         let mut max_changed = false;
@@ -1705,6 +2503,65 @@ impl SettingsWindow {
             self.settings.llm_postprocess.max_input_chars = max_chars.clamp(500, 8000) as usize;
             self.check_changes();
         }
+        self.ui_llm_oversize_input(ui);
+
+        ui.add_space(4.0);
+        let mut limit_output = self.settings.llm_postprocess.max_output_tokens.is_some();
+        let mut max_tokens = self
+            .settings
+            .llm_postprocess
+            .effective_max_output_tokens()
+            .unwrap_or(DEFAULT_MAX_OUTPUT_TOKENS);
+        let mut tokens_changed = false;
+        ui.horizontal(|ui| {
+            if ui
+                .checkbox(&mut limit_output, i18n::tr("label-llm-max-output"))
+                .changed()
+            {
+                tokens_changed = true;
+            }
+            ui.add_enabled_ui(limit_output, |ui| {
+                if ui
+                    .add(
+                        egui::Slider::new(
+                            &mut max_tokens,
+                            MIN_MAX_OUTPUT_TOKENS..=MAX_MAX_OUTPUT_TOKENS,
+                        )
+                        .logarithmic(true)
+                        .show_value(true),
+                    )
+                    .changed()
+                {
+                    tokens_changed = true;
+                }
+            });
+        });
+        if !limit_output {
+            ui.small(i18n::tr("note-llm-max-output-unlimited"));
+        }
+        if tokens_changed {
+            self.settings.llm_postprocess.max_output_tokens = limit_output
+                .then(|| max_tokens.clamp(MIN_MAX_OUTPUT_TOKENS, MAX_MAX_OUTPUT_TOKENS));
+            self.check_changes();
+        }
+
+        ui.add_space(4.0);
+        let mut temperature = self.settings.llm_postprocess.effective_temperature();
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-llm-temperature"));
+            if ui
+                .add(
+                    egui::Slider::new(&mut temperature, 0.0..=MAX_TEMPERATURE)
+                        .step_by(0.05)
+                        .fixed_decimals(2),
+                )
+                .on_hover_text(i18n::tr("tooltip-llm-temperature"))
+                .changed()
+            {
+                self.settings.llm_postprocess.temperature = temperature.clamp(0.0, MAX_TEMPERATURE);
+                self.check_changes();
+            }
+        });
 
         ui.add_space(4.0);
         let mut timeout = self.settings.llm_postprocess.timeout_secs as i32;
@@ -1723,21 +2580,202 @@ impl SettingsWindow {
             self.settings.llm_postprocess.timeout_secs = timeout.clamp(3, 60) as u64;
             self.check_changes();
         }
-
-        ui.add_space(4.0);
-        let mut apply_autopaste = self.settings.llm_postprocess.apply_to_autopaste;
-        if ui
-            .checkbox(&mut apply_autopaste, i18n::tr("label-llm-apply-autopaste"))
+        let mut models_timeout = self.settings.llm_postprocess.models_timeout_secs as i32;
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-llm-models-timeout"));
+            if ui
+                .add(egui::Slider::new(&mut models_timeout, 3..=30).show_value(true))
+                .on_hover_text(i18n::tr("tooltip-llm-models-timeout"))
+                .changed()
+            {
+                self.settings.llm_postprocess.models_timeout_secs =
+                    models_timeout.clamp(3, 30) as u64;
+                self.check_changes();
+            }
+            ui.small(i18n::tr("note-llm-timeout-unit"));
+        });
+        let mut poll_models = self.settings.llm_postprocess.poll_models;
+        if ui
+            .checkbox(&mut poll_models, i18n::tr("label-llm-poll-models"))
+            .on_hover_text(i18n::tr("tooltip-llm-poll-models"))
+            .changed()
+        {
+            self.settings.llm_postprocess.poll_models = poll_models;
+            if !poll_models {
+                self.llm_model_poll = None;
+            }
+            self.check_changes();
+        }
+
+        ui.add_space(4.0);
+        let mut apply_autopaste = self.settings.llm_postprocess.apply_to_autopaste;
+        if ui
+            .checkbox(&mut apply_autopaste, i18n::tr("label-llm-apply-autopaste"))
             .changed()
         {
             self.settings.llm_postprocess.apply_to_autopaste = apply_autopaste;
             self.check_changes();
         }
 
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-clipboard-mode"));
+            let label_for = |mode: ClipboardMode| match mode {
+                ClipboardMode::PolishedOnly => i18n::tr("option-clipboard-polished"),
+                ClipboardMode::RawOnly => i18n::tr("option-clipboard-raw"),
+                ClipboardMode::RawThenPolished => i18n::tr("option-clipboard-raw-then-polished"),
+                ClipboardMode::BothWithSeparator => i18n::tr("option-clipboard-both"),
+            };
+            let mut selected = self.settings.clipboard_mode;
+            egui::ComboBox::from_id_salt("clipboard_mode_combo")
+                .selected_text(label_for(selected))
+                .show_ui(ui, |ui| {
+                    for mode in [
+                        ClipboardMode::PolishedOnly,
+                        ClipboardMode::RawOnly,
+                        ClipboardMode::RawThenPolished,
+                        ClipboardMode::BothWithSeparator,
+                    ] {
+                        ui.selectable_value(&mut selected, mode, label_for(mode));
+                    }
+                });
+            if selected != self.settings.clipboard_mode {
+                self.settings.clipboard_mode = selected;
+                self.check_changes();
+            }
+        });
+
         ui.add_space(10.0);
         self.ui_llm_prompt_test_section(ui);
     }
 
+    // Start from the latest transcript in history, or a sample when there is none
+    fn open_llm_prompt_preview(&mut self) {
+        self.refresh_llm_history_if_needed();
+        let transcript = self
+            .llm_history_entries
+            .last()
+            .map(|e| e.transcript.clone())
+            .unwrap_or_else(|| i18n::tr("llm-preview-sample-transcript"));
+        self.llm_prompt_preview = Some(transcript);
+    }
+
+    // Summary preset output options (Markdown list clean-up)
+    fn ui_llm_summary_markdown(&mut self, ui: &mut egui::Ui) {
+        let llm = &mut self.settings.llm_postprocess;
+        let mut changed = ui
+            .checkbox(
+                &mut llm.summary_markdown,
+                i18n::tr("label-llm-summary-markdown"),
+            )
+            .on_hover_text(i18n::tr("tooltip-llm-summary-markdown"))
+            .changed();
+        ui.add_enabled_ui(llm.summary_markdown, |ui| {
+            ui.indent("llm_summary_markdown_indent", |ui| {
+                changed |= ui
+                    .checkbox(
+                        &mut llm.summary_markdown_heading,
+                        i18n::tr("label-llm-summary-heading"),
+                    )
+                    .changed();
+            });
+        });
+        if changed {
+            self.check_changes();
+        }
+    }
+
+    fn ui_llm_prompt_preview_window(&mut self, ui: &mut egui::Ui) {
+        if self.llm_prompt_preview.is_none() {
+            return;
+        }
+        let dictionary = crate::dictionary::llm_prompt_hint(&self.dict_entries);
+        let language_hint = self.llm_language_hint();
+        let Some(transcript) = self.llm_prompt_preview.as_mut() else {
+            return;
+        };
+        let mut open = true;
+        egui::Window::new(i18n::tr("title-llm-preview-prompt"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(520.0)
+            .show(ui.ctx(), |ui_win| {
+                ui_win.label(i18n::tr("label-llm-test-transcript"));
+                ui_win.add(
+                    egui::TextEdit::multiline(transcript)
+                        .desired_rows(4)
+                        .desired_width(f32::INFINITY),
+                );
+                let preview = preview_prompt(
+                    &self.settings.llm_postprocess,
+                    transcript,
+                    dictionary.as_deref(),
+                    language_hint.as_deref(),
+                );
+                if preview.truncated_input {
+                    ui_win.colored_label(
+                        ui_win.visuals().warn_fg_color,
+                        i18n::tr("label-llm-test-truncated"),
+                    );
+                }
+                if preview.transcript_appended {
+                    ui_win.colored_label(
+                        ui_win.visuals().warn_fg_color,
+                        i18n::tr("msg-llm-preview-transcript-appended"),
+                    );
+                }
+                ui_win.add_space(6.0);
+                egui::ScrollArea::vertical()
+                    .id_salt("llm_prompt_preview_scroll")
+                    .max_height(420.0)
+                    .show(ui_win, |ui_s| {
+                        ui_s.label(i18n::tr("label-llm-system"));
+                        let mut system_display = preview
+                            .system
+                            .clone()
+                            .unwrap_or_else(|| i18n::tr("label-llm-preview-no-system"));
+                        ui_s.add(
+                            egui::TextEdit::multiline(&mut system_display)
+                                .desired_rows(3)
+                                .desired_width(f32::INFINITY)
+                                .interactive(false),
+                        );
+                        ui_s.add_space(4.0);
+                        ui_s.label(i18n::tr("label-llm-user"));
+                        let mut user_display = preview.user.clone();
+                        ui_s.add(
+                            egui::TextEdit::multiline(&mut user_display)
+                                .desired_rows(6)
+                                .desired_width(f32::INFINITY)
+                                .interactive(false),
+                        );
+                        ui_s.add_space(4.0);
+                        egui::CollapsingHeader::new(i18n::tr("label-llm-preview-payload"))
+                            .id_salt("llm_prompt_preview_payload")
+                            .show(ui_s, |ui_c| {
+                                let mut json = preview.payload_json.clone();
+                                ui_c.add(
+                                    egui::TextEdit::multiline(&mut json)
+                                        .code_editor()
+                                        .desired_rows(8)
+                                        .desired_width(f32::INFINITY)
+                                        .interactive(false),
+                                );
+                                if ui_c
+                                    .button(i18n::tr("btn-llm-preview-copy-payload"))
+                                    .clicked()
+                                {
+                                    ui_c.ctx().copy_text(preview.payload_json.clone());
+                                }
+                            });
+                    });
+            });
+        if !open {
+            self.llm_prompt_preview = None;
+        }
+    }
+
     fn ui_llm_prompt_test_section(&mut self, ui: &mut egui::Ui) {
         let mut open_flag = self.llm_prompt_test.open;
         if ui
@@ -1874,6 +2912,430 @@ impl SettingsWindow {
         });
     }
 
+    // What is stored and where, on/off switch and redaction patterns
+    fn ui_history_privacy(&mut self, ui: &mut egui::Ui) {
+        ui.colored_label(
+            ui.visuals().warn_fg_color,
+            format!(
+                "{} {}",
+                i18n::tr("warn-history-stored"),
+                history_file_path().display()
+            ),
+        );
+        ui.add_space(4.0);
+        if ui
+            .checkbox(
+                &mut self.settings.history_enabled,
+                i18n::tr("chk-history-enabled"),
+            )
+            .changed()
+        {
+            self.check_changes();
+        }
+        let header = egui::RichText::new(i18n::tr("label-history-redaction")).strong();
+        egui::CollapsingHeader::new(header)
+            .id_salt("history_redaction")
+            .default_open(!self.settings.history_redaction_patterns.is_empty())
+            .show(ui, |ui| {
+                ui.label(
+                    egui::RichText::new(i18n::tr("hint-history-redaction"))
+                        .small()
+                        .color(ui.visuals().weak_text_color()),
+                );
+                self.sync_redaction_errors();
+                let mut changed = false;
+                let mut remove = None;
+                for (idx, pattern) in self
+                    .settings
+                    .history_redaction_patterns
+                    .iter_mut()
+                    .enumerate()
+                {
+                    ui.horizontal(|ui| {
+                        changed |= ui
+                            .add(
+                                egui::TextEdit::singleline(pattern)
+                                    .font(egui::TextStyle::Monospace)
+                                    .desired_width(280.0),
+                            )
+                            .changed();
+                        if ui.small_button("✕").clicked() {
+                            remove = Some(idx);
+                        }
+                    });
+                    if let Some((_, Some(err))) = self.redaction_errors.get(idx) {
+                        ui.colored_label(
+                            ui.visuals().warn_fg_color,
+                            i18n::tr("msg-history-redaction-invalid"),
+                        )
+                        .on_hover_text(err.as_str());
+                    }
+                }
+                if let Some(idx) = remove {
+                    self.settings.history_redaction_patterns.remove(idx);
+                    changed = true;
+                }
+                ui.horizontal(|ui| {
+                    if ui.button(i18n::tr("btn-history-redaction-add")).clicked() {
+                        self.settings.history_redaction_patterns.push(String::new());
+                        changed = true;
+                    }
+                    let has_card = self
+                        .settings
+                        .history_redaction_patterns
+                        .iter()
+                        .any(|p| p == CARD_NUMBER_PATTERN);
+                    if ui
+                        .add_enabled(
+                            !has_card,
+                            egui::Button::new(i18n::tr("btn-history-redaction-card")),
+                        )
+                        .clicked()
+                    {
+                        self.settings
+                            .history_redaction_patterns
+                            .push(CARD_NUMBER_PATTERN.to_string());
+                        changed = true;
+                    }
+                });
+                if changed {
+                    self.check_changes();
+                }
+            });
+    }
+
+    // Keeps the errors of unchanged patterns instead of compiling every frame
+    fn sync_redaction_errors(&mut self) {
+        let patterns = &self.settings.history_redaction_patterns;
+        let cached = &self.redaction_errors;
+        if cached.len() == patterns.len() && cached.iter().zip(patterns).all(|((c, _), p)| c == p) {
+            return;
+        }
+        self.redaction_errors = patterns
+            .iter()
+            .enumerate()
+            .map(|(idx, pattern)| match cached.get(idx) {
+                Some((c, err)) if c == pattern => (c.clone(), err.clone()),
+                _ => (pattern.clone(), redaction_pattern_error(pattern)),
+            })
+            .collect();
+    }
+
+    // Palette picker plus per-state color buttons
+    fn ui_status_colors(&mut self, ui: &mut egui::Ui) {
+        let before = self.settings.status_colors;
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-status-colors"));
+            let current = StatusColors::palettes()
+                .into_iter()
+                .find(|(p, _)| *p == self.settings.status_colors)
+                .map(|(_, key)| i18n::tr(key))
+                .unwrap_or_else(|| i18n::tr("option-status-colors-custom"));
+            egui::ComboBox::from_id_salt("status_colors_combo")
+                .selected_text(current)
+                .show_ui(ui, |ui| {
+                    for (palette, key) in StatusColors::palettes() {
+                        ui.selectable_value(
+                            &mut self.settings.status_colors,
+                            palette,
+                            i18n::tr(key),
+                        );
+                    }
+                });
+        });
+        ui.horizontal_wrapped(|ui| {
+            let colors = &mut self.settings.status_colors;
+            for (rgb, key) in [
+                (&mut colors.idle, "status-idle"),
+                (&mut colors.recording, "status-recording"),
+                (&mut colors.processing, "status-processing"),
+                (&mut colors.post_processing, "status-post-processing"),
+                (&mut colors.busy, "status-busy"),
+            ] {
+                ui.color_edit_button_srgb(rgb);
+                ui.label(i18n::tr(key));
+                ui.add_space(6.0);
+            }
+        });
+        if self.settings.status_colors != before {
+            self.check_changes();
+        }
+    }
+
+    // Retention limits and "Clear history" (top of the History tab)
+    fn ui_history_retention(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-history-max-entries"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut self.settings.history_max_entries)
+                        .range(1..=HISTORY_MAX_ENTRIES_LIMIT),
+                )
+                .changed();
+        });
+        ui.horizontal(|ui| {
+            let mut by_age = self.settings.history_retention_days.is_some();
+            if ui
+                .checkbox(&mut by_age, i18n::tr("chk-history-retention"))
+                .changed()
+            {
+                self.settings.history_retention_days = by_age.then_some(30);
+                changed = true;
+            }
+            if let Some(days) = self.settings.history_retention_days.as_mut() {
+                changed |= ui.add(egui::DragValue::new(days).range(1..=3650)).changed();
+                ui.label(i18n::tr("label-history-days"));
+            }
+        });
+        ui.label(
+            egui::RichText::new(i18n::tr("hint-history-retention"))
+                .small()
+                .color(ui.visuals().weak_text_color()),
+        );
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            if !self.llm_history_confirm_clear {
+                let has_entries = !self.llm_history_entries.is_empty();
+                if ui
+                    .add_enabled(
+                        has_entries,
+                        egui::Button::new(i18n::tr("btn-history-clear")),
+                    )
+                    .clicked()
+                {
+                    self.llm_history_confirm_clear = true;
+                }
+                return;
+            }
+            ui.colored_label(
+                ui.visuals().warn_fg_color,
+                i18n::tr("msg-history-clear-confirm"),
+            );
+            if ui.button(i18n::tr("btn-history-clear-confirm")).clicked() {
+                self.llm_history_confirm_clear = false;
+                match clear_history() {
+                    Ok(()) => self.reload_llm_history(),
+                    Err(err) => {
+                        self.llm_history_error =
+                            Some(format!("{}: {}", i18n::tr("msg-history-clear-failed"), err));
+                    }
+                }
+            }
+            if ui.button(i18n::tr("btn-cancel")).clicked() {
+                self.llm_history_confirm_clear = false;
+            }
+        });
+        if changed {
+            self.check_changes();
+            // Show what the new limits keep (the file is pruned on the next entry)
+            self.reload_llm_history();
+        }
+    }
+
+    // Model used by the selected preset/custom mode instead of the global one
+    fn ui_llm_mode_model(&mut self, ui: &mut egui::Ui, mode_id: &str) {
+        let llm = &self.settings.llm_postprocess;
+        let mut model = llm.mode_model(mode_id).unwrap_or_default().to_string();
+        let global = llm.model.trim().to_string();
+        let mut changed = false;
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-llm-mode-model"));
+            changed |= ui
+                .add(
+                    egui::TextEdit::singleline(&mut model)
+                        .desired_width(200.0)
+                        .hint_text(global),
+                )
+                .on_hover_text(i18n::tr("tooltip-llm-mode-model"))
+                .changed();
+            egui::ComboBox::from_id_salt("llm_mode_model_candidates")
+                .selected_text(i18n::tr("llm-model-dropdown-placeholder"))
+                .show_ui(ui, |ui| {
+                    if ui
+                        .selectable_label(false, i18n::tr("option-llm-mode-model-global"))
+                        .clicked()
+                    {
+                        model.clear();
+                        changed = true;
+                    }
+                    for opt in &self.llm_model_options {
+                        if ui.selectable_label(false, &opt.label).clicked() {
+                            model = opt.id.clone();
+                            changed = true;
+                        }
+                    }
+                });
+        });
+        if changed {
+            self.settings
+                .llm_postprocess
+                .set_mode_model(mode_id, &model);
+            self.check_changes();
+        }
+    }
+
+    // Truncate long transcripts or split them into several requests
+    fn ui_llm_oversize_input(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-llm-oversize-input"));
+            let label = |mode: OversizeInput| match mode {
+                OversizeInput::Truncate => i18n::tr("option-llm-oversize-truncate"),
+                OversizeInput::Chunk => i18n::tr("option-llm-oversize-chunk"),
+            };
+            let before = self.settings.llm_postprocess.oversize_input;
+            egui::ComboBox::from_id_salt("llm_oversize_input_combo")
+                .selected_text(label(before))
+                .show_ui(ui, |ui| {
+                    for mode in [OversizeInput::Truncate, OversizeInput::Chunk] {
+                        ui.selectable_value(
+                            &mut self.settings.llm_postprocess.oversize_input,
+                            mode,
+                            label(mode),
+                        );
+                    }
+                });
+            if self.settings.llm_postprocess.oversize_input != before {
+                self.check_changes();
+            }
+        });
+        ui.label(
+            egui::RichText::new(i18n::tr("hint-llm-oversize-input"))
+                .small()
+                .color(ui.visuals().weak_text_color()),
+        );
+    }
+
+    // Presets and saved custom modes as (id, label)
+    fn llm_mode_options(&self) -> Vec<(String, String)> {
+        let mut options = vec![
+            (PRESET_ID_FORMAT.to_string(), i18n::tr("llm-mode-format")),
+            (PRESET_ID_SUMMARY.to_string(), i18n::tr("llm-mode-summary")),
+            (
+                PRESET_ID_FURIGANA.to_string(),
+                i18n::tr("llm-mode-furigana"),
+            ),
+        ];
+        for custom in &self.settings.llm_postprocess.custom_prompts {
+            options.push((custom.id.clone(), custom.name.clone()));
+        }
+        for chain in &self.settings.llm_postprocess.chain_modes {
+            options.push((
+                chain.id.clone(),
+                format!("{} ({})", chain.name, i18n::tr("llm-mode-chain-suffix")),
+            ));
+        }
+        options
+    }
+
+    // Deletions red and struck through, insertions green on a tinted background
+    fn diff_layout(ui: &egui::Ui, spans: &[DiffSpan]) -> egui::text::LayoutJob {
+        let font_id = egui::TextStyle::Body.resolve(ui.style());
+        let deleted = ui.visuals().error_fg_color;
+        let inserted = egui::Color32::from_rgb(60, 170, 90);
+        let mut job = egui::text::LayoutJob::default();
+        for span in spans {
+            let mut format = egui::TextFormat {
+                font_id: font_id.clone(),
+                color: ui.visuals().text_color(),
+                ..Default::default()
+            };
+            match span {
+                DiffSpan::Equal(_) => {}
+                DiffSpan::Delete(_) => {
+                    format.color = deleted;
+                    format.strikethrough = egui::Stroke::new(1.0, deleted);
+                }
+                DiffSpan::Insert(_) => {
+                    format.color = inserted;
+                    format.background = inserted.gamma_multiply(0.15);
+                }
+            }
+            job.append(span.text(), 0.0, format);
+        }
+        job.wrap.max_width = ui.available_width();
+        job
+    }
+
+    // Text search (as in the dictionary tab) and date range
+    fn ui_history_filter(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            let icon = egui::RichText::new(Icon::Search.unicode())
+                .size(16.0)
+                .color(ui.visuals().weak_text_color());
+            ui.label(icon);
+            let hint = egui::RichText::new(i18n::tr("hint-history-search"))
+                .color(ui.visuals().weak_text_color());
+            let te = egui::TextEdit::singleline(&mut self.llm_history_filter_text)
+                .hint_text(hint)
+                .desired_width(220.0);
+            ui.add(te);
+            if !self.llm_history_filter_text.is_empty()
+                && ui
+                    .add(egui::Button::new(i18n::tr("btn-clear")).small())
+                    .clicked()
+            {
+                self.llm_history_filter_text.clear();
+            }
+            ui.add_space(8.0);
+            ui.label(i18n::tr("label-history-date"));
+            egui::ComboBox::from_id_salt("llm_history_date_filter")
+                .selected_text(i18n::tr(self.llm_history_date_filter.label_key()))
+                .show_ui(ui, |ui| {
+                    for filter in HistoryDateFilter::ALL {
+                        ui.selectable_value(
+                            &mut self.llm_history_date_filter,
+                            filter,
+                            i18n::tr(filter.label_key()),
+                        );
+                    }
+                });
+        });
+    }
+
+    // Result of "Reprocess" for the entry shown in the details
+    fn ui_history_rerun_result(&self, ui: &mut egui::Ui, index: usize) {
+        let rerun = &self.llm_history_rerun;
+        if rerun.selected_entry != Some(index) {
+            return;
+        }
+        if rerun.in_progress {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(i18n::tr("msg-llm-prompt-test-running"));
+            });
+        }
+        if let Some(err) = &rerun.error {
+            ui.colored_label(ui.visuals().warn_fg_color, err);
+        }
+        let Some(output) = &rerun.output else {
+            return;
+        };
+        ui.add_space(6.0);
+        let mut heading = i18n::tr("label-llm-history-rerun-output");
+        if let Some(latency_ms) = rerun.latency_ms {
+            heading = format!("{} ({:.2}s)", heading, latency_ms as f32 / 1000.0);
+        }
+        ui.label(heading);
+        if rerun.truncated_input {
+            ui.colored_label(
+                ui.visuals().warn_fg_color,
+                i18n::tr("label-llm-test-truncated"),
+            );
+        }
+        let mut output_text = output.clone();
+        ui.add(
+            egui::TextEdit::multiline(&mut output_text)
+                .desired_rows(6)
+                .desired_width(f32::INFINITY)
+                .interactive(false),
+        );
+        if ui.button(i18n::tr("btn-llm-test-copy-output")).clicked() {
+            ui.ctx().copy_text(output.clone());
+        }
+    }
+
     pub fn ui_section_history(&mut self, ui: &mut egui::Ui) {
         self.refresh_llm_history_if_needed();
         ui.add_space(8.0);
@@ -1882,6 +3344,10 @@ impl SettingsWindow {
             .strong();
         ui.heading(heading);
         ui.add_space(4.0);
+        self.ui_history_privacy(ui);
+        ui.add_space(6.0);
+        self.ui_history_retention(ui);
+        ui.add_space(6.0);
 
         if let Some(err) = &self.llm_history_error {
             ui.colored_label(ui.visuals().warn_fg_color, err);
@@ -1893,6 +3359,24 @@ impl SettingsWindow {
             return;
         }
 
+        self.ui_history_filter(ui);
+        ui.add_space(6.0);
+        let now = Local::now();
+        let visible: Vec<usize> = (0..self.llm_history_entries.len())
+            .filter(|&i| {
+                history_entry_matches_filter(
+                    &self.llm_history_entries[i],
+                    &self.llm_history_filter_text,
+                    self.llm_history_date_filter,
+                    now,
+                )
+            })
+            .collect();
+        if visible.is_empty() {
+            ui.label(i18n::tr("msg-history-no-matches"));
+            return;
+        }
+
         let base_height = ui.text_style_height(&egui::TextStyle::Body);
         let row_height = base_height.max(18.0) * 1.3;
         let list_height = row_height * 5.0;
@@ -1904,7 +3388,8 @@ impl SettingsWindow {
             .max_height(list_height)
             .auto_shrink([false; 2])
             .show(ui, |ui| {
-                for (display_pos, entry) in self.llm_history_entries.iter().enumerate().rev() {
+                for &display_pos in visible.iter().rev() {
+                    let entry = &self.llm_history_entries[display_pos];
                     let is_selected = new_selection == Some(display_pos);
                     ui.horizontal(|row| {
                         row.set_min_height(row_height);
@@ -1963,8 +3448,9 @@ impl SettingsWindow {
                 }
             });
 
-        if new_selection.is_none() {
-            new_selection = self.llm_history_entries.len().checked_sub(1);
+        // Keep the details on a visible entry
+        if !new_selection.is_some_and(|i| visible.contains(&i)) {
+            new_selection = visible.last().copied();
         }
         self.llm_history_selected = new_selection;
 
@@ -2019,6 +3505,24 @@ impl SettingsWindow {
             i18n::tr("label-llm-history-latency"),
             entry.llm_latency_ms as f32 / 1000.0
         ));
+        if entry.recording_duration_secs > 0.0 {
+            ui.label(format!(
+                "{} {:.1}s · {} {} · {} {}",
+                i18n::tr("label-llm-history-recording"),
+                entry.recording_duration_secs,
+                entry.char_count,
+                i18n::tr("label-llm-history-chars"),
+                entry.word_count,
+                i18n::tr("label-llm-history-words"),
+            ));
+        }
+        if entry.words_per_minute > 0.0 {
+            ui.label(format!(
+                "{} {:.0}",
+                i18n::tr("label-llm-history-wpm"),
+                entry.words_per_minute
+            ));
+        }
         if entry.truncated_input {
             ui.colored_label(
                 ui.visuals().warn_fg_color,
@@ -2027,6 +3531,21 @@ impl SettingsWindow {
         }
 
         ui.add_space(6.0);
+        // Mode for "Reprocess": defaults to the current mode (the unsaved draft is not offered)
+        let mode_options = self.llm_mode_options();
+        if !mode_options
+            .iter()
+            .any(|(id, _)| *id == self.llm_history_rerun_mode)
+        {
+            let current = &self.settings.llm_postprocess.mode_id;
+            self.llm_history_rerun_mode = mode_options
+                .iter()
+                .find(|(id, _)| id == current)
+                .unwrap_or(&mode_options[0])
+                .0
+                .clone();
+        }
+        let mut rerun_requested = false;
         ui.horizontal(|ui| {
             if ui
                 .button(i18n::tr("btn-llm-history-copy-transcript"))
@@ -2043,6 +3562,43 @@ impl SettingsWindow {
                 ui.ctx().copy_text(entry.llm_output.clone());
             }
         });
+        ui.horizontal(|ui| {
+            let has_transcript = !entry.transcript.trim().is_empty();
+            let selected_label = mode_options
+                .iter()
+                .find(|(id, _)| *id == self.llm_history_rerun_mode)
+                .map(|(_, label)| label.clone())
+                .unwrap_or_default();
+            ui.add_enabled_ui(has_transcript, |ui| {
+                egui::ComboBox::from_id_salt("llm_history_rerun_mode")
+                    .selected_text(selected_label)
+                    .show_ui(ui, |ui| {
+                        for (id, label) in &mode_options {
+                            ui.selectable_value(
+                                &mut self.llm_history_rerun_mode,
+                                id.clone(),
+                                label,
+                            );
+                        }
+                    });
+            });
+            if ui
+                .add_enabled(
+                    has_transcript && !self.llm_history_rerun.in_progress,
+                    egui::Button::new(i18n::tr("btn-llm-history-rerun")),
+                )
+                .on_hover_text(i18n::tr("tooltip-llm-history-rerun"))
+                .on_disabled_hover_text(if has_transcript {
+                    i18n::tr("msg-llm-prompt-test-running")
+                } else {
+                    i18n::tr("tooltip-llm-history-rerun-no-transcript")
+                })
+                .clicked()
+            {
+                rerun_requested = true;
+            }
+        });
+        self.ui_history_rerun_result(ui, selected_index);
 
         ui.add_space(6.0);
         let base_text_height = ui.text_style_height(&egui::TextStyle::Body);
@@ -2068,6 +3624,30 @@ impl SettingsWindow {
             .interactive(false);
         ui.add_sized([text_box_width, text_box_height], output_widget);
 
+        ui.add_space(6.0);
+        egui::CollapsingHeader::new(i18n::tr("label-llm-history-diff"))
+            .id_salt("llm_hist_diff")
+            .default_open(false)
+            .show(ui, |ui| {
+                ui.label(
+                    egui::RichText::new(i18n::tr("hint-llm-history-diff"))
+                        .small()
+                        .color(ui.visuals().weak_text_color()),
+                );
+                // Computed once per entry (keyed by timestamp)
+                let cached = self
+                    .llm_history_diff
+                    .as_ref()
+                    .is_some_and(|(ts, _)| *ts == entry.timestamp);
+                if !cached {
+                    let spans = word_diff(&entry.transcript, &entry.llm_output);
+                    self.llm_history_diff = Some((entry.timestamp.clone(), spans));
+                }
+                if let Some((_, spans)) = &self.llm_history_diff {
+                    ui.label(Self::diff_layout(ui, spans));
+                }
+            });
+
         if entry.settings.custom_prompt_system.is_some()
             || entry.settings.custom_prompt_user.is_some()
         {
@@ -2099,6 +3679,9 @@ impl SettingsWindow {
                     }
                 });
         }
+        if rerun_requested {
+            self.request_llm_history_rerun(selected_index);
+        }
     }
 
     fn request_llm_connection_test(&mut self) {
@@ -2107,24 +3690,69 @@ impl SettingsWindow {
         }
         let tx = self.llm_async_tx.clone();
         let settings = self.settings.llm_postprocess.clone();
+        self.llm_test_id += 1;
+        let id = self.llm_test_id;
         self.llm_test_in_progress = true;
+        self.llm_test_started_at = Some(Instant::now());
         self.llm_test_error = None;
-        self.llm_test_message = Some(i18n::tr("msg-llm-test-running"));
+        self.llm_test_message = Some(format!(
+            "{}\n{}",
+            i18n::tr("msg-llm-test-running"),
+            settings.effective_base_url()
+        ));
         std::thread::spawn(move || {
             let result = crate::llm::run_connection_test(&settings).map_err(|e| e.to_string());
-            let _ = tx.send(LlmUiMessage::TestResult(result));
+            let _ = tx.send(LlmUiMessage::TestResult(id, result));
         });
     }
 
+    // The blocking request can't be interrupted; the worker finishes on its own
+    // and its result is ignored because the id no longer matches.
+    fn cancel_llm_connection_test(&mut self) {
+        self.llm_test_id += 1;
+        self.llm_test_in_progress = false;
+        self.llm_test_started_at = None;
+        self.llm_test_error = None;
+        self.llm_test_message = Some(i18n::tr("msg-llm-test-cancelled"));
+    }
+
     fn request_llm_prompt_test(&mut self, transcript: String) {
         if self.llm_prompt_test.in_progress {
             return;
         }
-        let tx = self.llm_async_tx.clone();
-        let settings = self.settings.llm_postprocess.clone();
-        let language_hint = self.llm_language_hint();
         self.clear_llm_prompt_test_result();
         self.llm_prompt_test.in_progress = true;
+        let settings = self.settings.llm_postprocess.clone();
+        self.spawn_llm_process(settings, transcript, LlmUiMessage::PromptTest);
+    }
+
+    // Run the entry's stored transcript through `llm_history_rerun_mode`
+    fn request_llm_history_rerun(&mut self, index: usize) {
+        let Some(entry) = self.llm_history_entries.get(index) else {
+            return;
+        };
+        let transcript = entry.transcript.clone();
+        let mut settings = self.settings.llm_postprocess.clone();
+        settings.mode_id = self.llm_history_rerun_mode.clone();
+        self.llm_history_rerun = LlmPromptTestState {
+            in_progress: true,
+            selected_entry: Some(index),
+            ..Default::default()
+        };
+        self.spawn_llm_process(settings, transcript, move |result| {
+            LlmUiMessage::HistoryRerun(index, result)
+        });
+    }
+
+    // Run `settings` on `transcript` off the UI thread
+    fn spawn_llm_process(
+        &self,
+        settings: LlmPostProcessSettings,
+        transcript: String,
+        wrap: impl FnOnce(Result<PostProcessOutcome, String>) -> LlmUiMessage + Send + 'static,
+    ) {
+        let tx = self.llm_async_tx.clone();
+        let language_hint = self.llm_language_hint();
         std::thread::spawn(move || {
             let processor = LlmPostProcessor::new();
             let result = processor
@@ -2139,7 +3767,7 @@ impl SettingsWindow {
                     }
                     msg
                 });
-            let _ = tx.send(LlmUiMessage::PromptTest(result));
+            let _ = tx.send(wrap(result));
         });
     }
 
@@ -2157,6 +3785,33 @@ impl SettingsWindow {
         });
     }
 
+    // Called only while the LLM details are drawn, so retries stop with the tab
+    // hidden or the window closed. Each attempt is a one-shot fetch thread bounded
+    // by the model list timeout; nothing keeps running in the background.
+    fn poll_llm_model_list(&mut self, ctx: &egui::Context) {
+        if !self.settings.llm_postprocess.poll_models || !self.llm_model_options.is_empty() {
+            self.llm_model_poll = None;
+            return;
+        }
+        let now = Instant::now();
+        let (_, next) = *self.llm_model_poll.get_or_insert((0, now));
+        if self.llm_fetching_models {
+            return;
+        }
+        if now >= next {
+            self.request_llm_model_list();
+        } else {
+            ctx.request_repaint_after(next - now);
+        }
+    }
+
+    fn back_off_llm_model_poll(&mut self) {
+        if let Some((attempts, next)) = &mut self.llm_model_poll {
+            *next = Instant::now() + model_poll_delay(*attempts);
+            *attempts += 1;
+        }
+    }
+
     fn sync_llm_custom_editor(&mut self) {
         let mode_id = self.settings.llm_postprocess.mode_id.clone();
         if mode_id == MODE_ID_CUSTOM_DRAFT {
@@ -2177,7 +3832,9 @@ impl SettingsWindow {
             }
             return;
         }
-        if is_builtin_mode_id(&mode_id) {
+        if is_builtin_mode_id(&mode_id)
+            || self.settings.llm_postprocess.chain_mode(&mode_id).is_some()
+        {
             self.llm_mode_loaded_id = None;
             return;
         }
@@ -2394,7 +4051,10 @@ impl SettingsWindow {
     // key_to_string moved to hotkey.rs
 
     pub fn set_current_used_model_path(&mut self, p: PathBuf) {
-        self.current_used_model = Some(p);
+        if self.current_used_model.as_ref() != Some(&p) {
+            self.current_used_preset = crate::transcription::detect_model_preset(&p);
+            self.current_used_model = Some(p);
+        }
     }
 
     // 入力デバイスとメーター関連は audio_meter.rs へ移動
@@ -2408,17 +4068,13 @@ impl SettingsWindow {
     }
 
     pub fn set_last_ui_mode(&mut self, mode: &str) {
-        self.settings.last_ui_mode = mode.to_string();
         // 即時保存（UI操作ではないため check_changes は使わない）
-        self.save_settings();
-        self.original_settings = self.settings.clone();
+        self.persist_now(|s| s.last_ui_mode = mode.to_string());
     }
 
     // フローティングウィンドウの位置を保存（即時保存）
     pub fn set_floating_position(&mut self, pos: egui::Pos2) {
-        self.settings.floating_position = Some([pos.x, pos.y]);
-        self.save_settings();
-        self.original_settings = self.settings.clone();
+        self.persist_now(|s| s.floating_position = Some([pos.x, pos.y]));
     }
 
     // 保存済みのフローティング位置を取得
@@ -2428,19 +4084,106 @@ impl SettingsWindow {
             .map(|xy| egui::pos2(xy[0], xy[1]))
     }
 
+    // ミニHUDの位置を保存（即時保存）
+    pub fn set_floating_mini_position(&mut self, pos: egui::Pos2) {
+        self.persist_now(|s| s.floating_mini_position = Some([pos.x, pos.y]));
+    }
+
+    // Main window resize/maximize (throttled save: a drag resizes every frame)
+    pub fn remember_window_geometry(&mut self, size: egui::Vec2, maximized: bool) {
+        let s = &self.settings;
+        // Keep the restored size while maximized
+        let size = if maximized {
+            s.window_size
+        } else {
+            Some([size.x.round(), size.y.round()])
+        };
+        if s.window_size != size || s.window_maximized != maximized {
+            self.persist_later(|s| {
+                s.window_size = size;
+                s.window_maximized = maximized;
+            });
+        }
+    }
+
+    pub fn get_floating_mini_position(&self) -> Option<egui::Pos2> {
+        self.settings
+            .floating_mini_position
+            .map(|xy| egui::pos2(xy[0], xy[1]))
+    }
+
     // 設定画面からの「モデル適用」要求を取り出す（1回限り）
     // take_model_to_apply は whisper_models.rs へ移動
 
     fn check_changes(&mut self) {
         let changed = self.settings != self.original_settings;
-        if changed {
-            // 常時自動保存（元に戻すは廃止）
-            self.save_settings();
-            self.original_settings = self.settings.clone();
-            self.has_unsaved_changes = false;
-            self.save_status_message = Some(i18n::tr("msg-settings-saved"));
+        if changed && self.settings.settings_autosave {
+            self.commit_settings();
         } else {
-            self.has_unsaved_changes = false;
+            // Manual save mode: wait for Save / Revert
+            self.has_unsaved_changes = changed;
+        }
+    }
+
+    // Written by the throttled flush so dragging a slider does not rewrite the
+    // file every frame
+    fn commit_settings(&mut self) {
+        self.original_settings = self.settings.clone();
+        self.has_unsaved_changes = false;
+        self.save_status_message = Some(i18n::tr("msg-settings-saved"));
+        self.queue_save();
+    }
+
+    pub fn autosave_enabled(&self) -> bool {
+        self.settings.settings_autosave
+    }
+
+    // Save / Revert bar under the tabs (manual save mode only)
+    pub fn ui_save_bar(&mut self, ui: &mut egui::Ui) {
+        ui.separator();
+        ui.horizontal(|ui| {
+            let pending = self.has_unsaved_changes;
+            if ui
+                .add_enabled(pending, egui::Button::new(i18n::tr("btn-save")))
+                .clicked()
+            {
+                self.commit_settings();
+                self.flush_pending_save();
+            }
+            if ui
+                .add_enabled(pending, egui::Button::new(i18n::tr("btn-revert")))
+                .on_hover_text(i18n::tr("tooltip-revert-settings"))
+                .clicked()
+            {
+                self.revert_settings();
+            }
+            if pending {
+                ui.colored_label(ui.visuals().warn_fg_color, i18n::tr("msg-settings-unsaved"));
+            } else if let Some(msg) = &self.save_status_message {
+                ui.label(
+                    egui::RichText::new(msg)
+                        .small()
+                        .color(ui.visuals().weak_text_color()),
+                );
+            }
+        });
+    }
+
+    // Auto-save toggle; the choice itself is saved at once
+    fn ui_settings_autosave(&mut self, ui: &mut egui::Ui) {
+        let mut autosave = self.settings.settings_autosave;
+        if ui
+            .checkbox(&mut autosave, i18n::tr("chk-settings-autosave"))
+            .on_hover_text(i18n::tr("tooltip-settings-autosave"))
+            .changed()
+        {
+            if autosave {
+                // Pending edits are saved along with the switch
+                self.settings.settings_autosave = true;
+                self.check_changes();
+            } else {
+                self.persist_now(|s| s.settings_autosave = false);
+            }
         }
     }
 
@@ -2467,6 +4210,49 @@ impl Default for SettingsWindow {
 
 // ライセンスリンクの描画（一般タブの末尾）
 impl SettingsWindow {
+    // Linux: how auto-paste inserts the text (paste shortcut, primary selection or typing)
+    fn ui_linux_paste_method(&mut self, ui: &mut egui::Ui) {
+        let label = |m: LinuxPasteMethod| match m {
+            LinuxPasteMethod::CtrlV => i18n::tr("option-paste-ctrl-v"),
+            LinuxPasteMethod::CtrlShiftV => i18n::tr("option-paste-ctrl-shift-v"),
+            LinuxPasteMethod::MiddleClickPrimary => i18n::tr("option-paste-middle-click"),
+            LinuxPasteMethod::TypeText => i18n::tr("option-paste-type-text"),
+        };
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-paste-method"));
+            let current = self.settings.linux_paste_method;
+            let mut chosen = current;
+            egui::ComboBox::from_id_salt("linux_paste_method_combo")
+                .selected_text(label(current))
+                .show_ui(ui, |ui| {
+                    for m in [
+                        LinuxPasteMethod::CtrlV,
+                        LinuxPasteMethod::CtrlShiftV,
+                        LinuxPasteMethod::MiddleClickPrimary,
+                        LinuxPasteMethod::TypeText,
+                    ] {
+                        ui.selectable_value(&mut chosen, m, label(m));
+                    }
+                });
+            if chosen != current {
+                self.settings.linux_paste_method = chosen;
+                self.check_changes();
+            }
+        });
+        let note_key = match self.settings.linux_paste_method {
+            LinuxPasteMethod::MiddleClickPrimary => Some("note-paste-middle-click"),
+            LinuxPasteMethod::TypeText => Some("note-paste-type-text"),
+            _ => None,
+        };
+        if let Some(key) = note_key {
+            ui.label(
+                egui::RichText::new(i18n::tr(key))
+                    .small()
+                    .color(ui.visuals().weak_text_color()),
+            );
+        }
+    }
+
     // 自動ペーストに関するOS別トラブルシュート（折りたたみ）
     fn ui_auto_paste_troubleshoot(&mut self, ui: &mut egui::Ui) {
         let header = egui::RichText::new(i18n::tr("troubleshoot-autopaste-title")).strong();
@@ -2474,6 +4260,21 @@ impl SettingsWindow {
             .default_open(false)
             .show(ui, |ui| {
                 ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("label-auto-paste-delay"));
+                    if ui
+                        .add(
+                            egui::Slider::new(&mut self.settings.auto_paste_delay_ms, 0..=2000)
+                                .step_by(50.0)
+                                .suffix(" ms"),
+                        )
+                        .on_hover_text(i18n::tr("tooltip-auto-paste-delay"))
+                        .changed()
+                    {
+                        self.check_changes();
+                    }
+                });
+                ui.add_space(6.0);
 
                 #[cfg(target_os = "macos")]
                 {
@@ -2539,3 +4340,149 @@ impl SettingsWindow {
 
     // removed: legacy ui_licenses_link (inline link is implemented in ui_section_general)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{model_poll_delay, Settings, VsyncMode};
+    use crate::llm::{
+        LlmPostProcessSettings, MODE_ID_CUSTOM_DRAFT, PRESET_ID_FORMAT, PRESET_ID_SUMMARY,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn model_poll_backs_off_to_cap() {
+        let delays: Vec<u64> = (0..7).map(|n| model_poll_delay(n).as_secs()).collect();
+        assert_eq!(delays, vec![2, 4, 8, 16, 30, 30, 30]);
+        assert_eq!(model_poll_delay(u32::MAX), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn custom_mode_survives_reload() {
+        let toml_text = r#"
+[llm_postprocess]
+enabled = true
+mode_id = "custom:meeting-notes"
+
+[[llm_postprocess.custom_prompts]]
+id = "custom:meeting-notes"
+name = "Meeting notes"
+user_prompt = "Summarize: {{transcript}}"
+"#;
+        let mut settings: Settings = toml::from_str(toml_text).expect("settings.toml parses");
+        settings.llm_postprocess.ensure_mode_valid();
+        assert_eq!(settings.llm_postprocess.mode_id, "custom:meeting-notes");
+
+        // Round-trip through the saved form keeps the selection
+        let saved = toml::to_string(&settings).unwrap();
+        let mut reloaded: Settings = toml::from_str(&saved).unwrap();
+        reloaded.llm_postprocess.ensure_mode_valid();
+        assert_eq!(reloaded.llm_postprocess.mode_id, "custom:meeting-notes");
+    }
+
+    #[test]
+    fn legacy_custom_modes_are_migrated() {
+        // Entry without id (older build) selected by its display name
+        let toml_text = r#"
+[llm_postprocess]
+mode_id = "Meeting notes"
+
+[[llm_postprocess.custom_prompts]]
+name = "Meeting notes"
+user_prompt = "{{transcript}}"
+"#;
+        let mut settings: Settings = toml::from_str(toml_text).expect("settings.toml parses");
+        settings.llm_postprocess.ensure_mode_valid();
+        let id = settings.llm_postprocess.custom_prompts[0].id.clone();
+        assert_eq!(id, "custom:meeting-notes");
+        assert_eq!(settings.llm_postprocess.mode_id, id);
+
+        // Only a genuinely missing prompt falls back to the draft
+        settings.llm_postprocess.mode_id = "custom:deleted".to_string();
+        settings.llm_postprocess.ensure_mode_valid();
+        assert_eq!(settings.llm_postprocess.mode_id, MODE_ID_CUSTOM_DRAFT);
+    }
+
+    #[test]
+    fn chain_modes_survive_validation_and_drop_deleted_steps() {
+        let mut llm = LlmPostProcessSettings::default();
+        let custom = llm.create_custom_mode("Polite", "", "{{transcript}}");
+        let chain =
+            llm.create_chain_mode("Polish", vec![PRESET_ID_FORMAT.to_string(), custom.clone()]);
+        assert_eq!(chain, "chain:polish");
+        llm.mode_id = chain.clone();
+        llm.ensure_mode_valid();
+        assert_eq!(llm.mode_id, chain);
+
+        assert!(llm.remove_custom_mode(&custom));
+        assert_eq!(llm.chain_mode(&chain).unwrap().steps, [PRESET_ID_FORMAT]);
+        assert!(llm.remove_chain_mode(&chain));
+        assert_eq!(llm.mode_id, PRESET_ID_FORMAT);
+    }
+
+    #[test]
+    fn mode_model_overrides_the_global_model() {
+        let mut llm = LlmPostProcessSettings {
+            model: "small".into(),
+            ..Default::default()
+        };
+        let custom = llm.create_custom_mode("Notes", "", "{{transcript}}");
+        llm.set_mode_model(PRESET_ID_SUMMARY, " big ");
+        llm.set_mode_model(&custom, "medium");
+
+        llm.mode_id = PRESET_ID_FORMAT.to_string();
+        assert_eq!(llm.effective_model(), "small");
+        llm.mode_id = PRESET_ID_SUMMARY.to_string();
+        assert_eq!(llm.effective_model(), "big");
+        llm.mode_id = custom.clone();
+        assert_eq!(llm.effective_model(), "medium");
+
+        llm.set_mode_model(&custom, "");
+        assert_eq!(llm.effective_model(), "small");
+    }
+
+    #[test]
+    fn input_gain_is_remembered_per_device() {
+        let mut s = Settings {
+            input_device_uid: Some("usb-mic".into()),
+            input_gain_percent: 120.0,
+            ..Default::default()
+        };
+        s.remember_input_gain();
+        s.input_device_uid = None;
+        s.input_device = Some("Headset".into());
+        s.input_gain_percent = 80.0;
+        s.remember_input_gain();
+
+        s.input_device_uid = Some("usb-mic".into());
+        s.recall_input_gain();
+        assert_eq!(s.input_gain_percent, 120.0);
+        // Unknown device keeps the current value
+        s.input_device_uid = Some("laptop".into());
+        s.recall_input_gain();
+        assert_eq!(s.input_gain_percent, 120.0);
+    }
+
+    #[test]
+    fn vsync_env_overrides_setting() {
+        assert!(VsyncMode::On.resolve(None));
+        assert!(!VsyncMode::Off.resolve(None));
+        assert!(!VsyncMode::On.resolve(Some("0")));
+        assert!(VsyncMode::Off.resolve(Some("on")));
+        assert_eq!(VsyncMode::Auto.resolve(None), !cfg!(target_os = "linux"));
+    }
+
+    #[test]
+    fn language_fallback_applies_below_threshold_on_auto() {
+        let mut s = Settings {
+            auto_detect_fallback: Some("ja".into()),
+            auto_detect_min_confidence: 0.6,
+            ..Default::default()
+        };
+        let fallback = s.language_fallback().expect("auto with a fallback");
+        assert!(fallback.resolve("en", 0.4).fell_back);
+        assert!(!fallback.resolve("en", 0.8).fell_back);
+        assert!(!fallback.resolve("ja", 0.1).fell_back);
+        s.whisper_language = "en".into();
+        assert_eq!(s.language_fallback(), None);
+    }
+}
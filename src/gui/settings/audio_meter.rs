@@ -1,8 +1,52 @@
-// no extra sync imports needed here
+use std::time::Instant;
 
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 
 use super::SettingsWindow;
+use crate::audio::device::{input_device_names, input_device_uids};
+
+// Clip threshold: -0.1 dBFS
+pub(super) const CLIP_THRESHOLD: f32 = 0.988_553;
+// Keep the peak for 1s, then fall at 20 dB/s
+const PEAK_HOLD_SECS: f32 = 1.0;
+const PEAK_DECAY_DB_PER_SEC: f32 = 20.0;
+
+/// Peak-hold of the raw input (0.0..=1.0, before input gain)
+pub(super) struct PeakHold {
+    value: f32,
+    held_at: Instant,
+    updated_at: Instant,
+}
+
+impl Default for PeakHold {
+    fn default() -> Self {
+        let now = Instant::now();
+        Self {
+            value: 0.0,
+            held_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+impl PeakHold {
+    pub(super) fn update(&mut self, block_peak: f32) {
+        let now = Instant::now();
+        if block_peak >= self.value {
+            self.value = block_peak;
+            self.held_at = now;
+        } else if now.duration_since(self.held_at).as_secs_f32() > PEAK_HOLD_SECS {
+            let dt = now.duration_since(self.updated_at).as_secs_f32();
+            let decayed = self.value * 10f32.powf(-PEAK_DECAY_DB_PER_SEC * dt / 20.0);
+            self.value = decayed.max(block_peak);
+        }
+        self.updated_at = now;
+    }
+
+    pub(super) fn value(&self) -> f32 {
+        self.value
+    }
+}
 
 impl SettingsWindow {
     pub(super) fn refresh_device_lists(&mut self) {
@@ -10,6 +54,7 @@ impl SettingsWindow {
         self.input_devices.clear();
         self.input_map.clear();
         self.input_names.clear();
+        self.input_uids.clear();
         self.input_hosts.clear();
         // Collect available hosts
         let mut hosts: Vec<String> = Vec::new();
@@ -22,7 +67,10 @@ impl SettingsWindow {
         for host_id in cpal::available_hosts() {
             if let Ok(host) = cpal::host_from_id(host_id) {
                 if let Ok(devs) = host.input_devices() {
-                    for (i, d) in devs.enumerate() {
+                    let devs: Vec<cpal::Device> = devs.collect();
+                    // IDs over the full host list so they match what capture sees
+                    let uids = input_device_uids(&input_device_names(&devs));
+                    for (i, d) in devs.into_iter().enumerate() {
                         let host_name = format!("{:?}", host_id).to_lowercase();
 
                         // Quick capability check: can we get a default input config?
@@ -65,6 +113,7 @@ impl SettingsWindow {
                         self.input_devices.push(format!("{}: {}", host_name, name));
                         self.input_map.push((host_name.clone(), i));
                         self.input_names.push(name);
+                        self.input_uids.push(uids[i].clone());
                         println!(
                             "[DEV-LIST] added: host={} idx={} name={}",
                             host_name,
@@ -78,7 +127,9 @@ impl SettingsWindow {
         if self.input_devices.is_empty() {
             self.input_devices.push("(system default)".into());
             self.input_map.push(("default".into(), 0));
+            self.input_uids.push(String::new());
         }
+        self.resolve_input_device_uid();
         // Output devices (default host only)
         self.output_devices.clear();
         let host_out = cpal::default_host();
@@ -93,6 +144,73 @@ impl SettingsWindow {
         self.output_devices.dedup();
     }
 
+    /// Reflect the core's hot-plug state (chosen microphone unplugged)
+    pub fn set_input_device_fallback(&mut self, fallback: bool) {
+        if fallback && !self.input_device_fallback {
+            self.refresh_device_lists();
+        }
+        self.input_device_fallback = fallback;
+    }
+
+    pub fn take_input_reselect_request(&mut self) -> bool {
+        std::mem::take(&mut self.input_reselect_requested)
+    }
+
+    // Stable ID of the device at (host, per-host index) in the current list
+    pub(super) fn input_uid_for(&self, host: &str, idx: usize) -> Option<String> {
+        self.input_map
+            .iter()
+            .position(|(h, i)| h == host && *i == idx)
+            .and_then(|pos| self.input_uids.get(pos))
+            .filter(|u| !u.is_empty())
+            .cloned()
+    }
+
+    // Keep the saved index pointing at the saved device after replugs; older
+    // settings without an ID adopt the one at the saved index if the name matches
+    fn resolve_input_device_uid(&mut self) {
+        let Some(host) = self.settings.input_host.clone() else {
+            return;
+        };
+        match self.settings.input_device_uid.clone() {
+            Some(uid) => {
+                let found = self
+                    .input_map
+                    .iter()
+                    .zip(&self.input_uids)
+                    .position(|((h, _), u)| *h == host && *u == uid);
+                if let Some(pos) = found {
+                    let idx = self.input_map[pos].1;
+                    if self.settings.input_device_index_in_host != Some(idx) {
+                        println!(
+                            "[DEV-LIST] device '{}' moved: index {:?} -> {}",
+                            uid, self.settings.input_device_index_in_host, idx
+                        );
+                        self.settings.input_device_index_in_host = Some(idx);
+                        self.check_changes();
+                    }
+                }
+            }
+            None => {
+                let Some(idx) = self.settings.input_device_index_in_host else {
+                    return;
+                };
+                let pos = self
+                    .input_map
+                    .iter()
+                    .position(|(h, i)| *h == host && *i == idx);
+                let name_matches = pos.is_some_and(|p| {
+                    self.settings.input_device.is_none()
+                        || self.input_names.get(p) == self.settings.input_device.as_ref()
+                });
+                if name_matches {
+                    self.settings.input_device_uid = self.input_uid_for(&host, idx);
+                    self.check_changes();
+                }
+            }
+        }
+    }
+
     pub(super) fn restart_meter(&mut self) {
         if self.is_meter_active {
             self.stop_meter();
@@ -107,6 +225,7 @@ impl SettingsWindow {
         }
         self.meter_device_name = None;
         *self.meter_level.lock().unwrap() = 0.0;
+        *self.meter_peak.lock().unwrap() = PeakHold::default();
     }
 
     // Allow stopping the meter from outside the UI safely
@@ -164,19 +283,25 @@ impl SettingsWindow {
         let sample_format = supported.sample_format();
         let config: cpal::StreamConfig = supported.config();
         let level = self.meter_level.clone();
+        let peak = self.meter_peak.clone();
         let stream = match sample_format {
             cpal::SampleFormat::I16 => device.build_input_stream(
                 &config,
                 move |data: &[i16], _| {
                     let mut sum = 0.0f32;
+                    let mut block_peak = 0.0f32;
                     for &s in data {
                         let f = s as f32 / 32768.0;
                         sum += f * f;
+                        block_peak = block_peak.max(f.abs());
                     }
                     let rms = (sum / (data.len().max(1) as f32)).sqrt();
                     if let Ok(mut l) = level.lock() {
                         *l = 0.85 * (*l) + 0.15 * rms;
                     }
+                    if let Ok(mut p) = peak.lock() {
+                        p.update(block_peak);
+                    }
                 },
                 move |_e| {},
                 None,
@@ -185,14 +310,19 @@ impl SettingsWindow {
                 &config,
                 move |data: &[u16], _| {
                     let mut sum = 0.0f32;
+                    let mut block_peak = 0.0f32;
                     for &s in data {
                         let f = (s as f32 - 32768.0) / 32768.0;
                         sum += f * f;
+                        block_peak = block_peak.max(f.abs());
                     }
                     let rms = (sum / (data.len().max(1) as f32)).sqrt();
                     if let Ok(mut l) = level.lock() {
                         *l = 0.85 * (*l) + 0.15 * rms;
                     }
+                    if let Ok(mut p) = peak.lock() {
+                        p.update(block_peak);
+                    }
                 },
                 move |_e| {},
                 None,
@@ -201,13 +331,18 @@ impl SettingsWindow {
                 &config,
                 move |data: &[f32], _| {
                     let mut sum = 0.0f32;
+                    let mut block_peak = 0.0f32;
                     for &f in data {
                         sum += f * f;
+                        block_peak = block_peak.max(f.abs());
                     }
                     let rms = (sum / (data.len().max(1) as f32)).sqrt();
                     if let Ok(mut l) = level.lock() {
                         *l = 0.85 * (*l) + 0.15 * rms;
                     }
+                    if let Ok(mut p) = peak.lock() {
+                        p.update(block_peak);
+                    }
                 },
                 move |_e| {},
                 None,
@@ -255,19 +390,25 @@ impl SettingsWindow {
         let sample_format = supported.sample_format();
         let config: cpal::StreamConfig = supported.config();
         let level = self.meter_level.clone();
+        let peak = self.meter_peak.clone();
         let stream = match sample_format {
             cpal::SampleFormat::I16 => device.build_input_stream(
                 &config,
                 move |data: &[i16], _| {
                     let mut sum = 0.0f32;
+                    let mut block_peak = 0.0f32;
                     for &s in data {
                         let f = s as f32 / 32768.0;
                         sum += f * f;
+                        block_peak = block_peak.max(f.abs());
                     }
                     let rms = (sum / (data.len().max(1) as f32)).sqrt();
                     if let Ok(mut l) = level.lock() {
                         *l = 0.85 * (*l) + 0.15 * rms;
                     }
+                    if let Ok(mut p) = peak.lock() {
+                        p.update(block_peak);
+                    }
                 },
                 move |_e| {},
                 None,
@@ -276,14 +417,19 @@ impl SettingsWindow {
                 &config,
                 move |data: &[u16], _| {
                     let mut sum = 0.0f32;
+                    let mut block_peak = 0.0f32;
                     for &s in data {
                         let f = (s as f32 - 32768.0) / 32768.0;
                         sum += f * f;
+                        block_peak = block_peak.max(f.abs());
                     }
                     let rms = (sum / (data.len().max(1) as f32)).sqrt();
                     if let Ok(mut l) = level.lock() {
                         *l = 0.85 * (*l) + 0.15 * rms;
                     }
+                    if let Ok(mut p) = peak.lock() {
+                        p.update(block_peak);
+                    }
                 },
                 move |_e| {},
                 None,
@@ -292,13 +438,18 @@ impl SettingsWindow {
                 &config,
                 move |data: &[f32], _| {
                     let mut sum = 0.0f32;
+                    let mut block_peak = 0.0f32;
                     for &f in data {
                         sum += f * f;
+                        block_peak = block_peak.max(f.abs());
                     }
                     let rms = (sum / (data.len().max(1) as f32)).sqrt();
                     if let Ok(mut l) = level.lock() {
                         *l = 0.85 * (*l) + 0.15 * rms;
                     }
+                    if let Ok(mut p) = peak.lock() {
+                        p.update(block_peak);
+                    }
                 },
                 move |_e| {},
                 None,
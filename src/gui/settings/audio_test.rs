@@ -1,6 +1,8 @@
 use super::SettingsWindow;
-use crate::audio::stream::build_input_stream_f32;
+use crate::audio::stream::{build_input_stream_f32, StreamStats};
+use crate::i18n;
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use eframe::egui;
 use hound::{SampleFormat as WavSampleFormat, WavSpec, WavWriter};
 use std::sync::{Arc, Mutex};
 
@@ -57,10 +59,19 @@ impl SettingsWindow {
         let buf: Arc<Mutex<Vec<f32>>> = Arc::new(Mutex::new(Vec::with_capacity(
             sr as usize * ch as usize * 60,
         )));
-        let stream = build_input_stream_f32(&device, config.clone(), sample_format, buf.clone())
-            .map_err(|e| format!("{:?}", e))?;
+        let stats = Arc::new(StreamStats::new(sr, ch));
+        let stream = build_input_stream_f32(
+            &device,
+            config.clone(),
+            sample_format,
+            buf.clone(),
+            stats.clone(),
+        )
+        .map_err(|e| format!("{:?}", e))?;
         stream.play().map_err(|e| e.to_string())?;
         self.test_stream = Some(stream);
+        self.test_stats = Some(stats);
+        self.test_report = None;
         self.test_buffer = Some(buf);
         self.test_sample_rate = sr;
         self.test_channels = ch;
@@ -74,6 +85,7 @@ impl SettingsWindow {
             // Pause first so OS releases the device promptly
             let _ = s.pause();
         }
+        self.test_report = self.test_stats.take().map(|stats| stats.report());
         let data = if let Some(buf) = self.test_buffer.take() {
             let guard = buf.lock().map_err(|_| "Failed to lock recording buffer")?;
             guard.clone()
@@ -114,4 +126,42 @@ impl SettingsWindow {
         writer.finalize().map_err(|e| e.to_string())?;
         Ok(())
     }
+
+    // Numbers from the last test recording, shown under the test button
+    pub(super) fn ui_test_recording_report(&self, ui: &mut egui::Ui) {
+        let Some(report) = &self.test_report else {
+            return;
+        };
+        let problems = report.stream_errors > 0 || report.dropouts > 0;
+        let counts = format!(
+            "{} {} · {} {}",
+            i18n::tr("label-test-stream-errors"),
+            report.stream_errors,
+            i18n::tr("label-test-dropouts"),
+            report.dropouts
+        );
+        if problems {
+            ui.colored_label(ui.visuals().warn_fg_color, counts)
+                .on_hover_text(i18n::tr("tooltip-test-dropouts"));
+        } else {
+            ui.label(counts);
+        }
+        let latency = match (report.avg_latency_ms, report.max_latency_ms) {
+            (Some(avg), Some(max)) => format!("{:.1} / {:.1} ms", avg, max),
+            _ => i18n::tr("label-test-not-reported"),
+        };
+        ui.label(format!("{} {}", i18n::tr("label-test-latency"), latency))
+            .on_hover_text(i18n::tr("tooltip-test-latency"));
+        let rate = match (report.measured_rate, report.drift_ppm()) {
+            (Some(rate), Some(ppm)) => format!(
+                "{:.0} Hz ({} {} Hz, {:+.0} ppm)",
+                rate,
+                i18n::tr("label-test-nominal"),
+                report.nominal_rate,
+                ppm
+            ),
+            _ => i18n::tr("label-test-too-short"),
+        };
+        ui.label(format!("{} {}", i18n::tr("label-test-sample-rate"), rate));
+    }
 }
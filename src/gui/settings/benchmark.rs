@@ -0,0 +1,119 @@
+use super::SettingsWindow;
+use crate::core::ModelBenchmark;
+use crate::i18n;
+use eframe::egui;
+
+impl SettingsWindow {
+    /// Button in the Model tab; the app runs the benchmark and reports back
+    pub(super) fn ui_benchmark_button(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            let button = ui
+                .add_enabled(
+                    !self.benchmark_running,
+                    egui::Button::new(i18n::tr("btn-run-benchmark")),
+                )
+                .on_hover_text(i18n::tr("tooltip-run-benchmark"));
+            if button.clicked() {
+                self.benchmark_requested = true;
+                self.benchmark_running = true;
+                self.benchmark_results = None;
+            }
+            if self.benchmark_running {
+                ui.spinner();
+            }
+        });
+    }
+
+    pub fn take_benchmark_request(&mut self) -> bool {
+        std::mem::take(&mut self.benchmark_requested)
+    }
+
+    pub fn set_benchmark_results(&mut self, results: Result<Vec<ModelBenchmark>, String>) {
+        self.benchmark_running = false;
+        self.benchmark_results = Some(results);
+    }
+
+    // One row per downloaded model: load time, transcription time, speed, accuracy
+    pub(super) fn ui_benchmark_window(&mut self, ctx: &egui::Context) {
+        if !self.benchmark_running && self.benchmark_results.is_none() {
+            return;
+        }
+        let mut open = true;
+        let mut window = egui::Window::new(i18n::tr("title-benchmark"));
+        // No close button until the run finishes
+        if self.benchmark_results.is_some() {
+            window = window.open(&mut open);
+        }
+        window
+            .default_width(560.0)
+            .resizable(true)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                let results = match &self.benchmark_results {
+                    None => {
+                        ui.ctx()
+                            .request_repaint_after(std::time::Duration::from_millis(200));
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label(i18n::tr("msg-benchmark-running"));
+                        });
+                        return;
+                    }
+                    Some(Err(err)) => {
+                        ui.colored_label(ui.visuals().error_fg_color, err);
+                        return;
+                    }
+                    Some(Ok(results)) if results.is_empty() => {
+                        ui.label(i18n::tr("msg-benchmark-no-models"));
+                        return;
+                    }
+                    Some(Ok(results)) => results,
+                };
+                egui::Grid::new("benchmark_grid")
+                    .num_columns(5)
+                    .striped(true)
+                    .spacing(egui::vec2(12.0, 6.0))
+                    .show(ui, |ui| {
+                        for key in [
+                            "label-benchmark-model",
+                            "label-benchmark-load",
+                            "label-benchmark-transcribe",
+                            "label-benchmark-speed",
+                            "label-benchmark-accuracy",
+                        ] {
+                            ui.label(egui::RichText::new(i18n::tr(key)).strong());
+                        }
+                        ui.end_row();
+                        for bench in results {
+                            ui.label(i18n::tr(bench.label_key))
+                                .on_hover_text(bench.filename);
+                            match &bench.outcome {
+                                Ok(run) => {
+                                    ui.label(format!("{:.1}s", run.load.as_secs_f32()));
+                                    ui.label(format!("{:.1}s", run.transcribe.as_secs_f32()));
+                                    ui.label(format!("{:.1}×", run.real_time_factor));
+                                    let accuracy = run
+                                        .accuracy
+                                        .map(|a| format!("{:.0}%", a * 100.0))
+                                        .unwrap_or_else(|| "—".to_string());
+                                    ui.label(accuracy).on_hover_text(&run.text);
+                                }
+                                Err(err) => {
+                                    ui.colored_label(ui.visuals().error_fg_color, err);
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+                ui.add_space(6.0);
+                ui.label(
+                    egui::RichText::new(i18n::tr("hint-benchmark"))
+                        .small()
+                        .color(ui.visuals().weak_text_color()),
+                );
+            });
+        if !open {
+            self.benchmark_results = None;
+        }
+    }
+}
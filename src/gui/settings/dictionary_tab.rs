@@ -1,11 +1,16 @@
 use eframe::egui;
 
-use crate::dictionary::{save_dictionary, DictionaryEntry};
+use crate::dictionary::{
+    find_conflicts, merge_into_first, save_dictionary, DictionaryConflict, DictionaryEntry,
+};
 use lucide_icons::Icon;
 
 use super::SettingsWindow;
 use crate::i18n;
 
+// Language chips offered in the entry editor (same set as the Whisper language list)
+const DICT_LOCALES: &[&str] = &["ja", "en", "zh", "es", "fr", "de", "ko", "pt", "ru", "hi"];
+
 fn entry_matches_filter(entry: &DictionaryEntry, query: &str) -> bool {
     let q = query.trim();
     if q.is_empty() {
@@ -24,6 +29,68 @@ fn entry_matches_filter(entry: &DictionaryEntry, query: &str) -> bool {
     false
 }
 
+/// Editor field that should receive keyboard focus on the next frame
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DictEditorFocus {
+    Canonical,
+    Alias(usize),
+}
+
+// Single-line TextEdit gives up focus on Enter; treat that as "submit"
+fn enter_pressed(ui: &egui::Ui, resp: &egui::Response) -> bool {
+    resp.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter))
+}
+
+// One line per conflict: `"alias" → A / B` or `"Canonical" ×2`
+fn conflict_text(entries: &[DictionaryEntry], conflict: &DictionaryConflict) -> String {
+    match conflict {
+        DictionaryConflict::AmbiguousAlias {
+            alias,
+            entries: rows,
+        } => {
+            let targets: Vec<&str> = rows
+                .iter()
+                .filter_map(|&i| entries.get(i))
+                .map(|e| e.canonical.as_str())
+                .collect();
+            format!(
+                "{} \"{}\" → {}",
+                i18n::tr("label-dict-conflict-alias"),
+                alias,
+                targets.join(" / ")
+            )
+        }
+        DictionaryConflict::DuplicateCanonical {
+            canonical,
+            entries: rows,
+        } => format!(
+            "{} \"{}\" ×{}",
+            i18n::tr("label-dict-conflict-canonical"),
+            canonical,
+            rows.len()
+        ),
+    }
+}
+
+// Hover text for the row marker of an entry involved in any conflict
+fn conflict_detail(conflicts: &[DictionaryConflict], index: usize) -> Option<String> {
+    let lines: Vec<String> = conflicts
+        .iter()
+        .filter(|c| c.entries().contains(&index))
+        .map(|c| match c {
+            DictionaryConflict::AmbiguousAlias { alias, .. } => {
+                format!("{} \"{}\"", i18n::tr("label-dict-conflict-alias"), alias)
+            }
+            DictionaryConflict::DuplicateCanonical { canonical, .. } => format!(
+                "{} \"{}\"",
+                i18n::tr("label-dict-conflict-canonical"),
+                canonical
+            ),
+        })
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
 impl SettingsWindow {
     pub(crate) fn ui_dictionary_section(&mut self, ui: &mut egui::Ui) {
         let strong = ui.visuals().strong_text_color();
@@ -81,6 +148,7 @@ impl SettingsWindow {
                                 self.dict_dirty = false;
                                 self.save_status_message = Some(i18n::tr("msg-dict-reloaded"));
                                 self.pending_apply_dictionary = true; // update core as well
+                                self.refresh_dict_conflicts();
                             }
                             Err(e) => {
                                 self.save_status_message =
@@ -91,8 +159,35 @@ impl SettingsWindow {
                     if ui.button(i18n::tr("btn-add-entry")).clicked() {
                         self.open_dict_editor_new();
                     }
+                    if ui.button(i18n::tr("btn-dict-import-csv")).clicked() {
+                        self.import_dictionary_csv();
+                    }
+                    if ui.button(i18n::tr("btn-dict-export-csv")).clicked() {
+                        self.export_dictionary_csv();
+                    }
                 });
+                if let Some(msg) = &self.dict_csv_message {
+                    ui.add_space(4.0);
+                    ui.label(msg);
+                }
+                self.ui_dictionary_reapply(ui);
+
+                ui.add_space(8.0);
 
+                // Edit budget for entries with fuzzy matching enabled
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("label-dict-fuzzy-distance"));
+                    if ui
+                        .add(egui::Slider::new(
+                            &mut self.settings.dictionary_fuzzy_max_distance,
+                            1..=3,
+                        ))
+                        .on_hover_text(i18n::tr("tooltip-dict-fuzzy-distance"))
+                        .changed()
+                    {
+                        self.check_changes();
+                    }
+                });
                 ui.add_space(8.0);
 
                 // Search filter (magnifier icon + hint)
@@ -116,19 +211,29 @@ impl SettingsWindow {
                     }
                 });
                 ui.add_space(6.0);
+                self.ui_dict_conflicts(ui);
 
                 // Grid header
                 let mut edit_to_open: Option<usize> = None;
                 let mut delete_index: Option<usize> = None;
+                let mut toggle_index: Option<usize> = None;
                 // Adjust alias column width (~2/3 of previous) + new Include column (~1/3)
                 let base = (ui.available_width() * 0.275).max(180.0);
                 let alias_col_width = (base * (2.0 / 3.0)).max(140.0);
                 let cond_col_width = (base * (1.0 / 3.0)).max(120.0);
                 egui::Grid::new("dict_grid")
-                    .num_columns(4)
+                    .num_columns(5)
                     .spacing(egui::vec2(10.0, 6.0))
                     .striped(true)
                     .show(ui, |ui| {
+                        ui.allocate_ui_with_layout(
+                            egui::vec2(0.0, 0.0),
+                            egui::Layout::left_to_right(egui::Align::Center),
+                            |ui| {
+                                ui.strong(i18n::tr("col-enabled"))
+                                    .on_hover_text(i18n::tr("tooltip-dict-enabled"));
+                            },
+                        );
                         // Header row: vertically centered
                         ui.allocate_ui_with_layout(
                             egui::vec2(0.0, 0.0),
@@ -167,12 +272,53 @@ impl SettingsWindow {
                             if !entry_matches_filter(entry, &self.dict_filter_text) {
                                 continue;
                             }
+                            ui.allocate_ui_with_layout(
+                                egui::vec2(0.0, 0.0),
+                                egui::Layout::left_to_right(egui::Align::Center),
+                                |ui| {
+                                    let mut enabled = entry.enabled;
+                                    if ui
+                                        .checkbox(&mut enabled, "")
+                                        .on_hover_text(i18n::tr("tooltip-dict-enabled"))
+                                        .changed()
+                                    {
+                                        toggle_index = Some(i);
+                                    }
+                                },
+                            );
                             // Canonical: vertically centered
                             ui.allocate_ui_with_layout(
                                 egui::vec2(0.0, 0.0),
                                 egui::Layout::left_to_right(egui::Align::Center),
                                 |ui| {
-                                    ui.label(entry.canonical.as_str());
+                                    if entry.enabled {
+                                        ui.label(entry.canonical.as_str());
+                                    } else {
+                                        ui.label(
+                                            egui::RichText::new(entry.canonical.as_str())
+                                                .strikethrough()
+                                                .color(ui.visuals().weak_text_color()),
+                                        );
+                                    }
+                                    if let Some(detail) = conflict_detail(&self.dict_conflicts, i) {
+                                        ui.colored_label(ui.visuals().warn_fg_color, "⚠")
+                                            .on_hover_text(detail);
+                                    }
+                                    if entry.fuzzy {
+                                        ui.label(
+                                            egui::RichText::new("≈")
+                                                .color(ui.visuals().weak_text_color()),
+                                        )
+                                        .on_hover_text(i18n::tr("chk-dict-fuzzy"));
+                                    }
+                                    if !entry.locales.is_empty() {
+                                        ui.label(
+                                            egui::RichText::new(entry.locales.join(","))
+                                                .small()
+                                                .color(ui.visuals().weak_text_color()),
+                                        )
+                                        .on_hover_text(i18n::tr("label-dict-locales"));
+                                    }
                                 },
                             );
                             let alias_preview = if entry.aliases.is_empty() {
@@ -226,6 +372,18 @@ impl SettingsWindow {
                     });
 
                 // Apply row actions after the grid borrow ends
+                if let Some(idx) = toggle_index {
+                    if let Some(entry) = self.dict_entries.get_mut(idx) {
+                        entry.enabled = !entry.enabled;
+                    }
+                    if let Err(e) = save_dictionary(&self.dict_entries) {
+                        self.save_status_message =
+                            Some(format!("{} {}", i18n::tr("msg-dict-save-failed"), e));
+                    } else {
+                        self.pending_apply_dictionary = true;
+                    }
+                    self.refresh_dict_conflicts();
+                }
                 if let Some(idx) = delete_index {
                     self.dict_entries.remove(idx);
                     if let Err(e) = save_dictionary(&self.dict_entries) {
@@ -235,6 +393,7 @@ impl SettingsWindow {
                         self.save_status_message = Some(i18n::tr("msg-dict-entry-deleted"));
                         self.pending_apply_dictionary = true; // apply to core
                     }
+                    self.refresh_dict_conflicts();
                 }
                 if let Some(i) = edit_to_open {
                     self.open_dict_editor_edit(i);
@@ -257,14 +416,28 @@ impl SettingsWindow {
                         .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
                         .default_size(egui::vec2(420.0, 340.0))
                         .show(ui.ctx(), |ui| {
+                            let can_save = !self.dict_editor_canonical.trim().is_empty();
+                            // Esc cancels from anywhere in the dialog
+                            if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                                action = Some(EditorAction::Cancel);
+                            }
+                            let focus = self.dict_editor_focus.take();
                             ui.label(i18n::tr("label-standard"));
-                            ui.add(
+                            let resp = ui.add(
                                 egui::TextEdit::singleline(&mut self.dict_editor_canonical)
                                     .desired_width(360.0),
                             );
+                            if focus == Some(DictEditorFocus::Canonical) {
+                                resp.request_focus();
+                            }
+                            if enter_pressed(ui, &resp) && can_save {
+                                action = Some(EditorAction::Save);
+                            }
                             ui.add_space(6.0);
                             ui.label(i18n::tr("label-aliases"));
                             let mut alias_to_remove: Option<usize> = None;
+                            let mut next_focus: Option<DictEditorFocus> = None;
+                            let alias_count = self.dict_editor_aliases.len();
                             egui::ScrollArea::vertical()
                                 .id_salt("dict_aliases_scroll")
                                 .max_height(220.0)
@@ -273,10 +446,23 @@ impl SettingsWindow {
                                         self.dict_editor_aliases.iter_mut().enumerate()
                                     {
                                         ui.horizontal(|ui| {
-                                            ui.add(
+                                            let resp = ui.add(
                                                 egui::TextEdit::singleline(alias)
                                                     .desired_width(360.0),
                                             );
+                                            if focus == Some(DictEditorFocus::Alias(j)) {
+                                                resp.request_focus();
+                                                resp.scroll_to_me(None);
+                                            }
+                                            // Enter moves to the next alias; on the last one it saves
+                                            if enter_pressed(ui, &resp) {
+                                                if j + 1 < alias_count {
+                                                    next_focus =
+                                                        Some(DictEditorFocus::Alias(j + 1));
+                                                } else if can_save {
+                                                    action = Some(EditorAction::Save);
+                                                }
+                                            }
                                             if ui
                                                 .add(
                                                     egui::Button::new(i18n::tr("btn-delete"))
@@ -294,7 +480,35 @@ impl SettingsWindow {
                             }
                             if ui.button(i18n::tr("btn-add-alias")).clicked() {
                                 self.dict_editor_aliases.push(String::new());
+                                next_focus = Some(DictEditorFocus::Alias(
+                                    self.dict_editor_aliases.len() - 1,
+                                ));
                             }
+                            ui.checkbox(&mut self.dict_editor_fuzzy, i18n::tr("chk-dict-fuzzy"))
+                                .on_hover_text(i18n::tr("tooltip-dict-fuzzy"));
+                            self.dict_editor_focus = next_focus;
+                            ui.add_space(10.0);
+
+                            // Locales (apply only when the transcription language matches)
+                            ui.strong(i18n::tr("label-dict-locales"));
+                            ui.small(
+                                egui::RichText::new(i18n::tr("hint-dict-locales"))
+                                    .color(ui.visuals().weak_text_color()),
+                            );
+                            ui.horizontal_wrapped(|ui| {
+                                for code in DICT_LOCALES {
+                                    let pos =
+                                        self.dict_editor_locales.iter().position(|l| l == code);
+                                    if ui.selectable_label(pos.is_some(), *code).clicked() {
+                                        match pos {
+                                            Some(i) => {
+                                                self.dict_editor_locales.remove(i);
+                                            }
+                                            None => self.dict_editor_locales.push(code.to_string()),
+                                        }
+                                    }
+                                }
+                            });
                             ui.add_space(10.0);
 
                             // Include (apply only when input text contains any of these)
@@ -346,8 +560,6 @@ impl SettingsWindow {
                                         style.spacing.item_spacing.x = 8.0;
                                         ui.set_style(style);
 
-                                        let can_save =
-                                            !self.dict_editor_canonical.trim().is_empty();
                                         // Right: Save (prominent)
                                         let save_label =
                                             egui::RichText::new(i18n::tr("btn-save-apply"))
@@ -393,8 +605,136 @@ impl SettingsWindow {
             });
     }
 
+    pub(crate) fn refresh_dict_conflicts(&mut self) {
+        self.dict_conflicts = find_conflicts(&self.dict_entries);
+    }
+
+    // Warning list above the grid; duplicate canonicals can be merged in place
+    fn ui_dict_conflicts(&mut self, ui: &mut egui::Ui) {
+        if self.dict_conflicts.is_empty() {
+            return;
+        }
+        let warn = ui.visuals().warn_fg_color;
+        ui.colored_label(warn, i18n::tr("msg-dict-conflicts"));
+        let mut merge: Option<Vec<usize>> = None;
+        for conflict in &self.dict_conflicts {
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(conflict_text(&self.dict_entries, conflict)).color(warn),
+                );
+                if let DictionaryConflict::DuplicateCanonical { entries, .. } = conflict {
+                    if ui
+                        .small_button(i18n::tr("btn-dict-merge"))
+                        .on_hover_text(i18n::tr("tooltip-dict-merge"))
+                        .clicked()
+                    {
+                        merge = Some(entries.clone());
+                    }
+                }
+            });
+        }
+        ui.add_space(6.0);
+        if let Some(indices) = merge {
+            merge_into_first(&mut self.dict_entries, &indices);
+            self.refresh_dict_conflicts();
+            match save_dictionary(&self.dict_entries) {
+                Ok(()) => {
+                    self.save_status_message = Some(i18n::tr("msg-dict-saved"));
+                    self.pending_apply_dictionary = true;
+                }
+                Err(e) => {
+                    self.save_status_message =
+                        Some(format!("{} {}", i18n::tr("msg-dict-save-failed"), e));
+                }
+            }
+        }
+    }
+
+    // Re-run the (saved) dictionary on the last transcript to check an edit
+    fn ui_dictionary_reapply(&mut self, ui: &mut egui::Ui) {
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            let available = self.last_transcript_available;
+            let button = ui
+                .add_enabled(
+                    available,
+                    egui::Button::new(i18n::tr("btn-dict-reapply-last")),
+                )
+                .on_hover_text(i18n::tr("tooltip-dict-reapply-last"))
+                .on_disabled_hover_text(i18n::tr("tooltip-dict-reapply-unavailable"));
+            if button.clicked() {
+                let with_llm = self.dict_reapply_with_llm && self.settings.llm_postprocess.enabled;
+                self.dict_reapply_requested = Some(with_llm);
+            }
+            if self.settings.llm_postprocess.enabled {
+                ui.checkbox(
+                    &mut self.dict_reapply_with_llm,
+                    i18n::tr("chk-dict-reapply-llm"),
+                );
+            }
+        });
+    }
+
+    fn import_dictionary_csv(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("CSV", &["csv"])
+            .pick_file()
+        else {
+            return;
+        };
+        match crate::dictionary::import_csv(&mut self.dict_entries, &path) {
+            Ok(summary) => {
+                if let Err(e) = save_dictionary(&self.dict_entries) {
+                    self.dict_csv_message =
+                        Some(format!("{} {}", i18n::tr("msg-dict-save-failed"), e));
+                    return;
+                }
+                self.pending_apply_dictionary = true;
+                self.refresh_dict_conflicts();
+                let mut msg = format!(
+                    "{} {} / {} {}",
+                    i18n::tr("msg-dict-import-added"),
+                    summary.added,
+                    i18n::tr("msg-dict-import-merged"),
+                    summary.merged
+                );
+                if summary.skipped > 0 {
+                    msg.push_str(&format!(
+                        " / {} {}",
+                        i18n::tr("msg-dict-import-skipped"),
+                        summary.skipped
+                    ));
+                }
+                self.dict_csv_message = Some(msg);
+            }
+            Err(e) => {
+                self.dict_csv_message =
+                    Some(format!("{} {}", i18n::tr("msg-dict-import-failed"), e));
+            }
+        }
+    }
+
+    fn export_dictionary_csv(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("CSV", &["csv"])
+            .set_file_name("dictionary.csv")
+            .save_file()
+        else {
+            return;
+        };
+        self.dict_csv_message = Some(
+            match crate::dictionary::export_csv(&self.dict_entries, &path) {
+                Ok(()) => format!("{} {}", i18n::tr("msg-dict-exported"), path.display()),
+                Err(e) => format!("{} {}", i18n::tr("msg-dict-export-failed"), e),
+            },
+        );
+    }
+
     pub(crate) fn open_dict_editor_new(&mut self) {
         self.dict_editor_open = true;
+        self.dict_editor_focus = Some(DictEditorFocus::Canonical);
+        self.dict_editor_fuzzy = false;
+        self.dict_editor_locales.clear();
         self.dict_editor_edit_index = None;
         self.dict_editor_canonical.clear();
         // For a new entry, start with a single alias input row
@@ -414,11 +754,15 @@ impl SettingsWindow {
                 self.dict_editor_aliases.push(String::new());
             }
             self.dict_editor_includes = e.include.clone();
+            self.dict_editor_fuzzy = e.fuzzy;
+            self.dict_editor_locales = e.locales.clone();
         } else {
             self.dict_editor_canonical.clear();
             self.dict_editor_aliases.clear();
             self.dict_editor_aliases.push(String::new());
             self.dict_editor_includes.clear();
+            self.dict_editor_fuzzy = false;
+            self.dict_editor_locales.clear();
         }
     }
 
@@ -444,10 +788,18 @@ impl SettingsWindow {
         let mut seen2 = std::collections::HashSet::new();
         include.retain(|a| seen2.insert(a.clone()));
 
+        // Editing keeps the entry's on/off state; new entries start enabled
+        let enabled = self
+            .dict_editor_edit_index
+            .and_then(|i| self.dict_entries.get(i))
+            .is_none_or(|e| e.enabled);
         let new_entry = DictionaryEntry {
             canonical,
             aliases,
             include,
+            fuzzy: self.dict_editor_fuzzy,
+            locales: self.dict_editor_locales.clone(),
+            enabled,
         };
         match self.dict_editor_edit_index {
             Some(i) => {
@@ -459,6 +811,7 @@ impl SettingsWindow {
                 self.dict_entries.push(new_entry);
             }
         }
+        self.refresh_dict_conflicts();
         // persist and apply
         match save_dictionary(&self.dict_entries) {
             Ok(()) => {
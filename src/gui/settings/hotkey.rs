@@ -2,7 +2,11 @@ use eframe::egui;
 // use lucide icons in tabs; content headings remain plain
 
 use super::SettingsWindow;
+use crate::hotkey::{
+    find_conflicts, format_hotkey, parse_hotkey, HotkeyAction, HotkeyBinding, HotkeyMode,
+};
 use crate::i18n;
+use crate::llm::{PRESET_ID_FORMAT, PRESET_ID_FURIGANA, PRESET_ID_SUMMARY};
 
 impl SettingsWindow {
     pub(super) fn ui_hotkey_section(&mut self, ui: &mut egui::Ui) {
@@ -39,10 +43,81 @@ impl SettingsWindow {
                         .inner;
 
                     if text_edit.changed() {
-                        self.settings.hotkey_recording = self.hotkey_input.clone();
+                        self.apply_hotkey_input();
+                    }
+                    let capture_label = if self.hotkey_capturing {
+                        i18n::tr("btn-hotkey-capture-cancel")
+                    } else {
+                        i18n::tr("btn-hotkey-capture")
+                    };
+                    if ui
+                        .button(capture_label)
+                        .on_hover_text(i18n::tr("tooltip-hotkey-capture"))
+                        .clicked()
+                    {
+                        self.hotkey_capturing = !self.hotkey_capturing;
+                        self.hotkey_capture_error = None;
+                        // Typed keys must not reach the text field while capturing
+                        ui.memory_mut(|m| m.stop_text_input());
+                    }
+                });
+                if self.hotkey_capturing {
+                    self.ui_hotkey_capture(ui);
+                } else if parse_hotkey(&self.hotkey_input).is_err() {
+                    ui.colored_label(
+                        egui::Color32::from_rgb(220, 53, 69),
+                        i18n::tr("msg-hotkey-invalid"),
+                    );
+                }
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("label-hotkey-mode"));
+                    ui.add_space(10.0);
+                    let display = match self.settings.hotkey_mode {
+                        HotkeyMode::Toggle => i18n::tr("option-hotkey-toggle"),
+                        HotkeyMode::PushToTalk => i18n::tr("option-hotkey-push-to-talk"),
+                    };
+                    let mut changed = false;
+                    egui::ComboBox::from_id_salt("hotkey_mode_combo")
+                        .selected_text(display)
+                        .show_ui(ui, |ui| {
+                            changed |= ui
+                                .selectable_value(
+                                    &mut self.settings.hotkey_mode,
+                                    HotkeyMode::Toggle,
+                                    i18n::tr("option-hotkey-toggle"),
+                                )
+                                .changed();
+                            changed |= ui
+                                .selectable_value(
+                                    &mut self.settings.hotkey_mode,
+                                    HotkeyMode::PushToTalk,
+                                    i18n::tr("option-hotkey-push-to-talk"),
+                                )
+                                .changed();
+                        });
+                    if changed {
+                        self.check_changes();
+                    }
+                });
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("label-hotkey-debounce"));
+                    ui.add_space(10.0);
+                    if ui
+                        .add(
+                            egui::Slider::new(&mut self.settings.hotkey_debounce_ms, 0..=1000)
+                                .step_by(10.0)
+                                .suffix(" ms"),
+                        )
+                        .on_hover_text(i18n::tr("tooltip-hotkey-debounce"))
+                        .changed()
+                    {
                         self.check_changes();
                     }
                 });
+                ui.add_space(6.0);
+                self.ui_hotkey_bindings(ui);
                 // Input help (subtle, slightly larger)
                 ui.add_space(6.0);
                 let help_color = ui.visuals().weak_text_color();
@@ -73,8 +148,18 @@ impl SettingsWindow {
                         .size(help_size)
                         .color(help_color),
                 );
+                if self.settings.hotkey_mode == HotkeyMode::PushToTalk {
+                    ui.label(
+                        egui::RichText::new(i18n::tr("hotkey-push-to-talk-note"))
+                            .size(help_size)
+                            .color(help_color),
+                    );
+                }
                 // Note: changing hotkey may require app restart (especially on macOS)
-                if self.settings.hotkey_recording != self.original_settings.hotkey_recording {
+                if self.settings.hotkey_recording != self.original_settings.hotkey_recording
+                    || self.settings.hotkey_mode != self.original_settings.hotkey_mode
+                    || self.settings.hotkey_bindings != self.original_settings.hotkey_bindings
+                {
                     ui.add_space(4.0);
                     ui.label(
                         egui::RichText::new(i18n::tr("hotkey-restart-note"))
@@ -84,6 +169,30 @@ impl SettingsWindow {
                 }
             });
 
+        // External control without hotkeys (Waybar, scripts)
+        #[cfg(unix)]
+        {
+            ui.add_space(6.0);
+            if ui
+                .checkbox(&mut self.settings.ipc_socket, i18n::tr("chk-ipc-socket"))
+                .on_hover_text(format!(
+                    "{}\n{}",
+                    i18n::tr("tooltip-ipc-socket"),
+                    crate::utils::ipc::socket_path().display()
+                ))
+                .changed()
+            {
+                self.check_changes();
+            }
+            if self.settings.ipc_socket != self.original_settings.ipc_socket {
+                ui.label(
+                    egui::RichText::new(i18n::tr("note-ipc-socket-restart"))
+                        .small()
+                        .color(ui.visuals().weak_text_color()),
+                );
+            }
+        }
+
         // OS-specific troubleshooting (collapsible)
         ui.add_space(6.0);
         self.ui_hotkey_troubleshoot(ui);
@@ -91,6 +200,240 @@ impl SettingsWindow {
 }
 
 impl SettingsWindow {
+    pub fn is_capturing_hotkey(&self) -> bool {
+        self.hotkey_capturing
+    }
+
+    // `hotkey_input` becomes the recording hotkey
+    fn apply_hotkey_input(&mut self) {
+        self.settings.hotkey_recording = self.hotkey_input.clone();
+        // Keep the primary binding in sync with the legacy field
+        if let Some(first) = self.settings.hotkey_bindings.first_mut() {
+            if first.action == HotkeyAction::ToggleRecord {
+                first.hotkey = self.hotkey_input.clone();
+            }
+        }
+        self.check_changes();
+    }
+
+    // Live preview of held modifiers; the next key press (Escape cancels) becomes
+    // the hotkey if it can be registered
+    fn ui_hotkey_capture(&mut self, ui: &mut egui::Ui) {
+        let (held, pressed) = ui.input_mut(|i| {
+            let pressed = i.events.iter().find_map(|e| match e {
+                egui::Event::Key {
+                    key,
+                    pressed: true,
+                    modifiers,
+                    ..
+                } => Some((*key, *modifiers)),
+                _ => None,
+            });
+            // Nothing else (tab shortcuts, text fields) sees the captured keys
+            i.events
+                .retain(|e| !matches!(e, egui::Event::Key { .. } | egui::Event::Text(_)));
+            (i.modifiers, pressed)
+        });
+        match pressed {
+            Some((egui::Key::Escape, m)) if m.is_none() => {
+                self.hotkey_capturing = false;
+                self.hotkey_capture_error = None;
+            }
+            Some((key, m)) => match format_hotkey(m.ctrl, m.alt, m.shift, m.mac_cmd, key.name()) {
+                Ok(hotkey) => {
+                    self.hotkey_input = hotkey;
+                    self.hotkey_capturing = false;
+                    self.hotkey_capture_error = None;
+                    self.apply_hotkey_input();
+                }
+                Err(e) => self.hotkey_capture_error = Some(e.to_string()),
+            },
+            None => {}
+        }
+        if !self.hotkey_capturing {
+            return;
+        }
+        let mut preview: Vec<&str> = [
+            (held.ctrl, "Ctrl"),
+            (held.alt, "Alt"),
+            (held.shift, "Shift"),
+            (held.mac_cmd, "Cmd"),
+        ]
+        .into_iter()
+        .filter_map(|(down, name)| down.then_some(name))
+        .collect();
+        preview.push("…");
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-hotkey-capture"));
+            ui.label(egui::RichText::new(preview.join("+")).monospace().strong());
+        });
+        if let Some(err) = &self.hotkey_capture_error {
+            ui.colored_label(
+                ui.visuals().warn_fg_color,
+                format!("{} ({})", i18n::tr("msg-hotkey-capture-invalid"), err),
+            );
+        }
+    }
+
+    // Extra hotkeys (entries after the primary toggle)
+    fn ui_hotkey_bindings(&mut self, ui: &mut egui::Ui) {
+        ui.label(i18n::tr("label-hotkey-bindings"));
+        let mut modes: Vec<(String, String)> = vec![
+            (PRESET_ID_FORMAT.to_string(), i18n::tr("llm-mode-format")),
+            (PRESET_ID_SUMMARY.to_string(), i18n::tr("llm-mode-summary")),
+            (
+                PRESET_ID_FURIGANA.to_string(),
+                i18n::tr("llm-mode-furigana"),
+            ),
+        ];
+        for mode in &self.settings.llm_postprocess.custom_prompts {
+            modes.push((mode.id.clone(), mode.name.clone()));
+        }
+        let conflicts = find_conflicts(&self.settings.hotkey_bindings);
+        let mut changed = false;
+        let mut remove: Option<usize> = None;
+        for (idx, binding) in self.settings.hotkey_bindings.iter_mut().enumerate().skip(1) {
+            ui.horizontal(|ui| {
+                if ui
+                    .add(egui::TextEdit::singleline(&mut binding.hotkey).desired_width(140.0))
+                    .changed()
+                {
+                    changed = true;
+                }
+                let action_label = match &binding.action {
+                    HotkeyAction::ToggleRecord => i18n::tr("option-hotkey-action-toggle"),
+                    HotkeyAction::ToggleRecordWithMode(_) => {
+                        i18n::tr("option-hotkey-action-toggle-mode")
+                    }
+                    HotkeyAction::ShowSettings => i18n::tr("option-hotkey-action-settings"),
+                    HotkeyAction::TogglePause => i18n::tr("option-hotkey-action-pause"),
+                    HotkeyAction::UndoPaste => i18n::tr("option-hotkey-action-undo-paste"),
+                    HotkeyAction::PasteLast => i18n::tr("option-hotkey-action-paste-last"),
+                };
+                egui::ComboBox::from_id_salt(("hotkey_action_combo", idx))
+                    .selected_text(action_label)
+                    .show_ui(ui, |ui| {
+                        if ui
+                            .selectable_label(
+                                binding.action == HotkeyAction::ToggleRecord,
+                                i18n::tr("option-hotkey-action-toggle"),
+                            )
+                            .clicked()
+                        {
+                            binding.action = HotkeyAction::ToggleRecord;
+                            changed = true;
+                        }
+                        if ui
+                            .selectable_label(
+                                matches!(binding.action, HotkeyAction::ToggleRecordWithMode(_)),
+                                i18n::tr("option-hotkey-action-toggle-mode"),
+                            )
+                            .clicked()
+                            && !matches!(binding.action, HotkeyAction::ToggleRecordWithMode(_))
+                        {
+                            binding.action =
+                                HotkeyAction::ToggleRecordWithMode(PRESET_ID_SUMMARY.to_string());
+                            changed = true;
+                        }
+                        if ui
+                            .selectable_label(
+                                binding.action == HotkeyAction::ShowSettings,
+                                i18n::tr("option-hotkey-action-settings"),
+                            )
+                            .clicked()
+                        {
+                            binding.action = HotkeyAction::ShowSettings;
+                            changed = true;
+                        }
+                        if ui
+                            .selectable_label(
+                                binding.action == HotkeyAction::TogglePause,
+                                i18n::tr("option-hotkey-action-pause"),
+                            )
+                            .clicked()
+                        {
+                            binding.action = HotkeyAction::TogglePause;
+                            changed = true;
+                        }
+                        if ui
+                            .selectable_label(
+                                binding.action == HotkeyAction::UndoPaste,
+                                i18n::tr("option-hotkey-action-undo-paste"),
+                            )
+                            .clicked()
+                        {
+                            binding.action = HotkeyAction::UndoPaste;
+                            changed = true;
+                        }
+                        if ui
+                            .selectable_label(
+                                binding.action == HotkeyAction::PasteLast,
+                                i18n::tr("option-hotkey-action-paste-last"),
+                            )
+                            .clicked()
+                        {
+                            binding.action = HotkeyAction::PasteLast;
+                            changed = true;
+                        }
+                    });
+                if let HotkeyAction::ToggleRecordWithMode(mode_id) = &mut binding.action {
+                    let current = modes
+                        .iter()
+                        .find(|(id, _)| id == mode_id)
+                        .map(|(_, label)| label.clone())
+                        .unwrap_or_else(|| mode_id.clone());
+                    egui::ComboBox::from_id_salt(("hotkey_mode_id_combo", idx))
+                        .selected_text(current)
+                        .show_ui(ui, |ui| {
+                            for (id, label) in &modes {
+                                if ui
+                                    .selectable_label(*mode_id == *id, label.as_str())
+                                    .clicked()
+                                {
+                                    *mode_id = id.clone();
+                                    changed = true;
+                                }
+                            }
+                        });
+                }
+                if ui.button(i18n::tr("btn-delete")).clicked() {
+                    remove = Some(idx);
+                }
+            });
+            if parse_hotkey(&binding.hotkey).is_err() {
+                ui.colored_label(
+                    egui::Color32::from_rgb(220, 53, 69),
+                    i18n::tr("msg-hotkey-invalid"),
+                );
+            } else if conflicts.contains(&idx) {
+                ui.colored_label(
+                    egui::Color32::from_rgb(220, 53, 69),
+                    i18n::tr("msg-hotkey-conflict"),
+                );
+            }
+        }
+        if conflicts.contains(&0) && self.settings.hotkey_bindings.len() > 1 {
+            ui.colored_label(
+                egui::Color32::from_rgb(220, 53, 69),
+                i18n::tr("msg-hotkey-conflict-primary"),
+            );
+        }
+        if let Some(idx) = remove {
+            self.settings.hotkey_bindings.remove(idx);
+            changed = true;
+        }
+        if ui.button(i18n::tr("btn-add-hotkey")).clicked() {
+            self.settings.hotkey_bindings.push(HotkeyBinding {
+                hotkey: String::new(),
+                action: HotkeyAction::ToggleRecordWithMode(PRESET_ID_SUMMARY.to_string()),
+            });
+            changed = true;
+        }
+        if changed {
+            self.check_changes();
+        }
+    }
+
     // OS-specific troubleshooting for hotkeys (collapsible)
     fn ui_hotkey_troubleshoot(&mut self, ui: &mut egui::Ui) {
         let header = egui::RichText::new(i18n::tr("troubleshoot-hotkey-title")).strong();
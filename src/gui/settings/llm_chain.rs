@@ -0,0 +1,117 @@
+use super::SettingsWindow;
+use crate::i18n;
+use crate::llm::{PRESET_ID_FORMAT, PRESET_ID_SUMMARY};
+use eframe::egui;
+
+impl SettingsWindow {
+    /// New chain (format, then summarize) selected as the current mode
+    pub(super) fn create_llm_chain(&mut self) -> String {
+        let id = self.settings.llm_postprocess.create_chain_mode(
+            &i18n::tr("llm-chain-default-name"),
+            vec![PRESET_ID_FORMAT.to_string(), PRESET_ID_SUMMARY.to_string()],
+        );
+        self.settings.llm_postprocess.mode_id = id.clone();
+        self.check_changes();
+        id
+    }
+
+    // Name and ordered steps of the selected chain; steps pick presets or custom modes
+    pub(super) fn ui_llm_chain_editor(&mut self, ui: &mut egui::Ui, chain_id: &str) {
+        let llm = &self.settings.llm_postprocess;
+        let step_options: Vec<(String, String)> = self
+            .llm_mode_options()
+            .into_iter()
+            .filter(|(id, _)| llm.is_chain_step(id))
+            .collect();
+        let label_for = |id: &str| {
+            step_options
+                .iter()
+                .find(|(option, _)| option == id)
+                .map_or_else(|| id.to_string(), |(_, label)| label.clone())
+        };
+        let Some(chain) = self.settings.llm_postprocess.chain_mode_mut(chain_id) else {
+            return;
+        };
+
+        let mut changed = false;
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-llm-custom-name"));
+            changed |= ui.text_edit_singleline(&mut chain.name).changed();
+        });
+        ui.add_space(4.0);
+        ui.label(i18n::tr("label-llm-chain-steps"));
+        let count = chain.steps.len();
+        let mut move_up = None;
+        let mut move_down = None;
+        let mut remove = None;
+        for (index, step) in chain.steps.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label(format!("{}.", index + 1));
+                egui::ComboBox::from_id_salt(("llm_chain_step", index))
+                    .selected_text(label_for(step.as_str()))
+                    .show_ui(ui, |ui| {
+                        for (id, label) in &step_options {
+                            changed |= ui.selectable_value(step, id.clone(), label).changed();
+                        }
+                    });
+                if ui
+                    .add_enabled(index > 0, egui::Button::new("⬆").small())
+                    .clicked()
+                {
+                    move_up = Some(index);
+                }
+                if ui
+                    .add_enabled(index + 1 < count, egui::Button::new("⬇").small())
+                    .clicked()
+                {
+                    move_down = Some(index);
+                }
+                if ui
+                    .add_enabled(count > 1, egui::Button::new("✕").small())
+                    .on_hover_text(i18n::tr("tooltip-llm-chain-remove-step"))
+                    .clicked()
+                {
+                    remove = Some(index);
+                }
+            });
+        }
+        if let Some(index) = move_up {
+            chain.steps.swap(index - 1, index);
+            changed = true;
+        }
+        if let Some(index) = move_down {
+            chain.steps.swap(index, index + 1);
+            changed = true;
+        }
+        if let Some(index) = remove {
+            chain.steps.remove(index);
+            changed = true;
+        }
+
+        let mut delete_clicked = false;
+        ui.horizontal(|ui| {
+            if ui.button(i18n::tr("btn-llm-chain-add-step")).clicked() {
+                chain.steps.push(PRESET_ID_FORMAT.to_string());
+                changed = true;
+            }
+            delete_clicked = ui
+                .button(i18n::tr("btn-llm-delete-custom"))
+                .on_hover_text(i18n::tr("tooltip-llm-delete-chain"))
+                .clicked();
+        });
+        ui.label(
+            egui::RichText::new(i18n::tr("hint-llm-chain"))
+                .small()
+                .color(ui.visuals().weak_text_color()),
+        );
+
+        if delete_clicked && self.settings.llm_postprocess.remove_chain_mode(chain_id) {
+            self.sync_llm_custom_editor();
+            changed = true;
+        }
+        if changed {
+            self.check_changes();
+        }
+    }
+}
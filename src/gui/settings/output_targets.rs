@@ -0,0 +1,88 @@
+use super::SettingsWindow;
+use crate::core::OutputTarget;
+use crate::i18n;
+use eframe::egui;
+use std::path::PathBuf;
+
+impl SettingsWindow {
+    // Clipboard and auto-paste toggles, then the notes files transcripts are appended to
+    pub(super) fn ui_output_targets(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        let mut clipboard = self.settings.has_output_target(&OutputTarget::Clipboard);
+        if ui
+            .checkbox(&mut clipboard, i18n::tr("chk-output-clipboard"))
+            .on_hover_text(i18n::tr("tooltip-output-clipboard"))
+            .changed()
+        {
+            self.settings
+                .set_output_target(OutputTarget::Clipboard, clipboard);
+            changed = true;
+        }
+        let mut auto_paste = self.settings.auto_paste;
+        if ui
+            .checkbox(&mut auto_paste, i18n::tr("label-auto-paste"))
+            .changed()
+        {
+            self.settings
+                .set_output_target(OutputTarget::AutoPaste, auto_paste);
+            changed = true;
+        }
+
+        ui.label(i18n::tr("label-output-append-files"));
+        let mut remove = None;
+        ui.indent("output_append_files_indent", |ui| {
+            for (index, target) in self.settings.output_targets.iter().enumerate() {
+                let OutputTarget::AppendFile { path } = target else {
+                    continue;
+                };
+                ui.horizontal(|ui| {
+                    ui.monospace(path.display().to_string());
+                    if ui
+                        .small_button("✕")
+                        .on_hover_text(i18n::tr("tooltip-output-append-remove"))
+                        .clicked()
+                    {
+                        remove = Some(index);
+                    }
+                });
+            }
+            if ui
+                .button(i18n::tr("btn-output-append-add"))
+                .on_hover_text(i18n::tr("tooltip-output-append-add"))
+                .clicked()
+            {
+                if let Some(path) = pick_notes_file() {
+                    self.settings
+                        .set_output_target(OutputTarget::AppendFile { path }, true);
+                    changed = true;
+                }
+            }
+            ui.label(
+                egui::RichText::new(i18n::tr("hint-output-append-files"))
+                    .small()
+                    .color(ui.visuals().weak_text_color()),
+            );
+        });
+        if let Some(index) = remove {
+            self.settings.output_targets.remove(index);
+            changed = true;
+        }
+        if self.settings.output_targets.is_empty() {
+            ui.label(
+                egui::RichText::new(i18n::tr("msg-output-no-targets"))
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+        if changed {
+            self.check_changes();
+        }
+    }
+}
+
+fn pick_notes_file() -> Option<PathBuf> {
+    rfd::FileDialog::new()
+        .add_filter("Text", &["md", "txt", "org"])
+        .set_file_name("notes.md")
+        .save_file()
+}
@@ -1,5 +1,6 @@
 use std::io::Write;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 use crate::utils::app_config_dir;
 
@@ -7,9 +8,79 @@ use crate::utils::app_config_dir;
 
 use super::{Settings, SettingsWindow};
 
+// Auto-saved edits reach settings.toml at most this often
+const SAVE_INTERVAL: Duration = Duration::from_millis(500);
+
+// Timer for the throttled write: the first edit of a burst starts it
+#[derive(Default)]
+pub(super) struct SaveThrottle {
+    queued_at: Option<Instant>,
+}
+
+impl SaveThrottle {
+    fn queue(&mut self, now: Instant) {
+        self.queued_at.get_or_insert(now);
+    }
+
+    // Forget the queued write; true when one was queued
+    fn take(&mut self) -> bool {
+        self.queued_at.take().is_some()
+    }
+
+    // Time left at `now` (zero once due); None when nothing is queued
+    fn due_in(&self, now: Instant) -> Option<Duration> {
+        let queued_at = self.queued_at?;
+        Some(SAVE_INTERVAL.saturating_sub(now.saturating_duration_since(queued_at)))
+    }
+}
+
 impl SettingsWindow {
     pub(super) fn save_settings(&self) {
-        if let Ok(config_str) = toml::to_string(&self.settings) {
+        Self::write_settings(&self.settings);
+    }
+
+    // Save one change right away without committing other pending edits
+    // (same as save_settings while auto-save is on)
+    pub(super) fn persist_now(&mut self, apply: impl Fn(&mut Settings)) {
+        apply(&mut self.settings);
+        apply(&mut self.original_settings);
+        Self::write_settings(&self.original_settings);
+        self.save_throttle.take();
+    }
+
+    // Like persist_now, but left to the throttled write
+    pub(super) fn persist_later(&mut self, apply: impl Fn(&mut Settings)) {
+        apply(&mut self.settings);
+        apply(&mut self.original_settings);
+        self.queue_save();
+    }
+
+    // Mark the committed settings dirty; the first edit of a burst starts the timer
+    pub(super) fn queue_save(&mut self) {
+        self.save_throttle.queue(Instant::now());
+    }
+
+    /// Write queued settings once the interval has passed; otherwise returns
+    /// how long until they are due
+    pub fn flush_pending_save_if_due(&mut self) -> Option<Duration> {
+        let left = self.save_throttle.due_in(Instant::now())?;
+        if left.is_zero() {
+            self.flush_pending_save();
+            None
+        } else {
+            Some(left)
+        }
+    }
+
+    /// Write queued settings now (focus loss, close, exit)
+    pub fn flush_pending_save(&mut self) {
+        if self.save_throttle.take() {
+            Self::write_settings(&self.original_settings);
+        }
+    }
+
+    fn write_settings(settings: &Settings) {
+        if let Ok(config_str) = toml::to_string(settings) {
             let config_path = Self::get_config_path();
 
             // Create settings directory if missing
@@ -44,7 +115,74 @@ impl SettingsWindow {
         app_config_dir().join("settings.toml")
     }
 
+    // Re-read settings and dictionary after they were replaced on disk (bundle import)
+    pub(super) fn reload_from_disk(&mut self) {
+        let mut settings = Self::load_settings().unwrap_or_default();
+        settings.migrate_hotkey_bindings();
+        settings.migrate_output_targets();
+        settings.llm_postprocess.ensure_mode_valid();
+        self.settings = settings;
+        self.save_settings();
+        self.original_settings = self.settings.clone();
+        self.has_unsaved_changes = false;
+        self.apply_loaded_settings();
+
+        if let Ok(list) = crate::dictionary::load_or_init_dictionary() {
+            self.dict_entries = list;
+            self.dict_dirty = false;
+            self.pending_apply_dictionary = true;
+            self.refresh_dict_conflicts();
+        }
+    }
+
     // removed: legacy root config migration helpers
 
     // Removed: Ollama/ONNX-related settings
+
+    // Manual save mode: drop pending edits and restore the saved settings
+    pub(super) fn revert_settings(&mut self) {
+        self.settings = self.original_settings.clone();
+        self.has_unsaved_changes = false;
+        self.apply_loaded_settings();
+        self.save_status_message = Some(crate::i18n::tr("msg-settings-reverted"));
+    }
+
+    // Push replaced settings to the editors and global state the UI set live
+    fn apply_loaded_settings(&mut self) {
+        self.hotkey_input = self.settings.hotkey_recording.clone();
+        crate::utils::sound::set_enabled(self.settings.sound_enabled);
+        crate::utils::sound::set_volume_percent(self.settings.sound_volume_percent);
+        self.settings.apply_event_sounds();
+        crate::i18n::set_ui_language_preference(&self.settings.ui_language);
+        self.sync_llm_custom_editor();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SaveThrottle, SAVE_INTERVAL};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn burst_of_edits_is_due_one_interval_after_the_first() {
+        let start = Instant::now();
+        let mut throttle = SaveThrottle::default();
+        assert_eq!(throttle.due_in(start), None);
+
+        throttle.queue(start);
+        // Later edits in the burst do not push the write back
+        throttle.queue(start + Duration::from_millis(300));
+        assert_eq!(
+            throttle.due_in(start + Duration::from_millis(300)),
+            Some(SAVE_INTERVAL - Duration::from_millis(300))
+        );
+        assert_eq!(throttle.due_in(start + SAVE_INTERVAL), Some(Duration::ZERO));
+
+        // Flushing clears it; the next edit starts a new interval
+        assert!(throttle.take());
+        assert!(!throttle.take());
+        assert_eq!(throttle.due_in(start + SAVE_INTERVAL), None);
+        throttle.queue(start + SAVE_INTERVAL);
+        assert_eq!(throttle.due_in(start + SAVE_INTERVAL), Some(SAVE_INTERVAL));
+    }
 }
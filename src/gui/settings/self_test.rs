@@ -0,0 +1,109 @@
+use super::SettingsWindow;
+use crate::core::{SelfTestStage, StageOutcome};
+use crate::i18n;
+use eframe::egui;
+
+impl SettingsWindow {
+    /// Button in the Model tab; the app runs the test and reports back
+    pub(super) fn ui_self_test_button(&mut self, ui: &mut egui::Ui) {
+        ui.add_space(10.0);
+        ui.horizontal(|ui| {
+            let button = ui
+                .add_enabled(
+                    !self.self_test_running,
+                    egui::Button::new(i18n::tr("btn-run-self-test")),
+                )
+                .on_hover_text(i18n::tr("tooltip-run-self-test"));
+            if button.clicked() {
+                self.self_test_requested = true;
+                self.self_test_running = true;
+                self.self_test_results = None;
+            }
+            if self.self_test_running {
+                ui.spinner();
+            }
+        });
+    }
+
+    pub fn take_self_test_request(&mut self) -> bool {
+        std::mem::take(&mut self.self_test_requested)
+    }
+
+    pub fn set_self_test_results(&mut self, stages: Vec<SelfTestStage>) {
+        self.self_test_running = false;
+        self.self_test_results = Some(stages);
+    }
+
+    // Per-stage timing and output
+    pub(super) fn ui_self_test_window(&mut self, ctx: &egui::Context) {
+        if !self.self_test_running && self.self_test_results.is_none() {
+            return;
+        }
+        let mut open = true;
+        let mut window = egui::Window::new(i18n::tr("title-self-test"));
+        // No close button until the run finishes
+        if self.self_test_results.is_some() {
+            window = window.open(&mut open);
+        }
+        window
+            .default_width(520.0)
+            .resizable(true)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                let Some(stages) = &self.self_test_results else {
+                    ui.ctx()
+                        .request_repaint_after(std::time::Duration::from_millis(200));
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(i18n::tr("msg-self-test-running"));
+                    });
+                    return;
+                };
+                egui::Grid::new("self_test_grid")
+                    .num_columns(3)
+                    .spacing(egui::vec2(12.0, 6.0))
+                    .show(ui, |ui| {
+                        for stage in stages {
+                            ui.label(
+                                egui::RichText::new(i18n::tr(stage.kind.label_key())).strong(),
+                            );
+                            ui.label(format!("{:.2}s", stage.elapsed.as_secs_f32()));
+                            match &stage.outcome {
+                                StageOutcome::Passed(text) => {
+                                    let text = if text.is_empty() {
+                                        i18n::tr("msg-self-test-empty")
+                                    } else {
+                                        text.clone()
+                                    };
+                                    ui.label(text);
+                                }
+                                StageOutcome::Skipped(reason) => {
+                                    ui.label(
+                                        egui::RichText::new(i18n::tr(reason))
+                                            .color(ui.visuals().weak_text_color()),
+                                    );
+                                }
+                                StageOutcome::Failed(err) => {
+                                    ui.colored_label(ui.visuals().error_fg_color, err);
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+                ui.add_space(6.0);
+                let summary = if stages.iter().all(SelfTestStage::passed) {
+                    i18n::tr("msg-self-test-passed")
+                } else {
+                    i18n::tr("msg-self-test-failed")
+                };
+                ui.label(
+                    egui::RichText::new(summary)
+                        .small()
+                        .color(ui.visuals().weak_text_color()),
+                );
+            });
+        if !open {
+            self.self_test_results = None;
+        }
+    }
+}
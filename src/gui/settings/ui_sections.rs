@@ -1,11 +1,18 @@
-use super::SettingsWindow;
+use super::{whisper_language_label, SettingsWindow, WHISPER_LANGUAGES};
+use crate::app::chunk_processor::MAX_PARALLEL_CHUNKS;
 // Icons are used on tab labels; content headings remain plain
-use crate::audio::VadStrategy;
+use crate::audio::{VadStrategy, VAD_SILENCE_DB_RANGE};
+use crate::core::{MaxRecordAction, SoundTiming};
 use crate::i18n;
+use crate::transcription::{available_threads, max_threads, WhisperTask};
+use crate::utils::sound::SoundEvent;
 use crate::utils::{app_config_dir, reveal_in_file_manager};
 use eframe::egui;
 use std::time::{Duration, Instant};
 
+// Starting point when the segment confidence filter is switched on
+const DEFAULT_MIN_SEGMENT_CONFIDENCE: f32 = 0.4;
+
 impl SettingsWindow {
     pub(super) fn ui_input_devices_section(&mut self, ui: &mut egui::Ui) {
         // I/O devices
@@ -46,10 +53,20 @@ impl SettingsWindow {
                     let db = 20.0 * (level.max(1e-9)).log10();
                     let db_clamped = db.clamp(-60.0, 0.0);
                     let bar = ((db_clamped + 60.0) / 60.0).clamp(0.0, 1.0);
+                    // Clipping: peak-hold (incl. gain) at or above -0.1 dBFS
+                    let peak = self.meter_peak.lock().unwrap().value() * gain;
                     ui.horizontal(|ui| {
                         ui.label(i18n::tr("label-input-level"));
                         ui.add(egui::ProgressBar::new(bar).desired_width(220.0));
                         ui.monospace(format!("{:.1} dBFS", db));
+                        if peak >= super::audio_meter::CLIP_THRESHOLD {
+                            ui.label(
+                                egui::RichText::new("CLIP")
+                                    .strong()
+                                    .color(egui::Color32::from_rgb(220, 53, 69)),
+                            )
+                            .on_hover_text(i18n::tr("tooltip-input-clipping"));
+                        }
                     });
                 }
                 // Show sensitivity slider only while meter is active
@@ -63,6 +80,7 @@ impl SettingsWindow {
                                 .suffix("%"),
                         );
                         if slider.changed() {
+                            self.settings.remember_input_gain();
                             self.check_changes();
                         }
                         ui.add_space(8.0);
@@ -83,6 +101,7 @@ impl SettingsWindow {
                                 let factor = 10f32.powf((target_db - curr_db) / 20.0);
                                 let new_gain = (curr_gain * factor).clamp(0.2, 2.0);
                                 self.settings.input_gain_percent = (new_gain * 100.0).round();
+                                self.settings.remember_input_gain();
                                 self.check_changes();
                             }
                         }
@@ -141,15 +160,20 @@ impl SettingsWindow {
                                 }
                             }
                             if let Some((h, oi, oname)) = chosen {
+                                // Keep the current sensitivity for the device being left
+                                self.settings.remember_input_gain();
                                 if let Some(i) = oi {
+                                    self.settings.input_device_uid = self.input_uid_for(&h, i);
                                     self.settings.input_host = Some(h);
                                     self.settings.input_device_index_in_host = Some(i);
                                     self.settings.input_device = oname; // temporarily store display name
                                 } else {
                                     self.settings.input_host = None;
                                     self.settings.input_device_index_in_host = None;
+                                    self.settings.input_device_uid = None;
                                     self.settings.input_device = None;
                                 }
+                                self.settings.recall_input_gain();
                                 self.check_changes();
                                 self.restart_meter();
                             }
@@ -200,6 +224,50 @@ impl SettingsWindow {
                         }
                     }
                 });
+                if !self.is_test_recording {
+                    self.ui_test_recording_report(ui);
+                }
+                if self.input_device_fallback {
+                    ui.horizontal(|ui| {
+                        ui.colored_label(
+                            ui.visuals().warn_fg_color,
+                            i18n::tr("msg-input-device-missing"),
+                        );
+                        if ui.button(i18n::tr("btn-input-reselect")).clicked() {
+                            self.input_reselect_requested = true;
+                            self.refresh_device_lists();
+                        }
+                    });
+                }
+                if ui
+                    .checkbox(
+                        &mut self.settings.input_auto_reselect,
+                        i18n::tr("label-input-auto-reselect"),
+                    )
+                    .on_hover_text(i18n::tr("tooltip-input-auto-reselect"))
+                    .changed()
+                {
+                    self.check_changes();
+                }
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("label-preroll"));
+                    let slider = ui.add(
+                        egui::Slider::new(&mut self.settings.preroll_ms, 0..=1000)
+                            .step_by(50.0)
+                            .suffix(" ms"),
+                    );
+                    if slider.changed() {
+                        self.check_changes();
+                    }
+                });
+                if self.settings.preroll_ms > 0 {
+                    ui.label(
+                        egui::RichText::new(i18n::tr("preroll-note"))
+                            .small()
+                            .color(ui.visuals().weak_text_color()),
+                    );
+                }
                 ui.add_space(6.0);
                 ui.horizontal(|ui| {
                     ui.label(i18n::tr("label-output-device"));
@@ -266,13 +334,621 @@ impl SettingsWindow {
                         }
                     });
                 });
+                // Per-event sounds: mute individually or pick a custom file
+                ui.add_space(6.0);
+                ui.add_enabled_ui(self.settings.sound_enabled, |ui| {
+                    egui::Grid::new("event_sounds_grid")
+                        .num_columns(3)
+                        .spacing([10.0, 6.0])
+                        .show(ui, |ui| {
+                            for event in
+                                [SoundEvent::Start, SoundEvent::Complete, SoundEvent::Error]
+                            {
+                                self.ui_event_sound_row(ui, event);
+                                ui.end_row();
+                            }
+                        });
+                    self.ui_complete_sound_timing(ui);
+                });
+                ui.label(
+                    egui::RichText::new(i18n::tr("note-event-sounds"))
+                        .small()
+                        .color(ui.visuals().weak_text_color()),
+                );
+                ui.add_space(6.0);
+                // Independent of the status sounds switch; uses the same device and volume
+                if ui
+                    .checkbox(
+                        &mut self.settings.readback_tts,
+                        i18n::tr("chk-readback-tts"),
+                    )
+                    .on_hover_text(i18n::tr("tooltip-readback-tts"))
+                    .changed()
+                {
+                    self.check_changes();
+                }
+            });
+    }
+
+    // Completion chime vs. paste: overlapping them is jarring on slow machines
+    fn ui_complete_sound_timing(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-complete-sound-timing"))
+                .on_hover_text(i18n::tr("tooltip-complete-sound-timing"));
+            let label_for = |timing: SoundTiming| match timing {
+                SoundTiming::AfterPaste => i18n::tr("option-sound-after-paste"),
+                SoundTiming::BeforePaste => i18n::tr("option-sound-before-paste"),
+                SoundTiming::SilentWithAutoPaste => i18n::tr("option-sound-silent-auto-paste"),
+            };
+            let mut selected = self.settings.complete_sound_timing;
+            egui::ComboBox::from_id_salt("complete_sound_timing_combo")
+                .selected_text(label_for(selected))
+                .show_ui(ui, |ui| {
+                    for timing in [
+                        SoundTiming::AfterPaste,
+                        SoundTiming::BeforePaste,
+                        SoundTiming::SilentWithAutoPaste,
+                    ] {
+                        ui.selectable_value(&mut selected, timing, label_for(timing));
+                    }
+                });
+            if selected != self.settings.complete_sound_timing {
+                self.settings.complete_sound_timing = selected;
+                self.check_changes();
+            }
+        });
+    }
+
+    fn ui_event_sound_row(&mut self, ui: &mut egui::Ui, event: SoundEvent) {
+        let (label_key, enabled, path) = match event {
+            SoundEvent::Start => (
+                "label-sound-start",
+                &mut self.settings.sound_start_enabled,
+                &mut self.settings.sound_start,
+            ),
+            SoundEvent::Complete => (
+                "label-sound-complete",
+                &mut self.settings.sound_complete_enabled,
+                &mut self.settings.sound_complete,
+            ),
+            SoundEvent::Error => (
+                "label-sound-error",
+                &mut self.settings.sound_error_enabled,
+                &mut self.settings.sound_error,
+            ),
+        };
+        let mut changed = ui.checkbox(enabled, i18n::tr(label_key)).changed();
+        let file_label = match path.as_ref() {
+            Some(p) => p
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| p.display().to_string()),
+            None => i18n::tr("label-sound-default"),
+        };
+        let missing = path.as_ref().map(|p| !p.exists()).unwrap_or(false);
+        if missing {
+            ui.colored_label(ui.visuals().warn_fg_color, file_label)
+                .on_hover_text(i18n::tr("tooltip-sound-file-missing"));
+        } else {
+            let resp = ui.label(file_label);
+            if let Some(p) = path.as_ref() {
+                resp.on_hover_text(p.display().to_string());
+            }
+        }
+        ui.horizontal(|ui| {
+            if ui.button(i18n::tr("btn-choose-file")).clicked() {
+                if let Some(p) = rfd::FileDialog::new()
+                    .add_filter("Audio", &["mp3", "wav", "ogg", "flac"])
+                    .pick_file()
+                {
+                    *path = Some(p);
+                    changed = true;
+                }
+            }
+            if path.is_some() && ui.button(i18n::tr("btn-sound-use-default")).clicked() {
+                *path = None;
+                changed = true;
+            }
+            if ui.button(i18n::tr("btn-test-play")).clicked() {
+                crate::utils::sound::set_output_device(self.settings.output_device.as_deref());
+                crate::utils::sound::play_sound_async(&crate::utils::sound::event_sound_path(
+                    event,
+                    path.as_deref(),
+                ));
+            }
+        });
+        if changed {
+            self.settings.apply_event_sounds();
+            self.check_changes();
+        }
+    }
+
+    // Export/import settings + dictionary as one .zip
+    pub(super) fn ui_config_bundle(&mut self, ui: &mut egui::Ui) {
+        egui::Frame::default()
+            .fill(ui.visuals().faint_bg_color)
+            .corner_radius(egui::CornerRadius::same(6))
+            .inner_margin(egui::Margin::symmetric(12, 8))
+            .show(ui, |ui| {
+                ui.set_min_width(ui.available_width());
+                ui.horizontal(|ui| {
+                    ui.label(i18n::tr("label-config-bundle"));
+                    if ui.button(i18n::tr("btn-export-config")).clicked() {
+                        self.export_config_bundle();
+                    }
+                    if ui.button(i18n::tr("btn-import-config")).clicked() {
+                        self.pick_config_bundle();
+                    }
+                });
+                ui.label(
+                    egui::RichText::new(i18n::tr("hint-config-bundle"))
+                        .small()
+                        .color(ui.visuals().weak_text_color()),
+                );
+                if let Some(msg) = &self.config_bundle_message {
+                    ui.label(msg);
+                }
+            });
+
+        let Some((path, info)) = self.config_import_pending.clone() else {
+            return;
+        };
+        let mut decision = None;
+        egui::Window::new(i18n::tr("title-import-config"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ui.ctx(), |ui| {
+                ui.label(path.display().to_string());
+                ui.label(format!(
+                    "{} {} ({})",
+                    i18n::tr("label-bundle-created"),
+                    info.created_at,
+                    info.app_version
+                ));
+                let mut contents = Vec::new();
+                if info.has_settings {
+                    contents.push(i18n::tr("label-bundle-settings"));
+                }
+                if let Some(n) = info.dictionary_entries {
+                    contents.push(format!("{} ({})", i18n::tr("label-bundle-dictionary"), n));
+                }
+                ui.label(format!(
+                    "{} {}",
+                    i18n::tr("label-bundle-contents"),
+                    contents.join(", ")
+                ));
+                ui.add_space(6.0);
+                ui.colored_label(
+                    ui.visuals().warn_fg_color,
+                    i18n::tr("msg-import-config-confirm"),
+                );
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if ui.button(i18n::tr("btn-import-config-overwrite")).clicked() {
+                        decision = Some(true);
+                    }
+                    if ui.button(i18n::tr("btn-cancel")).clicked() {
+                        decision = Some(false);
+                    }
+                });
+            });
+        match decision {
+            Some(true) => {
+                self.config_import_pending = None;
+                self.config_bundle_message =
+                    Some(match crate::utils::config::import_bundle(&path) {
+                        Ok(backup) => {
+                            self.reload_from_disk();
+                            format!("{} {}", i18n::tr("msg-config-imported"), backup.display())
+                        }
+                        Err(e) => format!("{} {:#}", i18n::tr("msg-config-import-failed"), e),
+                    });
+            }
+            Some(false) => self.config_import_pending = None,
+            None => {}
+        }
+    }
+
+    fn export_config_bundle(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("ZIP", &["zip"])
+            .set_file_name("hootvoice-settings.zip")
+            .save_file()
+        else {
+            return;
+        };
+        // The committed settings: edits not saved yet (manual save mode) stay out
+        let exported = toml::to_string(&self.original_settings)
+            .map_err(anyhow::Error::from)
+            .and_then(|settings| crate::utils::config::export_bundle(&path, &settings));
+        self.config_bundle_message = Some(match exported {
+            Ok(()) => format!("{} {}", i18n::tr("msg-config-exported"), path.display()),
+            Err(e) => format!("{} {:#}", i18n::tr("msg-config-export-failed"), e),
+        });
+    }
+
+    fn pick_config_bundle(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("ZIP", &["zip"])
+            .pick_file()
+        else {
+            return;
+        };
+        match crate::utils::config::inspect_bundle(&path) {
+            Ok(info) => {
+                self.config_bundle_message = None;
+                self.config_import_pending = Some((path, info));
+            }
+            Err(e) => {
+                self.config_bundle_message =
+                    Some(format!("{} {:#}", i18n::tr("msg-config-import-failed"), e));
+            }
+        }
+    }
+
+    fn ui_model_mirror(&mut self, ui: &mut egui::Ui) {
+        ui.add_space(6.0);
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-model-mirror"));
+            let mut text = self
+                .settings
+                .model_download_base_url
+                .clone()
+                .unwrap_or_default();
+            let resp = ui.add(
+                egui::TextEdit::singleline(&mut text)
+                    .hint_text(crate::transcription::model::DEFAULT_MODEL_BASE_URL)
+                    .desired_width(360.0),
+            );
+            if resp.changed() {
+                // Empty input means "use the default host"
+                self.settings.model_download_base_url = if text.trim().is_empty() {
+                    None
+                } else {
+                    Some(text)
+                };
+                self.check_changes();
+            }
+        });
+        if let Some(Err(e)) = self
+            .settings
+            .model_download_base_url
+            .as_deref()
+            .map(crate::transcription::validate_model_base_url)
+        {
+            ui.colored_label(
+                ui.visuals().warn_fg_color,
+                format!("{} ({})", i18n::tr("msg-model-mirror-invalid"), e),
+            );
+        }
+        ui.label(
+            egui::RichText::new(i18n::tr("hint-model-mirror"))
+                .small()
+                .color(ui.visuals().weak_text_color()),
+        );
+    }
+
+    // Built-in + user blocklist for Whisper's stock silence phrases
+    fn ui_hallucination_filter(&mut self, ui: &mut egui::Ui) {
+        let mut changed = ui
+            .checkbox(
+                &mut self.settings.hallucination_filter,
+                i18n::tr("chk-hallucination-filter"),
+            )
+            .on_hover_text(i18n::tr("tooltip-hallucination-filter"))
+            .changed();
+        egui::CollapsingHeader::new(i18n::tr("label-hallucination-phrases"))
+            .id_salt("hallucination_phrases")
+            .default_open(!self.settings.hallucination_phrases.is_empty())
+            .show(ui, |ui| {
+                if self.settings.hallucination_filter {
+                    let lang = (self.settings.whisper_language != "auto")
+                        .then_some(self.settings.whisper_language.as_str());
+                    let builtins = crate::transcription::hallucination::builtin_phrases(lang);
+                    if !builtins.is_empty() {
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "{} {}",
+                                i18n::tr("label-hallucination-builtin"),
+                                builtins.join(" / ")
+                            ))
+                            .small()
+                            .color(ui.visuals().weak_text_color()),
+                        );
+                    }
+                }
+                ui.label(
+                    egui::RichText::new(i18n::tr("hint-hallucination-phrases"))
+                        .small()
+                        .color(ui.visuals().weak_text_color()),
+                );
+                let mut remove = None;
+                for (idx, phrase) in self.settings.hallucination_phrases.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        changed |= ui
+                            .add(egui::TextEdit::singleline(phrase).desired_width(280.0))
+                            .changed();
+                        if ui.small_button("✕").clicked() {
+                            remove = Some(idx);
+                        }
+                    });
+                }
+                if let Some(idx) = remove {
+                    self.settings.hallucination_phrases.remove(idx);
+                    changed = true;
+                }
+                if ui.button(i18n::tr("btn-hallucination-add")).clicked() {
+                    self.settings.hallucination_phrases.push(String::new());
+                    changed = true;
+                }
+            });
+        if changed {
+            self.check_changes();
+        }
+    }
+
+    // Confidence floor for Whisper segments (local backend; the remote API reports none)
+    fn ui_min_segment_confidence(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            let mut enabled = self.settings.min_segment_confidence.is_some();
+            if ui
+                .checkbox(&mut enabled, i18n::tr("chk-min-segment-confidence"))
+                .on_hover_text(i18n::tr("tooltip-min-segment-confidence"))
+                .changed()
+            {
+                self.settings.min_segment_confidence =
+                    enabled.then_some(DEFAULT_MIN_SEGMENT_CONFIDENCE);
+                changed = true;
+            }
+            if let Some(min) = self.settings.min_segment_confidence.as_mut() {
+                changed |= ui
+                    .add(egui::Slider::new(min, 0.0..=1.0).fixed_decimals(2))
+                    .changed();
+            }
+        });
+        if self.settings.min_segment_confidence.is_some() {
+            ui.label(
+                egui::RichText::new(i18n::tr("hint-min-segment-confidence"))
+                    .small()
+                    .color(ui.visuals().weak_text_color()),
+            );
+        }
+        if changed {
+            self.check_changes();
+        }
+    }
+
+    pub(super) fn ui_transcript_boilerplate(&mut self, ui: &mut egui::Ui) {
+        let mut changed = false;
+        egui::Grid::new("transcript_boilerplate_grid")
+            .num_columns(2)
+            .spacing(egui::vec2(8.0, 4.0))
+            .show(ui, |ui| {
+                ui.label(i18n::tr("label-transcript-prefix"));
+                changed |= ui
+                    .add(
+                        egui::TextEdit::multiline(&mut self.settings.transcript_prefix)
+                            .desired_rows(1)
+                            .desired_width(320.0),
+                    )
+                    .changed();
+                ui.end_row();
+                ui.label(i18n::tr("label-transcript-suffix"));
+                changed |= ui
+                    .add(
+                        egui::TextEdit::multiline(&mut self.settings.transcript_suffix)
+                            .desired_rows(1)
+                            .desired_width(320.0),
+                    )
+                    .changed();
+                ui.end_row();
             });
+        ui.label(
+            egui::RichText::new(i18n::tr("hint-transcript-boilerplate"))
+                .small()
+                .color(ui.visuals().weak_text_color()),
+        );
+        if changed {
+            self.check_changes();
+        }
+    }
+
+    // What happens at the max recording time
+    fn ui_max_record_action(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-max-record-action"));
+            let label = |action: MaxRecordAction| match action {
+                MaxRecordAction::FinalizeAndStop => i18n::tr("option-max-record-stop"),
+                MaxRecordAction::FinalizeAndAutoRestart => i18n::tr("option-max-record-restart"),
+                MaxRecordAction::StopWithWarning => i18n::tr("option-max-record-warn"),
+            };
+            let before = self.settings.max_record_action;
+            egui::ComboBox::from_id_salt("max_record_action_combo")
+                .selected_text(label(before))
+                .show_ui(ui, |ui| {
+                    for action in [
+                        MaxRecordAction::FinalizeAndStop,
+                        MaxRecordAction::FinalizeAndAutoRestart,
+                        MaxRecordAction::StopWithWarning,
+                    ] {
+                        ui.selectable_value(
+                            &mut self.settings.max_record_action,
+                            action,
+                            label(action),
+                        );
+                    }
+                });
+            if self.settings.max_record_action != before {
+                self.check_changes();
+            }
+        });
+        ui.label(
+            egui::RichText::new(i18n::tr("hint-max-record-action"))
+                .small()
+                .color(ui.visuals().weak_text_color()),
+        );
+    }
+
+    // Language used when auto-detect is unsure (short clips)
+    fn ui_auto_detect_fallback(&mut self, ui: &mut egui::Ui) {
+        let label = |lang: Option<&str>| match lang {
+            None => i18n::tr("option-auto-detect-fallback-none"),
+            Some(code) => whisper_language_label(code),
+        };
+        // The remote endpoint reports no detection confidence to compare against
+        let remote = self.settings.remote_transcription.enabled;
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            if remote {
+                ui.disable();
+            }
+            ui.label(i18n::tr("label-auto-detect-fallback"));
+            let fallback = &mut self.settings.auto_detect_fallback;
+            egui::ComboBox::from_id_salt("auto_detect_fallback_combo")
+                .selected_text(label(fallback.as_deref()))
+                .show_ui(ui, |ui| {
+                    changed |= ui.selectable_value(fallback, None, label(None)).changed();
+                    for (code, _) in WHISPER_LANGUAGES {
+                        changed |= ui
+                            .selectable_value(fallback, Some(code.to_string()), label(Some(code)))
+                            .changed();
+                    }
+                })
+                .response
+                .on_hover_text(i18n::tr("tooltip-auto-detect-fallback"));
+            if self.settings.auto_detect_fallback.is_some() {
+                ui.label(i18n::tr("label-auto-detect-min-confidence"));
+                changed |= ui
+                    .add(
+                        egui::Slider::new(&mut self.settings.auto_detect_min_confidence, 0.0..=1.0)
+                            .fixed_decimals(2),
+                    )
+                    .changed();
+            }
+        });
+        if remote {
+            ui.label(
+                egui::RichText::new(i18n::tr("hint-auto-detect-fallback-remote"))
+                    .small()
+                    .color(ui.visuals().weak_text_color()),
+            );
+        } else if self.settings.auto_detect_fallback.is_some() {
+            ui.label(
+                egui::RichText::new(i18n::tr("hint-auto-detect-fallback"))
+                    .small()
+                    .color(ui.visuals().weak_text_color()),
+            );
+        }
+        if changed {
+            self.check_changes();
+        }
+    }
+
+    // Transcribe vs. translate to English
+    fn ui_whisper_task(&mut self, ui: &mut egui::Ui) {
+        ui.add_space(6.0);
+        ui.horizontal(|ui| {
+            ui.label(i18n::tr("label-whisper-task"));
+            let label = |task: WhisperTask| match task {
+                WhisperTask::Transcribe => i18n::tr("option-task-transcribe"),
+                WhisperTask::Translate => i18n::tr("option-task-translate"),
+            };
+            let before = self.settings.whisper_task;
+            egui::ComboBox::from_id_salt("whisper_task_combo")
+                .selected_text(label(before))
+                .show_ui(ui, |ui| {
+                    for task in [WhisperTask::Transcribe, WhisperTask::Translate] {
+                        ui.selectable_value(&mut self.settings.whisper_task, task, label(task));
+                    }
+                })
+                .response
+                .on_hover_text(i18n::tr("tooltip-whisper-task"));
+            if self.settings.whisper_task != before {
+                self.check_changes();
+            }
+        });
+        if self.settings.whisper_task == WhisperTask::Translate {
+            ui.label(
+                egui::RichText::new(i18n::tr("hint-whisper-translate"))
+                    .small()
+                    .color(ui.visuals().weak_text_color()),
+            );
+        }
+    }
+
+    // Local whisper-rs or an OpenAI-compatible /audio/transcriptions endpoint
+    fn ui_transcription_backend(&mut self, ui: &mut egui::Ui) {
+        let remote = &mut self.settings.remote_transcription;
+        let mut changed = ui
+            .checkbox(&mut remote.enabled, i18n::tr("label-remote-transcription"))
+            .on_hover_text(i18n::tr("tooltip-remote-transcription"))
+            .changed();
+        if remote.enabled {
+            egui::Grid::new("remote_transcription_grid")
+                .num_columns(2)
+                .spacing(egui::vec2(8.0, 4.0))
+                .show(ui, |ui| {
+                    ui.label(i18n::tr("label-remote-base-url"));
+                    changed |= ui
+                        .add(egui::TextEdit::singleline(&mut remote.base_url).desired_width(320.0))
+                        .changed();
+                    ui.end_row();
+                    ui.label(i18n::tr("label-remote-api-key"));
+                    changed |= ui
+                        .add(
+                            egui::TextEdit::singleline(&mut remote.api_key)
+                                .password(true)
+                                .desired_width(320.0),
+                        )
+                        .changed();
+                    ui.end_row();
+                    ui.label(i18n::tr("label-remote-model"));
+                    changed |= ui
+                        .add(egui::TextEdit::singleline(&mut remote.model).desired_width(160.0))
+                        .changed();
+                    ui.end_row();
+                });
+            ui.label(
+                egui::RichText::new(i18n::tr("hint-remote-transcription"))
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+        if changed {
+            self.check_changes();
+        }
     }
 
     pub(super) fn ui_speech_model_section(&mut self, ui: &mut egui::Ui) {
         let strong = ui.visuals().strong_text_color();
         ui.heading(egui::RichText::new(i18n::tr("section-speech-model")).color(strong));
         ui.add_space(5.0);
+        if let Some(err) = self.model_load_error.clone() {
+            let error_color = ui.visuals().error_fg_color;
+            egui::Frame::default()
+                .stroke(egui::Stroke::new(1.0, error_color))
+                .corner_radius(egui::CornerRadius::same(6))
+                .inner_margin(egui::Margin::symmetric(12, 8))
+                .show(ui, |ui| {
+                    ui.set_min_width(ui.available_width());
+                    ui.colored_label(
+                        error_color,
+                        egui::RichText::new(i18n::tr("msg-model-load-failed-banner")).strong(),
+                    );
+                    ui.label(egui::RichText::new(err).small().monospace());
+                    ui.horizontal(|ui| {
+                        if ui.button(i18n::tr("btn-download")).clicked() {
+                            self.show_download_confirm = true;
+                        }
+                        if ui.button(i18n::tr("btn-dismiss")).clicked() {
+                            self.model_load_error = None;
+                        }
+                    });
+                });
+            ui.add_space(6.0);
+        }
         egui::Frame::default()
             .fill(ui.visuals().faint_bg_color)
             .corner_radius(egui::CornerRadius::same(6))
@@ -286,7 +962,7 @@ impl SettingsWindow {
                     ui.label(i18n::tr("label-preset"));
                     let presets: Vec<String> = super::SUPPORTED_MODELS
                         .iter()
-                        .map(|m| i18n::tr(m.label_key))
+                        .map(|m| format!("{} (~{} MB RAM)", i18n::tr(m.label_key), m.ram_mb))
                         .collect();
                     let mut idx = self.selected_model_index;
                     egui::ComboBox::from_id_salt("preset_model_combo")
@@ -327,6 +1003,7 @@ impl SettingsWindow {
                         }
                     }
                 });
+                self.ui_model_mirror(ui);
                 // Show progress just below the Download button
                 let downloading_now = *self.downloading.lock().unwrap();
                 if downloading_now {
@@ -403,145 +1080,47 @@ impl SettingsWindow {
                         size_mb
                     ));
                 }
+                ui.add_space(6.0);
+                self.ui_transcription_backend(ui);
                 // Language setting (Auto + common languages)
                 ui.add_space(6.0);
                 ui.horizontal(|ui| {
-                    ui.label(i18n::tr("label-language"));
-                    let display = match self.settings.whisper_language.as_str() {
-                        "auto" => i18n::tr("option-auto-detect"),
-                        "ja" => i18n::tr("option-japanese-ja"),
-                        "en" => i18n::tr("option-english-en"),
-                        "zh" => i18n::tr("option-chinese-zh"),
-                        "es" => i18n::tr("option-spanish-es"),
-                        "fr" => i18n::tr("option-french-fr"),
-                        "de" => i18n::tr("option-german-de"),
-                        "ko" => i18n::tr("option-korean-ko"),
-                        "pt" => i18n::tr("option-portuguese-pt"),
-                        "ru" => i18n::tr("option-russian-ru"),
-                        "hi" => i18n::tr("option-hindi-hi"),
-                        _ => self.settings.whisper_language.clone(),
-                    };
+                    ui.label(i18n::tr(
+                        if self.settings.whisper_task == WhisperTask::Translate {
+                            "label-source-language"
+                        } else {
+                            "label-language"
+                        },
+                    ));
                     let mut changed = false;
                     egui::ComboBox::from_id_salt("whisper_lang_combo")
-                        .selected_text(display)
+                        .selected_text(whisper_language_label(&self.settings.whisper_language))
                         .show_ui(ui, |ui| {
-                            if ui
-                                .selectable_label(
-                                    self.settings.whisper_language == "auto",
-                                    i18n::tr("option-auto-detect"),
-                                )
-                                .clicked()
-                            {
-                                self.settings.whisper_language = "auto".to_string();
-                                changed = true;
-                            }
-                            if ui
-                                .selectable_label(
-                                    self.settings.whisper_language == "ja",
-                                    i18n::tr("option-japanese-ja"),
-                                )
-                                .clicked()
-                            {
-                                self.settings.whisper_language = "ja".to_string();
-                                changed = true;
-                            }
-                            if ui
-                                .selectable_label(
-                                    self.settings.whisper_language == "en",
-                                    i18n::tr("option-english-en"),
-                                )
-                                .clicked()
-                            {
-                                self.settings.whisper_language = "en".to_string();
-                                changed = true;
-                            }
-                            if ui
-                                .selectable_label(
-                                    self.settings.whisper_language == "zh",
-                                    i18n::tr("option-chinese-zh"),
-                                )
-                                .clicked()
-                            {
-                                self.settings.whisper_language = "zh".to_string();
-                                changed = true;
-                            }
-                            if ui
-                                .selectable_label(
-                                    self.settings.whisper_language == "es",
-                                    i18n::tr("option-spanish-es"),
-                                )
-                                .clicked()
-                            {
-                                self.settings.whisper_language = "es".to_string();
-                                changed = true;
-                            }
-                            if ui
-                                .selectable_label(
-                                    self.settings.whisper_language == "fr",
-                                    i18n::tr("option-french-fr"),
-                                )
-                                .clicked()
-                            {
-                                self.settings.whisper_language = "fr".to_string();
-                                changed = true;
-                            }
-                            if ui
-                                .selectable_label(
-                                    self.settings.whisper_language == "de",
-                                    i18n::tr("option-german-de"),
-                                )
-                                .clicked()
-                            {
-                                self.settings.whisper_language = "de".to_string();
-                                changed = true;
-                            }
-                            if ui
-                                .selectable_label(
-                                    self.settings.whisper_language == "ko",
-                                    i18n::tr("option-korean-ko"),
-                                )
-                                .clicked()
-                            {
-                                self.settings.whisper_language = "ko".to_string();
-                                changed = true;
-                            }
-                            if ui
-                                .selectable_label(
-                                    self.settings.whisper_language == "pt",
-                                    i18n::tr("option-portuguese-pt"),
-                                )
-                                .clicked()
-                            {
-                                self.settings.whisper_language = "pt".to_string();
-                                changed = true;
-                            }
-                            if ui
-                                .selectable_label(
-                                    self.settings.whisper_language == "ru",
-                                    i18n::tr("option-russian-ru"),
-                                )
-                                .clicked()
-                            {
-                                self.settings.whisper_language = "ru".to_string();
-                                changed = true;
-                            }
-                            if ui
-                                .selectable_label(
-                                    self.settings.whisper_language == "hi",
-                                    i18n::tr("option-hindi-hi"),
-                                )
-                                .clicked()
-                            {
-                                self.settings.whisper_language = "hi".to_string();
-                                changed = true;
+                            let codes = std::iter::once("auto")
+                                .chain(WHISPER_LANGUAGES.iter().map(|(code, _)| *code));
+                            for code in codes {
+                                if ui
+                                    .selectable_label(
+                                        self.settings.whisper_language == code,
+                                        whisper_language_label(code),
+                                    )
+                                    .clicked()
+                                {
+                                    self.settings.whisper_language = code.to_string();
+                                    changed = true;
+                                }
                             }
                         });
                     if changed {
                         self.check_changes();
                     }
                 });
+                if self.settings.whisper_language == "auto" {
+                    self.ui_auto_detect_fallback(ui);
+                }
                 ui.add_space(4.0);
                 ui.label(i18n::tr("msg-language-accuracy"));
+                self.ui_whisper_task(ui);
 
                 // Download confirmation dialog
                 if self.show_download_confirm {
@@ -560,9 +1139,49 @@ impl SettingsWindow {
                                 size_mb
                             ));
                             ui_win.label(i18n::tr("msg-download-once"));
+                            if let Some(total) = self
+                                .system_memory_bytes
+                                .filter(|total| info.exceeds_memory(*total))
+                            {
+                                ui_win.add_space(4.0);
+                                ui_win.colored_label(
+                                    ui_win.visuals().warn_fg_color,
+                                    format!(
+                                        "{} (~{} MB / {} MB)",
+                                        i18n::tr("msg-model-low-memory"),
+                                        info.ram_mb,
+                                        total / 1_000_000
+                                    ),
+                                );
+                            }
+                            ui_win.add_space(4.0);
+                            // Effective URL (mirror applied) so users can verify the source
+                            let mirror = self.settings.model_download_base_url.as_deref();
+                            let mirror_err =
+                                mirror.filter(|m| !m.trim().is_empty()).and_then(|m| {
+                                    crate::transcription::validate_model_base_url(m).err()
+                                });
+                            ui_win.label(i18n::tr("label-download-url"));
+                            ui_win.label(
+                                egui::RichText::new(crate::transcription::model_download_url(
+                                    info, mirror,
+                                ))
+                                .monospace()
+                                .small(),
+                            );
+                            if let Some(err) = &mirror_err {
+                                ui_win.colored_label(
+                                    ui_win.visuals().warn_fg_color,
+                                    format!("{} ({})", i18n::tr("msg-model-mirror-invalid"), err),
+                                );
+                            }
                             ui_win.add_space(8.0);
                             ui_win.horizontal(|ui_h| {
-                                if ui_h.button(i18n::tr("btn-yes")).clicked() {
+                                let yes = ui_h.add_enabled(
+                                    mirror_err.is_none(),
+                                    egui::Button::new(i18n::tr("btn-yes")),
+                                );
+                                if yes.clicked() {
                                     self.start_download_current_selection();
                                     self.show_download_confirm = false;
                                 }
@@ -606,6 +1225,21 @@ impl SettingsWindow {
                             ),
                         );
                     }
+                    // e.g. a tiny model renamed or placed while large-v3 is selected
+                    let selected = &super::SUPPORTED_MODELS[self.selected_model_index];
+                    if let Some(detected) = self.current_used_preset {
+                        if same && detected.filename != selected.filename {
+                            ui.colored_label(
+                                ui.visuals().warn_fg_color,
+                                format!(
+                                    "{} {} / {}",
+                                    i18n::tr("msg-model-preset-mismatch"),
+                                    i18n::tr(detected.label_key),
+                                    i18n::tr(selected.label_key)
+                                ),
+                            );
+                        }
+                    }
                 }
                 ui.add_space(6.0);
                 // Advanced settings in a collapsed section (default closed)
@@ -645,6 +1279,86 @@ impl SettingsWindow {
                                 self.check_changes();
                             }
                         });
+                        ui.horizontal(|ui| {
+                            let mut manual = self.settings.whisper_threads.is_some();
+                            if ui
+                                .checkbox(&mut manual, i18n::tr("chk-whisper-threads"))
+                                .on_hover_text(i18n::tr("tooltip-whisper-threads"))
+                                .changed()
+                            {
+                                // Start from what auto would pick
+                                self.settings.whisper_threads = manual.then(|| {
+                                    available_threads(self.settings.whisper_use_physical_cores)
+                                        .clamp(1, 4) as usize
+                                });
+                                self.check_changes();
+                            }
+                            if let Some(threads) = self.settings.whisper_threads.as_mut() {
+                                if ui
+                                    .add(egui::DragValue::new(threads).range(1..=max_threads()))
+                                    .changed()
+                                {
+                                    self.check_changes();
+                                }
+                            }
+                        });
+                        ui.label(
+                            egui::RichText::new(i18n::tr("hint-whisper-threads"))
+                                .small()
+                                .color(ui.visuals().weak_text_color()),
+                        );
+
+                        ui.add_space(6.0);
+                        if ui
+                            .checkbox(
+                                &mut self.settings.normalize_audio,
+                                i18n::tr("chk-normalize-audio"),
+                            )
+                            .on_hover_text(i18n::tr("tooltip-normalize-audio"))
+                            .changed()
+                        {
+                            self.check_changes();
+                        }
+                        if ui
+                            .checkbox(
+                                &mut self.settings.trim_silence,
+                                i18n::tr("chk-trim-silence"),
+                            )
+                            .on_hover_text(i18n::tr("tooltip-trim-silence"))
+                            .changed()
+                        {
+                            self.check_changes();
+                        }
+                        if ui
+                            .checkbox(
+                                &mut self.settings.enable_spoken_commands,
+                                i18n::tr("chk-spoken-commands"),
+                            )
+                            .on_hover_text(i18n::tr("tooltip-spoken-commands"))
+                            .changed()
+                        {
+                            self.check_changes();
+                        }
+                        if ui
+                            .checkbox(
+                                &mut self.settings.local_autocapitalize,
+                                i18n::tr("chk-autocapitalize"),
+                            )
+                            .on_hover_text(i18n::tr("tooltip-autocapitalize"))
+                            .changed()
+                        {
+                            self.check_changes();
+                        }
+                        if ui
+                            .checkbox(
+                                &mut self.settings.keep_last_recording,
+                                i18n::tr("chk-keep-last-recording"),
+                            )
+                            .on_hover_text(i18n::tr("tooltip-keep-last-recording"))
+                            .changed()
+                        {
+                            self.check_changes();
+                        }
 
                         // Below: VAD (chunk split strategy)
                         ui.add_space(10.0);
@@ -656,15 +1370,17 @@ impl SettingsWindow {
                             let display = match self.settings.chunk_split_strategy {
                                 VadStrategy::Normal => i18n::tr("option-normal"),
                                 VadStrategy::Aggressive => i18n::tr("option-aggressive"),
+                                VadStrategy::Custom { .. } => i18n::tr("option-custom"),
                             };
+                            let current = self.settings.chunk_split_strategy;
+                            let is_custom = matches!(current, VadStrategy::Custom { .. });
                             let mut changed = false;
                             egui::ComboBox::from_id_salt("chunk_split_strategy_combo")
                                 .selected_text(display)
                                 .show_ui(ui, |ui| {
                                     if ui
                                         .selectable_label(
-                                            self.settings.chunk_split_strategy
-                                                == VadStrategy::Normal,
+                                            current == VadStrategy::Normal,
                                             i18n::tr("option-normal"),
                                         )
                                         .clicked()
@@ -674,8 +1390,7 @@ impl SettingsWindow {
                                     }
                                     if ui
                                         .selectable_label(
-                                            self.settings.chunk_split_strategy
-                                                == VadStrategy::Aggressive,
+                                            current == VadStrategy::Aggressive,
                                             i18n::tr("option-aggressive"),
                                         )
                                         .clicked()
@@ -684,11 +1399,117 @@ impl SettingsWindow {
                                             VadStrategy::Aggressive;
                                         changed = true;
                                     }
+                                    // Custom starts from whatever preset is active
+                                    if ui
+                                        .selectable_label(is_custom, i18n::tr("option-custom"))
+                                        .clicked()
+                                        && !is_custom
+                                    {
+                                        self.settings.chunk_split_strategy = current.to_custom();
+                                        changed = true;
+                                    }
                                 });
                             if changed {
                                 self.check_changes();
                             }
                         });
+                        // Sliders always show the effective values; editing one switches to Custom
+                        let (mut silence_db, mut min_silence_ms, mut min_speech_ms) =
+                            self.settings.chunk_split_strategy.params();
+                        let mut slider_changed = false;
+                        ui.horizontal(|ui| {
+                            ui.label(i18n::tr("label-vad-silence-db"));
+                            slider_changed |= ui
+                                .add(
+                                    egui::Slider::new(&mut silence_db, VAD_SILENCE_DB_RANGE)
+                                        .step_by(0.5)
+                                        .fixed_decimals(1)
+                                        .suffix(" dBFS"),
+                                )
+                                .on_hover_text(i18n::tr("tooltip-vad-silence-db"))
+                                .changed();
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label(i18n::tr("label-vad-min-silence"));
+                            slider_changed |= ui
+                                .add(
+                                    egui::Slider::new(&mut min_silence_ms, 100..=5000)
+                                        .step_by(50.0)
+                                        .suffix(" ms"),
+                                )
+                                .changed();
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label(i18n::tr("label-vad-min-speech"));
+                            slider_changed |= ui
+                                .add(
+                                    egui::Slider::new(&mut min_speech_ms, 0..=10000)
+                                        .step_by(100.0)
+                                        .suffix(" ms"),
+                                )
+                                .on_hover_text(i18n::tr("tooltip-vad-min-speech"))
+                                .changed();
+                        });
+                        if slider_changed {
+                            self.settings.chunk_split_strategy = VadStrategy::Custom {
+                                silence_db,
+                                min_silence_ms,
+                                min_speech_ms,
+                            };
+                            self.check_changes();
+                        }
+                        ui.label(
+                            egui::RichText::new(i18n::tr("note-vad-custom"))
+                                .small()
+                                .color(ui.visuals().weak_text_color()),
+                        );
+                        ui.add_space(6.0);
+                        ui.horizontal(|ui| {
+                            ui.label(i18n::tr("label-min-speech"));
+                            let old = self.settings.min_speech_secs;
+                            let slider = ui
+                                .add(
+                                    egui::Slider::new(
+                                        &mut self.settings.min_speech_secs,
+                                        0.0..=3.0,
+                                    )
+                                    .step_by(0.1)
+                                    .clamping(egui::SliderClamping::Always)
+                                    .suffix(" s"),
+                                )
+                                .on_hover_text(i18n::tr("tooltip-min-speech"));
+                            if slider.changed()
+                                && (self.settings.min_speech_secs - old).abs() > f32::EPSILON
+                            {
+                                self.check_changes();
+                            }
+                            if self.settings.min_speech_secs == 0.0 {
+                                ui.label(i18n::tr("label-disabled"));
+                            }
+                        });
+                        ui.add_space(6.0);
+                        self.ui_hallucination_filter(ui);
+                        ui.add_space(6.0);
+                        self.ui_min_segment_confidence(ui);
+                        ui.add_space(6.0);
+                        ui.horizontal(|ui| {
+                            ui.label(i18n::tr("label-parallel-chunks"));
+                            if ui
+                                .add(egui::Slider::new(
+                                    &mut self.settings.parallel_chunks,
+                                    1..=MAX_PARALLEL_CHUNKS,
+                                ))
+                                .on_hover_text(i18n::tr("tooltip-parallel-chunks"))
+                                .changed()
+                            {
+                                self.check_changes();
+                            }
+                        });
+                        ui.label(
+                            egui::RichText::new(i18n::tr("hint-parallel-chunks"))
+                                .small()
+                                .color(ui.visuals().weak_text_color()),
+                        );
 
                         // Advanced: auto‑stop (silence / max duration)
                         ui.add_space(10.0);
@@ -736,6 +1557,34 @@ impl SettingsWindow {
                                 ui.label(i18n::tr("label-disabled"));
                             }
                         });
+                        ui.add_enabled_ui(self.settings.max_record_secs > 0.0, |ui| {
+                            self.ui_max_record_action(ui);
+                        });
+                        // Stop phrase
+                        ui.add_space(6.0);
+                        ui.horizontal(|ui| {
+                            ui.label(i18n::tr("label-stop-phrase"));
+                            let mut text = self.settings.stop_phrase.clone().unwrap_or_default();
+                            let resp = ui.add(
+                                egui::TextEdit::singleline(&mut text)
+                                    .hint_text(i18n::tr("hint-stop-phrase-example"))
+                                    .desired_width(220.0),
+                            );
+                            if resp.changed() {
+                                // Empty input disables the stop phrase
+                                self.settings.stop_phrase = if text.trim().is_empty() {
+                                    None
+                                } else {
+                                    Some(text)
+                                };
+                                self.check_changes();
+                            }
+                        });
+                        ui.label(
+                            egui::RichText::new(i18n::tr("hint-stop-phrase"))
+                                .small()
+                                .color(ui.visuals().weak_text_color()),
+                        );
                     });
             });
     }
@@ -4,7 +4,9 @@ use std::thread;
 
 use eframe::egui;
 
-use crate::transcription::{download_with_progress_cancelable, SUPPORTED_MODELS};
+use crate::transcription::{
+    download_with_progress_cancelable, model_download_url, DownloadVerifyError, SUPPORTED_MODELS,
+};
 use crate::utils::app_config_dir;
 
 use super::SettingsWindow;
@@ -46,7 +48,7 @@ impl SettingsWindow {
                 ui.add_space(4.0);
                 // Rough estimates: memory and CPU time per minute
                 let size_mb = info.size_bytes as f64 / 1_000_000f64;
-                let mem_mb = (size_mb * 1.3).ceil();
+                let mem_mb = info.ram_mb as f64;
                 let time_per_minute = match info.speed_rating.round() as i32 {
                     // rough guide
                     5 => crate::i18n::tr("estimate-sec-per-min-10"),
@@ -77,6 +79,11 @@ impl SettingsWindow {
         self.show_download_confirm = true;
     }
 
+    /// Show (Some) or clear (None) the model load failure banner
+    pub fn set_model_load_error(&mut self, error: Option<String>) {
+        self.model_load_error = error;
+    }
+
     pub fn take_model_to_apply(&mut self) -> Option<PathBuf> {
         let pending = {
             let mut guard = self.pending_apply_model.lock().unwrap();
@@ -88,9 +95,9 @@ impl SettingsWindow {
                 .file_name()
                 .map(|f| PathBuf::from("models").join(f))
                 .unwrap_or_else(|| PathBuf::from("models/ggml-large-v3.bin"));
-            self.settings.whisper_model_path = rel;
+            // An explicit apply: saved even while other edits are pending
+            self.persist_now(|s| s.whisper_model_path = rel.clone());
             self.check_changes();
-            self.save_settings();
             self.save_status_message = Some("Model applied".to_string());
             // Return absolute path for actual loading
             Some(path)
@@ -103,6 +110,7 @@ impl SettingsWindow {
         let info = &SUPPORTED_MODELS[self.selected_model_index];
         // Download destination under OS-standard models dir
         let dest = app_config_dir().join("models").join(info.filename);
+        let url = model_download_url(info, self.settings.model_download_base_url.as_deref());
         *self.downloading.lock().unwrap() = true;
         *self.download_progress.lock().unwrap() = Some((0, info.size_bytes));
         *self.download_message.lock().unwrap() = Some(crate::i18n::tr("msg-download-started"));
@@ -116,8 +124,7 @@ impl SettingsWindow {
         let cancel = self.download_cancel_flag.clone();
 
         thread::spawn(move || {
-            let url = info.url;
-            let res = download_with_progress_cancelable(url, &dest, cancel, |done, total| {
+            let res = download_with_progress_cancelable(&url, &dest, cancel, |done, total| {
                 if let Ok(mut p) = prog.lock() {
                     *p = Some((done, total));
                 }
@@ -132,8 +139,17 @@ impl SettingsWindow {
                     }
                 }
                 Err(e) => {
+                    let text = match e.downcast_ref::<DownloadVerifyError>() {
+                        // "Error" prefix keeps the message styled as a failure
+                        Some(v) => format!(
+                            "Error: {} ({})",
+                            crate::i18n::tr("msg-download-verify-failed"),
+                            v.0
+                        ),
+                        None => format!("Error/Cancelled: {}", e),
+                    };
                     if let Ok(mut m) = msg.lock() {
-                        *m = Some(format!("Error/Cancelled: {}", e));
+                        *m = Some(text);
                     }
                 }
             }
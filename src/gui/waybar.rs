@@ -3,7 +3,7 @@ use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
-use crate::core::SimpleRecState;
+use crate::core::{ErrorEvent, SimpleRecState};
 use crate::i18n;
 
 fn status_path() -> PathBuf {
@@ -16,8 +16,28 @@ fn ensure_parent_dir(path: &Path) {
     }
 }
 
-pub fn write_status(state: SimpleRecState) {
+/// `queued`: a recording start is waiting for the current processing to finish
+pub fn write_status(
+    state: SimpleRecState,
+    hotkey_paused: bool,
+    error: Option<&ErrorEvent>,
+    queued: bool,
+) {
+    let error_text = error.map(|e| i18n::tr(e.message_key())).unwrap_or_default();
     let (text, tooltip, color, class, alt) = match state {
+        // Recent failure (shown for a few seconds after returning to idle)
+        SimpleRecState::Idle if error.is_some_and(ErrorEvent::is_notice) => {
+            ("!", &error_text, "#ffc107", "notice", "notice")
+        }
+        SimpleRecState::Idle if error.is_some() => ("!", &error_text, "#dc3545", "error", "error"),
+        // Paused only matters while idle; an in-flight recording still shows its state
+        SimpleRecState::Idle if hotkey_paused => (
+            "⏸",
+            &i18n::tr("status-hotkey-paused"),
+            "#6c757d",
+            "paused",
+            "paused",
+        ),
         SimpleRecState::Idle => ("○", &i18n::tr("status-idle"), "#22aa22", "idle", "idle"),
         SimpleRecState::Recording => (
             "●",
@@ -42,10 +62,33 @@ pub fn write_status(state: SimpleRecState) {
         ),
         SimpleRecState::Busy => ("●", &i18n::tr("status-busy"), "#6c757d", "busy", "busy"),
     };
-    let json = format!(
-        "{{\"text\":\"{}\",\"tooltip\":\"{}\",\"class\":\"{}\",\"alt\":\"{}\",\"color\":\"{}\"}}",
-        text, tooltip, class, alt, color
-    );
+    let queued_tooltip;
+    let (tooltip, class) = if queued
+        && matches!(
+            state,
+            SimpleRecState::Processing | SimpleRecState::PostProcessing
+        ) {
+        queued_tooltip = format!("{} · {}", tooltip, i18n::tr("status-queued"));
+        (&queued_tooltip, "queued")
+    } else {
+        (tooltip, class)
+    };
+    // pid/signals/socket let click handlers reach this instance (Waybar ignores them)
+    #[cfg(unix)]
+    let socket = crate::utils::ipc::active_socket_path().map(|p| p.display().to_string());
+    #[cfg(not(unix))]
+    let socket: Option<String> = None;
+    let json = serde_json::json!({
+        "text": text,
+        "tooltip": tooltip,
+        "class": class,
+        "alt": alt,
+        "color": color,
+        "pid": std::process::id(),
+        "signals": { "toggle": "SIGUSR1", "settings": "SIGUSR2" },
+        "socket": socket,
+    })
+    .to_string();
     let path = status_path();
     ensure_parent_dir(&path);
     // Atomic-ish write: write to temp then rename
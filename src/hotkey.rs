@@ -3,15 +3,81 @@ use global_hotkey::{
     hotkey::{Code, HotKey, Modifiers},
     GlobalHotKeyEvent, GlobalHotKeyManager,
 };
+use serde::{Deserialize, Serialize};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 type Callback = Box<dyn Fn() + Send + 'static>;
 
+// Shorter holds are stretched so the recorder has time to spin up before stopping
+const MIN_PUSH_TO_TALK_HOLD: Duration = Duration::from_millis(300);
+
+/// How the recording hotkey drives recording.
+///
+/// `PushToTalk` relies on key-release events. global-hotkey reports them on
+/// Windows, macOS and X11; Wayland has no global hotkeys at all (SIGUSR1 is
+/// always a toggle). Until the first release event is observed, a second
+/// press stops recording, so push-to-talk degrades to toggle where key-up is
+/// never delivered.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum HotkeyMode {
+    #[default]
+    Toggle,
+    PushToTalk,
+}
+
+/// What a global hotkey does when triggered.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum HotkeyAction {
+    ToggleRecord,
+    /// Record with a fixed LLM mode (e.g. `preset:summary`), ignoring the selected one
+    ToggleRecordWithMode(String),
+    ShowSettings,
+    /// Suspend/resume recording hotkeys (and SIGUSR1) without quitting
+    TogglePause,
+    /// Remove the text inserted by the last auto-paste (Backspace × length)
+    UndoPaste,
+    /// Copy and paste the last transcript again into the focused app
+    PasteLast,
+}
+
+impl HotkeyAction {
+    /// Recording actions follow the push-to-talk mode; others always fire on press
+    pub fn is_recording(&self) -> bool {
+        matches!(
+            self,
+            HotkeyAction::ToggleRecord | HotkeyAction::ToggleRecordWithMode(_)
+        )
+    }
+}
+
+/// A single hotkey → action binding persisted in settings.toml.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HotkeyBinding {
+    pub hotkey: String,
+    pub action: HotkeyAction,
+}
+
+/// Key edge delivered to binding handlers (`Up` only in push-to-talk mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyEdge {
+    Down,
+    Up,
+}
+
+struct Binding {
+    on_press: Callback,
+    // Some(..) for push-to-talk bindings
+    on_release: Option<Callback>,
+    pressed_at: Option<Instant>,
+}
+
 pub struct HotkeyManager {
     manager: GlobalHotKeyManager,
     hotkeys: Vec<HotKey>,
-    callbacks: Arc<Mutex<Vec<Callback>>>,
+    bindings: Arc<Mutex<Vec<Binding>>>,
     last_trigger_time: Arc<Mutex<Option<Instant>>>,
 }
 
@@ -22,22 +88,62 @@ impl HotkeyManager {
         Ok(Self {
             manager,
             hotkeys: Vec::new(),
-            callbacks: Arc::new(Mutex::new(Vec::new())),
+            bindings: Arc::new(Mutex::new(Vec::new())),
             last_trigger_time: Arc::new(Mutex::new(None)),
         })
     }
 
-    pub fn register_hotkey<F>(&mut self, hotkey_str: &str, callback: F) -> Result<()>
+    /// Register every binding; failures are returned per hotkey so one bad entry
+    /// does not prevent the others from working.
+    pub fn register_bindings<F>(
+        &mut self,
+        bindings: &[HotkeyBinding],
+        mode: HotkeyMode,
+        handler: F,
+    ) -> Vec<(String, anyhow::Error)>
     where
-        F: Fn() + Send + 'static,
+        F: Fn(&HotkeyAction, KeyEdge) + Send + Sync + 'static,
     {
-        let hotkey = self.parse_hotkey(hotkey_str)?;
+        let handler = Arc::new(handler);
+        let mut errors = Vec::new();
+        for binding in bindings {
+            let action = binding.action.clone();
+            let on_down: Callback = {
+                let handler = Arc::clone(&handler);
+                let action = action.clone();
+                Box::new(move || handler(&action, KeyEdge::Down))
+            };
+            let on_up: Option<Callback> = if mode == HotkeyMode::PushToTalk && action.is_recording()
+            {
+                let handler = Arc::clone(&handler);
+                Some(Box::new(move || handler(&action, KeyEdge::Up)))
+            } else {
+                None
+            };
+            if let Err(e) = self.register_binding(&binding.hotkey, on_down, on_up) {
+                errors.push((binding.hotkey.clone(), e));
+            }
+        }
+        errors
+    }
+
+    fn register_binding(
+        &mut self,
+        hotkey_str: &str,
+        on_press: Callback,
+        on_release: Option<Callback>,
+    ) -> Result<()> {
+        let hotkey = parse_hotkey(hotkey_str)?;
 
         self.manager.register(hotkey)?;
         self.hotkeys.push(hotkey);
 
-        let mut callbacks = self.callbacks.lock().unwrap();
-        callbacks.push(Box::new(callback));
+        let mut bindings = self.bindings.lock().unwrap();
+        bindings.push(Binding {
+            on_press,
+            on_release,
+            pressed_at: None,
+        });
 
         Ok(())
     }
@@ -48,120 +154,258 @@ impl HotkeyManager {
 
     pub fn spawn_event_thread(&self) {
         let hotkeys = self.hotkeys.clone();
-        let callbacks = Arc::clone(&self.callbacks);
+        let bindings = Arc::clone(&self.bindings);
         let last_trigger_time = Arc::clone(&self.last_trigger_time);
-        std::thread::spawn(move || loop {
-            if let Ok(event) = GlobalHotKeyEvent::receiver().try_recv() {
-                if event.state == global_hotkey::HotKeyState::Pressed {
-                    let mut last_time = last_trigger_time.lock().unwrap();
-                    let now = Instant::now();
-                    if let Some(last) = *last_time {
-                        if now.duration_since(last) < Duration::from_millis(500) {
-                            println!("[Hotkey] Debounced - ignoring rapid trigger");
-                            continue;
+        std::thread::spawn(move || {
+            // Flipped once the platform proves it delivers key-up events
+            let mut release_seen = false;
+            loop {
+                if let Ok(event) = GlobalHotKeyEvent::receiver().try_recv() {
+                    let Some(index) = hotkeys.iter().position(|h| h.id() == event.id) else {
+                        continue;
+                    };
+                    match event.state {
+                        global_hotkey::HotKeyState::Pressed => {
+                            let mut bindings = bindings.lock().unwrap();
+                            let Some(binding) = bindings.get_mut(index) else {
+                                continue;
+                            };
+                            // Held push-to-talk key: ignore auto-repeat, or treat as stop
+                            // when key-up has never been observed (toggle fallback)
+                            if binding.on_release.is_some() && binding.pressed_at.is_some() {
+                                if !release_seen {
+                                    binding.pressed_at = None;
+                                    println!("[Hotkey] No key-up seen; second press stops (toggle fallback)");
+                                    if let Some(ref on_release) = binding.on_release {
+                                        on_release();
+                                    }
+                                }
+                                continue;
+                            }
+
+                            let mut last_time = last_trigger_time.lock().unwrap();
+                            let now = Instant::now();
+                            if let Some(last) = *last_time {
+                                if now.duration_since(last) < Duration::from_millis(500) {
+                                    println!("[Hotkey] Debounced - ignoring rapid trigger");
+                                    continue;
+                                }
+                            }
+                            *last_time = Some(now);
+                            drop(last_time);
+
+                            if binding.on_release.is_some() {
+                                binding.pressed_at = Some(now);
+                                println!("[Hotkey] Key down (push-to-talk start)");
+                            } else {
+                                println!("[Hotkey] Executing callback");
+                            }
+                            (binding.on_press)();
                         }
-                    }
-                    *last_time = Some(now);
-                    drop(last_time);
-
-                    if let Some(index) = hotkeys.iter().position(|h| h.id() == event.id) {
-                        let callbacks = callbacks.lock().unwrap();
-                        if let Some(callback) = callbacks.get(index) {
-                            println!("[Hotkey] Executing callback (toggle recording)");
-                            callback();
+                        global_hotkey::HotKeyState::Released => {
+                            release_seen = true;
+                            let mut bindings = bindings.lock().unwrap();
+                            let Some(binding) = bindings.get_mut(index) else {
+                                continue;
+                            };
+                            let Some(pressed_at) = binding.pressed_at.take() else {
+                                continue;
+                            };
+                            if let Some(ref on_release) = binding.on_release {
+                                let held = pressed_at.elapsed();
+                                if held < MIN_PUSH_TO_TALK_HOLD {
+                                    std::thread::sleep(MIN_PUSH_TO_TALK_HOLD - held);
+                                }
+                                println!("[Hotkey] Key up (push-to-talk stop)");
+                                on_release();
+                            }
                         }
                     }
                 }
+                std::thread::sleep(Duration::from_millis(50));
             }
-            std::thread::sleep(Duration::from_millis(50));
         });
     }
+}
 
-    fn parse_hotkey(&self, hotkey_str: &str) -> Result<HotKey> {
-        // Normalize: replace full-width '+' with '+' and trim spaces
-        let normalized = hotkey_str.replace('＋', "+");
-        let parts: Vec<String> = normalized
-            .split('+')
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
-            .collect();
-        let mut modifiers = Modifiers::empty();
-        let mut key_code = None;
-
-        for part in parts {
-            match part.to_lowercase().as_str() {
-                "ctrl" | "control" => modifiers.insert(Modifiers::CONTROL),
-                "shift" => modifiers.insert(Modifiers::SHIFT),
-                "alt" | "option" => modifiers.insert(Modifiers::ALT),
-                "cmd" | "command" | "super" | "win" | "windows" => {
-                    modifiers.insert(Modifiers::SUPER)
-                }
-                key => {
-                    key_code = Some(self.parse_key_code(key)?);
-                }
+/// Parse a hotkey string such as "Ctrl+Shift+R".
+pub fn parse_hotkey(hotkey_str: &str) -> Result<HotKey> {
+    // Normalize: replace full-width '+' with '+' and trim spaces
+    let normalized = hotkey_str.replace('＋', "+");
+    let parts: Vec<String> = normalized
+        .split('+')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+    let mut modifiers = Modifiers::empty();
+    let mut key_code = None;
+
+    for part in parts {
+        match part.to_lowercase().as_str() {
+            "ctrl" | "control" => modifiers.insert(Modifiers::CONTROL),
+            "shift" => modifiers.insert(Modifiers::SHIFT),
+            "alt" | "option" => modifiers.insert(Modifiers::ALT),
+            "cmd" | "command" | "super" | "win" | "windows" => modifiers.insert(Modifiers::SUPER),
+            key => {
+                key_code = Some(parse_key_code(key)?);
             }
         }
+    }
 
-        let code = key_code.ok_or_else(|| anyhow::anyhow!("No key specified in hotkey"))?;
+    let code = key_code.ok_or_else(|| anyhow::anyhow!("No key specified in hotkey"))?;
 
-        Ok(HotKey::new(Some(modifiers), code))
+    Ok(HotKey::new(Some(modifiers), code))
+}
+
+fn parse_key_code(key: &str) -> Result<Code> {
+    Ok(match key.to_lowercase().as_str() {
+        "a" => Code::KeyA,
+        "b" => Code::KeyB,
+        "c" => Code::KeyC,
+        "d" => Code::KeyD,
+        "e" => Code::KeyE,
+        "f" => Code::KeyF,
+        "g" => Code::KeyG,
+        "h" => Code::KeyH,
+        "i" => Code::KeyI,
+        "j" => Code::KeyJ,
+        "k" => Code::KeyK,
+        "l" => Code::KeyL,
+        "m" => Code::KeyM,
+        "n" => Code::KeyN,
+        "o" => Code::KeyO,
+        "p" => Code::KeyP,
+        "q" => Code::KeyQ,
+        "r" => Code::KeyR,
+        "s" => Code::KeyS,
+        "t" => Code::KeyT,
+        "u" => Code::KeyU,
+        "v" => Code::KeyV,
+        "w" => Code::KeyW,
+        "x" => Code::KeyX,
+        "y" => Code::KeyY,
+        "z" => Code::KeyZ,
+        "0" => Code::Digit0,
+        "1" => Code::Digit1,
+        "2" => Code::Digit2,
+        "3" => Code::Digit3,
+        "4" => Code::Digit4,
+        "5" => Code::Digit5,
+        "6" => Code::Digit6,
+        "7" => Code::Digit7,
+        "8" => Code::Digit8,
+        "9" => Code::Digit9,
+        "f1" => Code::F1,
+        "f2" => Code::F2,
+        "f3" => Code::F3,
+        "f4" => Code::F4,
+        "f5" => Code::F5,
+        "f6" => Code::F6,
+        "f7" => Code::F7,
+        "f8" => Code::F8,
+        "f9" => Code::F9,
+        "f10" => Code::F10,
+        "f11" => Code::F11,
+        "f12" => Code::F12,
+        "space" => Code::Space,
+        "enter" | "return" => Code::Enter,
+        "tab" => Code::Tab,
+        "escape" | "esc" => Code::Escape,
+        _ => return Err(anyhow::anyhow!("Unknown key: {}", key)),
+    })
+}
+
+/// Canonical `Ctrl+Alt+Shift+Cmd+Key` string for a captured key press. Errors when
+/// it cannot be registered: an unknown key, or a non-function key without modifiers.
+pub fn format_hotkey(
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    command: bool,
+    key: &str,
+) -> Result<String> {
+    let code = parse_key_code(key)?;
+    let function_key = matches!(
+        code,
+        Code::F1
+            | Code::F2
+            | Code::F3
+            | Code::F4
+            | Code::F5
+            | Code::F6
+            | Code::F7
+            | Code::F8
+            | Code::F9
+            | Code::F10
+            | Code::F11
+            | Code::F12
+    );
+    if !(ctrl || alt || shift || command || function_key) {
+        return Err(anyhow::anyhow!("{} needs at least one modifier", key));
     }
+    let mut chars = key.chars();
+    let key = chars
+        .next()
+        .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+        .unwrap_or_default();
+    let parts: Vec<&str> = [
+        (ctrl, "Ctrl"),
+        (alt, "Alt"),
+        (shift, "Shift"),
+        (command, "Cmd"),
+    ]
+    .into_iter()
+    .filter_map(|(held, name)| held.then_some(name))
+    .chain(std::iter::once(key.as_str()))
+    .collect();
+    let hotkey = parts.join("+");
+    parse_hotkey(&hotkey)?;
+    Ok(hotkey)
+}
 
-    fn parse_key_code(&self, key: &str) -> Result<Code> {
-        Ok(match key.to_lowercase().as_str() {
-            "a" => Code::KeyA,
-            "b" => Code::KeyB,
-            "c" => Code::KeyC,
-            "d" => Code::KeyD,
-            "e" => Code::KeyE,
-            "f" => Code::KeyF,
-            "g" => Code::KeyG,
-            "h" => Code::KeyH,
-            "i" => Code::KeyI,
-            "j" => Code::KeyJ,
-            "k" => Code::KeyK,
-            "l" => Code::KeyL,
-            "m" => Code::KeyM,
-            "n" => Code::KeyN,
-            "o" => Code::KeyO,
-            "p" => Code::KeyP,
-            "q" => Code::KeyQ,
-            "r" => Code::KeyR,
-            "s" => Code::KeyS,
-            "t" => Code::KeyT,
-            "u" => Code::KeyU,
-            "v" => Code::KeyV,
-            "w" => Code::KeyW,
-            "x" => Code::KeyX,
-            "y" => Code::KeyY,
-            "z" => Code::KeyZ,
-            "0" => Code::Digit0,
-            "1" => Code::Digit1,
-            "2" => Code::Digit2,
-            "3" => Code::Digit3,
-            "4" => Code::Digit4,
-            "5" => Code::Digit5,
-            "6" => Code::Digit6,
-            "7" => Code::Digit7,
-            "8" => Code::Digit8,
-            "9" => Code::Digit9,
-            "f1" => Code::F1,
-            "f2" => Code::F2,
-            "f3" => Code::F3,
-            "f4" => Code::F4,
-            "f5" => Code::F5,
-            "f6" => Code::F6,
-            "f7" => Code::F7,
-            "f8" => Code::F8,
-            "f9" => Code::F9,
-            "f10" => Code::F10,
-            "f11" => Code::F11,
-            "f12" => Code::F12,
-            "space" => Code::Space,
-            "enter" | "return" => Code::Enter,
-            "tab" => Code::Tab,
-            "escape" | "esc" => Code::Escape,
-            _ => return Err(anyhow::anyhow!("Unknown key: {}", key)),
-        })
+/// Indices of bindings whose hotkeys resolve to the same key combination.
+pub fn find_conflicts(bindings: &[HotkeyBinding]) -> Vec<usize> {
+    let ids: Vec<Option<u32>> = bindings
+        .iter()
+        .map(|b| parse_hotkey(&b.hotkey).ok().map(|h| h.id()))
+        .collect();
+    let mut out = Vec::new();
+    for (i, id) in ids.iter().enumerate() {
+        if let Some(id) = id {
+            if ids
+                .iter()
+                .enumerate()
+                .any(|(j, other)| j != i && other == &Some(*id))
+            {
+                out.push(i);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_hotkey, parse_hotkey};
+
+    #[test]
+    fn captured_combos_are_canonical_and_registrable() {
+        assert_eq!(
+            format_hotkey(true, false, true, false, "r").unwrap(),
+            "Ctrl+Shift+R"
+        );
+        assert_eq!(
+            format_hotkey(false, false, false, false, "F9").unwrap(),
+            "F9"
+        );
+        assert_eq!(
+            format_hotkey(false, true, false, true, "space").unwrap(),
+            "Alt+Cmd+Space"
+        );
+        // A plain letter would swallow normal typing; unknown keys cannot register
+        assert!(format_hotkey(false, false, false, false, "R").is_err());
+        assert!(format_hotkey(true, false, false, false, "PageUp").is_err());
+        // Only '+' separates keys
+        assert!(parse_hotkey("control shift r").is_err());
     }
 }
@@ -0,0 +1,67 @@
+//! Splitting oversize transcripts into pieces that fit `max_input_chars`.
+
+// Sentence ends in Japanese and Latin text
+const SENTENCE_ENDS: &[char] = &['。', '！', '？', '.', '!', '?'];
+
+/// Split `text` into pieces of at most `max_chars` characters, preferring line
+/// breaks, then sentence ends, then whitespace in the second half of each piece.
+pub fn split_transcript(text: &str, max_chars: usize) -> Vec<String> {
+    let chars: Vec<char> = text.trim().chars().collect();
+    if max_chars == 0 || chars.len() <= max_chars {
+        return vec![chars.into_iter().collect()];
+    }
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    while start < chars.len() {
+        let end = (start + max_chars).min(chars.len());
+        let cut = if end == chars.len() {
+            end
+        } else {
+            let window = &chars[start + max_chars / 2..end];
+            let after = |pred: &dyn Fn(char) -> bool| {
+                window
+                    .iter()
+                    .rposition(|&c| pred(c))
+                    .map(|i| start + max_chars / 2 + i + 1)
+            };
+            after(&|c| c == '\n')
+                .or_else(|| after(&|c| SENTENCE_ENDS.contains(&c)))
+                .or_else(|| after(&char::is_whitespace))
+                .unwrap_or(end)
+        };
+        let piece: String = chars[start..cut].iter().collect();
+        let piece = piece.trim();
+        if !piece.is_empty() {
+            pieces.push(piece.to_string());
+        }
+        start = cut;
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_transcript;
+
+    #[test]
+    fn splits_at_natural_boundaries() {
+        assert_eq!(split_transcript(" short ", 10), vec!["short"]);
+        assert_eq!(
+            split_transcript("First sentence here. Second one", 24),
+            vec!["First sentence here.", "Second one"]
+        );
+        assert_eq!(
+            split_transcript("alpha beta gamma", 12),
+            vec!["alpha beta", "gamma"]
+        );
+        assert_eq!(
+            split_transcript("今日は晴れです。明日は雨です。", 10),
+            vec!["今日は晴れです。", "明日は雨です。"]
+        );
+        // No boundary: hard cut on characters, never inside a code point
+        assert_eq!(
+            split_transcript("あいうえおかき", 3),
+            vec!["あいう", "えおか", "き"]
+        );
+    }
+}
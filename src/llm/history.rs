@@ -2,6 +2,7 @@ use crate::llm::LlmPostProcessSettings;
 use crate::utils::app_config_dir;
 use chrono::Local;
 use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::Write;
@@ -9,18 +10,172 @@ use std::path::PathBuf;
 use std::sync::Mutex;
 
 pub const HISTORY_FILENAME: &str = "llm_history.yaml";
-pub const MAX_HISTORY_ENTRIES: usize = 20;
+pub const DEFAULT_HISTORY_MAX_ENTRIES: usize = 20;
+// Upper bound for the setting (the whole file is rewritten on every entry)
+pub const HISTORY_MAX_ENTRIES_LIMIT: usize = 5_000;
+
+/// How many history entries are kept and for how long
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoryRetention {
+    pub max_entries: usize,
+    // None keeps entries regardless of age
+    pub retention_days: Option<u32>,
+}
+
+impl Default for HistoryRetention {
+    fn default() -> Self {
+        Self {
+            max_entries: DEFAULT_HISTORY_MAX_ENTRIES,
+            retention_days: None,
+        }
+    }
+}
+
+impl HistoryRetention {
+    // Drop entries older than the retention period, then the oldest beyond the count.
+    // Entries with an unreadable timestamp are only dropped by count.
+    fn prune(&self, entries: &mut Vec<LlmHistoryEntry>, now: chrono::DateTime<Local>) {
+        if let Some(days) = self.retention_days {
+            let cutoff = now - chrono::Duration::days(i64::from(days));
+            entries.retain(|e| {
+                chrono::DateTime::parse_from_rfc3339(&e.timestamp)
+                    .map(|ts| ts >= cutoff)
+                    .unwrap_or(true)
+            });
+        }
+        let max = self.max_entries.clamp(1, HISTORY_MAX_ENTRIES_LIMIT);
+        if entries.len() > max {
+            let remove_count = entries.len() - max;
+            entries.drain(0..remove_count);
+        }
+    }
+}
 
 static HISTORY_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
 
+// Replacement for text matched by a redaction pattern
+pub const REDACTED_MARK: &str = "[redacted]";
+// Example offered in the History tab: 13–19 digits, optionally grouped by spaces/dashes
+pub const CARD_NUMBER_PATTERN: &str = r"\b\d(?:[ -]?\d){12,18}\b";
+
+/// Regex masks applied to the transcript and LLM output before they are saved
+#[derive(Debug, Clone, Default)]
+pub struct HistoryRedaction {
+    patterns: Vec<String>,
+    regexes: Vec<Regex>,
+}
+
+impl HistoryRedaction {
+    // Empty and invalid patterns are skipped (the settings UI reports them)
+    pub fn new(patterns: &[String]) -> Self {
+        let regexes = patterns
+            .iter()
+            .filter(|p| !p.trim().is_empty())
+            .filter_map(|p| Regex::new(p).ok())
+            .collect();
+        Self {
+            patterns: patterns.to_vec(),
+            regexes,
+        }
+    }
+
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    pub fn apply(&self, text: &str) -> String {
+        let mut out = text.to_string();
+        for re in &self.regexes {
+            if let std::borrow::Cow::Owned(replaced) = re.replace_all(&out, REDACTED_MARK) {
+                out = replaced;
+            }
+        }
+        out
+    }
+}
+
+/// Error message when `pattern` is not a valid regular expression
+pub fn redaction_pattern_error(pattern: &str) -> Option<String> {
+    Regex::new(pattern).err().map(|e| e.to_string())
+}
+
+/// Date range of the History tab filter
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HistoryDateFilter {
+    #[default]
+    All,
+    Today,
+    Last7Days,
+    Last30Days,
+}
+
+impl HistoryDateFilter {
+    pub const ALL: [Self; 4] = [Self::All, Self::Today, Self::Last7Days, Self::Last30Days];
+
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Self::All => "option-history-date-all",
+            Self::Today => "option-history-date-today",
+            Self::Last7Days => "option-history-date-7d",
+            Self::Last30Days => "option-history-date-30d",
+        }
+    }
+
+    // Earliest entry time kept (None = no limit)
+    fn since(self, now: chrono::DateTime<Local>) -> Option<chrono::DateTime<Local>> {
+        match self {
+            Self::All => None,
+            Self::Today => now
+                .date_naive()
+                .and_hms_opt(0, 0, 0)
+                .and_then(|midnight| midnight.and_local_timezone(Local).earliest()),
+            Self::Last7Days => Some(now - chrono::Duration::days(7)),
+            Self::Last30Days => Some(now - chrono::Duration::days(30)),
+        }
+    }
+}
+
+/// History tab filter: case-insensitive substring of the transcript or LLM output,
+/// within the date range. Unreadable timestamps only pass without a date limit.
+pub fn entry_matches_filter(
+    entry: &LlmHistoryEntry,
+    query: &str,
+    date: HistoryDateFilter,
+    now: chrono::DateTime<Local>,
+) -> bool {
+    if let Some(since) = date.since(now) {
+        let in_range = chrono::DateTime::parse_from_rfc3339(&entry.timestamp)
+            .map(|ts| ts >= since)
+            .unwrap_or(false);
+        if !in_range {
+            return false;
+        }
+    }
+    let q = query.trim().to_lowercase();
+    q.is_empty()
+        || entry.transcript.to_lowercase().contains(&q)
+        || entry.llm_output.to_lowercase().contains(&q)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct LlmHistoryEntry {
     pub timestamp: String,
+    // Missing in entries saved without the raw text (Reprocess is disabled for them)
+    #[serde(default)]
     pub transcript: String,
     pub llm_output: String,
     pub truncated_input: bool,
     pub llm_latency_ms: u64,
     pub settings: LlmHistorySettingsSnapshot,
+    // Dictation stats (older entries default to zero)
+    #[serde(default)]
+    pub recording_duration_secs: f32,
+    #[serde(default)]
+    pub char_count: usize,
+    #[serde(default)]
+    pub word_count: usize,
+    #[serde(default)]
+    pub words_per_minute: f32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -54,12 +209,19 @@ fn mode_label(snapshot: &LlmPostProcessSettings) -> String {
         "format".to_string()
     } else if snapshot.mode_id == crate::llm::PRESET_ID_SUMMARY {
         "summary".to_string()
+    } else if snapshot.mode_id == crate::llm::PRESET_ID_FURIGANA {
+        "furigana".to_string()
     } else {
         snapshot
             .custom_prompts
             .iter()
             .find(|p| p.id == snapshot.mode_id)
             .map(|p| p.name.clone())
+            .or_else(|| {
+                snapshot
+                    .chain_mode(&snapshot.mode_id)
+                    .map(|c| c.name.clone())
+            })
             .unwrap_or_else(|| snapshot.mode_id.clone())
     }
 }
@@ -91,7 +253,7 @@ fn build_settings_snapshot(settings: &LlmPostProcessSettings) -> LlmHistorySetti
 
     LlmHistorySettingsSnapshot {
         api_base_url: settings.effective_base_url(),
-        model: settings.model.clone(),
+        model: settings.effective_model(),
         mode_id: settings.mode_id.clone(),
         mode_label: mode_label(settings),
         language_override: settings.language_override.clone(),
@@ -100,12 +262,29 @@ fn build_settings_snapshot(settings: &LlmPostProcessSettings) -> LlmHistorySetti
     }
 }
 
+/// Character/word counts of the dictated transcript and the resulting words per minute.
+/// Words follow Unicode segmentation so CJK text is counted per word-like unit.
+fn dictation_stats(transcript: &str, duration_secs: f32) -> (usize, usize, f32) {
+    use unicode_segmentation::UnicodeSegmentation;
+    let char_count = transcript.chars().filter(|c| !c.is_whitespace()).count();
+    let word_count = transcript.unicode_words().count();
+    let wpm = if duration_secs > 0.0 {
+        word_count as f32 / (duration_secs / 60.0)
+    } else {
+        0.0
+    };
+    (char_count, word_count, wpm)
+}
+
 pub fn record_entry(
     transcript: &str,
     llm_output: &str,
     truncated_input: bool,
     llm_latency_ms: u128,
+    recording_duration_secs: f32,
     settings: &LlmPostProcessSettings,
+    retention: HistoryRetention,
+    redaction: &HistoryRedaction,
 ) -> anyhow::Result<HistorySaveOutcome> {
     let _guard = HISTORY_LOCK.lock().unwrap();
 
@@ -122,19 +301,22 @@ pub fn record_entry(
     };
 
     let mut entries = existing.entries;
+    let (char_count, word_count, words_per_minute) =
+        dictation_stats(transcript, recording_duration_secs);
     let entry = LlmHistoryEntry {
         timestamp: Local::now().to_rfc3339(),
-        transcript: transcript.to_string(),
-        llm_output: llm_output.to_string(),
+        transcript: redaction.apply(transcript),
+        llm_output: redaction.apply(llm_output),
         truncated_input,
         llm_latency_ms: llm_latency_ms.min(u64::MAX as u128) as u64,
         settings: build_settings_snapshot(settings),
+        recording_duration_secs,
+        char_count,
+        word_count,
+        words_per_minute,
     };
     entries.push(entry);
-    if entries.len() > MAX_HISTORY_ENTRIES {
-        let remove_count = entries.len() - MAX_HISTORY_ENTRIES;
-        entries.drain(0..remove_count);
-    }
+    retention.prune(&mut entries, Local::now());
     let total_entries = entries.len();
 
     let file = HistoryFile { entries };
@@ -148,14 +330,27 @@ pub fn record_entry(
     Ok(HistorySaveOutcome { total_entries })
 }
 
-pub fn load_entries() -> anyhow::Result<Vec<LlmHistoryEntry>> {
+/// Entries within `retention` (the file itself is pruned on the next write)
+pub fn load_entries(retention: HistoryRetention) -> anyhow::Result<Vec<LlmHistoryEntry>> {
     let path = history_path();
     if !path.exists() {
         return Ok(Vec::new());
     }
     let yaml = fs::read_to_string(&path)?;
     let file = serde_yaml::from_str::<HistoryFile>(&yaml).unwrap_or_default();
-    Ok(file.entries)
+    let mut entries = file.entries;
+    retention.prune(&mut entries, Local::now());
+    Ok(entries)
+}
+
+/// Delete all history entries
+pub fn clear_entries() -> anyhow::Result<()> {
+    let _guard = HISTORY_LOCK.lock().unwrap();
+    let path = history_path();
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
 }
 
 pub fn history_modified_time() -> Option<std::time::SystemTime> {
@@ -166,3 +361,97 @@ pub fn history_modified_time() -> Option<std::time::SystemTime> {
 pub fn history_file_path() -> PathBuf {
     history_path()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp: &str) -> LlmHistoryEntry {
+        LlmHistoryEntry {
+            timestamp: timestamp.to_string(),
+            transcript: String::new(),
+            llm_output: String::new(),
+            truncated_input: false,
+            llm_latency_ms: 0,
+            settings: build_settings_snapshot(&LlmPostProcessSettings::default()),
+            recording_duration_secs: 0.0,
+            char_count: 0,
+            word_count: 0,
+            words_per_minute: 0.0,
+        }
+    }
+
+    #[test]
+    fn prunes_by_age_then_count() {
+        let now = Local::now();
+        let days_ago = |d: i64| (now - chrono::Duration::days(d)).to_rfc3339();
+        let mut entries = vec![
+            entry(&days_ago(40)),
+            entry("not a date"),
+            entry(&days_ago(10)),
+            entry(&days_ago(2)),
+            entry(&days_ago(0)),
+        ];
+        HistoryRetention {
+            max_entries: 3,
+            retention_days: Some(30),
+        }
+        .prune(&mut entries, now);
+        let kept: Vec<&str> = entries.iter().map(|e| e.timestamp.as_str()).collect();
+        assert_eq!(kept, vec![days_ago(10), days_ago(2), days_ago(0)]);
+
+        let mut entries = vec![entry(&days_ago(400)), entry(&days_ago(1))];
+        HistoryRetention::default().prune(&mut entries, now);
+        assert_eq!(entries.len(), 2);
+    }
+
+    #[test]
+    fn entry_without_transcript_loads() {
+        let mut e = entry("2026-01-01T00:00:00+00:00");
+        e.llm_output = "output".into();
+        let yaml = serde_yaml::to_string(&e).unwrap();
+        let yaml: String = yaml
+            .lines()
+            .filter(|l| !l.starts_with("transcript:"))
+            .map(|l| format!("{}\n", l))
+            .collect();
+        let loaded: LlmHistoryEntry = serde_yaml::from_str(&yaml).unwrap();
+        assert!(loaded.transcript.is_empty());
+        assert_eq!(loaded.llm_output, "output");
+    }
+
+    #[test]
+    fn filters_by_text_and_date() {
+        let now = Local::now();
+        let mut old = entry(&(now - chrono::Duration::days(10)).to_rfc3339());
+        old.transcript = "Meeting notes about the Budget".into();
+        let mut recent = entry(&now.to_rfc3339());
+        recent.llm_output = "- budget approved".into();
+        let broken = entry("not a date");
+
+        let matches =
+            |e: &LlmHistoryEntry, q: &str, d: HistoryDateFilter| entry_matches_filter(e, q, d, now);
+        assert!(matches(&old, " budget ", HistoryDateFilter::All));
+        assert!(matches(&recent, "BUDGET", HistoryDateFilter::Today));
+        assert!(!matches(&old, "budget", HistoryDateFilter::Last7Days));
+        assert!(matches(&old, "", HistoryDateFilter::Last30Days));
+        assert!(!matches(&old, "invoice", HistoryDateFilter::All));
+        assert!(matches(&broken, "", HistoryDateFilter::All));
+        assert!(!matches(&broken, "", HistoryDateFilter::Today));
+    }
+
+    #[test]
+    fn redacts_card_numbers_and_skips_invalid_patterns() {
+        let redaction = HistoryRedaction::new(&[
+            CARD_NUMBER_PATTERN.to_string(),
+            "(unclosed".to_string(),
+            "secret-\\w+".to_string(),
+        ]);
+        assert_eq!(
+            redaction.apply("card 4111 1111 1111 1111, code secret-abc, order 12345"),
+            "card [redacted], code [redacted], order 12345"
+        );
+        assert!(redaction_pattern_error("(unclosed").is_some());
+        assert!(redaction_pattern_error(CARD_NUMBER_PATTERN).is_none());
+    }
+}
@@ -0,0 +1,85 @@
+//! Markdown clean-up for the summary preset: whatever bullet style the model
+//! drifts into ("* ", "• ", "1) ", "・"), the result is a plain "- " list with
+//! a blank line between any lead-in text and the list.
+
+// Bullet and numbering prefixes LLMs commonly emit instead of "- "
+const BULLET_MARKERS: &[&str] = &["- ", "* ", "+ ", "• ", "・", "– ", "— "];
+
+// Text after a bullet/number marker, or None for a plain line
+fn bullet_text(line: &str) -> Option<&str> {
+    // A bare marker is an empty bullet
+    if BULLET_MARKERS.iter().any(|m| m.trim_end() == line) {
+        return Some("");
+    }
+    if let Some(rest) = BULLET_MARKERS.iter().find_map(|m| line.strip_prefix(m)) {
+        return Some(rest.trim_start());
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits == 0 {
+        return None;
+    }
+    let rest = &line[digits..];
+    [". ", ") "]
+        .iter()
+        .find_map(|m| rest.strip_prefix(m))
+        .map(str::trim_start)
+}
+
+/// Normalize an LLM summary to a `- ` list. Lines before the first bullet stay as
+/// a lead-in paragraph; plain lines after it continue the previous bullet.
+/// `heading` (e.g. the date) is emitted as `## heading` above everything.
+pub fn markdown_bullet_list(text: &str, heading: Option<&str>) -> String {
+    let mut lead: Vec<&str> = Vec::new();
+    let mut bullets: Vec<String> = Vec::new();
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        match (bullet_text(line), bullets.last_mut()) {
+            (Some(item), _) if !item.is_empty() => bullets.push(item.to_string()),
+            (Some(_), _) => {}
+            (None, Some(last)) => {
+                last.push(' ');
+                last.push_str(line);
+            }
+            (None, None) => lead.push(line),
+        }
+    }
+    if bullets.is_empty() {
+        // Nothing list-like: keep the text, one bullet per line
+        bullets = lead.drain(..).map(str::to_string).collect();
+    }
+
+    let mut blocks: Vec<String> = Vec::new();
+    if let Some(h) = heading.map(str::trim).filter(|h| !h.is_empty()) {
+        blocks.push(format!("## {}", h));
+    }
+    if !lead.is_empty() {
+        blocks.push(lead.join("\n"));
+    }
+    if !bullets.is_empty() {
+        let list: Vec<String> = bullets.iter().map(|b| format!("- {}", b)).collect();
+        blocks.push(list.join("\n"));
+    }
+    blocks.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::markdown_bullet_list;
+
+    #[test]
+    fn normalizes_bullet_styles() {
+        let raw = "Summary:\n* First point\n  that wraps\n\n• Second\n2) Third\n・四つ目\n-\n";
+        assert_eq!(
+            markdown_bullet_list(raw, None),
+            "Summary:\n\n- First point that wraps\n- Second\n- Third\n- 四つ目"
+        );
+        assert_eq!(
+            markdown_bullet_list("one\ntwo", Some("2026-10-16")),
+            "## 2026-10-16\n\n- one\n- two"
+        );
+        // Years and decimals are not list numbers
+        assert_eq!(
+            markdown_bullet_list("- 2024.5 release", None),
+            "- 2024.5 release"
+        );
+    }
+}
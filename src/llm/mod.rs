@@ -5,11 +5,14 @@ use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::cmp::min;
-use std::collections::HashSet;
-use std::sync::Mutex;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
 use std::time::{Duration, Instant};
+use unicode_segmentation::UnicodeSegmentation;
 
+mod chunk;
 mod history;
+mod markdown;
 
 /// Default API base URL for OpenAI 互換ローカルエンドポイント (例: Ollama)。
 pub const DEFAULT_LOCAL_BASE_URL: &str = "http://localhost:11434/v1";
@@ -19,6 +22,17 @@ pub const DEFAULT_LOCAL_MODEL: &str = "llama3.1:8b";
 pub const DEFAULT_MAX_INPUT_CHARS: usize = 4_000;
 /// Default request timeout in seconds.
 pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+/// Default timeout for model listing and the connection test (should fail fast).
+pub const DEFAULT_MODELS_TIMEOUT_SECS: u64 = 10;
+/// Default `max_tokens` for post-processing responses.
+pub const DEFAULT_MAX_OUTPUT_TOKENS: u32 = 1024;
+/// Accepted range for a configured `max_tokens`.
+pub const MIN_MAX_OUTPUT_TOKENS: u32 = 64;
+pub const MAX_MAX_OUTPUT_TOKENS: u32 = 16_384;
+/// Default sampling temperature for post-processing.
+pub const DEFAULT_TEMPERATURE: f32 = 0.2;
+/// Maximum accepted sampling temperature.
+pub const MAX_TEMPERATURE: f32 = 2.0;
 
 const USER_AGENT_VALUE: &str = concat!("hootvoice/", env!("CARGO_PKG_VERSION"));
 const CHAT_COMPLETIONS_PATH: &str = "chat/completions";
@@ -37,28 +51,62 @@ const FORMAT_SYSTEM_GLOBAL: &str = "You receive an automatic transcript. Clean i
 const SUMMARY_SYSTEM_JA: &str = "以下の文字起こしを最大5つの簡潔な箇条書きで日本語のまま要約してください。各行は \"- \" で開始し、余計な前置きや感想は入れないでください。";
 const SUMMARY_SYSTEM_EN: &str = "Summarize the transcript into at most five concise bullet points written in English. Start each bullet with \"- \" and avoid any commentary.";
 const SUMMARY_SYSTEM_GLOBAL: &str = "Summarize the transcript into at most five concise bullet points. Prefer the transcript language when obvious, otherwise use English. Start each bullet with \"- \".";
+const FURIGANA_SYSTEM_JA: &str = "以下の文字起こしの漢字すべてに、直後の半角括弧でひらがなの読みを付けてください（例: 漢字(かんじ)、東京(とうきょう)に行(い)く）。熟語は語全体にまとめて読みを付け、ひらがな・カタカナ・英数字には付けません。本文の語句や句読点は一切変更せず、注釈付きのテキストのみを返してください。日本語以外の文章はそのまま返します。";
+const FURIGANA_SYSTEM_GLOBAL: &str = "Annotate every kanji word in the transcript with its hiragana reading in half-width parentheses right after it, e.g. 漢字(かんじ), 東京(とうきょう)に行(い)く. Give compound words one reading for the whole word and leave kana, Latin letters and digits unannotated. Do not change any other wording or punctuation. If the transcript is not Japanese, return it unchanged. Return only the annotated text.";
+const FURIGANA_USER_JA: &str = "注釈対象:\n{{transcript}}";
+const FURIGANA_USER_GLOBAL: &str = "Transcript:\n{{transcript}}";
 const FORMAT_USER_JA: &str = "校正対象:\n{{transcript}}";
 const FORMAT_USER_EN: &str = "Transcript to revise:\n{{transcript}}";
 const FORMAT_USER_GLOBAL: &str = "Transcript:\n{{transcript}}";
 const SUMMARY_USER_DEFAULT: &str = "{{transcript}}";
 pub const PRESET_ID_FORMAT: &str = "preset:format";
 pub const PRESET_ID_SUMMARY: &str = "preset:summary";
+pub const PRESET_ID_FURIGANA: &str = "preset:furigana";
 pub const MODE_ID_CUSTOM_DRAFT: &str = "custom:draft";
+const CUSTOM_ID_PREFIX: &str = "custom";
+const CHAIN_ID_PREFIX: &str = "chain";
 
 pub use history::{
+    clear_entries as clear_history, entry_matches_filter as history_entry_matches_filter,
     history_file_path, history_modified_time, load_entries as load_history_entries,
-    record_entry as record_history, LlmHistoryEntry, MAX_HISTORY_ENTRIES,
+    record_entry as record_history, redaction_pattern_error, HistoryDateFilter, HistoryRedaction,
+    HistoryRetention, LlmHistoryEntry, CARD_NUMBER_PATTERN, DEFAULT_HISTORY_MAX_ENTRIES,
+    HISTORY_MAX_ENTRIES_LIMIT,
 };
+pub use markdown::markdown_bullet_list;
 
 fn default_mode_id() -> String {
     PRESET_ID_FORMAT.to_string()
 }
 
+// TOML has no null: persist "no limit" as 0 so it survives a reload.
+fn serialize_max_output_tokens<S: serde::Serializer>(
+    value: &Option<u32>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_u32(value.unwrap_or(0))
+}
+
+fn deserialize_max_output_tokens<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<u32>, D::Error> {
+    let value = Option::<u32>::deserialize(deserializer)?;
+    Ok(value.filter(|v| *v > 0))
+}
+
 fn is_builtin_mode(id: &str) -> bool {
-    matches!(id, PRESET_ID_FORMAT | PRESET_ID_SUMMARY)
+    matches!(
+        id,
+        PRESET_ID_FORMAT | PRESET_ID_SUMMARY | PRESET_ID_FURIGANA
+    )
 }
 
 fn generate_custom_mode_id(existing: &HashSet<String>, name: &str) -> String {
+    generate_mode_id(CUSTOM_ID_PREFIX, existing, name)
+}
+
+// `<prefix>:<slug>`, numbered when the slug is taken
+fn generate_mode_id(prefix: &str, existing: &HashSet<String>, name: &str) -> String {
     let mut slug = name
         .trim()
         .chars()
@@ -74,21 +122,21 @@ fn generate_custom_mode_id(existing: &HashSet<String>, name: &str) -> String {
         .collect::<String>();
 
     if slug.trim_matches('-').is_empty() {
-        slug = "custom-mode".to_string();
+        slug = format!("{}-mode", prefix);
     } else {
         while slug.contains("--") {
             slug = slug.replace("--", "-");
         }
         slug = slug.trim_matches('-').to_string();
         if slug.is_empty() {
-            slug = "custom-mode".to_string();
+            slug = format!("{}-mode", prefix);
         }
     }
 
-    let mut candidate = format!("custom:{}", slug);
+    let mut candidate = format!("{}:{}", prefix, slug);
     let mut counter = 2;
     while existing.contains(&candidate) {
-        candidate = format!("custom:{}-{}", slug, counter);
+        candidate = format!("{}:{}-{}", prefix, slug, counter);
         counter += 1;
     }
     candidate
@@ -101,6 +149,7 @@ pub fn builtin_prompt_preview(mode_id: &str, locales: &[String]) -> Option<(Stri
     match mode_id {
         PRESET_ID_FORMAT => Some(format_prompt_strings(locales)),
         PRESET_ID_SUMMARY => Some(summary_prompt_strings(locales)),
+        PRESET_ID_FURIGANA => Some(furigana_prompt_strings(locales)),
         _ => None,
     }
 }
@@ -112,10 +161,15 @@ pub struct LlmPostProcessSettings {
     pub enabled: bool,
     pub api_base_url: String,
     pub model: String,
+    /// Built-in preset id -> model used instead of `model`
+    pub preset_models: HashMap<String, String>,
     #[serde(default = "default_mode_id", alias = "mode")]
     pub mode_id: String,
     #[serde(default)]
     pub custom_prompts: Vec<CustomPromptMode>,
+    /// Modes that run other modes in sequence (selected through `mode_id`)
+    #[serde(default)]
+    pub chain_modes: Vec<ChainMode>,
     #[serde(default)]
     pub custom_prompt_name: String,
     pub language_override: Option<String>,
@@ -124,17 +178,62 @@ pub struct LlmPostProcessSettings {
     #[serde(default)]
     pub custom_prompt: String,
     pub max_input_chars: usize,
+    /// What happens to transcripts longer than `max_input_chars`
+    pub oversize_input: OversizeInput,
+    /// `max_tokens` sent to the server (None omits the field; 0 in settings.toml).
+    #[serde(
+        serialize_with = "serialize_max_output_tokens",
+        deserialize_with = "deserialize_max_output_tokens"
+    )]
+    pub max_output_tokens: Option<u32>,
+    pub temperature: f32,
+    /// Chat completion (post-processing) timeout
     pub timeout_secs: u64,
+    /// Model listing and connection test timeout
+    pub models_timeout_secs: u64,
+    /// Keep retrying the model list while the LLM tab is open and it is empty
+    pub poll_models: bool,
     pub apply_to_autopaste: bool,
+    /// Summary preset only: normalize the result to a clean Markdown `- ` list
+    pub summary_markdown: bool,
+    /// With `summary_markdown`: put a `## YYYY-MM-DD` heading above the list
+    pub summary_markdown_heading: bool,
+}
+
+/// Handling of transcripts longer than `max_input_chars`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum OversizeInput {
+    /// Send only the first `max_input_chars` characters
+    #[default]
+    Truncate,
+    /// One request per piece, outputs joined; summaries get a final
+    /// summary-of-summaries request (map-then-reduce)
+    Chunk,
 }
 
 /// User defined custom prompt mode stored in settings.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+// Every field defaults so one incomplete entry from an older settings.toml cannot
+// fail the whole file (which would reset all settings, including `mode_id`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
 pub struct CustomPromptMode {
     pub id: String,
     pub name: String,
     pub system_prompt: Option<String>,
     pub user_prompt: String,
+    /// Model used for this mode instead of the global `model`
+    pub model_override: Option<String>,
+}
+
+/// Preset/custom modes run in order, each step's output being the next step's input.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ChainMode {
+    pub id: String,
+    pub name: String,
+    /// Mode ids; chains cannot be steps of other chains
+    pub steps: Vec<String>,
 }
 
 impl Default for LlmPostProcessSettings {
@@ -143,15 +242,24 @@ impl Default for LlmPostProcessSettings {
             enabled: false,
             api_base_url: DEFAULT_LOCAL_BASE_URL.to_string(),
             model: DEFAULT_LOCAL_MODEL.to_string(),
+            preset_models: HashMap::new(),
             mode_id: default_mode_id(),
             custom_prompts: Vec::new(),
+            chain_modes: Vec::new(),
             custom_prompt_name: "Custom prompt".to_string(),
             language_override: None,
             custom_prompt_system: String::new(),
             custom_prompt: "{{transcript}}".to_string(),
             max_input_chars: DEFAULT_MAX_INPUT_CHARS,
+            oversize_input: OversizeInput::Truncate,
+            max_output_tokens: Some(DEFAULT_MAX_OUTPUT_TOKENS),
+            temperature: DEFAULT_TEMPERATURE,
             timeout_secs: DEFAULT_TIMEOUT_SECS,
+            models_timeout_secs: DEFAULT_MODELS_TIMEOUT_SECS,
+            poll_models: true,
             apply_to_autopaste: true,
+            summary_markdown: false,
+            summary_markdown_heading: false,
         }
     }
 }
@@ -169,6 +277,8 @@ pub struct ConnectionTestOutcome {
     pub status: Option<u16>,
     pub duration_ms: u128,
     pub message: String,
+    /// Endpoint that answered (model list, or chat completions on fallback)
+    pub url: String,
 }
 
 /// Successful response from the LLM post-processing step.
@@ -177,6 +287,20 @@ pub struct PostProcessOutcome {
     pub content: String,
     pub truncated_input: bool,
     pub latency_ms: u128,
+    /// A chain stopped early; `content` is the last successful step's output
+    pub warning: Option<String>,
+}
+
+/// Resolved request shown by the prompt preview (nothing is sent).
+#[derive(Debug, Clone)]
+pub struct PromptPreview {
+    pub system: Option<String>,
+    pub user: String,
+    /// Request body as JSON (same as the real request, minus auth headers).
+    pub payload_json: String,
+    pub truncated_input: bool,
+    /// The user template has no `{{transcript}}`; the transcript was appended.
+    pub transcript_appended: bool,
 }
 
 /// Error information returned when an LLM request fails.
@@ -216,8 +340,12 @@ impl LlmPostProcessSettings {
         DEFAULT_LOCAL_MODEL
     }
 
-    /// Returns the model name ensuring defaults when settings still carry a previous provider.
+    /// Returns the model for the active mode: its override, else the global model
+    /// (with defaults when settings still carry a previous provider).
     pub fn effective_model(&self) -> String {
+        if let Some(model) = self.mode_model(self.mode_id.trim()) {
+            return model.to_string();
+        }
         if self.model.trim().is_empty() {
             Self::default_model().to_string()
         } else {
@@ -225,6 +353,21 @@ impl LlmPostProcessSettings {
         }
     }
 
+    /// Returns `max_tokens` clamped to the accepted range (None = let the server decide).
+    pub fn effective_max_output_tokens(&self) -> Option<u32> {
+        self.max_output_tokens
+            .map(|v| v.clamp(MIN_MAX_OUTPUT_TOKENS, MAX_MAX_OUTPUT_TOKENS))
+    }
+
+    /// Returns the sampling temperature clamped to 0.0..=2.0.
+    pub fn effective_temperature(&self) -> f32 {
+        if self.temperature.is_finite() {
+            self.temperature.clamp(0.0, MAX_TEMPERATURE)
+        } else {
+            DEFAULT_TEMPERATURE
+        }
+    }
+
     /// Returns locale priority list for prompt resolution.
     pub fn locale_priority(&self, language_hint: Option<&str>) -> Vec<String> {
         let mut locales = Vec::new();
@@ -282,19 +425,116 @@ impl LlmPostProcessSettings {
         self.custom_prompts.iter_mut().find(|mode| mode.id == id)
     }
 
+    /// Per-mode model override (custom mode field or `preset_models` entry)
+    pub fn mode_model(&self, mode_id: &str) -> Option<&str> {
+        let model = if is_builtin_mode(mode_id) {
+            self.preset_models.get(mode_id).map(String::as_str)
+        } else {
+            self.custom_prompt(mode_id)
+                .and_then(|mode| mode.model_override.as_deref())
+        };
+        model.map(str::trim).filter(|m| !m.is_empty())
+    }
+
+    /// Set or clear (empty `model`) the override of a preset or saved custom mode
+    pub fn set_mode_model(&mut self, mode_id: &str, model: &str) {
+        let model = Some(model.trim().to_string()).filter(|m| !m.is_empty());
+        if is_builtin_mode(mode_id) {
+            match model {
+                Some(model) => self.preset_models.insert(mode_id.to_string(), model),
+                None => self.preset_models.remove(mode_id),
+            };
+        } else if let Some(mode) = self.custom_prompt_mut(mode_id) {
+            mode.model_override = model;
+        }
+    }
+
+    pub fn chain_mode(&self, id: &str) -> Option<&ChainMode> {
+        self.chain_modes.iter().find(|chain| chain.id == id)
+    }
+
+    pub fn chain_mode_mut(&mut self, id: &str) -> Option<&mut ChainMode> {
+        self.chain_modes.iter_mut().find(|chain| chain.id == id)
+    }
+
+    /// Modes a chain can run: presets and saved custom modes
+    pub fn is_chain_step(&self, id: &str) -> bool {
+        is_builtin_mode(id) || self.custom_prompt(id).is_some()
+    }
+
+    pub fn create_chain_mode(&mut self, name: &str, steps: Vec<String>) -> String {
+        let existing: HashSet<String> = self
+            .chain_modes
+            .iter()
+            .map(|chain| chain.id.clone())
+            .collect();
+        let id = generate_mode_id(CHAIN_ID_PREFIX, &existing, name);
+        self.chain_modes.push(ChainMode {
+            id: id.clone(),
+            name: name.trim().to_string(),
+            steps,
+        });
+        id
+    }
+
+    pub fn remove_chain_mode(&mut self, id: &str) -> bool {
+        let before = self.chain_modes.len();
+        self.chain_modes.retain(|chain| chain.id != id);
+        if before == self.chain_modes.len() {
+            return false;
+        }
+        if self.mode_id == id {
+            self.mode_id = default_mode_id();
+        }
+        true
+    }
+
+    /// Give id-less/duplicate custom modes (older settings.toml) a unique id.
+    fn migrate_custom_prompt_ids(&mut self) {
+        let mut seen: HashSet<String> = HashSet::new();
+        seen.insert(PRESET_ID_FORMAT.to_string());
+        seen.insert(PRESET_ID_SUMMARY.to_string());
+        seen.insert(PRESET_ID_FURIGANA.to_string());
+        seen.insert(MODE_ID_CUSTOM_DRAFT.to_string());
+        for mode in self.custom_prompts.iter_mut() {
+            let id = mode.id.trim().to_string();
+            if id.is_empty() || seen.contains(&id) {
+                mode.id = generate_custom_mode_id(&seen, &mode.name);
+            } else {
+                mode.id = id;
+            }
+            seen.insert(mode.id.clone());
+        }
+    }
+
+    /// Normalize `mode_id`; falls back to the draft only when the referenced
+    /// custom prompt no longer exists.
     pub fn ensure_mode_valid(&mut self) {
+        self.migrate_custom_prompt_ids();
+        let trimmed = self.mode_id.trim();
+        if trimmed.len() != self.mode_id.len() {
+            self.mode_id = trimmed.to_string();
+        }
         if self.mode_id.is_empty() {
             self.mode_id = default_mode_id();
         }
         if self.mode_id == "custom" || self.mode_id == "preset:custom" {
             self.mode_id = MODE_ID_CUSTOM_DRAFT.to_string();
         }
-        if self.mode_id != MODE_ID_CUSTOM_DRAFT
-            && !is_builtin_mode(&self.mode_id)
-            && self.custom_prompt(&self.mode_id).is_none()
+        if self.mode_id == MODE_ID_CUSTOM_DRAFT
+            || is_builtin_mode(&self.mode_id)
+            || self.custom_prompt(&self.mode_id).is_some()
+            || self.chain_mode(&self.mode_id).is_some()
         {
-            self.mode_id = MODE_ID_CUSTOM_DRAFT.to_string();
+            return;
         }
+        // Older builds could store the mode by display name or with different casing
+        let legacy = self
+            .custom_prompts
+            .iter()
+            .find(|m| m.id.eq_ignore_ascii_case(&self.mode_id) || m.name.trim() == self.mode_id)
+            .map(|m| m.id.clone());
+        self.mode_id = legacy.unwrap_or_else(|| MODE_ID_CUSTOM_DRAFT.to_string());
     }
 
     pub fn begin_custom_draft(&mut self, language_hint: Option<&str>) {
@@ -320,6 +560,7 @@ impl LlmPostProcessSettings {
             .collect();
         existing.insert(PRESET_ID_FORMAT.to_string());
         existing.insert(PRESET_ID_SUMMARY.to_string());
+        existing.insert(PRESET_ID_FURIGANA.to_string());
         existing.insert(MODE_ID_CUSTOM_DRAFT.to_string());
 
         let id = generate_custom_mode_id(&existing, &final_name);
@@ -332,6 +573,7 @@ impl LlmPostProcessSettings {
                 Some(system_prompt.to_string())
             },
             user_prompt: user_prompt.to_string(),
+            model_override: None,
         };
         self.custom_prompts.push(mode);
         id
@@ -362,6 +604,9 @@ impl LlmPostProcessSettings {
         let before = self.custom_prompts.len();
         self.custom_prompts.retain(|mode| mode.id != id);
         if before != self.custom_prompts.len() {
+            for chain in self.chain_modes.iter_mut() {
+                chain.steps.retain(|step| step != id);
+            }
             if self.mode_id == id {
                 self.mode_id = default_mode_id();
             }
@@ -411,6 +656,113 @@ impl LlmPostProcessor {
         transcript: &str,
         dictionary_hint: Option<&str>,
         language_hint: Option<&str>,
+    ) -> LlmResult<PostProcessOutcome> {
+        match settings.chain_mode(settings.mode_id.trim()) {
+            Some(chain) => {
+                self.process_chain(settings, chain, transcript, dictionary_hint, language_hint)
+            }
+            None => self.process_mode(settings, transcript, dictionary_hint, language_hint),
+        }
+    }
+
+    // Steps in order; a failure after the first step keeps the last good output.
+    // Each step goes through `process_mode`, so an active backoff stops the chain.
+    fn process_chain(
+        &self,
+        settings: &LlmPostProcessSettings,
+        chain: &ChainMode,
+        transcript: &str,
+        dictionary_hint: Option<&str>,
+        language_hint: Option<&str>,
+    ) -> LlmResult<PostProcessOutcome> {
+        let steps: Vec<&String> = chain
+            .steps
+            .iter()
+            .filter(|step| settings.is_chain_step(step))
+            .collect();
+        let mut step_settings = settings.clone();
+        let mut done: Option<PostProcessOutcome> = None;
+        for (index, step) in steps.iter().enumerate() {
+            step_settings.mode_id = step.to_string();
+            let input = done.as_ref().map_or(transcript, |o| o.content.as_str());
+            let result = self.process_mode(&step_settings, input, dictionary_hint, language_hint);
+            done = Some(match (done, result) {
+                (None, Ok(outcome)) => outcome,
+                (Some(prev), Ok(outcome)) => PostProcessOutcome {
+                    truncated_input: prev.truncated_input || outcome.truncated_input,
+                    latency_ms: prev.latency_ms + outcome.latency_ms,
+                    ..outcome
+                },
+                (None, Err(err)) => return Err(err),
+                (Some(mut prev), Err(err)) => {
+                    let mut warning = format!(
+                        "Chain stopped at step {}/{} ({}): {}",
+                        index + 1,
+                        steps.len(),
+                        step,
+                        err.message
+                    );
+                    if let Some(wait) = err.retry_after_secs {
+                        warning = format!("{} (retry after {}s)", warning, wait);
+                    }
+                    prev.warning = Some(warning);
+                    return Ok(prev);
+                }
+            });
+        }
+        done.ok_or_else(|| LlmRequestError {
+            message: format!("Chain \"{}\" has no steps", chain.name),
+            status: None,
+            retry_after_secs: None,
+        })
+    }
+
+    // Map each piece through the mode; summaries are reduced by summarizing the
+    // joined partial summaries (truncated if even those are too long)
+    fn process_chunked(
+        &self,
+        settings: &LlmPostProcessSettings,
+        transcript: &str,
+        dictionary_hint: Option<&str>,
+        language_hint: Option<&str>,
+    ) -> LlmResult<PostProcessOutcome> {
+        let mut piece_settings = settings.clone();
+        piece_settings.oversize_input = OversizeInput::Truncate;
+        // Markdown normalization runs once, on the reduced summary
+        piece_settings.summary_markdown = false;
+        let mut outputs = Vec::new();
+        let mut latency_ms = 0;
+        for piece in chunk::split_transcript(transcript, settings.max_input_chars) {
+            let outcome =
+                self.process_mode(&piece_settings, &piece, dictionary_hint, language_hint)?;
+            latency_ms += outcome.latency_ms;
+            outputs.push(outcome.content);
+        }
+        let joined = outputs.join("\n\n");
+        if settings.mode_id != PRESET_ID_SUMMARY {
+            return Ok(PostProcessOutcome {
+                content: joined,
+                truncated_input: false,
+                latency_ms,
+                warning: None,
+            });
+        }
+        let mut reduce_settings = settings.clone();
+        reduce_settings.oversize_input = OversizeInput::Truncate;
+        let reduced =
+            self.process_mode(&reduce_settings, &joined, dictionary_hint, language_hint)?;
+        Ok(PostProcessOutcome {
+            latency_ms: latency_ms + reduced.latency_ms,
+            ..reduced
+        })
+    }
+
+    fn process_mode(
+        &self,
+        settings: &LlmPostProcessSettings,
+        transcript: &str,
+        dictionary_hint: Option<&str>,
+        language_hint: Option<&str>,
     ) -> LlmResult<PostProcessOutcome> {
         if !settings.enabled {
             return Err(LlmRequestError {
@@ -438,6 +790,9 @@ impl LlmPostProcessor {
         }
 
         let (prepared, truncated) = prepare_transcript(trimmed, settings.max_input_chars);
+        if truncated && settings.oversize_input == OversizeInput::Chunk {
+            return self.process_chunked(settings, trimmed, dictionary_hint, language_hint);
+        }
         if prepared.is_empty() {
             return Err(LlmRequestError {
                 message: "Transcript is empty after trimming".to_string(),
@@ -452,14 +807,16 @@ impl LlmPostProcessor {
                 if let Some(content) = extract_first_choice_text(&response) {
                     self.note_success();
                     let polished = content.trim().to_string();
+                    let content = if polished.is_empty() {
+                        content
+                    } else {
+                        polished
+                    };
                     return Ok(PostProcessOutcome {
-                        content: if polished.is_empty() {
-                            content
-                        } else {
-                            polished
-                        },
+                        content: summary_output_transform(settings, content),
                         truncated_input: truncated,
                         latency_ms,
+                        warning: None,
                     });
                 }
                 let mut err = LlmRequestError {
@@ -481,6 +838,22 @@ impl LlmPostProcessor {
         }
     }
 
+    /// Seconds left in the failure backoff (None when requests are allowed).
+    pub fn backoff_remaining_secs(&self) -> Option<u64> {
+        let state = self.state.lock().unwrap();
+        state
+            .next_retry_at
+            .and_then(|next| next.checked_duration_since(Instant::now()))
+            .map(|remaining| remaining.as_secs().max(1))
+    }
+
+    /// Clear the failure counter and any active backoff (e.g. the server is back).
+    pub fn reset_backoff(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures = 0;
+        state.next_retry_at = None;
+    }
+
     fn check_backoff(&self) -> Option<u64> {
         let mut state = self.state.lock().unwrap();
         if let Some(next) = state.next_retry_at {
@@ -506,9 +879,7 @@ impl LlmPostProcessor {
     }
 
     fn note_success(&self) {
-        let mut state = self.state.lock().unwrap();
-        state.consecutive_failures = 0;
-        state.next_retry_at = None;
+        self.reset_backoff();
     }
 }
 
@@ -542,6 +913,17 @@ struct ChatMessage {
     content: Option<Value>,
 }
 
+// Mode-specific output clean-up; custom modes pass through untouched
+fn summary_output_transform(settings: &LlmPostProcessSettings, content: String) -> String {
+    if settings.mode_id != PRESET_ID_SUMMARY || !settings.summary_markdown {
+        return content;
+    }
+    let heading = settings
+        .summary_markdown_heading
+        .then(|| chrono::Local::now().format("%Y-%m-%d").to_string());
+    markdown_bullet_list(&content, heading.as_deref())
+}
+
 fn prepare_transcript(text: &str, max_chars: usize) -> (String, bool) {
     let trimmed = text.trim();
     if trimmed.is_empty() {
@@ -591,9 +973,69 @@ fn build_chat_payload(
     ChatCompletionPayload {
         model: settings.effective_model(),
         messages: prompt_to_messages(resolved),
-        temperature: 0.2,
-        max_tokens: Some(1024),
+        temperature: settings.effective_temperature(),
+        max_tokens: settings.effective_max_output_tokens(),
+    }
+}
+
+/// Dry run of the post-processing request for `transcript` (same truncation and substitution).
+pub fn preview_prompt(
+    settings: &LlmPostProcessSettings,
+    transcript: &str,
+    dictionary_hint: Option<&str>,
+    language_hint: Option<&str>,
+) -> PromptPreview {
+    // A chain previews its first step (later inputs depend on the LLM output)
+    let first_step;
+    let settings = match settings
+        .chain_mode(settings.mode_id.trim())
+        .and_then(|chain| chain.steps.iter().find(|s| settings.is_chain_step(s)))
+    {
+        Some(step) => {
+            first_step = LlmPostProcessSettings {
+                mode_id: step.clone(),
+                ..settings.clone()
+            };
+            &first_step
+        }
+        None => settings,
+    };
+    let (prepared, truncated) = prepare_transcript(transcript, settings.max_input_chars);
+    let payload = build_chat_payload(settings, &prepared, dictionary_hint, language_hint);
+    let payload_json = serde_json::to_string_pretty(&payload).unwrap_or_default();
+    let mut system = None;
+    let mut user = String::new();
+    for message in payload.messages {
+        match message.role {
+            "system" => system = Some(message.content),
+            _ => user = message.content,
+        }
+    }
+    PromptPreview {
+        system,
+        user,
+        payload_json,
+        truncated_input: truncated,
+        transcript_appended: custom_user_template(settings)
+            .is_some_and(|t| !t.contains(PLACEHOLDER_TRANSCRIPT)),
+    }
+}
+
+// User template used by `resolve_prompt` for custom modes (None for presets)
+fn custom_user_template(settings: &LlmPostProcessSettings) -> Option<&str> {
+    let mut mode_id = settings.mode_id.trim();
+    if mode_id.is_empty() {
+        mode_id = PRESET_ID_FORMAT;
     }
+    if is_builtin_mode(mode_id) {
+        return None;
+    }
+    if mode_id != MODE_ID_CUSTOM_DRAFT {
+        if let Some(custom) = settings.custom_prompt(mode_id) {
+            return Some(custom.user_prompt.as_str());
+        }
+    }
+    Some(settings.custom_prompt.as_str())
 }
 
 fn resolve_prompt(
@@ -653,6 +1095,7 @@ fn resolve_builtin_prompt(
     let resolved = match mode_id {
         PRESET_ID_FORMAT => format_prompt_for_locales(&locales, transcript),
         PRESET_ID_SUMMARY => summary_prompt_for_locales(&locales, transcript),
+        PRESET_ID_FURIGANA => furigana_prompt_for_locales(&locales, transcript),
         _ => return None,
     };
     Some(resolved.apply_dictionary(dictionary))
@@ -776,6 +1219,40 @@ fn try_summary_prompt(locale: &str, transcript: &str) -> Option<PromptTemplateRe
     }
 }
 
+fn furigana_prompt_for_locales(locales: &[String], transcript: &str) -> PromptTemplateResolved {
+    let (system, user) = furigana_prompt_strings(locales);
+    PromptTemplateResolved {
+        system: Some(system),
+        user: user.replace(PLACEHOLDER_TRANSCRIPT, transcript),
+    }
+}
+
+fn furigana_prompt_strings(locales: &[String]) -> (String, String) {
+    for locale in locales {
+        if let Some(pair) = furigana_prompt_template_for_locale(locale) {
+            return pair;
+        }
+    }
+    (
+        FURIGANA_SYSTEM_GLOBAL.to_string(),
+        FURIGANA_USER_GLOBAL.to_string(),
+    )
+}
+
+// Readings only exist for Japanese; other locales fall through to the global
+// prompt, which returns non-Japanese text unchanged
+fn furigana_prompt_template_for_locale(locale: &str) -> Option<(String, String)> {
+    let normalized = locale.to_ascii_lowercase();
+    match normalized.as_str() {
+        "ja-jp" => Some((FURIGANA_SYSTEM_JA.to_string(), FURIGANA_USER_JA.to_string())),
+        "global" => Some((
+            FURIGANA_SYSTEM_GLOBAL.to_string(),
+            FURIGANA_USER_GLOBAL.to_string(),
+        )),
+        _ => None,
+    }
+}
+
 fn custom_prompt_to_resolved(
     system_prompt: &str,
     user_prompt: &str,
@@ -855,7 +1332,7 @@ fn execute_chat_completion(
         retry_after_secs: None,
     })?;
 
-    let headers = create_headers(settings, true).map_err(|e| LlmRequestError {
+    let headers = create_headers(true).map_err(|e| LlmRequestError {
         message: e.to_string(),
         status: None,
         retry_after_secs: None,
@@ -963,7 +1440,10 @@ struct ModelDetails {
 }
 
 pub fn run_connection_test(settings: &LlmPostProcessSettings) -> Result<ConnectionTestOutcome> {
-    run_connection_test_local(settings)
+    // The ping completion is tiny, so the whole test uses the short timeout
+    let mut probe = settings.clone();
+    probe.timeout_secs = settings.models_timeout_secs;
+    run_connection_test_local(&probe)
 }
 
 fn run_connection_test_chat(settings: &LlmPostProcessSettings) -> Result<ConnectionTestOutcome> {
@@ -994,10 +1474,12 @@ fn run_connection_test_chat(settings: &LlmPostProcessSettings) -> Result<Connect
         status: Some(status.as_u16()),
         duration_ms: latency_ms,
         message,
+        url: join_url(&settings.effective_base_url(), CHAT_COMPLETIONS_PATH),
     })
 }
 
 fn run_connection_test_local(settings: &LlmPostProcessSettings) -> Result<ConnectionTestOutcome> {
+    let base = settings.effective_base_url();
     let start = Instant::now();
     match fetch_models(settings) {
         Ok(models) => {
@@ -1009,27 +1491,35 @@ fn run_connection_test_local(settings: &LlmPostProcessSettings) -> Result<Connec
                 status: Some(200),
                 duration_ms: start.elapsed().as_millis(),
                 message,
+                url: join_url(&base, MODELS_PATH),
             })
         }
         Err(first_err) => {
-            let first_msg = first_err.to_string();
+            let first_msg = first_err.root_cause().to_string();
             match run_connection_test_chat(settings) {
                 Ok(mut outcome) => {
                     outcome.message = format!(
-                        "Chat completion OK (model fetch failed: {})",
+                        "Chat completion fallback OK (model list failed: {})",
                         preview_body(&first_msg)
                     );
                     Ok(outcome)
                 }
-                Err(second_err) => Err(first_err.context(second_err)),
+                // Report both attempts so the user can tell which path broke
+                Err(second_err) => Err(anyhow!(
+                    "Model list failed (GET {}): {}\nChat completion fallback failed (POST {}): {}",
+                    join_url(&base, MODELS_PATH),
+                    preview_body(&first_msg),
+                    join_url(&base, CHAT_COMPLETIONS_PATH),
+                    preview_body(&second_err.to_string())
+                )),
             }
         }
     }
 }
 
 pub fn fetch_models(settings: &LlmPostProcessSettings) -> Result<Vec<LlmModelInfo>> {
-    let client = build_client_with_timeout(settings.timeout_secs)?;
-    let headers = create_headers(settings, false)?;
+    let client = build_client_with_timeout(settings.models_timeout_secs)?;
+    let headers = create_headers(false)?;
     let url = join_url(&settings.effective_base_url(), MODELS_PATH);
     let response = client
         .get(&url)
@@ -1087,18 +1577,24 @@ fn map_model_item(item: &ModelItem) -> Option<LlmModelInfo> {
     })
 }
 
-fn build_client_with_timeout(timeout_secs: u64) -> Result<Client> {
+// One client per timeout, shared by every request (also remote transcription):
+// connections and the TLS setup are reused instead of rebuilt per call
+pub(crate) fn build_client_with_timeout(timeout_secs: u64) -> Result<Client> {
+    static CLIENTS: OnceLock<Mutex<HashMap<u64, Client>>> = OnceLock::new();
     let secs = timeout_secs.max(3).min(120);
-    Client::builder()
+    let mut clients = CLIENTS.get_or_init(Default::default).lock().unwrap();
+    if let Some(client) = clients.get(&secs) {
+        return Ok(client.clone());
+    }
+    let client = Client::builder()
         .timeout(Duration::from_secs(secs))
         .build()
-        .context("create HTTP client")
+        .context("create HTTP client")?;
+    clients.insert(secs, client.clone());
+    Ok(client)
 }
 
-fn create_headers(
-    _settings: &LlmPostProcessSettings,
-    include_content_type: bool,
-) -> Result<HeaderMap> {
+pub(crate) fn create_headers(include_content_type: bool) -> Result<HeaderMap> {
     let mut headers = HeaderMap::new();
     headers.insert(USER_AGENT, HeaderValue::from_static(USER_AGENT_VALUE));
     if include_content_type {
@@ -1141,25 +1637,85 @@ fn parse_retry_after_secs(headers: &HeaderMap) -> Option<u64> {
     headers
         .get("Retry-After")
         .and_then(|value| value.to_str().ok())
-        .and_then(|s| s.trim().parse::<u64>().ok())
+        .and_then(|s| retry_after_secs_at(s, chrono::Utc::now()))
 }
 
-fn preview_body(body: &str) -> String {
-    let mut out = String::new();
-    let mut count = 0usize;
-    let mut truncated = false;
-    for ch in body.chars() {
-        if count >= MAX_ERROR_BODY_PREVIEW {
-            truncated = true;
-            break;
-        }
-        out.push(ch);
-        count += 1;
+// `Retry-After` is delay-seconds or an HTTP-date (RFC 7231 7.1.3); a date in
+// the past means retry now
+fn retry_after_secs_at(value: &str, now: chrono::DateTime<chrono::Utc>) -> Option<u64> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(secs);
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value)
+        .map(|d| d.with_timezone(&chrono::Utc))
+        .or_else(|_| {
+            // Obsolete forms recipients must still accept: RFC 850 and asctime
+            ["%A, %d-%b-%y %H:%M:%S GMT", "%a %b %e %H:%M:%S %Y"]
+                .iter()
+                .find_map(|format| chrono::NaiveDateTime::parse_from_str(value, format).ok())
+                .map(|naive| naive.and_utc())
+                .ok_or(())
+        })
+        .ok()?;
+    Some((date - now).num_seconds().max(0) as u64)
+}
+
+/// First `MAX_ERROR_BODY_PREVIEW` grapheme clusters of a response body, with "…"
+/// only when something was cut. `body` comes from a lossy UTF-8 decode, so a
+/// multi-byte sequence cut off by the server ends in U+FFFD, which is dropped.
+pub(crate) fn preview_body(body: &str) -> String {
+    let body = body.trim().trim_end_matches('\u{FFFD}').trim_end();
+    match body.grapheme_indices(true).nth(MAX_ERROR_BODY_PREVIEW) {
+        Some((end, _)) => format!("{}…", body[..end].trim_end()),
+        None => body.to_string(),
     }
-    let trimmed = out.trim();
-    if truncated {
-        format!("{}…", trimmed)
-    } else {
-        trimmed.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{preview_body, retry_after_secs_at, MAX_ERROR_BODY_PREVIEW};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn retry_after_accepts_seconds_and_http_dates() {
+        let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap();
+        assert_eq!(retry_after_secs_at(" 120 ", now), Some(120));
+        assert_eq!(
+            retry_after_secs_at("Wed, 21 Oct 2015 07:29:30 GMT", now),
+            Some(90)
+        );
+        assert_eq!(
+            retry_after_secs_at("Wednesday, 21-Oct-15 07:28:45 GMT", now),
+            Some(45)
+        );
+        assert_eq!(
+            retry_after_secs_at("Wed Oct 21 07:28:05 2015", now),
+            Some(5)
+        );
+        // Past dates mean "retry now"
+        assert_eq!(
+            retry_after_secs_at("Wed, 21 Oct 2015 07:00:00 GMT", now),
+            Some(0)
+        );
+        assert_eq!(retry_after_secs_at("soon", now), None);
+    }
+
+    #[test]
+    fn preview_body_cuts_on_graphemes() {
+        let short = " {\"error\": \"モデルが見つかりません\"}\n";
+        assert_eq!(
+            preview_body(short),
+            "{\"error\": \"モデルが見つかりません\"}"
+        );
+        // Voiced kana written as base + combining mark stay together at the cut
+        let ga = "\u{304B}\u{3099}";
+        let long = ga.repeat(MAX_ERROR_BODY_PREVIEW + 5);
+        let preview = preview_body(&long);
+        assert_eq!(preview, format!("{}…", ga.repeat(MAX_ERROR_BODY_PREVIEW)));
+        // Exactly at the limit, or a cut-off trailing sequence: nothing is truncated
+        let exact = "エラー".repeat(MAX_ERROR_BODY_PREVIEW / 3);
+        assert_eq!(preview_body(&exact), exact);
+        assert_eq!(preview_body("内部エラー\u{FFFD}"), "内部エラー");
     }
 }
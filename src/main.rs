@@ -10,6 +10,7 @@ use eframe::NativeOptions;
 
 mod app;
 mod audio;
+mod cli;
 mod core;
 mod dictionary;
 mod gui;
@@ -40,10 +41,13 @@ fn init_logging() {
     let default_directives = "info,egui=error,epaint=error,eframe=error,egui_wgpu=error,wgpu=error,wgpu_core=error,wgpu_hal=error,naga=error";
     let filter =
         EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default_directives));
-    tracing_subscriber::fmt()
-        .with_env_filter(filter)
-        .with_target(false)
-        .init();
+    let builder = tracing_subscriber::fmt().with_env_filter(filter);
+    // HOOTVOICE_LOG_FORMAT=json: JSON lines for log tooling
+    if utils::logfile::log_format() == utils::logfile::LogFormat::Json {
+        builder.event_format(utils::logfile::JsonEventFormat).init();
+    } else {
+        builder.with_target(false).init();
+    }
 }
 
 fn acquire_single_instance_lock() -> Result<AcquireResult, io::Error> {
@@ -84,6 +88,39 @@ enum AcquireResult {
 }
 
 fn main() -> Result<()> {
+    // Client mode: forward a command to the running instance and exit
+    #[cfg(unix)]
+    {
+        let args: Vec<String> = std::env::args().collect();
+        if args.get(1).map(String::as_str) == Some("--send") {
+            let command = args.get(2).map(String::as_str).unwrap_or("toggle");
+            match utils::ipc::send_command(command) {
+                Ok(reply) => {
+                    println!("{}", reply);
+                    std::process::exit(if reply == "ok" { 0 } else { 1 });
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+    // Headless mode: transcribe a file and exit without opening any window
+    match cli::CliArgs::parse(&std::env::args().collect::<Vec<_>>()) {
+        Ok(Some(args)) => {
+            if let Err(e) = cli::run(args) {
+                eprintln!("Error: {:#}", e);
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    }
     // macOS: always capture stdout/stderr to persistent log so Finder launches are diagnosable
     #[cfg(target_os = "macos")]
     {
@@ -115,6 +152,12 @@ fn main() -> Result<()> {
 
     // Update checks disabled for now; may restore on a separate thread later
 
+    // Window size/maximized state and vsync come from the saved settings
+    let saved = gui::launcher::read_saved_settings();
+    let window_size = saved
+        .window_size
+        .map_or(egui::vec2(880.0, 620.0), |[w, h]| egui::vec2(w, h));
+
     // Launch GUI application (Wayland-friendly)
     let options = NativeOptions {
         viewport: egui::ViewportBuilder::default()
@@ -122,37 +165,26 @@ fn main() -> Result<()> {
             .with_title(APP_NAME) // Window title (also macOS menu)
             // macOS: avoid overriding Dock icon; always use Info.plist .icns
             .with_icon(egui::IconData::default())
-            // Slightly larger default window so the first‑run wizard fits comfortably
-            .with_inner_size(egui::vec2(880.0, 620.0))
+            // Slightly larger default window so the first‑run wizard fits comfortably;
+            // later launches reuse the last size
+            .with_inner_size(window_size)
             .with_resizable(true)
             .with_transparent(false)
-            .with_maximized(false)
+            .with_maximized(saved.window_maximized)
             .with_fullscreen(false)
             // Start visible to show the settings screen on first launch
             .with_visible(true),
         renderer: eframe::Renderer::Wgpu,
-        // VSync configuration:
-        // - On Linux, some drivers/compositors can cause frequent surface timeouts
+        // VSync configuration (`vsync_mode` setting, General tab):
+        // - Auto: on Linux, some drivers/compositors can cause frequent surface timeouts
         //   when waiting for vsync, which leads to repeated warnings like:
         //   "Dropped frame with error: A timeout was encountered while trying to acquire the next frame".
-        //   To avoid this, default to disabling vsync on Linux (maps to AutoNoVsync).
-        // - On other platforms keep vsync enabled by default.
-        // - Allow override via env var: HOOTVOICE_VSYNC=0/1
-        vsync: {
-            let env = std::env::var("HOOTVOICE_VSYNC").ok();
-            if let Some(v) = env.as_deref() {
-                matches!(v, "1" | "true" | "TRUE" | "on" | "ON")
-            } else {
-                #[cfg(target_os = "linux")]
-                {
-                    false
-                }
-                #[cfg(not(target_os = "linux"))]
-                {
-                    true
-                }
-            }
-        },
+        //   To avoid this, Auto disables vsync on Linux (maps to AutoNoVsync) and
+        //   keeps it enabled on other platforms.
+        // - Env var HOOTVOICE_VSYNC=0/1 overrides the setting
+        vsync: saved
+            .vsync_mode
+            .resolve(std::env::var("HOOTVOICE_VSYNC").ok().as_deref()),
         multisampling: 0,
         depth_buffer: 0,
         stencil_buffer: 0,
@@ -174,9 +206,8 @@ fn main() -> Result<()> {
             }
             // Configure UI fonts
             gui::fonts::setup_custom_fonts(&cc.egui_ctx);
-            // Slightly upscale UI for readability
-            let ppp = cc.egui_ctx.pixels_per_point();
-            cc.egui_ctx.set_pixels_per_point(ppp * 1.10);
+            // Theme and UI scale (default 110% of the OS scale for readability)
+            gui::launcher::apply_startup_appearance(&cc.egui_ctx);
             cc.egui_ctx
                 .request_repaint_after(std::time::Duration::from_secs(5));
             Ok(Box::new(RootApp::new()))
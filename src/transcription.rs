@@ -1,5 +1,18 @@
+pub mod autocapitalize;
+pub mod hallucination;
 pub mod model;
+pub mod remote;
+pub mod spoken_commands;
+pub mod stop_phrase;
+pub mod subtitles;
 pub mod whisper;
 
-pub use model::{download_with_progress_cancelable, ensure_model, SUPPORTED_MODELS};
-pub use whisper::{transcribe_with_state, WhisperOptimizationParams};
+pub use model::{
+    detect_model_preset, download_with_progress_cancelable, ensure_model, model_download_url,
+    validate_model_base_url, DownloadVerifyError, SUPPORTED_MODELS,
+};
+pub use remote::TranscriptionBackend;
+pub use whisper::{
+    available_threads, max_threads, threads_param, transcribe_with_state, LanguageFallback,
+    WhisperOptimizationParams, WhisperTask, WHISPER_SAMPLE_RATE,
+};
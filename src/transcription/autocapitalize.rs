@@ -0,0 +1,102 @@
+//! Local sentence casing for transcripts that no LLM formats: capitalize
+//! sentence starts and, in English, the pronoun "I".
+
+// Languages whose scripts have no letter case: the text is left untouched
+const UNCASED_LANGUAGES: &[&str] = &["ja", "zh", "yue", "ko", "th"];
+
+// Dots that do not end an English sentence
+const EN_ABBREVIATIONS: &[&str] = &["e.g.", "i.e.", "etc.", "vs.", "mr.", "mrs.", "ms.", "dr."];
+
+fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '’'
+}
+
+// Standalone "i", including contractions ("i'm", "i’ve"), but not the "i." of "i.e."
+fn is_pronoun_i(chars: &[char], i: usize) -> bool {
+    let before = i.checked_sub(1).map(|j| chars[j]);
+    let after = chars.get(i + 1).copied();
+    let dotted_abbreviation =
+        after == Some('.') && chars.get(i + 2).is_some_and(|c| c.is_alphanumeric());
+    !before.is_some_and(|c| c.is_alphanumeric() || is_apostrophe(c))
+        && !after.is_some_and(char::is_alphanumeric)
+        && !dotted_abbreviation
+}
+
+fn ends_with_abbreviation(chars: &[char]) -> bool {
+    let start = chars
+        .iter()
+        .rposition(|c| c.is_whitespace())
+        .map_or(0, |i| i + 1);
+    let word: String = chars[start..].iter().collect();
+    EN_ABBREVIATIONS.contains(&word.to_lowercase().as_str())
+}
+
+/// Capitalize the first letter of each sentence (start of text, after `.`/`!`/`?`
+/// plus whitespace, after a line break). `language` is the Whisper language
+/// (selected or detected); None still fixes sentence starts.
+pub fn autocapitalize(text: &str, language: Option<&str>) -> String {
+    let lang = language.unwrap_or_default().to_ascii_lowercase();
+    let lang = lang.split(['-', '_']).next().unwrap_or_default();
+    if UNCASED_LANGUAGES.contains(&lang) {
+        return text.to_string();
+    }
+    let english = lang == "en";
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut sentence_start = true;
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_alphanumeric() {
+            if sentence_start {
+                out.extend(c.to_uppercase());
+            } else if english && c == 'i' && is_pronoun_i(&chars, i) {
+                out.push('I');
+            } else {
+                out.push(c);
+            }
+            sentence_start = false;
+            continue;
+        }
+        out.push(c);
+        if c == '\n' {
+            sentence_start = true;
+        } else if matches!(c, '.' | '!' | '?')
+            && chars.get(i + 1).is_some_and(|n| n.is_whitespace())
+            && !(english && ends_with_abbreviation(&chars[..=i]))
+        {
+            sentence_start = true;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::autocapitalize;
+
+    #[test]
+    fn capitalizes_sentences_and_pronoun() {
+        assert_eq!(
+            autocapitalize("so i think it works. does it? yes, i'm sure", Some("en")),
+            "So I think it works. Does it? Yes, I'm sure"
+        );
+        assert_eq!(
+            autocapitalize("see e.g. this file.\nthen version 2.5 is out", Some("en")),
+            "See e.g. this file.\nThen version 2.5 is out"
+        );
+        assert_eq!(
+            autocapitalize("two modes, i.e. fast and slow. it was i.", Some("en")),
+            "Two modes, i.e. fast and slow. It was I."
+        );
+        // "I" is English-only; sentence starts apply to other cased languages
+        assert_eq!(
+            autocapitalize("bonjour. ici il fait beau", Some("fr")),
+            "Bonjour. Ici il fait beau"
+        );
+    }
+
+    #[test]
+    fn leaves_uncased_languages_alone() {
+        let text = "今日は晴れです。ok. thanks";
+        assert_eq!(autocapitalize(text, Some("ja")), text);
+    }
+}
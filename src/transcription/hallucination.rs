@@ -0,0 +1,108 @@
+//! Whisper hallucination filter: on silence or low-SNR audio Whisper tends to
+//! emit the same stock phrases ("Thank you for watching", "ご視聴ありがとうございました").
+//! A transcript that consists only of a blocklisted phrase is suppressed.
+
+const BUILTIN_EN: &[&str] = &[
+    "Thank you for watching",
+    "Thanks for watching",
+    "Thank you so much for watching",
+    "Thank you for watching and see you next time",
+    "Please subscribe to my channel",
+    "Like and subscribe",
+    "Subtitles by the Amara.org community",
+];
+
+const BUILTIN_JA: &[&str] = &[
+    "ご視聴ありがとうございました",
+    "ご清聴ありがとうございました",
+    "最後までご視聴いただきありがとうございます",
+    "最後までご視聴いただきありがとうございました",
+    "チャンネル登録よろしくお願いします",
+    "チャンネル登録お願いします",
+    "字幕視聴ありがとうございました",
+];
+
+/// Built-in phrases for a Whisper language code (None/auto: every language)
+pub fn builtin_phrases(language: Option<&str>) -> Vec<&'static str> {
+    match language {
+        Some("en") => BUILTIN_EN.to_vec(),
+        Some("ja") => BUILTIN_JA.to_vec(),
+        Some(_) => Vec::new(),
+        None => BUILTIN_EN.iter().chain(BUILTIN_JA).copied().collect(),
+    }
+}
+
+// Lowercase letters/digits only; full-width ASCII folded to half-width
+fn normalize(text: &str) -> String {
+    text.chars()
+        .map(|c| match c as u32 {
+            0xFF01..=0xFF5E => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            _ => c,
+        })
+        .flat_map(char::to_lowercase)
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+/// Normalized blocklist: built-ins (when enabled) plus the user's own phrases
+pub fn blocklist(language: Option<&str>, builtin: bool, custom: &[String]) -> Vec<String> {
+    let builtins = if builtin {
+        builtin_phrases(language)
+    } else {
+        Vec::new()
+    };
+    let mut list: Vec<String> = builtins
+        .into_iter()
+        .chain(custom.iter().map(String::as_str))
+        .map(normalize)
+        .filter(|p| !p.is_empty())
+        .collect();
+    list.sort();
+    list.dedup();
+    list
+}
+
+/// True when the whole transcript is a blocklisted phrase (possibly repeated,
+/// e.g. "Thanks for watching. Thanks for watching."). Punctuation, spacing and
+/// case are ignored.
+pub fn is_hallucination(text: &str, blocklist: &[String]) -> bool {
+    let text = normalize(text);
+    if text.is_empty() {
+        return false;
+    }
+    blocklist
+        .iter()
+        .any(|phrase| text.split(phrase.as_str()).all(str::is_empty))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blocklist, is_hallucination};
+
+    #[test]
+    fn matches_whole_transcript_only() {
+        let list = blocklist(None, true, &[]);
+        assert!(is_hallucination("Thanks for watching!", &list));
+        assert!(is_hallucination("ご視聴ありがとうございました。", &list));
+        assert!(is_hallucination(
+            "Thank you for watching. Thank you for watching.",
+            &list
+        ));
+        assert!(!is_hallucination(
+            "Thanks for watching the demo, here are the notes",
+            &list
+        ));
+        assert!(!is_hallucination("", &list));
+    }
+
+    #[test]
+    fn language_and_custom_phrases() {
+        let custom = vec!["  Ｂｙｅ．".to_string(), String::new()];
+        let list = blocklist(Some("en"), true, &custom);
+        assert!(is_hallucination("bye", &list));
+        assert!(!is_hallucination("ご視聴ありがとうございました", &list));
+        let list = blocklist(Some("ja"), false, &custom);
+        assert!(!is_hallucination("ご視聴ありがとうございました", &list));
+        assert!(is_hallucination("Bye!", &list));
+    }
+}
@@ -1,6 +1,6 @@
 use anyhow::{anyhow, Context, Result};
 use reqwest::blocking as http;
-use reqwest::header::RANGE;
+use reqwest::header::{CONTENT_RANGE, RANGE};
 use reqwest::redirect::Policy as RedirectPolicy;
 use std::fs::{File, OpenOptions};
 use std::io::{Read, Write};
@@ -15,6 +15,7 @@ pub struct ModelInfo {
     pub filename: &'static str,
     pub url: &'static str,
     pub size_bytes: u64,         // approximate/declared size
+    pub ram_mb: u64,             // approximate runtime memory (whisper.cpp figures)
     pub label_key: &'static str, // i18n key for short label
     pub speed_rating: f32,       // 1..=5 (5 fastest)
     pub quality_rating: f32,     // 1..=5 (5 best)
@@ -26,6 +27,7 @@ pub const SUPPORTED_MODELS: &[ModelInfo] = &[
         filename: "ggml-tiny.bin",
         url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-tiny.bin",
         size_bytes: 39 * 1_000_000,
+        ram_mb: 273,
         label_key: "model-label-tiny",
         speed_rating: 5.0,
         quality_rating: 2.0,
@@ -35,6 +37,7 @@ pub const SUPPORTED_MODELS: &[ModelInfo] = &[
         filename: "ggml-base.bin",
         url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-base.bin",
         size_bytes: 142 * 1_000_000,
+        ram_mb: 388,
         label_key: "model-label-base",
         speed_rating: 4.0,
         quality_rating: 3.0,
@@ -44,6 +47,7 @@ pub const SUPPORTED_MODELS: &[ModelInfo] = &[
         filename: "ggml-small.bin",
         url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-small.bin",
         size_bytes: 465 * 1_000_000,
+        ram_mb: 852,
         label_key: "model-label-small",
         speed_rating: 3.0,
         quality_rating: 4.0,
@@ -53,6 +57,7 @@ pub const SUPPORTED_MODELS: &[ModelInfo] = &[
         filename: "ggml-medium.bin",
         url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-medium.bin",
         size_bytes: 1_500 * 1_000_000,
+        ram_mb: 2_100,
         label_key: "model-label-medium",
         speed_rating: 2.0,
         quality_rating: 4.5,
@@ -62,6 +67,7 @@ pub const SUPPORTED_MODELS: &[ModelInfo] = &[
         filename: "ggml-large-v3.bin",
         url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-large-v3.bin",
         size_bytes: 3_095 * 1_000_000, // ~2.9GB
+        ram_mb: 3_900,
         label_key: "model-label-large",
         speed_rating: 1.0,
         quality_rating: 5.0,
@@ -69,12 +75,195 @@ pub const SUPPORTED_MODELS: &[ModelInfo] = &[
     },
 ];
 
+// Warn when a model needs more than this share of physical memory
+const RAM_WARN_FRACTION: f64 = 0.5;
+
+impl ModelInfo {
+    /// True when the model's runtime memory is a large share of `total_bytes`
+    pub fn exceeds_memory(&self, total_bytes: u64) -> bool {
+        (self.ram_mb * 1_000_000) as f64 > total_bytes as f64 * RAM_WARN_FRACTION
+    }
+}
+
 pub fn model_info_for_filename(name: &str) -> Option<&'static ModelInfo> {
     SUPPORTED_MODELS.iter().find(|m| m.filename == name)
 }
 
-/// Auto-download Whisper model if missing
-pub fn ensure_model(model_path: &Path) -> Result<()> {
+// ggml whisper header: GGML_MAGIC, then i32 hparams (n_vocab, n_audio_ctx,
+// n_audio_state, n_audio_head, n_audio_layer, ...)
+const HEADER_LEN: usize = 4 * 6;
+
+// Preset of the same size class, by encoder depth
+fn preset_for_audio_layers(layers: i32) -> Option<&'static ModelInfo> {
+    let size = match layers {
+        4 => "tiny",
+        6 => "base",
+        12 => "small",
+        24 => "medium",
+        32 => "large",
+        _ => return None,
+    };
+    preset_for_name(size)
+}
+
+// Size class from a file name ("ggml-small.en-q5_1.bin" -> small)
+fn preset_for_name(name: &str) -> Option<&'static ModelInfo> {
+    let name = name.to_ascii_lowercase();
+    ["tiny", "base", "small", "medium", "large"]
+        .iter()
+        .find(|size| name.contains(*size))
+        .and_then(|size| {
+            SUPPORTED_MODELS
+                .iter()
+                .find(|m| m.filename.starts_with(&format!("ggml-{}", size)))
+        })
+}
+
+fn preset_from_header(header: &[u8]) -> Option<&'static ModelInfo> {
+    let word = |i: usize| {
+        header
+            .get(i * 4..i * 4 + 4)
+            .map(|b| [b[0], b[1], b[2], b[3]])
+    };
+    if word(0)? != GGML_MAGIC {
+        return None;
+    }
+    preset_for_audio_layers(i32::from_le_bytes(word(5)?))
+}
+
+/// Size class of a model file: from the ggml header when readable, else from the
+/// file name. Used to warn when the file does not match the selected preset.
+pub fn detect_model_preset(path: &Path) -> Option<&'static ModelInfo> {
+    let mut header = [0u8; HEADER_LEN];
+    let from_header = File::open(path)
+        .and_then(|mut f| f.read_exact(&mut header))
+        .ok()
+        .and_then(|_| preset_from_header(&header));
+    from_header.or_else(|| preset_for_name(path.file_name()?.to_str()?))
+}
+
+/// Common prefix of the built-in model URLs (swapped out when a mirror is configured)
+pub const DEFAULT_MODEL_BASE_URL: &str =
+    "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/";
+
+/// Validate a mirror base URL (http/https with a host); returns it with a trailing slash
+pub fn validate_model_base_url(input: &str) -> Result<String, String> {
+    let trimmed = input.trim();
+    let url = reqwest::Url::parse(trimmed).map_err(|e| e.to_string())?;
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err(format!("unsupported scheme: {}", url.scheme()));
+    }
+    if url.host_str().is_none_or(|h| h.is_empty()) {
+        return Err("missing host".to_string());
+    }
+    if url.query().is_some() || url.fragment().is_some() {
+        return Err("query/fragment not allowed".to_string());
+    }
+    let mut out = trimmed.to_string();
+    if !out.ends_with('/') {
+        out.push('/');
+    }
+    Ok(out)
+}
+
+/// Download URL for `info`; a valid `mirror_base` replaces the default host/prefix
+/// while keeping the per-model path.
+pub fn model_download_url(info: &ModelInfo, mirror_base: Option<&str>) -> String {
+    let Some(base) = mirror_base
+        .filter(|b| !b.trim().is_empty())
+        .and_then(|b| validate_model_base_url(b).ok())
+    else {
+        return info.url.to_string();
+    };
+    let path = info
+        .url
+        .strip_prefix(DEFAULT_MODEL_BASE_URL)
+        .unwrap_or(info.filename);
+    format!("{}{}", base, path)
+}
+
+// First bytes of every whisper.cpp ggml model (u32 0x67676d6c, little-endian)
+const GGML_MAGIC: [u8; 4] = *b"lmgg";
+
+/// Downloaded file failed the size/header check (the partial file has been deleted)
+#[derive(Debug)]
+pub struct DownloadVerifyError(pub String);
+
+impl std::fmt::Display for DownloadVerifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "download verification failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for DownloadVerifyError {}
+
+// Check the finished temp file before it replaces `dest`.
+// `expected_len` comes from Content-Length/Content-Range (0 = unknown).
+fn check_download(tmp_path: &Path, dest: &Path, expected_len: u64) -> Result<(), String> {
+    let len = std::fs::metadata(tmp_path)
+        .map_err(|e| format!("cannot stat {}: {e}", tmp_path.display()))?
+        .len();
+    if expected_len > 0 && len != expected_len {
+        return Err(format!(
+            "size mismatch ({} bytes, server announced {})",
+            len, expected_len
+        ));
+    }
+    let Some(info) = dest
+        .file_name()
+        .and_then(|s| s.to_str())
+        .and_then(model_info_for_filename)
+    else {
+        // Not a Whisper model (e.g. update installer): size check only
+        return Ok(());
+    };
+    // Declared sizes are approximate; anything far below is truncated or an error page
+    if len < info.size_bytes / 10 * 9 {
+        return Err(format!(
+            "file too small for {} ({} bytes, expected about {})",
+            info.filename, len, info.size_bytes
+        ));
+    }
+    let mut magic = [0u8; 4];
+    File::open(tmp_path)
+        .and_then(|mut f| f.read_exact(&mut magic))
+        .map_err(|e| format!("cannot read header: {e}"))?;
+    if magic != GGML_MAGIC {
+        return Err(format!(
+            "{} is not a ggml model (bad header)",
+            info.filename
+        ));
+    }
+    Ok(())
+}
+
+// Verify then atomically move into place; a bad file is deleted so the next try starts fresh
+fn verify_and_promote(tmp_path: &Path, dest: &Path, expected_len: u64) -> Result<()> {
+    if let Err(reason) = check_download(tmp_path, dest, expected_len) {
+        eprintln!("[Download] Verification failed: {}", reason);
+        let _ = std::fs::remove_file(tmp_path);
+        return Err(DownloadVerifyError(reason).into());
+    }
+    std::fs::rename(tmp_path, dest).context("rename downloaded file")?;
+    Ok(())
+}
+
+// Total size from `Content-Range: bytes 100-199/200` (None when unknown/`*`)
+fn content_range_total(resp: &http::Response) -> Option<u64> {
+    resp.headers()
+        .get(CONTENT_RANGE)?
+        .to_str()
+        .ok()?
+        .rsplit('/')
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Auto-download Whisper model if missing (from `mirror_base` when set, see
+/// `model_download_url`)
+pub fn ensure_model(model_path: &Path, mirror_base: Option<&str>) -> Result<()> {
     if model_path.exists() {
         return Ok(());
     }
@@ -86,8 +275,11 @@ pub fn ensure_model(model_path: &Path) -> Result<()> {
         .unwrap_or("ggml-small.bin");
     let model_info = model_info_for_filename(filename);
     let url = model_info
-        .map(|m| m.url)
-        .unwrap_or("https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-small.bin");
+        .or_else(|| model_info_for_filename("ggml-small.bin"))
+        .map(|m| model_download_url(m, mirror_base))
+        .unwrap_or_else(|| {
+            "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-small.bin".to_string()
+        });
 
     let expected_size = model_info.map(|m| m.size_bytes).unwrap_or(0);
     let size_mb = expected_size as f64 / 1_000_000.0;
@@ -103,7 +295,7 @@ pub fn ensure_model(model_path: &Path) -> Result<()> {
     eprintln!("Downloading...");
 
     // Download with progress callback
-    download_with_progress(url, model_path, |downloaded, total| {
+    download_with_progress(&url, model_path, |downloaded, total| {
         let percent = if total > 0 {
             (downloaded as f64 / total as f64) * 100.0
         } else {
@@ -163,6 +355,7 @@ where
         resp.version(),
         resp.status()
     );
+    let announced = resp.content_length().unwrap_or(0);
     let total = resp
         .content_length()
         .or_else(|| {
@@ -197,10 +390,9 @@ where
         on_progress(downloaded, total);
     }
     file.flush().ok();
+    drop(file);
 
-    // atomically move into place
-    std::fs::rename(&tmp_path, dest).context("rename downloaded file")?;
-    Ok(())
+    verify_and_promote(&tmp_path, dest, announced)
 }
 
 /// Cancelable variant of download_with_progress
@@ -253,9 +445,30 @@ where
         resp.version(),
         resp.status()
     );
+    if resp.status().as_u16() == 416 && start > 0 {
+        // Range past the end: the partial may already be complete, so verify it
+        if content_range_total(&resp) == Some(start) {
+            return verify_and_promote(&tmp_path, dest, start);
+        }
+        let _ = std::fs::remove_file(&tmp_path);
+        return Err(anyhow!(
+            "download failed: {} (stale partial removed)",
+            resp.status()
+        ));
+    }
     if !(resp.status().is_success() || resp.status().as_u16() == 206) {
         return Err(anyhow!("download failed: {}", resp.status()));
     }
+    // If resume was requested but server ignored Range (returned 200), restart from scratch
+    let resume_ok = start > 0 && resp.status().as_u16() == 206;
+    // Exact final size announced by the server (0 = unknown), used to verify the result
+    let announced = if resume_ok {
+        content_range_total(&resp)
+            .or_else(|| resp.content_length().map(|n| start + n))
+            .unwrap_or(0)
+    } else {
+        resp.content_length().unwrap_or(0)
+    };
     // total expected size
     let remaining = resp.content_length().unwrap_or(0);
     let mut total = start + remaining;
@@ -268,8 +481,6 @@ where
             .unwrap_or(0);
     }
 
-    // If resume was requested but server ignored Range (returned 200), restart from scratch
-    let resume_ok = start > 0 && resp.status().as_u16() == 206;
     // open file (append if resuming and server accepted range, otherwise create new)
     let mut file: File = if resume_ok {
         OpenOptions::new()
@@ -306,8 +517,36 @@ where
         on_progress(base + downloaded, total);
     }
     file.flush().ok();
-    std::fs::rename(&tmp_path, dest).context("rename downloaded file")?;
-    Ok(())
+    drop(file);
+    // Resumed partials are checked as a whole before promotion
+    verify_and_promote(&tmp_path, dest, announced)
 }
 
 // removed: default_model_path, supported_model_labels (unused)
+
+#[cfg(test)]
+mod tests {
+    use super::{preset_for_name, preset_from_header, GGML_MAGIC};
+
+    fn header(audio_layers: i32) -> Vec<u8> {
+        let mut out = GGML_MAGIC.to_vec();
+        for value in [51864, 1500, 384, 6, audio_layers] {
+            out.extend_from_slice(&i32::to_le_bytes(value));
+        }
+        out
+    }
+
+    #[test]
+    fn detects_size_class() {
+        let filename = |h: &[u8]| preset_from_header(h).map(|m| m.filename);
+        assert_eq!(filename(&header(4)), Some("ggml-tiny.bin"));
+        assert_eq!(filename(&header(32)), Some("ggml-large-v3.bin"));
+        assert_eq!(filename(&header(7)), None);
+        assert_eq!(filename(b"GGUF\0\0\0\0"), None);
+        assert_eq!(
+            preset_for_name("ggml-small.en-q5_1.bin").map(|m| m.filename),
+            Some("ggml-small.bin")
+        );
+        assert!(preset_for_name("custom.bin").is_none());
+    }
+}
@@ -0,0 +1,241 @@
+//! OpenAI-compatible `/audio/transcriptions` backend: each VAD chunk is sent as a
+//! 16 kHz mono WAV instead of running local whisper-rs (`/audio/translations`
+//! for the translate task). Dictionary/LLM steps
+//! run on the returned text exactly as for local transcription.
+
+use anyhow::{anyhow, bail, Context, Result};
+use reqwest::blocking::multipart::{Form, Part};
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
+use std::time::Instant;
+
+use super::whisper::{Segment, TranscriptionResult, WhisperTask, WHISPER_SAMPLE_RATE};
+
+pub const DEFAULT_REMOTE_BASE_URL: &str = "https://api.openai.com/v1";
+pub const DEFAULT_REMOTE_MODEL: &str = "whisper-1";
+/// Used when the API key field is empty
+pub const API_KEY_ENV: &str = "OPENAI_API_KEY";
+
+const TRANSCRIPTIONS_PATH: &str = "audio/transcriptions";
+const TRANSLATIONS_PATH: &str = "audio/translations";
+// Upload + inference of a long chunk on a busy server
+const REQUEST_TIMEOUT_SECS: u64 = 120;
+
+/// Where speech recognition runs
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum TranscriptionBackend {
+    #[default]
+    LocalWhisper,
+    RemoteHttp {
+        base_url: String,
+        api_key: String,
+        model: String,
+    },
+}
+
+impl TranscriptionBackend {
+    pub fn is_remote(&self) -> bool {
+        matches!(self, Self::RemoteHttp { .. })
+    }
+}
+
+/// Saved remote endpoint (settings.toml `[remote_transcription]`)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemoteTranscriptionSettings {
+    pub enabled: bool,
+    pub base_url: String,
+    pub api_key: String,
+    pub model: String,
+}
+
+impl Default for RemoteTranscriptionSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            base_url: DEFAULT_REMOTE_BASE_URL.to_string(),
+            api_key: String::new(),
+            model: DEFAULT_REMOTE_MODEL.to_string(),
+        }
+    }
+}
+
+impl RemoteTranscriptionSettings {
+    pub fn backend(&self) -> TranscriptionBackend {
+        if !self.enabled {
+            return TranscriptionBackend::LocalWhisper;
+        }
+        let or_default = |value: &str, default: &str| {
+            let value = value.trim();
+            if value.is_empty() {
+                default.to_string()
+            } else {
+                value.to_string()
+            }
+        };
+        TranscriptionBackend::RemoteHttp {
+            base_url: or_default(&self.base_url, DEFAULT_REMOTE_BASE_URL),
+            api_key: self.api_key.trim().to_string(),
+            model: or_default(&self.model, DEFAULT_REMOTE_MODEL),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TranscriptionResponse {
+    text: String,
+    #[serde(default)]
+    language: Option<String>,
+}
+
+/// Transcribe 16 kHz mono samples with the remote endpoint
+pub fn transcribe_remote(
+    base_url: &str,
+    api_key: &str,
+    model: &str,
+    pcm: &[f32],
+    language: Option<&str>,
+    task: WhisperTask,
+) -> Result<TranscriptionResult> {
+    let client = crate::llm::build_client_with_timeout(REQUEST_TIMEOUT_SECS)?;
+    let mut headers = crate::llm::create_headers(false)?;
+    let env_key = std::env::var(API_KEY_ENV).unwrap_or_default();
+    let key = if api_key.is_empty() {
+        &env_key
+    } else {
+        api_key
+    };
+    if !key.is_empty() {
+        let value = HeaderValue::from_str(&format!("Bearer {}", key))
+            .map_err(|_| anyhow!("API key contains invalid characters"))?;
+        headers.insert(AUTHORIZATION, value);
+    }
+
+    let file = Part::bytes(encode_wav(pcm)?)
+        .file_name("audio.wav")
+        .mime_str("audio/wav")?;
+    let mut form = Form::new()
+        .part("file", file)
+        .text("model", model.to_string())
+        .text("response_format", "json");
+    // The translations endpoint has no language field (output is always English)
+    if let Some(lang) = language.filter(|_| task == WhisperTask::Transcribe) {
+        form = form.text("language", lang.to_string());
+    }
+
+    let url = endpoint_url(base_url, task);
+    let start = Instant::now();
+    let response = client
+        .post(&url)
+        .headers(headers)
+        .multipart(form)
+        .send()
+        .with_context(|| format!("POST {}", url))?;
+    let status = response.status();
+    let body = response.text().unwrap_or_default();
+    if !status.is_success() {
+        let preview = crate::llm::preview_body(&body);
+        bail!("HTTP {} from {}: {}", status.as_u16(), url, preview);
+    }
+    let (text, detected) = parse_response(&body)?;
+    let duration = start.elapsed().as_secs_f32();
+    let audio_sec = pcm.len() as f32 / WHISPER_SAMPLE_RATE as f32;
+    Ok(TranscriptionResult {
+        segments: vec![Segment {
+            start: 0.0,
+            end: audio_sec,
+            text: text.clone(),
+            no_speech_prob: 0.0,
+            // The JSON response carries no token probabilities: never filtered
+            avg_logprob: 0.0,
+        }],
+        text,
+        duration_sec: duration,
+        rtf: if audio_sec > 0.0 {
+            duration / audio_sec
+        } else {
+            0.0
+        },
+        language: language.map(str::to_string).or(detected),
+        detection: None,
+        dropped_segments: Vec::new(),
+    })
+}
+
+fn endpoint_url(base_url: &str, task: WhisperTask) -> String {
+    let path = match task {
+        WhisperTask::Transcribe => TRANSCRIPTIONS_PATH,
+        WhisperTask::Translate => TRANSLATIONS_PATH,
+    };
+    format!("{}/{}", base_url.trim_end_matches('/'), path)
+}
+
+// 16-bit PCM WAV in memory
+fn encode_wav(pcm: &[f32]) -> Result<Vec<u8>> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: WHISPER_SAMPLE_RATE,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut cursor = Cursor::new(Vec::new());
+    {
+        let mut writer = hound::WavWriter::new(&mut cursor, spec)?;
+        for &s in pcm {
+            writer.write_sample((s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)?;
+        }
+        writer.finalize()?;
+    }
+    Ok(cursor.into_inner())
+}
+
+// `{"text": "..."}`; some servers also report the detected language (verbose_json)
+fn parse_response(body: &str) -> Result<(String, Option<String>)> {
+    let parsed: TranscriptionResponse =
+        serde_json::from_str(body).context("parse transcription response")?;
+    Ok((parsed.text.trim().to_string(), parsed.language))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_wav, endpoint_url, parse_response, RemoteTranscriptionSettings};
+    use super::{TranscriptionBackend, WhisperTask, DEFAULT_REMOTE_MODEL};
+
+    #[test]
+    fn wav_and_request_helpers() {
+        let wav = encode_wav(&[0.0, 1.0, -1.0, 2.0]).unwrap();
+        assert_eq!(&wav[..4], b"RIFF");
+        // 44-byte header + 4 samples × 2 bytes
+        assert_eq!(wav.len(), 44 + 8);
+        assert_eq!(
+            endpoint_url("https://api.openai.com/v1/", WhisperTask::Transcribe),
+            "https://api.openai.com/v1/audio/transcriptions"
+        );
+        assert_eq!(
+            endpoint_url("http://localhost:8000/v1", WhisperTask::Translate),
+            "http://localhost:8000/v1/audio/translations"
+        );
+        let (text, lang) = parse_response(r#"{"text":" Hello there. ","language":"en"}"#).unwrap();
+        assert_eq!(text, "Hello there.");
+        assert_eq!(lang.as_deref(), Some("en"));
+        assert!(parse_response("<html>").is_err());
+    }
+
+    #[test]
+    fn settings_select_backend() {
+        let mut settings = RemoteTranscriptionSettings::default();
+        assert_eq!(settings.backend(), TranscriptionBackend::LocalWhisper);
+        settings.enabled = true;
+        settings.model = "  ".into();
+        settings.base_url = "http://localhost:8000/v1".into();
+        assert_eq!(
+            settings.backend(),
+            TranscriptionBackend::RemoteHttp {
+                base_url: "http://localhost:8000/v1".into(),
+                api_key: String::new(),
+                model: DEFAULT_REMOTE_MODEL.into(),
+            }
+        );
+    }
+}
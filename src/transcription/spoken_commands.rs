@@ -0,0 +1,204 @@
+//! Spoken punctuation/formatting commands ("new line", "comma", "改行", ...)
+//! applied to the raw Whisper transcript before dictionary replacement.
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Spacing {
+    // Punctuation: glued to the previous word
+    AttachLeft,
+    // Line/paragraph break: surrounding spaces removed
+    Break,
+    // Opening quote/bracket: glued to the next word
+    OpenRight,
+}
+
+struct Command {
+    phrase: &'static str,
+    output: &'static str,
+    spacing: Spacing,
+    // Capitalize the next letter (sentence start)
+    sentence_end: bool,
+}
+
+const fn cmd(phrase: &'static str, output: &'static str, spacing: Spacing, end: bool) -> Command {
+    Command {
+        phrase,
+        output,
+        spacing,
+        sentence_end: end,
+    }
+}
+
+const EN_COMMANDS: &[Command] = &[
+    cmd("new paragraph", "\n\n", Spacing::Break, true),
+    cmd("new line", "\n", Spacing::Break, true),
+    cmd("newline", "\n", Spacing::Break, true),
+    cmd("period", ".", Spacing::AttachLeft, true),
+    cmd("full stop", ".", Spacing::AttachLeft, true),
+    cmd("question mark", "?", Spacing::AttachLeft, true),
+    cmd("exclamation mark", "!", Spacing::AttachLeft, true),
+    cmd("exclamation point", "!", Spacing::AttachLeft, true),
+    cmd("comma", ",", Spacing::AttachLeft, false),
+    cmd("colon", ":", Spacing::AttachLeft, false),
+    cmd("semicolon", ";", Spacing::AttachLeft, false),
+    cmd("open quote", "\"", Spacing::OpenRight, false),
+    cmd("close quote", "\"", Spacing::AttachLeft, false),
+    cmd("open paren", "(", Spacing::OpenRight, false),
+    cmd("close paren", ")", Spacing::AttachLeft, false),
+];
+
+const JA_COMMANDS: &[Command] = &[
+    cmd("改段落", "\n\n", Spacing::Break, false),
+    cmd("改行", "\n", Spacing::Break, false),
+    cmd("句点", "。", Spacing::AttachLeft, false),
+    cmd("読点", "、", Spacing::AttachLeft, false),
+    cmd("疑問符", "？", Spacing::AttachLeft, false),
+    cmd("感嘆符", "！", Spacing::AttachLeft, false),
+    cmd("かぎかっこ閉じ", "」", Spacing::AttachLeft, false),
+    cmd("かぎかっこ", "「", Spacing::OpenRight, false),
+];
+
+// Punctuation Whisper tends to add around a spoken command ("Hello, comma, world.")
+const STRAY_PUNCT: &[char] = &[',', '.', '、', '。'];
+
+// Command set for the Whisper language ("auto"/other languages get both sets)
+fn commands_for(language: Option<&str>) -> Vec<&'static Command> {
+    let lang = language.unwrap_or("auto").to_ascii_lowercase();
+    let mut list: Vec<&'static Command> = match lang.split(['-', '_']).next() {
+        Some("en") => EN_COMMANDS.iter().collect(),
+        Some("ja") => JA_COMMANDS.iter().collect(),
+        _ => EN_COMMANDS.iter().chain(JA_COMMANDS.iter()).collect(),
+    };
+    // Longest phrase first ("かぎかっこ閉じ" before "かぎかっこ")
+    list.sort_by_key(|c| std::cmp::Reverse(c.phrase.len()));
+    list
+}
+
+fn is_word_char(c: Option<char>) -> bool {
+    c.is_some_and(|c| c.is_alphanumeric())
+}
+
+// Command starting at byte `i`; ASCII phrases must be whole words (case-insensitive)
+fn match_command<'a>(
+    text: &str,
+    i: usize,
+    commands: &[&'a Command],
+) -> Option<(&'a Command, usize)> {
+    let rest = &text.as_bytes()[i..];
+    commands.iter().copied().find_map(|c| {
+        let p = c.phrase.as_bytes();
+        if rest.len() < p.len() || !rest[..p.len()].eq_ignore_ascii_case(p) {
+            return None;
+        }
+        let end = i + p.len();
+        if c.phrase.is_ascii()
+            && (is_word_char(text[..i].chars().next_back())
+                || is_word_char(text[end..].chars().next()))
+        {
+            return None;
+        }
+        Some((c, end))
+    })
+}
+
+fn trim_end_matching(out: &mut String, pred: impl Fn(char) -> bool) {
+    while let Some(c) = out.chars().next_back() {
+        if !pred(c) {
+            break;
+        }
+        out.pop();
+    }
+}
+
+/// Replace spoken commands in `text` with their symbols/line breaks.
+/// `language` is the Whisper language setting (None = auto-detect).
+pub fn apply_spoken_commands(text: &str, language: Option<&str>) -> String {
+    let commands = commands_for(language);
+    let mut out = String::with_capacity(text.len());
+    let mut capitalize_next = false;
+    let mut i = 0;
+    while i < text.len() {
+        if let Some((c, end)) = match_command(text, i, &commands) {
+            match c.spacing {
+                Spacing::AttachLeft => {
+                    trim_end_matching(&mut out, |ch| ch == ' ' || STRAY_PUNCT.contains(&ch));
+                }
+                Spacing::Break => {
+                    trim_end_matching(&mut out, |ch| ch == ' ' || ch == ',' || ch == '、');
+                }
+                Spacing::OpenRight => {
+                    if out
+                        .chars()
+                        .next_back()
+                        .is_some_and(|ch| ch.is_ascii_alphanumeric())
+                    {
+                        out.push(' ');
+                    }
+                }
+            }
+            out.push_str(c.output);
+            capitalize_next = c.sentence_end;
+            // Drop Whisper's punctuation after the command; breaks/openers also eat spaces
+            let eat_spaces = c.spacing != Spacing::AttachLeft;
+            let skipped = text[end..]
+                .char_indices()
+                .find(|&(_, ch)| !(STRAY_PUNCT.contains(&ch) || (eat_spaces && ch == ' ')))
+                .map(|(j, _)| j)
+                .unwrap_or(text.len() - end);
+            i = end + skipped;
+            continue;
+        }
+        let Some(ch) = text[i..].chars().next() else {
+            break;
+        };
+        if capitalize_next && ch.is_alphabetic() {
+            out.extend(ch.to_uppercase());
+        } else {
+            out.push(ch);
+        }
+        if !ch.is_whitespace() {
+            capitalize_next = false;
+        }
+        i += ch.len_utf8();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::apply_spoken_commands;
+
+    #[test]
+    fn english_punctuation_and_breaks() {
+        assert_eq!(
+            apply_spoken_commands("hello comma world period new line thanks", Some("en")),
+            "hello, world.\nThanks"
+        );
+        // Whisper's own punctuation around the command is dropped
+        assert_eq!(
+            apply_spoken_commands("Is it done, question mark. Yes.", Some("en")),
+            "Is it done? Yes."
+        );
+        assert_eq!(
+            apply_spoken_commands("he said open quote hi close quote", None),
+            "he said \"hi\""
+        );
+        // Only whole words
+        assert_eq!(
+            apply_spoken_commands("periodic commas", Some("en")),
+            "periodic commas"
+        );
+    }
+
+    #[test]
+    fn japanese_commands() {
+        assert_eq!(
+            apply_spoken_commands(
+                "今日は晴れ句点改行明日は、かぎかっこ雨かぎかっこ閉じ",
+                Some("ja")
+            ),
+            "今日は晴れ。\n明日は、「雨」"
+        );
+        // English phrases are not applied for Japanese
+        assert_eq!(apply_spoken_commands("new line", Some("ja")), "new line");
+    }
+}
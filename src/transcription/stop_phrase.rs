@@ -0,0 +1,112 @@
+//! Stop-phrase matching ("end dictation", "入力終了", ...) for ending a
+//! recording by voice. Case/width-insensitive; punctuation and spacing ignored.
+
+// Folded characters with their byte offset in the original text.
+// Non-alphanumeric runs collapse into a single ' ' separator.
+fn fold(text: &str) -> Vec<(usize, char)> {
+    let mut out: Vec<(usize, char)> = Vec::with_capacity(text.len());
+    for (i, c) in text.char_indices() {
+        // Full-width ASCII (Ａ, １, ...) -> half-width
+        let c = match c as u32 {
+            0xFF01..=0xFF5E => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            _ => c,
+        };
+        for lc in c.to_lowercase() {
+            if lc.is_alphanumeric() {
+                out.push((i, lc));
+            } else if out.last().is_some_and(|&(_, p)| p != ' ') {
+                out.push((i, ' '));
+            }
+        }
+    }
+    if out.last().is_some_and(|&(_, p)| p == ' ') {
+        out.pop();
+    }
+    out
+}
+
+/// Normalized form of a configured stop phrase (None when it has no letters/digits).
+pub fn normalize_stop_phrase(phrase: &str) -> Option<String> {
+    let folded: String = fold(phrase).into_iter().map(|(_, c)| c).collect();
+    if folded.is_empty() {
+        None
+    } else {
+        Some(folded)
+    }
+}
+
+// Byte offset (in `text`) of the last occurrence of the normalized phrase.
+// Latin phrases must start/end on word boundaries ("end" does not match "friend").
+fn find_last(text: &str, normalized: &str) -> Option<usize> {
+    let hay = fold(text);
+    let needle: Vec<char> = normalized.chars().collect();
+    if needle.is_empty() || hay.len() < needle.len() {
+        return None;
+    }
+    let is_word = |c: char| c.is_ascii_alphanumeric();
+    (0..=hay.len() - needle.len()).rev().find_map(|k| {
+        let end = k + needle.len();
+        if !hay[k..end]
+            .iter()
+            .map(|&(_, c)| c)
+            .eq(needle.iter().copied())
+        {
+            return None;
+        }
+        if is_word(needle[0]) && k > 0 && is_word(hay[k - 1].1) {
+            return None;
+        }
+        if is_word(needle[needle.len() - 1]) && end < hay.len() && is_word(hay[end].1) {
+            return None;
+        }
+        Some(hay[k].0)
+    })
+}
+
+/// True when `text` contains the (normalized) stop phrase.
+pub fn contains_stop_phrase(text: &str, normalized: &str) -> bool {
+    find_last(text, normalized).is_some()
+}
+
+/// Cut the transcript at the last stop phrase (recording stopped right after it).
+/// Returns None when the phrase is not found.
+pub fn strip_stop_phrase(text: &str, normalized: &str) -> Option<String> {
+    let start = find_last(text, normalized)?;
+    let head = text[..start].trim_end_matches(|c: char| c.is_whitespace() || c == ',' || c == '、');
+    Some(head.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{contains_stop_phrase, normalize_stop_phrase, strip_stop_phrase};
+
+    #[test]
+    fn matches_ignoring_case_and_punctuation() {
+        let p = normalize_stop_phrase("  End dictation ").unwrap();
+        assert!(contains_stop_phrase("OK, that's all. END-dictation.", &p));
+        assert!(!contains_stop_phrase("my friend dictation app", &p));
+        assert_eq!(normalize_stop_phrase(" ... "), None);
+
+        let ja = normalize_stop_phrase("入力終了").unwrap();
+        assert!(contains_stop_phrase("明日は雨です。入力終了。", &ja));
+    }
+
+    #[test]
+    fn strips_phrase_and_tail() {
+        let p = normalize_stop_phrase("end dictation").unwrap();
+        assert_eq!(
+            strip_stop_phrase("Send the report. End dictation. Thank you.", &p).as_deref(),
+            Some("Send the report.")
+        );
+        assert_eq!(
+            strip_stop_phrase("Send it, end dictation", &p).as_deref(),
+            Some("Send it")
+        );
+        assert_eq!(strip_stop_phrase("Nothing here", &p), None);
+        let ja = normalize_stop_phrase("入力終了").unwrap();
+        assert_eq!(
+            strip_stop_phrase("明日は雨です。入力終了", &ja).as_deref(),
+            Some("明日は雨です。")
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! SRT/WebVTT export of the last transcription. Cue times are seconds from the
+//! start of the recording/file (VAD chunk offsets already applied).
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// One subtitle cue
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubtitleCue {
+    pub start: f32,
+    pub end: f32,
+    pub text: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubtitleFormat {
+    Srt,
+    Vtt,
+}
+
+impl SubtitleFormat {
+    /// `.vtt` selects WebVTT; anything else is SRT
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("vtt") => Self::Vtt,
+            _ => Self::Srt,
+        }
+    }
+}
+
+// HH:MM:SS,mmm (SRT) or HH:MM:SS.mmm (VTT)
+fn timestamp(secs: f32, separator: char) -> String {
+    let ms = (secs.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        separator,
+        ms % 1000
+    )
+}
+
+/// Render cues; empty cues are skipped and numbering stays consecutive
+pub fn render(cues: &[SubtitleCue], format: SubtitleFormat) -> String {
+    let mut out = String::new();
+    let separator = match format {
+        SubtitleFormat::Srt => ',',
+        SubtitleFormat::Vtt => {
+            out.push_str("WEBVTT\n\n");
+            '.'
+        }
+    };
+    let cues = cues.iter().filter(|c| !c.text.trim().is_empty());
+    for (idx, cue) in cues.enumerate() {
+        if format == SubtitleFormat::Srt {
+            let _ = writeln!(out, "{}", idx + 1);
+        }
+        // Players reject zero-length cues
+        let end = cue.end.max(cue.start + 0.001);
+        let _ = writeln!(
+            out,
+            "{} --> {}\n{}\n",
+            timestamp(cue.start, separator),
+            timestamp(end, separator),
+            cue.text.trim()
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render, SubtitleCue, SubtitleFormat};
+
+    fn cues() -> Vec<SubtitleCue> {
+        vec![
+            SubtitleCue {
+                start: 0.5,
+                end: 2.25,
+                text: "Hello there.".into(),
+            },
+            SubtitleCue {
+                start: 2.25,
+                end: 2.25,
+                text: "  ".into(),
+            },
+            SubtitleCue {
+                start: 3661.0,
+                end: 3662.5,
+                text: " Second line ".into(),
+            },
+        ]
+    }
+
+    #[test]
+    fn renders_srt() {
+        assert_eq!(
+            render(&cues(), SubtitleFormat::Srt),
+            "1\n00:00:00,500 --> 00:00:02,250\nHello there.\n\n\
+             2\n01:01:01,000 --> 01:01:02,500\nSecond line\n\n"
+        );
+    }
+
+    #[test]
+    fn renders_vtt() {
+        assert_eq!(
+            render(&cues(), SubtitleFormat::Vtt),
+            "WEBVTT\n\n00:00:00.500 --> 00:00:02.250\nHello there.\n\n\
+             01:01:01.000 --> 01:01:02.500\nSecond line\n\n"
+        );
+    }
+}
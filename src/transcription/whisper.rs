@@ -1,14 +1,51 @@
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use std::time::Instant;
 use unicode_categories::UnicodeCategories;
 use whisper_rs::{FullParams, SamplingStrategy, WhisperState};
 
+/// Whisper only accepts 16 kHz mono; capture, pre-roll and file decoding all resample to it
+pub const WHISPER_SAMPLE_RATE: u32 = 16_000;
+
 #[allow(dead_code)]
 pub struct TranscriptionResult {
     pub text: String,
     pub segments: Vec<Segment>,
     pub duration_sec: f32,
     pub rtf: f32,
+    /// Language Whisper used (the requested one, or the detected one on auto)
+    pub language: Option<String>,
+    /// Auto-detect result when a fallback language is configured
+    pub detection: Option<LanguageDetection>,
+    /// Segments left out of `text` for falling below `min_segment_confidence`
+    pub dropped_segments: Vec<Segment>,
+}
+
+/// Language used instead of auto-detect when Whisper is unsure (short clips)
+#[derive(Clone, Debug, PartialEq)]
+pub struct LanguageFallback {
+    pub language: String,
+    /// Detection probability below which `language` is used (0..1)
+    pub min_confidence: f32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LanguageDetection {
+    pub detected: String,
+    pub confidence: f32,
+    /// The fallback language was used instead of `detected`
+    pub fell_back: bool,
+}
+
+impl LanguageFallback {
+    /// Keep the detected language unless its probability is below the threshold
+    pub fn resolve(&self, detected: &str, confidence: f32) -> LanguageDetection {
+        LanguageDetection {
+            detected: detected.to_string(),
+            confidence,
+            fell_back: confidence < self.min_confidence && detected != self.language,
+        }
+    }
 }
 
 #[allow(dead_code)]
@@ -17,6 +54,26 @@ pub struct Segment {
     pub end: f32,
     pub text: String,
     pub no_speech_prob: f32,
+    /// Mean log-probability of the segment's text tokens (0 when unknown)
+    pub avg_logprob: f32,
+}
+
+impl Segment {
+    /// Average token probability (0..1)
+    pub fn confidence(&self) -> f32 {
+        self.avg_logprob.exp()
+    }
+}
+
+/// What Whisper produces from the audio
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WhisperTask {
+    /// Text in the spoken language
+    #[default]
+    Transcribe,
+    /// English text from speech in any language (the language setting is the source)
+    Translate,
 }
 
 #[derive(Clone, Debug)]
@@ -24,12 +81,19 @@ pub struct WhisperOptimizationParams {
     pub no_timestamps: bool,
     pub token_timestamps: bool,
     pub use_physical_cores: bool,
+    pub task: WhisperTask,
+    // Threads per inference (0 = auto: cores capped at 4); see `threads_param`
+    pub n_threads: i32,
     // New: decoding + context controls
     pub enable_beam_search: bool,
     pub beam_size: i32,
     pub temperature: f32,
     pub n_max_text_ctx: i32,
     pub no_context: bool,
+    // Only consulted when the language is auto
+    pub language_fallback: Option<LanguageFallback>,
+    // Segments below this `Segment::confidence` are left out of the text (None keeps all)
+    pub min_segment_confidence: Option<f32>,
 }
 
 impl Default for WhisperOptimizationParams {
@@ -38,6 +102,8 @@ impl Default for WhisperOptimizationParams {
             no_timestamps: true,
             token_timestamps: false,
             use_physical_cores: true,
+            task: WhisperTask::Transcribe,
+            n_threads: 0,
             // Prefer small beam search for stable punctuation
             enable_beam_search: true,
             beam_size: 3,
@@ -45,10 +111,33 @@ impl Default for WhisperOptimizationParams {
             // Keep long text context by default (but we disable context across chunks below)
             n_max_text_ctx: 16384,
             no_context: false,
+            language_fallback: None,
+            min_segment_confidence: None,
         }
     }
 }
 
+/// CPU threads available to Whisper
+pub fn available_threads(use_physical_cores: bool) -> i32 {
+    if use_physical_cores {
+        // Prefer physical cores (HT often doesn't help)
+        num_cpus::get_physical() as i32
+    } else {
+        // Or all logical cores
+        num_cpus::get() as i32
+    }
+}
+
+/// Upper bound for a manual thread count (logical cores)
+pub fn max_threads() -> usize {
+    num_cpus::get().max(1)
+}
+
+/// `whisper_threads` setting → `n_threads` (None = auto), clamped to the available cores
+pub fn threads_param(threads: Option<usize>) -> i32 {
+    threads.map_or(0, |n| n.clamp(1, max_threads()) as i32)
+}
+
 /// Reusable-state variant: call this repeatedly with the same `state` to avoid init overhead.
 pub fn transcribe_with_state(
     state: &mut WhisperState,
@@ -60,6 +149,34 @@ pub fn transcribe_with_state(
     let default_opt = WhisperOptimizationParams::default();
     let opt = optimization.unwrap_or(&default_opt);
 
+    // Threads
+    let n_threads = if opt.n_threads > 0 {
+        opt.n_threads
+    } else {
+        // Avoid too many threads (cap at 4)
+        available_threads(opt.use_physical_cores).min(4)
+    }
+    .max(1);
+
+    // Auto with a fallback: detect first, then decode in the chosen language
+    let detection = match (language, &opt.language_fallback) {
+        (None, Some(fallback)) => detect_language(state, pcm, n_threads)
+            .map(|(lang, p)| fallback.resolve(lang, p))
+            .ok(),
+        _ => None,
+    };
+    let resolved = detection
+        .as_ref()
+        .zip(opt.language_fallback.as_ref())
+        .map(|(d, f)| {
+            if d.fell_back {
+                &f.language
+            } else {
+                &d.detected
+            }
+        });
+    let language = resolved.map(String::as_str).or(language);
+
     // Recommendation: Greedy or small beam
     let mut params = if opt.enable_beam_search {
         FullParams::new(SamplingStrategy::BeamSearch {
@@ -73,20 +190,8 @@ pub fn transcribe_with_state(
     // Language, threading and features (auto-detect if None)
     params.set_language(language);
 
-    // Threads
-    let mut n_threads = if opt.use_physical_cores {
-        // Prefer physical cores (HT often doesn't help)
-        num_cpus::get_physical() as i32
-    } else {
-        // Or all logical cores
-        num_cpus::get() as i32
-    };
-    // Avoid too many threads (cap at 4)
-    if n_threads > 4 {
-        n_threads = 4;
-    }
-    params.set_n_threads(n_threads.max(1));
-    params.set_translate(false);
+    params.set_n_threads(n_threads);
+    params.set_translate(opt.task == WhisperTask::Translate);
 
     // Timestamp options (toggle for diagnostics)
     params.set_no_timestamps(opt.no_timestamps);
@@ -103,7 +208,8 @@ pub fn transcribe_with_state(
     params.set_no_context(true);
     // Mark field as used to satisfy Clippy when we keep forcing true
     let _ = opt.no_context;
-    params.set_single_segment(true);
+    // One segment per chunk unless timestamps are wanted (subtitle export)
+    params.set_single_segment(opt.no_timestamps);
     params.set_token_timestamps(false);
     // Reduce blank token influence
     params.set_suppress_blank(true);
@@ -135,14 +241,13 @@ pub fn transcribe_with_state(
         .context("whisper inference failed")?;
     let duration = start.elapsed();
 
-    let audio_sec = pcm.len() as f32 / 16_000.0;
+    let audio_sec = pcm.len() as f32 / WHISPER_SAMPLE_RATE as f32;
     let rtf = if audio_sec > 0.0 {
         duration.as_secs_f32() / audio_sec
     } else {
         0.0
     };
 
-    let mut full_text = String::new();
     let mut segments = Vec::new();
 
     let mut last_ns: f32 = 0.0;
@@ -152,18 +257,33 @@ pub fn transcribe_with_state(
         let end = seg.end_timestamp() as f32 / 100.0;
         let text = seg.to_string();
         let ns = seg.no_speech_probability();
-
-        full_text.push_str(&text);
-        full_text.push(' ');
+        // Special tokens ([_BEG_], timestamps) say nothing about the text
+        let logprobs: Vec<f32> = (0..seg.n_tokens())
+            .filter_map(|i| seg.get_token(i))
+            .filter(|token| !token.to_str_lossy().is_ok_and(|t| t.starts_with("[_")))
+            .map(|token| token.token_data().plog)
+            .collect();
+        let avg_logprob = if logprobs.is_empty() {
+            0.0
+        } else {
+            logprobs.iter().sum::<f32>() / logprobs.len() as f32
+        };
 
         segments.push(Segment {
             start,
             end,
             text,
             no_speech_prob: ns,
+            avg_logprob,
         });
         last_ns = ns;
     }
+    let (segments, dropped_segments) = split_low_confidence(segments, opt.min_segment_confidence);
+    let mut full_text = String::new();
+    for seg in &segments {
+        full_text.push_str(&seg.text);
+        full_text.push(' ');
+    }
 
     // Post-output filter
     let mut text_out = full_text.trim().to_string();
@@ -173,14 +293,57 @@ pub fn transcribe_with_state(
         text_out.clear();
     }
 
+    let detected = match language {
+        Some(lang) => Some(lang.to_string()),
+        None => state
+            .full_lang_id_from_state()
+            .ok()
+            .and_then(whisper_rs::get_lang_str)
+            .map(str::to_string),
+    };
+
     Ok(TranscriptionResult {
         text: text_out,
         segments,
         duration_sec: duration.as_secs_f32(),
         rtf,
+        language: detected,
+        detection,
+        dropped_segments,
     })
 }
 
+/// Kept and dropped segments: those with `confidence()` below `min` are dropped
+fn split_low_confidence(segments: Vec<Segment>, min: Option<f32>) -> (Vec<Segment>, Vec<Segment>) {
+    match min {
+        Some(min) => segments
+            .into_iter()
+            .partition(|seg| seg.confidence() >= min),
+        None => (segments, Vec::new()),
+    }
+}
+
+// Most likely language of the clip and its probability
+fn detect_language(
+    state: &mut WhisperState,
+    pcm: &[f32],
+    threads: i32,
+) -> Result<(&'static str, f32)> {
+    let threads = threads as usize;
+    state
+        .pcm_to_mel(pcm, threads)
+        .context("whisper mel spectrogram failed")?;
+    let (id, probs) = state
+        .lang_detect(0, threads)
+        .context("whisper language detection failed")?;
+    let lang = whisper_rs::get_lang_str(id).context("unknown language id")?;
+    let confidence = usize::try_from(id)
+        .ok()
+        .and_then(|i| probs.get(i).copied())
+        .unwrap_or(0.0);
+    Ok((lang, confidence))
+}
+
 // Whether the string consists only of punctuation/whitespace
 fn is_punct_or_space_only(s: &str) -> bool {
     s.chars()
@@ -267,3 +430,33 @@ fn collapse_repetitions(s: &str) -> String {
     }
     collapsed.trim().to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{split_low_confidence, Segment};
+
+    fn segment(text: &str, confidence: f32) -> Segment {
+        Segment {
+            start: 0.0,
+            end: 1.0,
+            text: text.to_string(),
+            no_speech_prob: 0.0,
+            avg_logprob: confidence.ln(),
+        }
+    }
+
+    #[test]
+    fn drops_segments_below_min_confidence() {
+        let texts = |segs: &[Segment]| segs.iter().map(|s| s.text.clone()).collect::<Vec<_>>();
+        let segments = vec![
+            segment("hello", 0.9),
+            segment("ご視聴", 0.2),
+            segment("world", 0.5),
+        ];
+        let (kept, dropped) = split_low_confidence(segments, Some(0.4));
+        assert_eq!(texts(&kept), ["hello", "world"]);
+        assert_eq!(texts(&dropped), ["ご視聴"]);
+        let (kept, dropped) = split_low_confidence(vec![segment("quiet", 0.01)], None);
+        assert_eq!((kept.len(), dropped.len()), (1, 0));
+    }
+}
@@ -1,11 +1,18 @@
+pub mod config;
+pub mod diagnostics;
+pub mod diff;
+#[cfg(unix)]
+pub mod ipc;
 pub mod keyboard;
 pub mod logfile;
+pub mod memory;
 pub mod mic;
 pub mod open;
 pub mod paths;
 #[cfg(target_os = "linux")]
 pub mod pipewire;
 pub mod sound;
+pub mod tts;
 pub mod update;
 
 // removed unused re-exports to narrow surface
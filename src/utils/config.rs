@@ -0,0 +1,314 @@
+//! Portable settings bundle: settings.toml (incl. custom LLM prompts) and the
+//! dictionary in one .zip for moving HootVoice to another machine.
+//!
+//! Any unzip tool can open the archive, and a bundle re-zipped by the OS file
+//! manager still imports.
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::fs;
+use std::io::{Cursor, Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::dictionary::{dictionary_path, Dictionary, DICTIONARY_FILENAME};
+use crate::utils::app_config_dir;
+
+const SETTINGS_FILENAME: &str = "settings.toml";
+const MANIFEST_ENTRY: &str = "hootvoice-bundle.toml";
+const BUNDLE_FORMAT: &str = "hootvoice-config";
+const BUNDLE_VERSION: u32 = 1;
+// Settings + dictionary are small; anything bigger is not ours
+const MAX_BUNDLE_BYTES: u64 = 16 * 1024 * 1024;
+
+/// settings.toml keys tied to this machine (devices, window positions, file paths).
+/// Dropped on export and kept from the current settings on import.
+pub const MACHINE_SPECIFIC_KEYS: &[&str] = &[
+    "whisper_model_path",
+    "input_device",
+    "input_host",
+    "input_device_index_in_host",
+    "input_device_uid",
+    "input_device_index",
+    "output_device",
+    "floating_position",
+    "floating_mini_position",
+    "window_size",
+    "window_maximized",
+    "last_ui_mode",
+    "preflight_mic_done",
+    "sound_start",
+    "sound_complete",
+    "sound_error",
+];
+
+// `[remote_transcription] api_key` is dropped on export and kept on import
+const REMOTE_SECTION: &str = "remote_transcription";
+const REMOTE_API_KEY: &str = "api_key";
+
+fn secret_table(settings: &mut toml::Table) -> Option<&mut toml::Table> {
+    settings.get_mut(REMOTE_SECTION)?.as_table_mut()
+}
+
+/// What a bundle contains (shown before importing)
+#[derive(Debug, Clone, PartialEq)]
+pub struct BundleInfo {
+    pub app_version: String,
+    pub created_at: String,
+    pub has_settings: bool,
+    pub dictionary_entries: Option<usize>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Manifest {
+    format: String,
+    version: u32,
+    app_version: String,
+    created_at: String,
+}
+
+fn settings_path() -> PathBuf {
+    app_config_dir().join(SETTINGS_FILENAME)
+}
+
+/// Write `settings` (settings.toml text, minus machine-specific keys) and the
+/// dictionary to `path`.
+pub fn export_bundle(path: &Path, settings: &str) -> Result<()> {
+    let manifest = Manifest {
+        format: BUNDLE_FORMAT.to_string(),
+        version: BUNDLE_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+    };
+    let mut entries: Vec<(&str, Vec<u8>)> =
+        vec![(MANIFEST_ENTRY, toml::to_string(&manifest)?.into_bytes())];
+
+    let mut table: toml::Table = toml::from_str(settings).context("parse settings.toml")?;
+    for key in MACHINE_SPECIFIC_KEYS {
+        table.remove(*key);
+    }
+    // API keys never leave this machine
+    if let Some(remote) = secret_table(&mut table) {
+        remote.remove(REMOTE_API_KEY);
+    }
+    entries.push((SETTINGS_FILENAME, toml::to_string(&table)?.into_bytes()));
+    let dict_file = dictionary_path();
+    if dict_file.exists() {
+        let bytes =
+            fs::read(&dict_file).with_context(|| format!("read {}", dict_file.display()))?;
+        entries.push((DICTIONARY_FILENAME, bytes));
+    }
+
+    let refs: Vec<(&str, &[u8])> = entries.iter().map(|(n, b)| (*n, b.as_slice())).collect();
+    fs::write(path, write_zip(&refs)?).with_context(|| format!("write {}", path.display()))?;
+    Ok(())
+}
+
+/// Validate a bundle without touching the config dir.
+pub fn inspect_bundle(path: &Path) -> Result<BundleInfo> {
+    let bundle = read_bundle(path)?;
+    Ok(bundle.info)
+}
+
+/// Restore a bundle into the config dir. Existing files are copied to a
+/// timestamped backup folder first; returns that folder.
+pub fn import_bundle(path: &Path) -> Result<PathBuf> {
+    let bundle = read_bundle(path)?;
+
+    let backup_dir = app_config_dir().join("backups").join(
+        chrono::Local::now()
+            .format("import-%Y%m%d-%H%M%S")
+            .to_string(),
+    );
+    fs::create_dir_all(&backup_dir).with_context(|| format!("create {}", backup_dir.display()))?;
+    for file in [settings_path(), dictionary_path()] {
+        if let (true, Some(name)) = (file.exists(), file.file_name()) {
+            fs::copy(&file, backup_dir.join(name))
+                .with_context(|| format!("back up {}", file.display()))?;
+        }
+    }
+
+    if let Some(imported) = bundle.settings {
+        // Keep this machine's devices/paths; everything else comes from the bundle
+        let mut merged: toml::Table = fs::read_to_string(settings_path())
+            .ok()
+            .and_then(|s| toml::from_str(&s).ok())
+            .unwrap_or_default();
+        let api_key = secret_table(&mut merged).and_then(|t| t.remove(REMOTE_API_KEY));
+        for (key, value) in imported {
+            if !MACHINE_SPECIFIC_KEYS.contains(&key.as_str()) {
+                merged.insert(key, value);
+            }
+        }
+        if let (Some(key), Some(table)) = (api_key, secret_table(&mut merged)) {
+            table.insert(REMOTE_API_KEY.to_string(), key);
+        }
+        write_config_file(&settings_path(), toml::to_string(&merged)?.as_bytes())?;
+    }
+    if let Some(bytes) = bundle.dictionary {
+        write_config_file(&dictionary_path(), &bytes)?;
+    }
+    Ok(backup_dir)
+}
+
+fn write_config_file(path: &Path, bytes: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("import.tmp");
+    fs::write(&tmp, bytes).with_context(|| format!("write {}", tmp.display()))?;
+    fs::rename(&tmp, path).with_context(|| format!("replace {}", path.display()))?;
+    Ok(())
+}
+
+struct Bundle {
+    info: BundleInfo,
+    settings: Option<toml::Table>,
+    dictionary: Option<Vec<u8>>,
+}
+
+fn read_bundle(path: &Path) -> Result<Bundle> {
+    let size = fs::metadata(path)
+        .with_context(|| format!("open {}", path.display()))?
+        .len();
+    if size > MAX_BUNDLE_BYTES {
+        bail!("file too large ({} bytes)", size);
+    }
+    let bytes = fs::read(path).with_context(|| format!("read {}", path.display()))?;
+    let entries = read_zip(&bytes)?;
+    let entry = |name: &str| {
+        entries
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, data)| data.as_slice())
+    };
+
+    let manifest: Manifest = entry(MANIFEST_ENTRY)
+        .ok_or_else(|| anyhow!("missing {}", MANIFEST_ENTRY))
+        .and_then(parse_toml)
+        .context("not a HootVoice settings bundle")?;
+    if manifest.format != BUNDLE_FORMAT {
+        bail!("not a HootVoice settings bundle ({})", manifest.format);
+    }
+    if manifest.version > BUNDLE_VERSION {
+        bail!(
+            "bundle version {} is newer than supported ({}); update HootVoice",
+            manifest.version,
+            BUNDLE_VERSION
+        );
+    }
+
+    let settings = entry(SETTINGS_FILENAME)
+        .map(parse_toml::<toml::Table>)
+        .transpose()
+        .context("invalid settings.toml in bundle")?;
+    let dictionary = entry(DICTIONARY_FILENAME).map(|b| b.to_vec());
+    let dictionary_entries = dictionary
+        .as_deref()
+        .map(|b| -> Result<usize> {
+            let dict: Dictionary = serde_yaml::from_slice(b)?;
+            Ok(dict.len())
+        })
+        .transpose()
+        .context("invalid dictionary in bundle")?;
+    if settings.is_none() && dictionary.is_none() {
+        bail!("bundle contains neither settings nor a dictionary");
+    }
+
+    Ok(Bundle {
+        info: BundleInfo {
+            app_version: manifest.app_version,
+            created_at: manifest.created_at,
+            has_settings: settings.is_some(),
+            dictionary_entries,
+        },
+        settings,
+        dictionary,
+    })
+}
+
+fn parse_toml<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    Ok(toml::from_str(std::str::from_utf8(bytes)?)?)
+}
+
+// Deflate-compressed entries stamped with the local time
+fn write_zip(entries: &[(&str, &[u8])]) -> Result<Vec<u8>> {
+    let modified = zip::DateTime::try_from(chrono::Local::now().naive_local()).unwrap_or_default();
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .last_modified_time(modified);
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, data) in entries {
+        writer.start_file(*name, options)?;
+        writer.write_all(data)?;
+    }
+    Ok(writer.finish()?.into_inner())
+}
+
+// File entries by their bare name: an archive re-zipped by Finder/Explorer may put
+// them in a folder (and add __MACOSX/ metadata, which is skipped)
+fn read_zip(bytes: &[u8]) -> Result<Vec<(String, Vec<u8>)>> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).context("not a zip archive")?;
+    let mut entries = Vec::with_capacity(archive.len());
+    for i in 0..archive.len() {
+        let file = archive.by_index(i)?;
+        if file.is_dir() || file.name().starts_with("__MACOSX/") {
+            continue;
+        }
+        let name = file
+            .name()
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let mut data = Vec::new();
+        // The size in the header can lie; cap what is inflated
+        file.take(MAX_BUNDLE_BYTES + 1)
+            .read_to_end(&mut data)
+            .with_context(|| format!("read '{}'", name))?;
+        if data.len() as u64 > MAX_BUNDLE_BYTES {
+            bail!("'{}' is too large", name);
+        }
+        entries.push((name, data));
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_zip, write_zip};
+    use std::io::{Cursor, Write};
+
+    #[test]
+    fn zip_round_trip() {
+        let zip = write_zip(&[("a.toml", b"x = 1\n"), ("辞書.yaml", b"")]).unwrap();
+        let entries = read_zip(&zip).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0], ("a.toml".to_string(), b"x = 1\n".to_vec()));
+        assert_eq!(entries[1].0, "辞書.yaml");
+    }
+
+    #[test]
+    fn reads_archives_rezipped_into_a_folder() {
+        // What Finder produces when compressing the extracted folder
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let stored = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        writer.add_directory("bundle/", stored).unwrap();
+        writer.start_file("bundle/a.toml", stored).unwrap();
+        writer.write_all(b"x = 1\n").unwrap();
+        writer
+            .start_file("__MACOSX/bundle/._a.toml", stored)
+            .unwrap();
+        writer.write_all(b"junk").unwrap();
+        let zip = writer.finish().unwrap().into_inner();
+
+        let entries = read_zip(&zip).unwrap();
+        assert_eq!(entries, vec![("a.toml".to_string(), b"x = 1\n".to_vec())]);
+    }
+
+    #[test]
+    fn rejects_broken_archives() {
+        assert!(read_zip(b"not a zip").is_err());
+        let zip = write_zip(&[("a.txt", b"hello")]).unwrap();
+        assert!(read_zip(&zip[..zip.len() / 2]).is_err());
+    }
+}
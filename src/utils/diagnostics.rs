@@ -0,0 +1,129 @@
+//! "Copy diagnostics" report for bug reports: app/OS/model/device summary plus
+//! recent log lines, with API keys, URL credentials and the home directory masked.
+
+use regex::Regex;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Log lines included at the end of the report
+pub const DIAGNOSTICS_LOG_LINES: usize = 200;
+const MASK: &str = "***";
+
+fn secret_patterns() -> &'static [(Regex, &'static str)] {
+    static PATTERNS: OnceLock<Vec<(Regex, &'static str)>> = OnceLock::new();
+    PATTERNS.get_or_init(|| {
+        [
+            (r"(?i)(bearer\s+)[^\s'\x22]+", "${1}***"),
+            (
+                r"(?i)(api[_-]?key[\x22']?\s*[=:]\s*[\x22']?)[^\s'\x22&,]+",
+                "${1}***",
+            ),
+            (r"\bsk-[A-Za-z0-9_-]{8,}", "sk-***"),
+        ]
+        .into_iter()
+        .map(|(re, rep)| (Regex::new(re).expect("valid pattern"), rep))
+        .collect()
+    })
+}
+
+/// Drop credentials and query values from a URL (`https://u:p@host/v1?key=x`)
+pub fn mask_url(url: &str) -> String {
+    let (scheme, rest) = url.split_once("://").unwrap_or(("", url));
+    let (authority, path) = match rest.find('/') {
+        Some(i) => rest.split_at(i),
+        None => (rest, ""),
+    };
+    let host = match authority.rsplit_once('@') {
+        Some((_, host)) => format!("{}@{}", MASK, host),
+        None => authority.to_string(),
+    };
+    let path = match path.split_once('?') {
+        Some((p, _)) => format!("{}?{}", p, MASK),
+        None => path.to_string(),
+    };
+    if scheme.is_empty() {
+        format!("{}{}", host, path)
+    } else {
+        format!("{}://{}{}", scheme, host, path)
+    }
+}
+
+/// Mask known secrets, key-like tokens and the home directory
+pub fn redact(text: &str, home: Option<&Path>, secrets: &[&str]) -> String {
+    let mut out = text.to_string();
+    // Very short values would mask unrelated text
+    for secret in secrets.iter().map(|s| s.trim()).filter(|s| s.len() >= 4) {
+        out = out.replace(secret, MASK);
+    }
+    for (re, rep) in secret_patterns() {
+        out = re.replace_all(&out, *rep).into_owned();
+    }
+    if let Some(home) = home.and_then(Path::to_str).filter(|h| h.len() > 1) {
+        out = out.replace(home, "~");
+    }
+    out
+}
+
+/// `key: value` summary followed by the most recent log lines, redacted as a whole
+pub fn build_report(
+    summary: &[(&str, String)],
+    log_lines: &[String],
+    home: Option<&Path>,
+    secrets: &[&str],
+) -> String {
+    let mut report = String::from("## HootVoice diagnostics\n\n");
+    for (key, value) in summary {
+        report.push_str(&format!("- {}: {}\n", key, value));
+    }
+    let skip = log_lines.len().saturating_sub(DIAGNOSTICS_LOG_LINES);
+    report.push_str(&format!(
+        "\n### Recent log ({} lines)\n\n```\n",
+        log_lines.len() - skip
+    ));
+    for line in &log_lines[skip..] {
+        report.push_str(line.trim_end());
+        report.push('\n');
+    }
+    report.push_str("```\n");
+    redact(&report, home, secrets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_report, mask_url, redact};
+    use std::path::Path;
+
+    #[test]
+    fn masks_urls_and_secrets() {
+        assert_eq!(
+            mask_url("https://user:pw@api.example.com/v1?key=abc"),
+            "https://***@api.example.com/v1?***"
+        );
+        assert_eq!(
+            mask_url("http://localhost:11434/v1"),
+            "http://localhost:11434/v1"
+        );
+        let text = "Authorization: Bearer abc.def\napi_key = \"xyz123\"\ntoken sk-proj1234567890\n\
+                    model /home/alice/.config/hootvoice/models/ggml-base.bin\nmy-own-secret";
+        let out = redact(
+            text,
+            Some(Path::new("/home/alice")),
+            &["my-own-secret", "ab"],
+        );
+        assert_eq!(
+            out,
+            "Authorization: Bearer ***\napi_key = \"***\"\ntoken sk-***\n\
+             model ~/.config/hootvoice/models/ggml-base.bin\n***"
+        );
+    }
+
+    #[test]
+    fn report_keeps_recent_lines() {
+        let lines: Vec<String> = (0..250).map(|i| format!("line {}", i)).collect();
+        let report = build_report(&[("version", "1.0".into())], &lines, None, &[]);
+        assert!(report.contains("- version: 1.0\n"));
+        assert!(report.contains("Recent log (200 lines)"));
+        assert!(!report.contains("line 49\n"));
+        assert!(report.contains("line 50\nline 51"));
+    }
+}
@@ -0,0 +1,151 @@
+//! Word-level diff for the History tab (raw transcript vs. LLM output).
+//! Latin words, whitespace runs and punctuation are tokens; CJK text has no
+//! spaces, so each character is its own token there.
+
+/// Above this many token pairs the texts are shown as a full replacement
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffSpan {
+    Equal(String),
+    Insert(String),
+    Delete(String),
+}
+
+impl DiffSpan {
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Equal(t) | Self::Insert(t) | Self::Delete(t) => t,
+        }
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() && (c.is_ascii() || !is_cjk(c))
+}
+
+// Scripts written without spaces between words
+pub(crate) fn is_cjk(c: char) -> bool {
+    matches!(c as u32,
+        0x3040..=0x30FF     // Hiragana, Katakana
+        | 0x3400..=0x4DBF   // CJK Extension A
+        | 0x4E00..=0x9FFF   // CJK Unified Ideographs
+        | 0xF900..=0xFAFF   // CJK Compatibility Ideographs
+        | 0xAC00..=0xD7AF   // Hangul syllables
+        | 0xFF66..=0xFF9F) // Half-width Katakana
+}
+
+fn tokenize(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let same_run = |n: char| {
+            (c.is_whitespace() && n.is_whitespace()) || (is_word_char(c) && is_word_char(n))
+        };
+        match chars.peek() {
+            Some(&(j, n)) if !same_run(n) => {
+                tokens.push(&text[start..j]);
+                start = j;
+            }
+            None => tokens.push(&text[start..i + c.len_utf8()]),
+            _ => {}
+        }
+    }
+    tokens
+}
+
+fn push(spans: &mut Vec<DiffSpan>, span: DiffSpan) {
+    match (spans.last_mut(), span) {
+        (Some(DiffSpan::Equal(a)), DiffSpan::Equal(b))
+        | (Some(DiffSpan::Insert(a)), DiffSpan::Insert(b))
+        | (Some(DiffSpan::Delete(a)), DiffSpan::Delete(b)) => a.push_str(&b),
+        (_, span) => spans.push(span),
+    }
+}
+
+/// Diff `old` against `new`: deletions come before insertions at each change
+pub fn word_diff(old: &str, new: &str) -> Vec<DiffSpan> {
+    let a = tokenize(old);
+    let b = tokenize(new);
+    let prefix = a.iter().zip(&b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (mid_a, mid_b) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+    let mut spans = Vec::new();
+    push(&mut spans, DiffSpan::Equal(a[..prefix].concat()));
+    if mid_a.len().saturating_mul(mid_b.len()) > MAX_DIFF_CELLS {
+        push(&mut spans, DiffSpan::Delete(mid_a.concat()));
+        push(&mut spans, DiffSpan::Insert(mid_b.concat()));
+    } else {
+        // LCS lengths of the suffixes, then walk forward
+        let (n, m) = (mid_a.len(), mid_b.len());
+        let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[i * (m + 1) + j] = if mid_a[i] == mid_b[j] {
+                    lcs[(i + 1) * (m + 1) + j + 1] + 1
+                } else {
+                    lcs[(i + 1) * (m + 1) + j].max(lcs[i * (m + 1) + j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < n || j < m {
+            if i < n && j < m && mid_a[i] == mid_b[j] {
+                push(&mut spans, DiffSpan::Equal(mid_a[i].to_string()));
+                i += 1;
+                j += 1;
+            } else if j == m || (i < n && lcs[(i + 1) * (m + 1) + j] >= lcs[i * (m + 1) + j + 1]) {
+                push(&mut spans, DiffSpan::Delete(mid_a[i].to_string()));
+                i += 1;
+            } else {
+                push(&mut spans, DiffSpan::Insert(mid_b[j].to_string()));
+                j += 1;
+            }
+        }
+    }
+    push(&mut spans, DiffSpan::Equal(a[a.len() - suffix..].concat()));
+    spans.retain(|s| !s.text().is_empty());
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{word_diff, DiffSpan};
+
+    fn eq(s: &str) -> DiffSpan {
+        DiffSpan::Equal(s.into())
+    }
+    fn ins(s: &str) -> DiffSpan {
+        DiffSpan::Insert(s.into())
+    }
+    fn del(s: &str) -> DiffSpan {
+        DiffSpan::Delete(s.into())
+    }
+
+    #[test]
+    fn diffs_words_and_cjk_characters() {
+        assert_eq!(
+            word_diff("so um the meeting is at three", "The meeting is at 3."),
+            vec![
+                del("so um the"),
+                ins("The"),
+                eq(" meeting is at "),
+                del("three"),
+                ins("3."),
+            ]
+        );
+        assert_eq!(
+            word_diff("今日はえーと晴れです", "今日は晴れです。"),
+            vec![eq("今日は"), del("えーと"), eq("晴れです"), ins("。")]
+        );
+        assert_eq!(word_diff("same", "same"), vec![eq("same")]);
+        assert_eq!(word_diff("", "new"), vec![ins("new")]);
+    }
+}
@@ -0,0 +1,140 @@
+//! Local command socket (Unix domain) so Waybar click handlers and scripts can
+//! control the running app without knowing its PID:
+//! `hootvoice --send toggle` or `echo toggle | socat - UNIX-CONNECT:<socket>`.
+//! `start`/`stop` never flip the state, so pedals and stream decks that send
+//! separate press/release commands cannot get out of sync.
+
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::utils::app_config_dir;
+
+const SOCKET_NAME: &str = "hootvoice.sock";
+// A client that connects but never sends a line must not block the listener
+const READ_TIMEOUT: Duration = Duration::from_secs(2);
+
+// Path of the listening socket (for status.json)
+static ACTIVE_SOCKET: OnceLock<PathBuf> = OnceLock::new();
+
+/// Commands accepted on the socket (one per line)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IpcCommand {
+    /// Start/stop recording (same as SIGUSR1)
+    Toggle,
+    /// Start recording; no-op unless idle
+    Start,
+    /// Stop recording and transcribe; no-op unless recording
+    Stop,
+    /// Open the Settings window (same as SIGUSR2)
+    Show,
+}
+
+impl IpcCommand {
+    fn parse(line: &str) -> Option<Self> {
+        match line.trim().to_ascii_lowercase().as_str() {
+            "toggle" => Some(Self::Toggle),
+            "start" => Some(Self::Start),
+            "stop" => Some(Self::Stop),
+            "show" | "settings" => Some(Self::Show),
+            _ => None,
+        }
+    }
+}
+
+/// `$XDG_RUNTIME_DIR/hootvoice.sock`, or a private directory in the config
+/// directory when unset
+pub fn socket_path() -> PathBuf {
+    match std::env::var_os("XDG_RUNTIME_DIR").filter(|v| !v.is_empty()) {
+        Some(dir) => PathBuf::from(dir).join(SOCKET_NAME),
+        None => fallback_socket_dir().join(SOCKET_NAME),
+    }
+}
+
+fn fallback_socket_dir() -> PathBuf {
+    app_config_dir().join("ipc")
+}
+
+/// Socket the listener is bound to (None when disabled or failed)
+pub fn active_socket_path() -> Option<&'static PathBuf> {
+    ACTIVE_SOCKET.get()
+}
+
+/// Bind the socket and handle commands on a background thread.
+/// `handler` returns Err with a short reason when the command was ignored (e.g. paused).
+pub fn spawn_listener<F>(handler: F) -> std::io::Result<PathBuf>
+where
+    F: Fn(IpcCommand) -> Result<(), &'static str> + Send + 'static,
+{
+    let path = socket_path();
+    if let Some(dir) = path.parent() {
+        if dir == fallback_socket_dir() {
+            // The socket is created with the umask's mode before the chmod below,
+            // so only its owner may reach it through this directory
+            std::fs::DirBuilder::new()
+                .recursive(true)
+                .mode(0o700)
+                .create(dir)?;
+            std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700))?;
+        } else {
+            std::fs::create_dir_all(dir)?;
+        }
+    }
+    // Left over from a previous run (the single-instance lock rules out a live owner)
+    if path.exists() {
+        std::fs::remove_file(&path)?;
+    }
+    let listener = UnixListener::bind(&path)?;
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+    let _ = ACTIVE_SOCKET.set(path.clone());
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else {
+                continue;
+            };
+            let _ = handle_client(stream, &handler);
+        }
+    });
+    Ok(path)
+}
+
+fn handle_client<F>(stream: UnixStream, handler: &F) -> std::io::Result<()>
+where
+    F: Fn(IpcCommand) -> Result<(), &'static str>,
+{
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+    let reply = match IpcCommand::parse(&line) {
+        Some(cmd) => match handler(cmd) {
+            Ok(()) => "ok".to_string(),
+            Err(reason) => format!("error: {}", reason),
+        },
+        None => format!("error: unknown command '{}'", line.trim()),
+    };
+    let mut stream = stream;
+    writeln!(stream, "{}", reply)
+}
+
+/// Send one command to the running app and return its reply
+pub fn send_command(command: &str) -> std::io::Result<String> {
+    let path = socket_path();
+    let mut stream = UnixStream::connect(&path).map_err(|e| {
+        std::io::Error::new(
+            e.kind(),
+            format!(
+                "cannot connect to {} ({}); is HootVoice running?",
+                path.display(),
+                e
+            ),
+        )
+    })?;
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    writeln!(stream, "{}", command.trim())?;
+    let mut reply = String::new();
+    BufReader::new(&stream).read_line(&mut reply)?;
+    Ok(reply.trim().to_string())
+}
@@ -1,5 +1,20 @@
+use serde::{Deserialize, Serialize};
 use std::process::Command;
 
+/// How auto-paste delivers the text on Linux
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LinuxPasteMethod {
+    #[default]
+    CtrlV,
+    // Terminals (GNOME Terminal, Konsole, kitty, ...)
+    CtrlShiftV,
+    // Paste the PRIMARY selection with a middle click at the pointer
+    MiddleClickPrimary,
+    // Type the characters; works where programmatic paste is blocked
+    TypeText,
+}
+
 #[cfg(target_os = "macos")]
 mod macos_input {
     use std::os::raw::c_void;
@@ -42,6 +57,7 @@ mod macos_input {
 
     // Virtual key codes (ANSI layout)
     const KEY_V: CGKeyCode = 9; // kVK_ANSI_V
+    const KEY_DELETE: CGKeyCode = 51; // kVK_Delete (Backspace)
 
     fn ax_is_trusted_prompt(_prompt: bool) -> bool {
         unsafe { AXIsProcessTrusted() }
@@ -88,6 +104,128 @@ mod macos_input {
             true
         }
     }
+
+    pub fn send_backspaces(count: usize) -> bool {
+        if !ax_is_trusted_prompt(true) {
+            eprintln!("[undo_paste] Accessibility permission not granted.");
+            return false;
+        }
+        unsafe {
+            let src = CGEventSourceCreate(1);
+            if src.is_null() {
+                return false;
+            }
+            for _ in 0..count {
+                for down in [true, false] {
+                    let ev = CGEventCreateKeyboardEvent(src, KEY_DELETE, down);
+                    if ev.is_null() {
+                        CFRelease(src);
+                        return false;
+                    }
+                    // No modifiers (Option+Delete would remove whole words)
+                    CGEventSetFlags(ev, 0);
+                    CGEventPost(KCG_HID_EVENT_TAP, ev);
+                    CFRelease(ev as *const c_void);
+                }
+                std::thread::sleep(std::time::Duration::from_millis(2));
+            }
+            CFRelease(src);
+            true
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn run_ok(program: &str, args: &[&str]) -> bool {
+    Command::new(program)
+        .args(args)
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false)
+}
+
+/// Linux auto-paste with the selected method.
+/// `text` is typed for `TypeText`; `MiddleClickPrimary` expects it already in PRIMARY.
+#[cfg(target_os = "linux")]
+pub fn auto_paste_linux(method: LinuxPasteMethod, text: &str) -> bool {
+    let wayland = std::env::var("WAYLAND_DISPLAY").is_ok()
+        || std::env::var("XDG_SESSION_TYPE").unwrap_or_default() == "wayland";
+    let x11 = std::env::var("DISPLAY").is_ok();
+    match method {
+        LinuxPasteMethod::CtrlV => auto_paste(),
+        LinuxPasteMethod::CtrlShiftV => {
+            (wayland
+                && run_ok(
+                    "wtype",
+                    &["-M", "ctrl", "-M", "shift", "-k", "v", "-m", "shift", "-m", "ctrl"],
+                ))
+                || (x11 && run_ok("xdotool", &["key", "ctrl+shift+v"]))
+                // Ctrl(29)+Shift(42)+V(47)
+                || run_ok(
+                    "ydotool",
+                    &["key", "29:1", "42:1", "47:1", "47:0", "42:0", "29:0"],
+                )
+        }
+        LinuxPasteMethod::MiddleClickPrimary => {
+            // wtype cannot click; ydotool can on both X11 and Wayland
+            (x11 && !wayland && run_ok("xdotool", &["click", "2"]))
+                || run_ok("ydotool", &["click", "0xC2"])
+        }
+        LinuxPasteMethod::TypeText => {
+            if text.is_empty() {
+                return true;
+            }
+            (wayland && run_ok("wtype", &["--", text]))
+                || (x11 && run_ok("xdotool", &["type", "--clearmodifiers", "--", text]))
+                || run_ok("ydotool", &["type", "--", text])
+        }
+    }
+}
+
+/// Press Backspace `count` times in the focused app (undo of an auto-paste).
+/// Returns true if any method succeeds
+pub fn send_backspaces(count: usize) -> bool {
+    if count == 0 {
+        return true;
+    }
+    #[cfg(target_os = "linux")]
+    {
+        let wayland = std::env::var("WAYLAND_DISPLAY").is_ok()
+            || std::env::var("XDG_SESSION_TYPE").unwrap_or_default() == "wayland";
+        let x11 = std::env::var("DISPLAY").is_ok();
+        let repeat = count.to_string();
+        let wtype_args: Vec<&str> = std::iter::repeat(["-k", "BackSpace"])
+            .take(count)
+            .flatten()
+            .collect();
+        // BackSpace = 14
+        let ydotool_args: Vec<&str> = std::iter::once("key")
+            .chain(std::iter::repeat(["14:1", "14:0"]).take(count).flatten())
+            .collect();
+        (wayland && run_ok("wtype", &wtype_args))
+            || (x11
+                && run_ok(
+                    "xdotool",
+                    &["key", "--clearmodifiers", "--repeat", &repeat, "BackSpace"],
+                ))
+            || run_ok("ydotool", &ydotool_args)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        macos_input::send_backspaces(count)
+    }
+    #[cfg(target_os = "windows")]
+    {
+        let script = format!(
+            "Add-Type -AssemblyName System.Windows.Forms; [System.Windows.Forms.SendKeys]::SendWait('{{BS {}}}')",
+            count
+        );
+        Command::new("powershell")
+            .args(["-Command", &script])
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false)
+    }
 }
 
 /// Auto-paste feature (send Ctrl/Cmd+V)
@@ -1,27 +1,182 @@
+use chrono::{DateTime, Local};
 use std::collections::VecDeque;
 use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, OnceLock};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::fmt::{format, FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::registry::LookupSpan;
 
-/// Append a log line to an in-memory ring buffer (max 1000 lines) and persist to file.
-/// Runtime behavior is append-only for performance; no file rewrite.
+/// `HOOTVOICE_LOG_FORMAT=json` writes debug.log and stderr as JSON lines
+pub const LOG_FORMAT_ENV: &str = "HOOTVOICE_LOG_FORMAT";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// `[HH:MM:SS.mmm] [Category] message`
+    #[default]
+    Text,
+    /// `{"timestamp":..,"level":..,"category":..,"message":..}` per line
+    Json,
+}
+
+/// Format chosen by `LOG_FORMAT_ENV` (read once)
+pub fn log_format() -> LogFormat {
+    static FORMAT: OnceLock<LogFormat> = OnceLock::new();
+    *FORMAT.get_or_init(|| match std::env::var(LOG_FORMAT_ENV) {
+        Ok(v) if v.eq_ignore_ascii_case("json") => LogFormat::Json,
+        _ => LogFormat::Text,
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogLevel {
+    Error,
+    Warning,
+    Info,
+}
+
+impl LogLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warn",
+            Self::Info => "info",
+        }
+    }
+}
+
+/// One debug-log line. Level and category come from the leading `[Tag]`s of
+/// the message ("[Whisper] [Error] ..." is an error in the Whisper category).
+#[derive(Clone, Debug)]
+pub struct LogEntry {
+    pub timestamp: DateTime<Local>,
+    pub level: LogLevel,
+    /// First tag that is not a level, without brackets ("" when there is none)
+    pub category: String,
+    /// Message after the leading tags
+    pub message: String,
+    /// Human-readable line as shown in the Logs tab
+    pub line: String,
+}
+
+impl LogEntry {
+    pub fn new(message: &str) -> Self {
+        Self::at(Local::now(), message)
+    }
+
+    fn at(timestamp: DateTime<Local>, message: &str) -> Self {
+        let mut rest = message.trim_start();
+        let mut tags = Vec::new();
+        while let Some((tag, after)) = rest
+            .strip_prefix('[')
+            .and_then(|r| r.split_once(']'))
+            .filter(|(tag, _)| !tag.is_empty() && !tag.contains(char::is_whitespace))
+        {
+            tags.push(tag);
+            rest = after.trim_start();
+        }
+        let level = if tags.contains(&"Error") {
+            LogLevel::Error
+        } else if tags.contains(&"Warning") {
+            LogLevel::Warning
+        } else {
+            LogLevel::Info
+        };
+        Self {
+            timestamp,
+            level,
+            category: tags
+                .iter()
+                .find(|t| !matches!(**t, "Error" | "Warning"))
+                .copied()
+                .unwrap_or_default()
+                .to_string(),
+            message: rest.to_string(),
+            line: format!("[{}] {}", timestamp.format("%H:%M:%S%.3f"), message),
+        }
+    }
+
+    pub fn json(&self) -> String {
+        serde_json::json!({
+            "timestamp": self.timestamp.to_rfc3339(),
+            "level": self.level.as_str(),
+            "category": self.category,
+            "message": self.message,
+        })
+        .to_string()
+    }
+}
+
+/// tracing event formatter for `LogFormat::Json` (category = event target)
+pub struct JsonEventFormat;
+
+impl<S, N> FormatEvent<S, N> for JsonEventFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        _ctx: &FmtContext<'_, S, N>,
+        mut writer: format::Writer<'_>,
+        event: &Event<'_>,
+    ) -> std::fmt::Result {
+        let mut fields = FieldText::default();
+        event.record(&mut fields);
+        let meta = event.metadata();
+        let level = match *meta.level() {
+            Level::ERROR => LogLevel::Error,
+            Level::WARN => LogLevel::Warning,
+            _ => LogLevel::Info,
+        };
+        let line = serde_json::json!({
+            "timestamp": Local::now().to_rfc3339(),
+            "level": level.as_str(),
+            "category": meta.target(),
+            "message": fields.0,
+        });
+        writeln!(writer, "{}", line)
+    }
+}
+
+// `message` followed by any other fields as ` key=value`
+#[derive(Default)]
+struct FieldText(String);
+
+impl Visit for FieldText {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0.insert_str(0, &format!("{:?}", value));
+        } else {
+            self.0.push_str(&format!(" {}={:?}", field.name(), value));
+        }
+    }
+}
+
+/// Append a log entry to an in-memory ring buffer (max 1000 lines) and persist to file
+/// in the `log_format()`. Runtime behavior is append-only for performance; no file rewrite.
 pub fn push_log_and_persist(
-    logs_arc: &Arc<Mutex<VecDeque<String>>>,
+    logs_arc: &Arc<Mutex<VecDeque<LogEntry>>>,
     log_path: &Path,
-    log_line: &str,
+    entry: LogEntry,
 ) {
+    let persisted = match log_format() {
+        LogFormat::Text => entry.line.clone(),
+        LogFormat::Json => entry.json(),
+    };
     if let Ok(mut logs) = logs_arc.lock() {
         if logs.len() >= 1000 {
             // Keep only the newest 1000 lines in memory
             logs.pop_front();
         }
-        logs.push_back(log_line.to_string());
+        logs.push_back(entry);
     }
 
     // Append-only during runtime for performance
     if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(log_path) {
-        let _ = writeln!(file, "{}", log_line);
+        let _ = writeln!(file, "{}", persisted);
     }
 }
 
@@ -59,3 +214,30 @@ pub fn trim_log_file_startup(log_path: &Path, max_lines: usize) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{LogEntry, LogLevel};
+
+    #[test]
+    fn entry_level_and_category_come_from_tags() {
+        let e = LogEntry::new("[Whisper] [Error] model failed: x");
+        assert_eq!(e.level, LogLevel::Error);
+        assert_eq!(e.category, "Whisper");
+        assert_eq!(e.message, "model failed: x");
+        assert!(e.line.ends_with("] [Whisper] [Error] model failed: x"));
+
+        let e = LogEntry::new("[Warning] [llm] slow");
+        assert_eq!((e.level, e.category.as_str()), (LogLevel::Warning, "llm"));
+        assert_eq!(e.message, "slow");
+
+        let e = LogEntry::new("[Error] disk full");
+        assert_eq!((e.level, e.category.as_str()), (LogLevel::Error, ""));
+
+        let e = LogEntry::new("plain [Error] text");
+        assert_eq!((e.level, e.category.as_str()), (LogLevel::Info, ""));
+        let json: serde_json::Value = serde_json::from_str(&e.json()).unwrap();
+        assert_eq!(json["level"], "info");
+        assert_eq!(json["message"], "plain [Error] text");
+    }
+}
@@ -0,0 +1,49 @@
+//! Physical memory query used to warn before picking a model that won't fit.
+
+/// Total physical memory in bytes (None when it can't be determined)
+pub fn total_memory_bytes() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+        parse_meminfo_total(&meminfo)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let mut value: u64 = 0;
+        let mut len = std::mem::size_of::<u64>();
+        let ret = unsafe {
+            libc::sysctlbyname(
+                c"hw.memsize".as_ptr(),
+                &mut value as *mut u64 as *mut libc::c_void,
+                &mut len,
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+        (ret == 0 && value > 0).then_some(value)
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        None
+    }
+}
+
+// "MemTotal:       16314484 kB"
+#[cfg(any(target_os = "linux", test))]
+fn parse_meminfo_total(meminfo: &str) -> Option<u64> {
+    let line = meminfo.lines().find(|l| l.starts_with("MemTotal:"))?;
+    let kb: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_meminfo_total;
+
+    #[test]
+    fn parses_mem_total() {
+        let sample = "MemTotal:       16314484 kB\nMemFree:         1234 kB\n";
+        assert_eq!(parse_meminfo_total(sample), Some(16314484 * 1024));
+        assert_eq!(parse_meminfo_total("MemFree: 1 kB\n"), None);
+    }
+}
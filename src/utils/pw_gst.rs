@@ -41,7 +41,7 @@ pub fn start_pipewire_capture_to_buffer(
     let caps = gst::Caps::builder("audio/x-raw")
         .field("format", "F32LE")
         .field("channels", 1i32)
-        .field("rate", 16000i32)
+        .field("rate", crate::transcription::WHISPER_SAMPLE_RATE as i32)
         .build();
     let capsfilter = gst::ElementFactory::make("capsfilter")
         .property("caps", &caps)
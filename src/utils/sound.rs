@@ -5,7 +5,8 @@ use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::BufReader;
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::{mpsc, Arc, Mutex, OnceLock};
 use std::time::Duration;
 
@@ -20,10 +21,108 @@ pub fn play_sound_async(path: &str) {
     });
 }
 
+/// Status sound events; each can be muted or point to a custom file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundEvent {
+    Start,
+    Complete,
+    Error,
+}
+
+impl SoundEvent {
+    /// Bundled sound used when no custom file is set
+    pub fn default_path(self) -> &'static str {
+        match self {
+            SoundEvent::Start => "sounds/start.mp3",
+            SoundEvent::Complete => "sounds/complete.mp3",
+            SoundEvent::Error => "sounds/fail.mp3",
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            SoundEvent::Start => 0,
+            SoundEvent::Complete => 1,
+            SoundEvent::Error => 2,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct EventSound {
+    enabled: bool,
+    path: Option<PathBuf>,
+}
+
+// Per-event settings (start/complete/error)
+static EVENT_SOUNDS: OnceLock<Mutex<[EventSound; 3]>> = OnceLock::new();
+
+fn event_store() -> &'static Mutex<[EventSound; 3]> {
+    EVENT_SOUNDS.get_or_init(|| {
+        let d = EventSound {
+            enabled: true,
+            path: None,
+        };
+        Mutex::new([d.clone(), d.clone(), d])
+    })
+}
+
+pub fn set_event_sound(event: SoundEvent, enabled: bool, path: Option<&Path>) {
+    if let Ok(mut g) = event_store().lock() {
+        g[event.index()] = EventSound {
+            enabled,
+            path: path.map(|p| p.to_path_buf()),
+        };
+    }
+}
+
+/// File to play for `event`; a missing custom file falls back to the bundled one
+pub fn event_sound_path(event: SoundEvent, custom: Option<&Path>) -> String {
+    match custom {
+        Some(p) if p.exists() => p.to_string_lossy().into_owned(),
+        _ => event.default_path().to_string(),
+    }
+}
+
+/// Play the sound configured for `event` (no-op when that event is muted)
+pub fn play_event_async(event: SoundEvent) {
+    let Some(ev) = event_store().lock().ok().map(|g| g[event.index()].clone()) else {
+        return;
+    };
+    if ev.enabled {
+        play_sound_async(&event_sound_path(event, ev.path.as_deref()));
+    }
+}
+
+/// Short double beep warning that the recording is about to hit its time cap;
+/// generated so it can't be mistaken for the start/complete sounds
+pub fn play_warning_async() {
+    let _ = get_or_start_worker().send(SoundCmd::PlayWarningTone);
+}
+
+/// Play `event` and wait until it has finished (at most `timeout`)
+pub fn play_event_blocking(event: SoundEvent, timeout: Duration) {
+    let Some(ev) = event_store().lock().ok().map(|g| g[event.index()].clone()) else {
+        return;
+    };
+    if !ev.enabled {
+        return;
+    }
+    let (done_tx, done_rx) = mpsc::channel::<()>();
+    let path = event_sound_path(event, ev.path.as_deref());
+    if get_or_start_worker()
+        .send(SoundCmd::PlayPath(path, Some(done_tx)))
+        .is_ok()
+    {
+        // Disconnects when the worker drops the sender
+        let _ = done_rx.recv_timeout(timeout);
+    }
+}
+
 fn play_sound(path: &str) -> Result<()> {
     // Delegate to the sound worker (decode+playback in worker thread)
     let tx = get_or_start_worker();
-    let _ = tx.send(SoundCmd::PlayPath(path.to_string()));
+    let _ = tx.send(SoundCmd::PlayPath(path.to_string(), None));
     Ok(())
 }
 
@@ -35,10 +134,19 @@ static SOUND_TX: OnceLock<Mutex<Option<mpsc::Sender<SoundCmd>>>> = OnceLock::new
 static ENABLED: AtomicBool = AtomicBool::new(true);
 // Volume percent (0..=100)
 static VOLUME: AtomicU32 = AtomicU32::new(100);
+// Bumped by `stop_speech`; a readback stops once it no longer matches its own
+static SPEECH_GEN: AtomicU64 = AtomicU64::new(0);
 
 enum SoundCmd {
     SetDevice(Option<String>),
-    PlayPath(String),
+    // The optional sender is dropped once playback ends (or is skipped)
+    PlayPath(String, Option<mpsc::Sender<()>>),
+    PlayWarningTone,
+    // Synthesized readback: played even with status sounds off, file removed afterwards
+    PlaySpeech {
+        path: PathBuf,
+        generation: u64,
+    },
     StartLoop {
         key: String,
         path: String,
@@ -75,6 +183,12 @@ pub fn set_volume_percent(percent: f32) {
     VOLUME.store(clamped, Ordering::SeqCst);
 }
 
+/// Sound volume setting (0..=100)
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub fn volume_percent() -> u32 {
+    VOLUME.load(Ordering::SeqCst)
+}
+
 fn is_enabled() -> bool {
     ENABLED.load(Ordering::SeqCst)
 }
@@ -151,7 +265,7 @@ fn get_or_start_worker() -> mpsc::Sender<SoundCmd> {
                         ctx = None;
                     }
                 }
-                SoundCmd::PlayPath(p) => {
+                SoundCmd::PlayPath(p, _done) => {
                     if !is_enabled() {
                         continue;
                     }
@@ -183,6 +297,61 @@ fn get_or_start_worker() -> mpsc::Sender<SoundCmd> {
                         ctx = None; // drop OutputStream/Handle
                     }
                 }
+                SoundCmd::PlayWarningTone => {
+                    if !is_enabled() {
+                        continue;
+                    }
+                    if ctx.is_none() {
+                        ctx = rebuild_ctx(&current_name);
+                    }
+                    if let Some((_, ref handle)) = ctx {
+                        if let Ok(sink) = Sink::try_new(handle) {
+                            use rodio::Source;
+                            let beep = || {
+                                rodio::source::SineWave::new(880.0)
+                                    .take_duration(Duration::from_millis(120))
+                                    .amplify(0.25)
+                            };
+                            sink.set_volume(current_volume());
+                            sink.append(beep());
+                            sink.append(
+                                rodio::source::Zero::<f32>::new(1, 48_000)
+                                    .take_duration(Duration::from_millis(90)),
+                            );
+                            sink.append(beep());
+                            sink.sleep_until_end();
+                        }
+                    }
+                    if loops.is_empty() {
+                        ctx = None;
+                    }
+                }
+                SoundCmd::PlaySpeech { path, generation } => {
+                    if ctx.is_none() {
+                        ctx = rebuild_ctx(&current_name);
+                    }
+                    if let Some((_, ref handle)) = ctx {
+                        if let Ok(file) = File::open(&path) {
+                            if let Ok(decoder) = Decoder::new(BufReader::new(file)) {
+                                if let Ok(sink) = Sink::try_new(handle) {
+                                    sink.set_volume(current_volume());
+                                    sink.append(decoder);
+                                    // Polled so a new recording can cut the readback short
+                                    while !sink.empty()
+                                        && SPEECH_GEN.load(Ordering::SeqCst) == generation
+                                    {
+                                        std::thread::sleep(Duration::from_millis(50));
+                                    }
+                                    sink.stop();
+                                }
+                            }
+                        }
+                    }
+                    let _ = std::fs::remove_file(&path);
+                    if loops.is_empty() {
+                        ctx = None;
+                    }
+                }
                 SoundCmd::StartLoop { key, path, gap_ms } => {
                     // Stop existing loop if any
                     if let Some(flag) = loops.remove(&key) {
@@ -253,6 +422,22 @@ pub fn start_loop(key: &str, path: &str, gap_ms: u64) {
     });
 }
 
+/// Current readback generation; take it before synthesizing so a `stop_speech`
+/// in the meantime also cancels that readback
+pub fn speech_generation() -> u64 {
+    SPEECH_GEN.load(Ordering::SeqCst)
+}
+
+/// Queue a synthesized speech file (WAV); the file is deleted after playback
+pub fn play_speech(path: PathBuf, generation: u64) {
+    let _ = get_or_start_worker().send(SoundCmd::PlaySpeech { path, generation });
+}
+
+/// Cut off the readback that is playing or queued
+pub fn stop_speech() {
+    SPEECH_GEN.fetch_add(1, Ordering::SeqCst);
+}
+
 pub fn stop_loop(key: &str) {
     let tx = get_or_start_worker();
     let _ = tx.send(SoundCmd::StopLoop {
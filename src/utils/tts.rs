@@ -0,0 +1,168 @@
+//! Spoken readback of transcripts through the platform speech synthesizer.
+//! Speech is rendered to a WAV file and played by the sound worker, so it uses
+//! the selected output device and the sound volume. On Linux without espeak,
+//! `spd-say` speaks directly (default device, volume still applied).
+
+use anyhow::{bail, Context, Result};
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Each readback gets its own file: the previous one may still be playing
+static FILE_SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// Speak `text` and return once playback is queued. Blocks while synthesizing,
+/// so call it off the UI/paste path. `language` (e.g. "ja") picks the voice
+/// where the engine supports it.
+pub fn speak(text: &str, language: Option<&str>) -> Result<()> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(());
+    }
+    let generation = crate::utils::sound::speech_generation();
+    let path = std::env::temp_dir().join(format!(
+        "hootvoice-readback-{}-{}.wav",
+        std::process::id(),
+        FILE_SEQ.fetch_add(1, Ordering::SeqCst)
+    ));
+    #[cfg(target_os = "linux")]
+    if !has_espeak() {
+        return speak_direct(text, language);
+    }
+    if let Err(e) = synthesize(text, language, &path) {
+        let _ = std::fs::remove_file(&path);
+        return Err(e);
+    }
+    crate::utils::sound::play_speech(path, generation);
+    Ok(())
+}
+
+/// Render `text` to a WAV file without playing it (no `spd-say` fallback)
+pub fn synthesize_to_file(text: &str, language: Option<&str>, path: &Path) -> Result<()> {
+    synthesize(text, language, path)
+}
+
+/// Stop the readback in progress (a new recording started)
+pub fn stop() {
+    crate::utils::sound::stop_speech();
+    // Reaped on its own thread so starting a recording never waits on it
+    #[cfg(target_os = "linux")]
+    if !has_espeak() {
+        std::thread::spawn(|| {
+            let _ = Command::new("spd-say")
+                .arg("--cancel")
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status();
+        });
+    }
+}
+
+// Run `cmd`, writing `input` to its stdin
+fn run_with_stdin(mut cmd: Command, input: &str) -> Result<()> {
+    let program = cmd.get_program().to_string_lossy().into_owned();
+    let mut child = cmd
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("{} not available", program))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        bail!("{} exited with {}", program, status);
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn synthesize(text: &str, _language: Option<&str>, path: &Path) -> Result<()> {
+    // System voice; `say` picks WAVE from the extension with an explicit data format
+    let mut cmd = Command::new("say");
+    cmd.args(["--data-format=LEI16@22050", "-f", "-", "-o"])
+        .arg(path);
+    run_with_stdin(cmd, text)
+}
+
+#[cfg(target_os = "windows")]
+fn synthesize(text: &str, _language: Option<&str>, path: &Path) -> Result<()> {
+    // SAPI through System.Speech; stdin is read as UTF-8 so non-ASCII text survives
+    let script = "[Console]::InputEncoding=[Text.Encoding]::UTF8; \
+        Add-Type -AssemblyName System.Speech; \
+        $s=New-Object System.Speech.Synthesis.SpeechSynthesizer; \
+        $s.SetOutputToWaveFile($env:HOOTVOICE_TTS_OUT); \
+        $s.Speak([Console]::In.ReadToEnd()); $s.Dispose()";
+    use std::os::windows::process::CommandExt;
+    // The app has no console of its own; without this a window flashes per readback
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+    let mut cmd = Command::new("powershell");
+    cmd.args(["-NoProfile", "-NonInteractive", "-Command", script])
+        .env("HOOTVOICE_TTS_OUT", path)
+        .creation_flags(CREATE_NO_WINDOW);
+    run_with_stdin(cmd, text)
+}
+
+#[cfg(target_os = "linux")]
+const ESPEAK_BINARIES: &[&str] = &["espeak-ng", "espeak"];
+
+// Probed once per run instead of on every readback
+#[cfg(target_os = "linux")]
+fn has_espeak() -> bool {
+    static HAS_ESPEAK: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *HAS_ESPEAK.get_or_init(|| {
+        ESPEAK_BINARIES.iter().any(|bin| {
+            Command::new(bin)
+                .arg("--version")
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .is_ok()
+        })
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn synthesize(text: &str, language: Option<&str>, path: &Path) -> Result<()> {
+    let mut last_err = None;
+    for bin in ESPEAK_BINARIES {
+        let mut cmd = Command::new(bin);
+        cmd.arg("--stdin").arg("-w").arg(path);
+        if let Some(lang) = language {
+            cmd.args(["-v", lang]);
+        }
+        match run_with_stdin(cmd, text) {
+            Ok(()) => return Ok(()),
+            Err(e) => last_err = Some(e),
+        }
+    }
+    Err(last_err.unwrap_or_else(|| anyhow::anyhow!("espeak not available")))
+}
+
+// speech-dispatcher cannot write a file; its volume runs from -100 to 100 (0 = normal)
+#[cfg(target_os = "linux")]
+fn speak_direct(text: &str, language: Option<&str>) -> Result<()> {
+    let volume = crate::utils::sound::volume_percent() as i32 - 100;
+    let mut cmd = Command::new("spd-say");
+    cmd.args(["-i", &volume.to_string()]);
+    if let Some(lang) = language {
+        cmd.args(["-l", lang]);
+    }
+    cmd.arg("--").arg(text);
+    let status = cmd
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .context("No speech synthesizer found (install espeak-ng or speech-dispatcher)")?;
+    if !status.success() {
+        bail!("spd-say exited with {}", status);
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn synthesize(_text: &str, _language: Option<&str>, _path: &Path) -> Result<()> {
+    bail!("Text-to-speech is not supported on this platform")
+}